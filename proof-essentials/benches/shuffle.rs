@@ -124,9 +124,11 @@ fn criterion_benchmark(c: &mut Criterion) {
             );
             let bench_id =
                 BenchmarkId::new("number_of_ciphers:", format!("({} * {} = {})", m, n, m * n));
-            let mut fs_rng = FS::from_seed(b"Initialised with some input");
             group.bench_function(bench_id, |b| {
-                b.iter(|| ShuffleArgument::verify(&parameters, &statement, &proof, &mut fs_rng))
+                b.iter(|| {
+                    let mut fs_rng = FS::from_seed(b"Initialised with some input");
+                    ShuffleArgument::verify(&parameters, &statement, &proof, &mut fs_rng).unwrap()
+                })
             });
         }
     }
@@ -0,0 +1,63 @@
+//! Instantiations of the encryption and commitment schemes and of the arguments over the supported curves.
+//!
+//! Everything in this crate is generic over `ProjectiveCurve`. The modules below fix the curve and provide
+//! type aliases for the common instances. Apart from starknet, each curve is gated behind a feature:
+//! `bls12_381` (G1 of BLS12-381), `bn254` (G1 of BN254) and `jubjub` (the twisted Edwards curve embedded in
+//! BLS12-381, for use inside SNARK circuits). `all_curves` enables all of them.
+
+/// Define type aliases for the schemes and arguments of this crate over `$curve`.
+macro_rules! instantiate_curve {
+    ($curve:ty) => {
+        use crate::homomorphic_encryption::el_gamal;
+        use crate::vector_commitment::pedersen;
+        use crate::zkp::arguments::shuffle;
+        use crate::zkp::proofs::{chaum_pedersen_dl_equality, schnorr_identification};
+
+        pub type Curve = $curve;
+        pub type Affine = <Curve as ark_ec::ProjectiveCurve>::Affine;
+        pub type Scalar = <Curve as ark_ec::ProjectiveCurve>::ScalarField;
+
+        pub type ElGamal = el_gamal::ElGamal<Curve>;
+        pub type Plaintext = el_gamal::Plaintext<Curve>;
+        pub type Ciphertext = el_gamal::Ciphertext<Curve>;
+        pub type PublicKey = el_gamal::PublicKey<Curve>;
+
+        pub type Pedersen = pedersen::PedersenCommitment<Curve>;
+        pub type CommitKey = pedersen::CommitKey<Curve>;
+        pub type Commitment = pedersen::Commitment<Curve>;
+
+        pub type ShuffleArgument<'a> = shuffle::ShuffleArgument<'a, Scalar, ElGamal, Pedersen>;
+        pub type DLEquality<'a> = chaum_pedersen_dl_equality::DLEquality<'a, Curve>;
+        pub type SchnorrIdentification = schnorr_identification::SchnorrIdentification<Curve>;
+    };
+}
+
+pub mod starknet {
+    //! Starknet curve, a short Weierstrass curve over the field of Starknet.
+    instantiate_curve!(starknet_curve::Projective);
+}
+
+#[cfg(feature = "bls12_381")]
+pub mod bls12_381 {
+    //! G1 of the BLS12-381 pairing-friendly curve.
+    instantiate_curve!(ark_bls12_381::G1Projective);
+
+    pub type Kzg = crate::vector_commitment::kzg::KZGCommitment<ark_bls12_381::Bls12_381>;
+}
+
+#[cfg(feature = "bn254")]
+pub mod bn254 {
+    //! G1 of the BN254 pairing-friendly curve, whose scalar field is the native field of Ethereum SNARKs.
+    instantiate_curve!(ark_bn254::G1Projective);
+
+    pub type Kzg = crate::vector_commitment::kzg::KZGCommitment<ark_bn254::Bn254>;
+}
+
+#[cfg(feature = "jubjub")]
+pub mod jubjub {
+    //! Jubjub, the twisted Edwards curve defined over the scalar field of BLS12-381. Its operations can be
+    //! verified efficiently inside SNARKs over BLS12-381.
+    instantiate_curve!(ark_ed_on_bls12_381::EdwardsProjective);
+}
+
+mod tests;
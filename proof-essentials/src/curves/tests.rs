@@ -0,0 +1,213 @@
+#[cfg(test)]
+mod test {
+    /// Run the same test suite against the instantiation in `crate::curves::$curve`.
+    macro_rules! curve_tests {
+        ($curve:ident) => {
+            mod $curve {
+                use crate::curves::$curve::*;
+                use crate::homomorphic_encryption::{el_gamal, HomomorphicEncryptionScheme};
+                use crate::utils::permutation::Permutation;
+                use crate::utils::rand::sample_vector;
+                use crate::utils::validation::{deserialize_validated, Validate};
+                use crate::vector_commitment::HomomorphicCommitmentScheme;
+                use crate::zkp::arguments::shuffle;
+                use crate::zkp::proofs::chaum_pedersen_dl_equality;
+                use crate::zkp::ArgumentOfKnowledge;
+
+                use ark_ec::{AffineCurve, ProjectiveCurve};
+                use ark_ff::Zero;
+                use ark_marlin::rng::FiatShamirRng;
+                use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+                use ark_std::{rand::thread_rng, UniformRand};
+                use blake2::Blake2s;
+
+                type FS = FiatShamirRng<Blake2s>;
+
+                fn round_trip<T>(value: &T) -> T
+                where
+                    T: CanonicalSerialize + CanonicalDeserialize + Validate,
+                {
+                    let mut bytes = Vec::new();
+                    value.serialize(&mut bytes).unwrap();
+                    assert_eq!(bytes.len(), value.serialized_size());
+                    let compressed = T::deserialize(&bytes[..]).unwrap();
+                    assert_eq!(Ok(()), compressed.validate());
+
+                    let mut bytes = Vec::new();
+                    value.serialize_uncompressed(&mut bytes).unwrap();
+                    assert_eq!(bytes.len(), value.uncompressed_size());
+                    deserialize_validated(&bytes[..]).unwrap()
+                }
+
+                #[test]
+                fn encryption_round_trip() {
+                    let rng = &mut thread_rng();
+
+                    let parameters = ElGamal::setup(rng).unwrap();
+                    let (pk, sk) = ElGamal::keygen(&parameters, rng).unwrap();
+                    let message = Plaintext::rand(rng);
+                    let ciphertext =
+                        ElGamal::encrypt(&parameters, &pk, &message, &Scalar::rand(rng)).unwrap();
+
+                    let received_parameters = round_trip(&parameters);
+                    assert_eq!(parameters.generator, received_parameters.generator);
+                    let received_pk: PublicKey = round_trip(&pk);
+                    assert_eq!(pk, received_pk);
+                    assert_eq!(message, round_trip(&message));
+
+                    let received_ciphertext = round_trip(&ciphertext);
+                    assert_eq!(ciphertext, received_ciphertext);
+                    assert_eq!(
+                        Ok(message),
+                        ElGamal::decrypt(&received_parameters, &sk, &received_ciphertext)
+                    );
+                }
+
+                #[test]
+                fn commitment_round_trip() {
+                    let rng = &mut thread_rng();
+
+                    let commit_key = Pedersen::setup(rng, 5);
+                    let values: Vec<Scalar> = sample_vector(rng, 5);
+                    let r = Scalar::rand(rng);
+                    let commitment = Pedersen::commit(&commit_key, &values, r).unwrap();
+
+                    let received_key: CommitKey = round_trip(&commit_key);
+                    assert_eq!(Ok(commitment), Pedersen::commit(&received_key, &values, r));
+                    assert_eq!(commitment, round_trip(&commitment));
+                }
+
+                #[test]
+                fn shuffle_argument() {
+                    let m = 2;
+                    let n = 4;
+                    let number_of_ciphers = n * m;
+
+                    let rng = &mut thread_rng();
+
+                    let encrypt_parameters = ElGamal::setup(rng).unwrap();
+                    let (pk, _) = ElGamal::keygen(&encrypt_parameters, rng).unwrap();
+                    let commit_key = Pedersen::setup(rng, n);
+                    let generator = el_gamal::Generator::<Curve>::rand(rng);
+
+                    let ciphers: Vec<Ciphertext> = sample_vector(rng, number_of_ciphers);
+                    let masking_factors: Vec<Scalar> = sample_vector(rng, number_of_ciphers);
+                    let permutation = Permutation::new(rng, number_of_ciphers);
+
+                    let shuffled_deck = permutation
+                        .permute_array(&ciphers)
+                        .iter()
+                        .zip(masking_factors.iter())
+                        .map(|(&cipher, masking_factor)| {
+                            let masking_cipher = ElGamal::encrypt(
+                                &encrypt_parameters,
+                                &pk,
+                                &Plaintext::zero(),
+                                masking_factor,
+                            )
+                            .unwrap();
+
+                            cipher + masking_cipher
+                        })
+                        .collect::<Vec<_>>();
+
+                    let parameters =
+                        shuffle::Parameters::new(&encrypt_parameters, &pk, &commit_key, &generator);
+                    let statement = shuffle::Statement::new(&ciphers, &shuffled_deck, m, n);
+                    let witness = shuffle::Witness::new(&permutation, &masking_factors);
+
+                    let mut fs_rng = FS::from_seed(b"Initialised with some input");
+                    let proof =
+                        ShuffleArgument::prove(rng, &parameters, &statement, &witness, &mut fs_rng)
+                            .unwrap();
+
+                    let received_proof = round_trip(&proof);
+                    let mut fs_rng = FS::from_seed(b"Initialised with some input");
+                    assert_eq!(
+                        Ok(()),
+                        ShuffleArgument::verify(
+                            &parameters,
+                            &statement,
+                            &received_proof,
+                            &mut fs_rng
+                        )
+                    );
+
+                    let new_permutation = Permutation::new(rng, number_of_ciphers);
+                    let bad_witness = shuffle::Witness::new(&new_permutation, &masking_factors);
+
+                    let mut fs_rng = FS::from_seed(b"Initialised with some input");
+                    let invalid_proof = ShuffleArgument::prove(
+                        rng,
+                        &parameters,
+                        &statement,
+                        &bad_witness,
+                        &mut fs_rng,
+                    )
+                    .unwrap();
+
+                    let mut fs_rng = FS::from_seed(b"Initialised with some input");
+                    assert_ne!(
+                        Ok(()),
+                        ShuffleArgument::verify(
+                            &parameters,
+                            &statement,
+                            &invalid_proof,
+                            &mut fs_rng
+                        )
+                    );
+                }
+
+                #[test]
+                fn chaum_pedersen_proof() {
+                    let rng = &mut thread_rng();
+
+                    let g = Curve::rand(rng).into_affine();
+                    let h = Curve::rand(rng).into_affine();
+                    let secret = Scalar::rand(rng);
+
+                    let point_a = g.mul(secret).into_affine();
+                    let point_b = h.mul(secret).into_affine();
+
+                    let crs = chaum_pedersen_dl_equality::Parameters::new(&g, &h);
+                    let statement = chaum_pedersen_dl_equality::Statement::new(&point_a, &point_b);
+
+                    let mut fs_rng = FS::from_seed(b"Initialised with some input");
+                    let proof =
+                        DLEquality::prove(rng, &crs, &statement, &secret, &mut fs_rng).unwrap();
+
+                    let received_proof = round_trip(&proof);
+                    assert_eq!(proof, received_proof);
+                    let mut fs_rng = FS::from_seed(b"Initialised with some input");
+                    assert_eq!(
+                        Ok(()),
+                        DLEquality::verify(&crs, &statement, &received_proof, &mut fs_rng)
+                    );
+
+                    let another_secret = Scalar::rand(rng);
+                    let mut fs_rng = FS::from_seed(b"Initialised with some input");
+                    let invalid_proof =
+                        DLEquality::prove(rng, &crs, &statement, &another_secret, &mut fs_rng)
+                            .unwrap();
+
+                    let mut fs_rng = FS::from_seed(b"Initialised with some input");
+                    assert_ne!(
+                        Ok(()),
+                        DLEquality::verify(&crs, &statement, &invalid_proof, &mut fs_rng)
+                    );
+                }
+            }
+        };
+    }
+
+    curve_tests!(starknet);
+
+    #[cfg(feature = "bls12_381")]
+    curve_tests!(bls12_381);
+
+    #[cfg(feature = "bn254")]
+    curve_tests!(bn254);
+
+    #[cfg(feature = "jubjub")]
+    curve_tests!(jubjub);
+}
@@ -1,3 +1,4 @@
+use ark_serialize::SerializationError;
 use thiserror::Error;
 
 /// This is an error that could occur when running a cryptographic primitive
@@ -6,6 +7,12 @@ pub enum CryptoError {
     #[error("Failed to verify {0} proof")]
     ProofVerificationError(String),
 
+    #[error("Failed to verify {0} proof at index {1} of the batch")]
+    BatchProofVerificationError(String, usize),
+
+    #[error("Batch verification error: proofs = {0} - inputs = {1}")]
+    BatchVerificationLengthError(usize, usize),
+
     #[error("Failed to output a {0} commitment: values {1} > bases {2}")]
     CommitmentLengthError(String, usize, usize),
 
@@ -24,12 +31,108 @@ pub enum CryptoError {
     #[error("Diagonals Error: left = {0} - right = {1}")]
     DiagonalLengthError(usize, usize),
 
+    #[error("Rerandomization error: original = {0} - rerandomized = {1}")]
+    RerandomizationLengthError(usize, usize),
+
+    #[error("Encryption error: messages = {0} - randomness = {1}")]
+    EncryptionLengthError(usize, usize),
+
+    #[error("Blinding error: vectors = {0} - blinding factors = {1}")]
+    BlindingLengthError(usize, usize),
+
+    #[error("Wide ciphertext error: column lengths {0} and {1} differ")]
+    WideLengthError(usize, usize),
+
+    #[error("Discrete logarithm is not in the range [0, {0})")]
+    DiscreteLogOutOfRange(u64),
+
+    #[error("Cannot encode {0} bytes into a plaintext: capacity is {1} bytes")]
+    PlaintextCapacityError(usize, usize),
+
+    #[error("Failed to encode data into a plaintext")]
+    EncodingError,
+
+    #[error("Plaintext is not a valid encoding")]
+    DecodingError,
+
+    #[error("Invalid modulus size: {0} bits")]
+    InvalidModulusSize(usize),
+
+    #[error("Invalid prime size: {0} bits")]
+    InvalidPrimeSize(usize),
+
+    #[error("Message is not smaller than the plaintext modulus")]
+    PlaintextOutOfRange,
+
+    #[error("Encryption randomness is not invertible")]
+    InvalidRandomness,
+
+    #[error("Ciphertext is not invertible")]
+    InvalidCiphertext,
+
     #[error("InvalidProductArgumentStatement")]
     InvalidProductArgumentStatement,
 
     #[error("InvalidShuffleStatement")]
     InvalidShuffleStatement,
 
+    #[error("Invalid threshold: {0} out of {1} parties")]
+    InvalidThreshold(usize, usize),
+
+    #[error("Invalid party index {0} for {1} parties")]
+    InvalidPartyIndex(usize, usize),
+
+    #[error("Invalid key generation broadcast from party {0}")]
+    InvalidKeyGenerationBroadcast(usize),
+
+    #[error("Invalid secret share from party {0} to party {1}")]
+    InvalidSecretShare(usize, usize),
+
+    #[error("Expected {0} secret shares, got {1}")]
+    ShareCountError(usize, usize),
+
+    #[error("Duplicate share from party {0}")]
+    DuplicatePartyIndex(usize),
+
+    #[error("No key generation broadcast")]
+    NoKeyGenerationBroadcast,
+
+    #[error("Invalid decryption share from party {0}")]
+    InvalidDecryptionShare(usize),
+
+    #[error("Expected at least {0} decryption shares, got {1}")]
+    NotEnoughDecryptionShares(usize, usize),
+
+    #[error("Decryption proof does not match the ciphertext")]
+    InvalidDecryption,
+
+    #[error("Inner product weights must be non-zero")]
+    ZeroInnerProductWeight,
+
+    #[error("Invalid range: {0} bits")]
+    InvalidRangeSize(usize),
+
+    #[error("Range proof error: commitments = {0} - openings = {1}")]
+    RangeProofLengthError(usize, usize),
+
+    #[error("Linear relation error: expected {0} - got {1}")]
+    LinearRelationLengthError(usize, usize),
+
+    #[error("OR composition error: witness index = {0} - statements = {1}")]
+    OrCompositionIndexError(usize, usize),
+
+    #[error("Point is not on the curve")]
+    PointNotOnCurve,
+
+    #[error("Point is not in the prime order subgroup")]
+    PointNotInSubgroup,
+
+    #[error("Unexpected identity point")]
+    IdentityPoint,
+
+    #[error("Deserialization error: {0}")]
+    DeserializationError(String),
+
     #[error("IoError: {0}")]
     IoError(String),
 }
@@ -39,3 +142,9 @@ impl From<std::io::Error> for CryptoError {
         Self::IoError(err.to_string())
     }
 }
+
+impl From<SerializationError> for CryptoError {
+    fn from(err: SerializationError) -> Self {
+        Self::DeserializationError(err.to_string())
+    }
+}
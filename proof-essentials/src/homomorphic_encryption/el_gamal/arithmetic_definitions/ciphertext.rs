@@ -1,5 +1,7 @@
-use super::super::Ciphertext;
-use ark_ec::{AffineCurve, ProjectiveCurve};
+use super::super::{Ciphertext, ProjectiveCiphertext};
+use crate::homomorphic_encryption::Accumulate;
+use ark_ec::{msm::VariableBaseMSM, AffineCurve, ProjectiveCurve};
+use ark_ff::PrimeField;
 use ark_std::{UniformRand, Zero};
 use rand::Rng;
 use std::ops::Mul;
@@ -44,6 +46,71 @@ impl<C: ProjectiveCurve> UniformRand for Ciphertext<C> {
     }
 }
 
+impl<C: ProjectiveCurve> std::ops::Add for ProjectiveCiphertext<C> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self(self.0 + rhs.0, self.1 + rhs.1)
+    }
+}
+
+impl<C: ProjectiveCurve> Mul<C::ScalarField> for ProjectiveCiphertext<C> {
+    type Output = Self;
+
+    fn mul(mut self, x: C::ScalarField) -> Self::Output {
+        self.0 *= x;
+        self.1 *= x;
+        self
+    }
+}
+
+impl<C: ProjectiveCurve> std::iter::Sum for ProjectiveCiphertext<C> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |a, b| a + b)
+    }
+}
+
+impl<C: ProjectiveCurve> Zero for ProjectiveCiphertext<C> {
+    fn zero() -> Self {
+        Self(C::zero(), C::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0.is_zero() && self.1.is_zero()
+    }
+}
+
+impl<C: ProjectiveCurve> Accumulate<C::ScalarField> for Ciphertext<C> {
+    type Accumulator = ProjectiveCiphertext<C>;
+
+    fn accumulator(&self) -> Self::Accumulator {
+        ProjectiveCiphertext(self.0.into_projective(), self.1.into_projective())
+    }
+
+    fn linear_combination(values: &[Self], scalars: &[C::ScalarField]) -> Self::Accumulator {
+        let scalars = scalars.iter().map(|s| s.into_repr()).collect::<Vec<_>>();
+        let c1 = values.iter().map(|c| c.0).collect::<Vec<_>>();
+        let c2 = values.iter().map(|c| c.1).collect::<Vec<_>>();
+
+        ProjectiveCiphertext(
+            VariableBaseMSM::multi_scalar_mul(&c1, &scalars),
+            VariableBaseMSM::multi_scalar_mul(&c2, &scalars),
+        )
+    }
+
+    fn normalize_batch(accumulators: &[Self::Accumulator]) -> Vec<Self> {
+        let points = accumulators
+            .iter()
+            .flat_map(|c| [c.0, c.1])
+            .collect::<Vec<_>>();
+
+        C::batch_normalization_into_affine(&points)
+            .chunks(2)
+            .map(|c| Self(c[0], c[1]))
+            .collect()
+    }
+}
+
 #[cfg(test)]
 mod test {
 
@@ -51,7 +118,7 @@ mod test {
     use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
     use ark_std::UniformRand;
     use rand::thread_rng;
-    use starknet_curve::Projective;
+    use starknet_curve::{Fr, Projective};
 
     #[test]
     fn serialize_unserialize_test() {
@@ -67,4 +134,19 @@ mod test {
         let deserialized = Ciphertext::<Projective>::deserialize(&serialized[..]).unwrap();
         assert_eq!(cipher, deserialized);
     }
+
+    #[test]
+    fn accumulate_test() {
+        let mut rng = thread_rng();
+        let a = Ciphertext::<Projective>::rand(&mut rng);
+        let b = Ciphertext::<Projective>::rand(&mut rng);
+        let x = Fr::rand(&mut rng);
+
+        let acc = a.accumulator() * x + b.accumulator();
+        assert_eq!(Ciphertext::normalize(&acc), a * x + b);
+        assert_eq!(
+            Ciphertext::normalize_batch(&[acc, ProjectiveCiphertext::zero(), a.accumulator()]),
+            vec![a * x + b, Ciphertext::zero(), a]
+        );
+    }
 }
@@ -0,0 +1,127 @@
+//! Reversible encoding of short byte strings and integers into ElGamal plaintexts, for Short Weierstrass curves.
+//!
+//! Data is embedded in the x coordinate of a point using Koblitz's try-and-increment method. The little-endian
+//! bytes of the x coordinate are laid out as:
+//!
+//! | counter (1 byte) | data, zero padded (`capacity` bytes) | length of data (1 byte) |
+//!
+//! The counter is incremented until x is the abscissa of a point in the prime order subgroup. Only as many
+//! bytes are used as keep x below the field modulus, so `capacity` is two bytes less than the largest number of
+//! whole bytes which fit in `modulus_bits - 1` bits (29 bytes for `starknet_curve`).
+//!
+//! Note that the encoding does not survive homomorphic operations: the sum of two encoded plaintexts is not the
+//! encoding of anything in general.
+
+use super::Plaintext;
+use crate::error::CryptoError;
+
+use ark_ec::short_weierstrass_jacobian::{GroupAffine, GroupProjective};
+use ark_ec::SWModelParameters;
+use ark_ff::{BigInteger, PrimeField};
+
+/// Number of candidate x coordinates tried before giving up. Each attempt succeeds with probability about
+/// 1 / (2 * cofactor), so failure is negligible for curves with a small cofactor.
+const MAX_ATTEMPTS: usize = 256;
+
+/// Number of bytes of the x coordinate used by the encoding: counter, data and length.
+fn encoded_size<P: SWModelParameters>() -> usize
+where
+    P::BaseField: PrimeField,
+{
+    (P::BaseField::size_in_bits() - 1) / 8
+}
+
+/// Maximum number of bytes which can be encoded into a single plaintext.
+pub fn capacity<P: SWModelParameters>() -> usize
+where
+    P::BaseField: PrimeField,
+{
+    (encoded_size::<P>() - 2).min(u8::MAX as usize)
+}
+
+/// Encode up to [`capacity`] bytes into a plaintext.
+pub fn encode<P: SWModelParameters>(
+    data: &[u8],
+) -> Result<Plaintext<GroupProjective<P>>, CryptoError>
+where
+    P::BaseField: PrimeField,
+{
+    let capacity = capacity::<P>();
+    if data.len() > capacity {
+        return Err(CryptoError::PlaintextCapacityError(data.len(), capacity));
+    }
+
+    let mut buffer = vec![0u8; encoded_size::<P>()];
+    buffer[1..1 + data.len()].copy_from_slice(data);
+    buffer[capacity + 1] = data.len() as u8;
+
+    for counter in 0..MAX_ATTEMPTS {
+        buffer[0] = counter as u8;
+        let x = P::BaseField::from_le_bytes_mod_order(&buffer);
+
+        if let Some(point) = GroupAffine::<P>::get_point_from_x(x, false) {
+            if point.is_in_correct_subgroup_assuming_on_curve() {
+                return Ok(Plaintext(point));
+            }
+        }
+    }
+
+    Err(CryptoError::EncodingError)
+}
+
+/// Recover the bytes encoded into a plaintext by [`encode`].
+pub fn decode<P: SWModelParameters>(
+    plaintext: &Plaintext<GroupProjective<P>>,
+) -> Result<Vec<u8>, CryptoError>
+where
+    P::BaseField: PrimeField,
+{
+    if plaintext.0.infinity {
+        return Err(CryptoError::DecodingError);
+    }
+
+    let capacity = capacity::<P>();
+    let size = encoded_size::<P>();
+    let bytes = plaintext.0.x.into_repr().to_bytes_le();
+
+    // Anything above the encoded bytes must be zero
+    if bytes[size..].iter().any(|&b| b != 0) {
+        return Err(CryptoError::DecodingError);
+    }
+
+    let length = bytes[capacity + 1] as usize;
+    if length > capacity {
+        return Err(CryptoError::DecodingError);
+    }
+
+    let (data, padding) = bytes[1..capacity + 1].split_at(length);
+    if padding.iter().any(|&b| b != 0) {
+        return Err(CryptoError::DecodingError);
+    }
+
+    Ok(data.to_vec())
+}
+
+/// Encode an integer, e.g. a card identifier, into a plaintext.
+pub fn encode_u64<P: SWModelParameters>(
+    value: u64,
+) -> Result<Plaintext<GroupProjective<P>>, CryptoError>
+where
+    P::BaseField: PrimeField,
+{
+    encode::<P>(&value.to_le_bytes())
+}
+
+/// Recover an integer encoded by [`encode_u64`].
+pub fn decode_u64<P: SWModelParameters>(
+    plaintext: &Plaintext<GroupProjective<P>>,
+) -> Result<u64, CryptoError>
+where
+    P::BaseField: PrimeField,
+{
+    let bytes: [u8; 8] = decode::<P>(plaintext)?
+        .try_into()
+        .map_err(|_| CryptoError::DecodingError)?;
+
+    Ok(u64::from_le_bytes(bytes))
+}
@@ -1,5 +1,11 @@
 use crate::error::CryptoError;
-use crate::homomorphic_encryption::HomomorphicEncryptionScheme;
+use crate::homomorphic_encryption::{HomomorphicEncryptionScheme, RingHomomorphicEncryptionScheme};
+use crate::utils::hash_to_curve::{derive_generators, HashToCurve};
+use crate::utils::validation::{
+    validate_non_identity_point, validate_point, Validate, ValidatePoint,
+};
+use crate::zkp::transcript::Transcript;
+use crate::zkp::{proofs::chaum_pedersen_dl_equality, ArgumentOfKnowledge};
 
 use ark_ec::{AffineCurve, ProjectiveCurve};
 use ark_ff::{fields::PrimeField, ToBytes, UniformRand};
@@ -12,7 +18,11 @@ use ark_std::{
 use std::hash::Hash;
 
 pub mod arithmetic_definitions;
+pub mod encoding;
+pub mod prepared;
+pub mod tally;
 mod tests;
+pub mod threshold;
 
 pub struct ElGamal<C: ProjectiveCurve> {
     _group: PhantomData<C>,
@@ -35,6 +45,16 @@ pub type SecretKey<C> = <C as ProjectiveCurve>::ScalarField;
 #[derive(Clone, Copy, PartialEq, Debug, CanonicalSerialize, CanonicalDeserialize)]
 pub struct Ciphertext<C: ProjectiveCurve>(pub C::Affine, pub C::Affine);
 
+/// Chaum-Pedersen proof that a plaintext is the decryption of a ciphertext under the secret key of a public key.
+pub type DecryptionProof<C> = chaum_pedersen_dl_equality::proof::Proof<C>;
+
+pub const VERIFIABLE_DECRYPTION_PROTOCOL_NAME: &[u8] = b"verifiable_decryption";
+
+/// Ciphertext in projective coordinates, used to accumulate linear combinations of ciphertexts without paying for
+/// an inversion after every operation.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct ProjectiveCiphertext<C: ProjectiveCurve>(pub C, pub C);
+
 impl<C: ProjectiveCurve> ToBytes for Ciphertext<C> {
     fn write<W: Write>(&self, mut w: W) -> ark_std::io::Result<()> {
         self.0.write(&mut w)?;
@@ -44,6 +64,130 @@ impl<C: ProjectiveCurve> ToBytes for Ciphertext<C> {
     }
 }
 
+impl<C: ProjectiveCurve> Validate for Parameters<C>
+where
+    C::Affine: ValidatePoint,
+{
+    fn validate(&self) -> Result<(), CryptoError> {
+        validate_non_identity_point(&self.generator)
+    }
+}
+
+impl<C: ProjectiveCurve> Validate for Plaintext<C>
+where
+    C::Affine: ValidatePoint,
+{
+    fn validate(&self) -> Result<(), CryptoError> {
+        validate_point(&self.0)
+    }
+}
+
+impl<C: ProjectiveCurve> Validate for Ciphertext<C>
+where
+    C::Affine: ValidatePoint,
+{
+    fn validate(&self) -> Result<(), CryptoError> {
+        validate_point(&self.0)?;
+        validate_point(&self.1)
+    }
+}
+
+impl<C: ProjectiveCurve> ElGamal<C>
+where
+    C::Affine: ValidatePoint,
+{
+    /// Check a public key received from another party. The identity is rejected since it would make every
+    /// ciphertext decryptable without the secret key.
+    pub fn validate_public_key(pk: &PublicKey<C>) -> Result<(), CryptoError> {
+        validate_non_identity_point(pk)
+    }
+}
+
+impl<C: ProjectiveCurve> ElGamal<C> {
+    /// Re-randomize a ciphertext by adding an encryption of zero under the same public key, i.e.
+    /// (c1 + r*generator, c2 + r*pk). The output decrypts to the same plaintext but is unlinkable to the input.
+    pub fn rerandomize(
+        pp: &Parameters<C>,
+        pk: &PublicKey<C>,
+        ciphertext: &Ciphertext<C>,
+        r: &C::ScalarField,
+    ) -> Result<Ciphertext<C>, CryptoError> {
+        let c1 = ciphertext.0 + pp.generator.mul(r.into_repr()).into_affine();
+        let c2 = ciphertext.1 + pk.mul(r.into_repr()).into_affine();
+
+        Ok(Ciphertext(c1, c2))
+    }
+
+    /// Decrypt a ciphertext (c1, c2) and prove that the plaintext m is correct, i.e. that the discrete logarithm
+    /// of `c2 - m` in base `c1` equals the discrete logarithm of the public key in base `generator`.
+    pub fn decrypt_with_proof<R: Rng, T: Transcript>(
+        rng: &mut R,
+        pp: &Parameters<C>,
+        sk: &SecretKey<C>,
+        ciphertext: &Ciphertext<C>,
+        transcript: &mut T,
+    ) -> Result<(Plaintext<C>, DecryptionProof<C>), CryptoError> {
+        let plaintext =
+            <Self as HomomorphicEncryptionScheme<C::ScalarField>>::decrypt(pp, sk, ciphertext)?;
+
+        let pk = pp.generator.mul(sk.into_repr()).into_affine();
+        let mask = ciphertext.0.mul(sk.into_repr()).into_affine();
+
+        transcript.domain_separator(VERIFIABLE_DECRYPTION_PROTOCOL_NAME);
+        transcript.append(b"ciphertext", ciphertext)?;
+
+        let parameters = chaum_pedersen_dl_equality::Parameters::new(&pp.generator, &ciphertext.0);
+        let statement = chaum_pedersen_dl_equality::Statement::new(&pk, &mask);
+        let proof = chaum_pedersen_dl_equality::DLEquality::prove(
+            rng,
+            &parameters,
+            &statement,
+            sk,
+            transcript,
+        )?;
+
+        Ok((plaintext, proof))
+    }
+
+    /// Check that `plaintext` is the decryption of `ciphertext` under the secret key of `pk`.
+    pub fn verify_decryption<T: Transcript>(
+        pp: &Parameters<C>,
+        pk: &PublicKey<C>,
+        ciphertext: &Ciphertext<C>,
+        plaintext: &Plaintext<C>,
+        proof: &DecryptionProof<C>,
+        transcript: &mut T,
+    ) -> Result<(), CryptoError> {
+        let mask = (ciphertext.1.into_projective() - plaintext.0.into_projective()).into_affine();
+
+        transcript.domain_separator(VERIFIABLE_DECRYPTION_PROTOCOL_NAME);
+        transcript.append(b"ciphertext", ciphertext)?;
+
+        let parameters = chaum_pedersen_dl_equality::Parameters::new(&pp.generator, &ciphertext.0);
+        let statement = chaum_pedersen_dl_equality::Statement::new(pk, &mask);
+
+        chaum_pedersen_dl_equality::DLEquality::verify(&parameters, &statement, proof, transcript)
+            .map_err(|_| CryptoError::InvalidDecryption)
+    }
+}
+
+impl<C: HashToCurve> ElGamal<C> {
+    pub const TRANSPARENT_SETUP_DST: &'static [u8] = b"PROOF-ESSENTIALS-V01-ELGAMAL-PARAMETERS";
+    pub const TRANSPARENT_GENERATOR_DST: &'static [u8] = b"PROOF-ESSENTIALS-V01-ELGAMAL-GENERATOR";
+
+    /// Derive the scheme's parameters from `label` by hashing to the curve instead of sampling them.
+    pub fn transparent_setup(label: &[u8]) -> Parameters<C> {
+        let generator = derive_generators::<C>(Self::TRANSPARENT_SETUP_DST, label, 1)[0];
+
+        Parameters { generator }
+    }
+
+    /// Derive a generator from `label` by hashing to the curve instead of sampling it.
+    pub fn transparent_generator(label: &[u8]) -> Generator<C> {
+        Plaintext(derive_generators::<C>(Self::TRANSPARENT_GENERATOR_DST, label, 1)[0])
+    }
+}
+
 impl<C: ProjectiveCurve> HomomorphicEncryptionScheme<C::ScalarField> for ElGamal<C>
 where
     C: ProjectiveCurve,
@@ -54,6 +198,7 @@ where
     type SecretKey = SecretKey<C>;
     type Plaintext = Plaintext<C>;
     type Ciphertext = Ciphertext<C>;
+    type Randomness = C::ScalarField;
 
     fn setup<R: Rng>(rng: &mut R) -> Result<Self::Parameters, CryptoError> {
         // get a random generator
@@ -115,3 +260,57 @@ where
         Ok(Plaintext(m))
     }
 }
+
+impl<C: ProjectiveCurve> RingHomomorphicEncryptionScheme for ElGamal<C> {
+    type Parameters = Parameters<C>;
+    type PublicKey = PublicKey<C>;
+    type SecretKey = SecretKey<C>;
+    type Scalar = C::ScalarField;
+    type Plaintext = Plaintext<C>;
+    type Randomness = C::ScalarField;
+    type Ciphertext = Ciphertext<C>;
+
+    fn setup<R: Rng>(rng: &mut R) -> Result<Self::Parameters, CryptoError> {
+        <Self as HomomorphicEncryptionScheme<C::ScalarField>>::setup(rng)
+    }
+
+    fn keygen<R: Rng>(
+        pp: &Self::Parameters,
+        rng: &mut R,
+    ) -> Result<(Self::PublicKey, Self::SecretKey), CryptoError> {
+        <Self as HomomorphicEncryptionScheme<C::ScalarField>>::keygen(pp, rng)
+    }
+
+    fn sample_randomness<R: Rng>(_pk: &Self::PublicKey, rng: &mut R) -> Self::Randomness {
+        C::ScalarField::rand(rng)
+    }
+
+    fn encrypt(
+        pp: &Self::Parameters,
+        pk: &Self::PublicKey,
+        message: &Self::Plaintext,
+        r: &Self::Randomness,
+    ) -> Result<Self::Ciphertext, CryptoError> {
+        <Self as HomomorphicEncryptionScheme<C::ScalarField>>::encrypt(pp, pk, message, r)
+    }
+
+    fn decrypt(
+        pp: &Self::Parameters,
+        sk: &Self::SecretKey,
+        ciphertext: &Self::Ciphertext,
+    ) -> Result<Self::Plaintext, CryptoError> {
+        <Self as HomomorphicEncryptionScheme<C::ScalarField>>::decrypt(pp, sk, ciphertext)
+    }
+
+    fn add(_pk: &Self::PublicKey, a: &Self::Ciphertext, b: &Self::Ciphertext) -> Self::Ciphertext {
+        *a + *b
+    }
+
+    fn mul(
+        _pk: &Self::PublicKey,
+        ciphertext: &Self::Ciphertext,
+        scalar: &Self::Scalar,
+    ) -> Self::Ciphertext {
+        *ciphertext * *scalar
+    }
+}
@@ -0,0 +1,145 @@
+use super::{Ciphertext, Parameters, Plaintext, PublicKey};
+use crate::error::CryptoError;
+use crate::utils::fixed_base::FixedBaseTable;
+
+use ark_ec::ProjectiveCurve;
+
+/// ElGamal encryption context for a fixed generator and public key. Both bases are the same for a whole game, so
+/// their multiples are precomputed once and every encryption or re-randomization only costs table lookups and
+/// additions instead of two variable-base scalar multiplications.
+#[derive(Clone)]
+pub struct PreparedEncryption<C: ProjectiveCurve> {
+    parameters: Parameters<C>,
+    public_key: PublicKey<C>,
+    generator_table: FixedBaseTable<C>,
+    public_key_table: FixedBaseTable<C>,
+}
+
+impl<C: ProjectiveCurve> PreparedEncryption<C> {
+    pub fn new(pp: &Parameters<C>, pk: &PublicKey<C>) -> Self {
+        Self {
+            parameters: *pp,
+            public_key: *pk,
+            generator_table: FixedBaseTable::new(&pp.generator),
+            public_key_table: FixedBaseTable::new(pk),
+        }
+    }
+
+    pub fn with_window_size(pp: &Parameters<C>, pk: &PublicKey<C>, window: usize) -> Self {
+        Self {
+            parameters: *pp,
+            public_key: *pk,
+            generator_table: FixedBaseTable::with_window_size(&pp.generator, window),
+            public_key_table: FixedBaseTable::with_window_size(pk, window),
+        }
+    }
+
+    pub fn parameters(&self) -> &Parameters<C> {
+        &self.parameters
+    }
+
+    pub fn public_key(&self) -> &PublicKey<C> {
+        &self.public_key
+    }
+
+    /// Encrypt a message, with the same output as `ElGamal::encrypt`.
+    pub fn encrypt(&self, message: &Plaintext<C>, r: &C::ScalarField) -> Ciphertext<C> {
+        let mut c2 = self.public_key_table.mul(r);
+        c2.add_assign_mixed(&message.0);
+
+        let c = C::batch_normalization_into_affine(&[self.generator_table.mul(r), c2]);
+
+        Ciphertext(c[0], c[1])
+    }
+
+    /// Encrypt each message with the matching randomness, e.g. to mask a whole deck.
+    pub fn batch_encrypt(
+        &self,
+        messages: &[Plaintext<C>],
+        randomness: &[C::ScalarField],
+    ) -> Result<Vec<Ciphertext<C>>, CryptoError> {
+        if messages.len() != randomness.len() {
+            return Err(CryptoError::EncryptionLengthError(
+                messages.len(),
+                randomness.len(),
+            ));
+        }
+
+        let masks = messages
+            .iter()
+            .map(|m| m.0)
+            .zip(self.public_key_table.batch_mul(randomness))
+            .map(|(m, mut mask)| {
+                mask.add_assign_mixed(&m);
+                mask
+            });
+
+        Ok(Self::ciphertexts(
+            self.generator_table.batch_mul(randomness),
+            masks,
+        ))
+    }
+
+    /// Re-randomize a ciphertext, with the same output as `ElGamal::rerandomize`.
+    pub fn rerandomize(&self, ciphertext: &Ciphertext<C>, r: &C::ScalarField) -> Ciphertext<C> {
+        let mut c1 = self.generator_table.mul(r);
+        c1.add_assign_mixed(&ciphertext.0);
+        let mut c2 = self.public_key_table.mul(r);
+        c2.add_assign_mixed(&ciphertext.1);
+
+        let c = C::batch_normalization_into_affine(&[c1, c2]);
+
+        Ciphertext(c[0], c[1])
+    }
+
+    /// Re-randomize each ciphertext with the matching randomness, e.g. to re-encrypt a whole deck after a
+    /// shuffle.
+    pub fn batch_rerandomize(
+        &self,
+        ciphertexts: &[Ciphertext<C>],
+        randomness: &[C::ScalarField],
+    ) -> Result<Vec<Ciphertext<C>>, CryptoError> {
+        if ciphertexts.len() != randomness.len() {
+            return Err(CryptoError::RerandomizationLengthError(
+                ciphertexts.len(),
+                randomness.len(),
+            ));
+        }
+
+        let c1 = ciphertexts
+            .iter()
+            .zip(self.generator_table.batch_mul(randomness))
+            .map(|(c, mut r_g)| {
+                r_g.add_assign_mixed(&c.0);
+                r_g
+            });
+        let c2 = ciphertexts
+            .iter()
+            .zip(self.public_key_table.batch_mul(randomness))
+            .map(|(c, mut r_pk)| {
+                r_pk.add_assign_mixed(&c.1);
+                r_pk
+            });
+
+        Ok(Self::ciphertexts(c1, c2))
+    }
+
+    /// Convert the components of the ciphertexts to affine coordinates with a single batch normalization.
+    fn ciphertexts<I, J>(c1: I, c2: J) -> Vec<Ciphertext<C>>
+    where
+        I: IntoIterator<Item = C>,
+        J: IntoIterator<Item = C>,
+    {
+        let mut points = c1.into_iter().collect::<Vec<_>>();
+        let len = points.len();
+        points.extend(c2);
+
+        let points = C::batch_normalization_into_affine(&points);
+
+        points[..len]
+            .iter()
+            .zip(&points[len..])
+            .map(|(&c1, &c2)| Ciphertext(c1, c2))
+            .collect()
+    }
+}
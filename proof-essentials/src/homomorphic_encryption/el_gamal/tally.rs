@@ -0,0 +1,69 @@
+//! Aggregate-then-reveal: the key holder decrypts the sum of a list of ciphertexts, e.g. encrypted votes, and
+//! proves that the decryption is correct. Anyone holding the public ciphertext list can recompute the aggregate
+//! and check the revealed result, while the individual ciphertexts stay hidden.
+
+use super::{Ciphertext, DecryptionProof, ElGamal, Parameters, Plaintext, PublicKey, SecretKey};
+use crate::error::CryptoError;
+use crate::utils::validation::{validate_point, Validate, ValidatePoint};
+use crate::zkp::transcript::Transcript;
+
+use ark_ec::ProjectiveCurve;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::{
+    io::{Read, Write},
+    rand::Rng,
+};
+
+/// Decryption of the sum of a list of ciphertexts, along with a proof of correct decryption.
+#[derive(Copy, Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Tally<C: ProjectiveCurve> {
+    pub plaintext: Plaintext<C>,
+    pub proof: DecryptionProof<C>,
+}
+
+impl<C: ProjectiveCurve> Validate for Tally<C>
+where
+    C::Affine: ValidatePoint,
+{
+    fn validate(&self) -> Result<(), CryptoError> {
+        validate_point(&self.plaintext.0)?;
+        self.proof.validate()
+    }
+}
+
+/// Homomorphically add the ciphertexts. The result encrypts the sum of their plaintexts.
+pub fn aggregate<C: ProjectiveCurve>(ciphertexts: &[Ciphertext<C>]) -> Ciphertext<C> {
+    ciphertexts.iter().copied().sum()
+}
+
+/// Decrypt the aggregate of `ciphertexts` and prove that the result is correct.
+pub fn tally<C: ProjectiveCurve, R: Rng, T: Transcript>(
+    rng: &mut R,
+    pp: &Parameters<C>,
+    sk: &SecretKey<C>,
+    ciphertexts: &[Ciphertext<C>],
+    transcript: &mut T,
+) -> Result<Tally<C>, CryptoError> {
+    let (plaintext, proof) =
+        ElGamal::decrypt_with_proof(rng, pp, sk, &aggregate(ciphertexts), transcript)?;
+
+    Ok(Tally { plaintext, proof })
+}
+
+/// Check a tally against the public list of ciphertexts it claims to decrypt.
+pub fn verify_tally<C: ProjectiveCurve, T: Transcript>(
+    pp: &Parameters<C>,
+    pk: &PublicKey<C>,
+    ciphertexts: &[Ciphertext<C>],
+    tally: &Tally<C>,
+    transcript: &mut T,
+) -> Result<(), CryptoError> {
+    ElGamal::verify_decryption(
+        pp,
+        pk,
+        &aggregate(ciphertexts),
+        &tally.plaintext,
+        &tally.proof,
+        transcript,
+    )
+}
@@ -1,10 +1,21 @@
 #[cfg(test)]
 mod test {
     use super::super::super::{el_gamal, HomomorphicEncryptionScheme};
+    use crate::error::CryptoError;
+    use crate::homomorphic_encryption::el_gamal::encoding::{
+        capacity, decode, decode_u64, encode, encode_u64,
+    };
     use crate::utils::rand::sample_vector;
+    use crate::utils::validation::{deserialize_validated, Validate};
 
+    use ark_ec::{AffineCurve, ProjectiveCurve};
+    use ark_ff::{One, Zero};
+    use ark_marlin::rng::FiatShamirRng;
+    use ark_serialize::CanonicalSerialize;
     use ark_std::rand::thread_rng;
-    use starknet_curve;
+    use blake2::Blake2s;
+    use rand::{Rng, RngCore};
+    use starknet_curve::{self, StarkwareParameters};
     use std::ops::Mul;
 
     // Define type aliases for succinctness
@@ -12,6 +23,7 @@ mod test {
     type Scalar = starknet_curve::Fr;
     type ElGamal = el_gamal::ElGamal<Curve>;
     type Plaintext = el_gamal::Plaintext<Curve>;
+    type FS = FiatShamirRng<Blake2s>;
     use ark_std::UniformRand;
 
     #[test]
@@ -95,4 +107,326 @@ mod test {
 
         assert_eq!(m3, decrypted)
     }
+
+    #[test]
+    fn transparent_setup() {
+        let rng = &mut thread_rng();
+
+        let parameters = ElGamal::transparent_setup(b"test");
+        assert_eq!(
+            parameters.generator,
+            ElGamal::transparent_setup(b"test").generator
+        );
+        assert_ne!(
+            parameters.generator,
+            ElGamal::transparent_setup(b"other test").generator
+        );
+        assert_ne!(
+            parameters.generator,
+            ElGamal::transparent_generator(b"test").0
+        );
+
+        let (pk, sk) = ElGamal::keygen(&parameters, rng).unwrap();
+        let m = Plaintext::rand(rng);
+        let c = ElGamal::encrypt(&parameters, &pk, &m, &Scalar::rand(rng)).unwrap();
+
+        assert_eq!(m, ElGamal::decrypt(&parameters, &sk, &c).unwrap());
+    }
+
+    #[test]
+    fn validate_untrusted_values() {
+        let rng = &mut thread_rng();
+
+        let parameters = ElGamal::setup(rng).unwrap();
+        let (pk, _) = ElGamal::keygen(&parameters, rng).unwrap();
+        let c =
+            ElGamal::encrypt(&parameters, &pk, &Plaintext::rand(rng), &Scalar::rand(rng)).unwrap();
+
+        assert_eq!(Ok(()), parameters.validate());
+        assert_eq!(Ok(()), c.validate());
+        assert_eq!(Ok(()), ElGamal::validate_public_key(&pk));
+
+        // The identity is a valid ciphertext component but neither a valid key nor generator
+        let identity = <Curve as ProjectiveCurve>::Affine::zero();
+        assert_eq!(
+            Ok(()),
+            el_gamal::Ciphertext::<Curve>(identity, c.1).validate()
+        );
+        assert_eq!(
+            ElGamal::validate_public_key(&identity),
+            Err(CryptoError::IdentityPoint)
+        );
+        assert_eq!(
+            el_gamal::Parameters::<Curve> {
+                generator: identity
+            }
+            .validate(),
+            Err(CryptoError::IdentityPoint)
+        );
+
+        let mut bytes = Vec::new();
+        let off_curve =
+            starknet_curve::Affine::new(c.0.x, c.0.y + starknet_curve::Fq::one(), false);
+        el_gamal::Ciphertext::<Curve>(c.0, off_curve)
+            .serialize_unchecked(&mut bytes)
+            .unwrap();
+        assert_eq!(
+            deserialize_validated::<el_gamal::Ciphertext<Curve>, _>(&bytes[..]),
+            Err(CryptoError::PointNotOnCurve)
+        );
+    }
+
+    #[test]
+    fn prepared_encryption() {
+        let rng = &mut thread_rng();
+        let n = 20;
+
+        let parameters = ElGamal::setup(rng).unwrap();
+        let (pk, sk) = ElGamal::keygen(&parameters, rng).unwrap();
+        let prepared = el_gamal::prepared::PreparedEncryption::new(&parameters, &pk);
+
+        let messages: Vec<Plaintext> = sample_vector(rng, n);
+        let randomness: Vec<Scalar> = sample_vector(rng, n);
+
+        let expected = messages
+            .iter()
+            .zip(randomness.iter())
+            .map(|(m, r)| ElGamal::encrypt(&parameters, &pk, m, r).unwrap())
+            .collect::<Vec<_>>();
+
+        let deck = prepared.batch_encrypt(&messages, &randomness).unwrap();
+        assert_eq!(deck, expected);
+        assert_eq!(prepared.encrypt(&messages[0], &randomness[0]), expected[0]);
+        assert_eq!(
+            messages[1],
+            ElGamal::decrypt(&parameters, &sk, &deck[1]).unwrap()
+        );
+
+        let randomness: Vec<Scalar> = sample_vector(rng, n);
+        let expected = deck
+            .iter()
+            .zip(randomness.iter())
+            .map(|(c, r)| ElGamal::rerandomize(&parameters, &pk, c, r).unwrap())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            prepared.batch_rerandomize(&deck, &randomness).unwrap(),
+            expected
+        );
+        assert_eq!(prepared.rerandomize(&deck[0], &randomness[0]), expected[0]);
+
+        assert_eq!(
+            prepared.batch_encrypt(&messages, &randomness[1..]),
+            Err(CryptoError::EncryptionLengthError(n, n - 1))
+        );
+        assert_eq!(
+            prepared.batch_rerandomize(&deck[1..], &randomness),
+            Err(CryptoError::RerandomizationLengthError(n - 1, n))
+        );
+    }
+
+    #[test]
+    fn verifiable_decryption() {
+        let rng = &mut thread_rng();
+
+        let parameters = ElGamal::setup(rng).unwrap();
+        let (pk, sk) = ElGamal::keygen(&parameters, rng).unwrap();
+
+        let message = Plaintext::rand(rng);
+        let ciphertext = ElGamal::encrypt(&parameters, &pk, &message, &Scalar::rand(rng)).unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let (plaintext, proof) =
+            ElGamal::decrypt_with_proof(rng, &parameters, &sk, &ciphertext, &mut fs_rng).unwrap();
+        assert_eq!(plaintext, message);
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            Ok(()),
+            ElGamal::verify_decryption(
+                &parameters,
+                &pk,
+                &ciphertext,
+                &plaintext,
+                &proof,
+                &mut fs_rng
+            )
+        );
+
+        // A wrong plaintext is rejected
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            Err(CryptoError::InvalidDecryption),
+            ElGamal::verify_decryption(
+                &parameters,
+                &pk,
+                &ciphertext,
+                &Plaintext::rand(rng),
+                &proof,
+                &mut fs_rng
+            )
+        );
+
+        // Decrypting with another key does not match the public key
+        let (_, other_sk) = ElGamal::keygen(&parameters, rng).unwrap();
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let (plaintext, proof) =
+            ElGamal::decrypt_with_proof(rng, &parameters, &other_sk, &ciphertext, &mut fs_rng)
+                .unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            Err(CryptoError::InvalidDecryption),
+            ElGamal::verify_decryption(
+                &parameters,
+                &pk,
+                &ciphertext,
+                &plaintext,
+                &proof,
+                &mut fs_rng
+            )
+        );
+    }
+
+    #[test]
+    fn tally() {
+        let rng = &mut thread_rng();
+        let n = 10;
+
+        let parameters = ElGamal::setup(rng).unwrap();
+        let (pk, sk) = ElGamal::keygen(&parameters, rng).unwrap();
+
+        // Each vote is the generator raised to 0 or 1, so the tally is the generator raised to the number of
+        // votes in favour
+        let votes = (0..n).map(|i| (i % 3 == 0) as u64).collect::<Vec<_>>();
+        let ciphertexts = votes
+            .iter()
+            .map(|&vote| {
+                let message =
+                    el_gamal::Plaintext(parameters.generator.mul(Scalar::from(vote)).into_affine());
+                ElGamal::encrypt(&parameters, &pk, &message, &Scalar::rand(rng)).unwrap()
+            })
+            .collect::<Vec<_>>();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let tally =
+            el_gamal::tally::tally(rng, &parameters, &sk, &ciphertexts, &mut fs_rng).unwrap();
+
+        let in_favour = votes.iter().sum::<u64>();
+        assert_eq!(
+            tally.plaintext.0,
+            parameters
+                .generator
+                .mul(Scalar::from(in_favour))
+                .into_affine()
+        );
+
+        let mut bytes = Vec::new();
+        tally.serialize_unchecked(&mut bytes).unwrap();
+        let received_tally: el_gamal::tally::Tally<Curve> =
+            deserialize_validated(&bytes[..]).unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            Ok(()),
+            el_gamal::tally::verify_tally(
+                &parameters,
+                &pk,
+                &ciphertexts,
+                &received_tally,
+                &mut fs_rng
+            )
+        );
+
+        // The tally does not match a list with a ciphertext dropped
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            Err(CryptoError::InvalidDecryption),
+            el_gamal::tally::verify_tally(
+                &parameters,
+                &pk,
+                &ciphertexts[1..],
+                &received_tally,
+                &mut fs_rng
+            )
+        );
+    }
+
+    #[test]
+    fn encode_decode_bytes() {
+        let rng = &mut thread_rng();
+        assert_eq!(capacity::<StarkwareParameters>(), 29);
+
+        for length in 0..=capacity::<StarkwareParameters>() {
+            let mut data = vec![0u8; length];
+            rng.fill_bytes(&mut data);
+
+            let plaintext = encode::<StarkwareParameters>(&data).unwrap();
+            assert!(plaintext.0.is_on_curve());
+            assert_eq!(data, decode::<StarkwareParameters>(&plaintext).unwrap());
+        }
+
+        // Trailing zeros are preserved
+        let data = [1, 0, 0];
+        let plaintext = encode::<StarkwareParameters>(&data).unwrap();
+        assert_eq!(
+            data.to_vec(),
+            decode::<StarkwareParameters>(&plaintext).unwrap()
+        );
+    }
+
+    #[test]
+    fn encode_decode_integers() {
+        let rng = &mut thread_rng();
+        for value in [0, 1, 51, u64::MAX, rng.gen()] {
+            let plaintext = encode_u64::<StarkwareParameters>(value).unwrap();
+            assert_eq!(
+                value,
+                decode_u64::<StarkwareParameters>(&plaintext).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn encoding_survives_encryption() {
+        let rng = &mut thread_rng();
+        let pp = ElGamal::setup(rng).unwrap();
+        let (pk, sk) = ElGamal::keygen(&pp, rng).unwrap();
+
+        let plaintext = encode::<StarkwareParameters>(b"ace of spades").unwrap();
+        let ciphertext = ElGamal::encrypt(&pp, &pk, &plaintext, &Scalar::rand(rng)).unwrap();
+        let decrypted = ElGamal::decrypt(&pp, &sk, &ciphertext).unwrap();
+
+        assert_eq!(
+            b"ace of spades".to_vec(),
+            decode::<StarkwareParameters>(&decrypted).unwrap()
+        );
+    }
+
+    #[test]
+    fn invalid_encoding_inputs() {
+        let data = vec![0u8; capacity::<StarkwareParameters>() + 1];
+        assert_eq!(
+            encode::<StarkwareParameters>(&data),
+            Err(CryptoError::PlaintextCapacityError(data.len(), 29))
+        );
+
+        // A random point is not a valid encoding with overwhelming probability
+        let rng = &mut thread_rng();
+        let random = el_gamal::Plaintext(Curve::rand(rng).into_affine());
+        assert_eq!(
+            decode::<StarkwareParameters>(&random),
+            Err(CryptoError::DecodingError)
+        );
+        assert_eq!(
+            decode::<StarkwareParameters>(&el_gamal::Plaintext(starknet_curve::Affine::zero())),
+            Err(CryptoError::DecodingError)
+        );
+
+        let five_bytes = encode::<StarkwareParameters>(&[1, 2, 3, 4, 5]).unwrap();
+        assert_eq!(
+            decode_u64::<StarkwareParameters>(&five_bytes),
+            Err(CryptoError::DecodingError)
+        );
+    }
 }
@@ -0,0 +1,118 @@
+use super::{dkg::KeyShare, lagrange_coefficient, ThresholdParameters};
+use crate::error::CryptoError;
+use crate::homomorphic_encryption::el_gamal::{Ciphertext, Parameters, Plaintext};
+use crate::utils::validation::{validate_point, Validate, ValidatePoint};
+use crate::zkp::transcript::Transcript;
+use crate::zkp::{proofs::chaum_pedersen_dl_equality, ArgumentOfKnowledge};
+
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::PrimeField;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::{
+    io::{Read, Write},
+    rand::Rng,
+};
+
+type DLEquality<'a, C> = chaum_pedersen_dl_equality::DLEquality<'a, C>;
+
+pub const PROTOCOL_NAME: &[u8] = b"threshold_decryption";
+
+/// Share of the decryption of a ciphertext (c1, c2) computed by a single key holder: `sk_i * c1`, along with
+/// a Chaum-Pedersen proof that `sk_i` is the discrete logarithm of the holder's public share.
+#[derive(Copy, Clone, CanonicalSerialize, CanonicalDeserialize)]
+pub struct DecryptionShare<C: ProjectiveCurve> {
+    pub index: usize,
+    pub share: C::Affine,
+    pub proof: chaum_pedersen_dl_equality::proof::Proof<C>,
+}
+
+impl<C: ProjectiveCurve> Validate for DecryptionShare<C>
+where
+    C::Affine: ValidatePoint,
+{
+    fn validate(&self) -> Result<(), CryptoError> {
+        validate_point(&self.share)?;
+        self.proof.validate()
+    }
+}
+
+/// Compute the decryption share of `ciphertext` for the holder of `key_share`.
+pub fn partial_decrypt<C: ProjectiveCurve, R: Rng, T: Transcript>(
+    rng: &mut R,
+    pp: &Parameters<C>,
+    key_share: &KeyShare<C>,
+    ciphertext: &Ciphertext<C>,
+    transcript: &mut T,
+) -> Result<DecryptionShare<C>, CryptoError> {
+    let share = ciphertext.0.mul(key_share.secret.into_repr()).into_affine();
+
+    transcript.domain_separator(PROTOCOL_NAME);
+    transcript.append(b"index", &(key_share.index as u32))?;
+
+    let parameters = chaum_pedersen_dl_equality::Parameters::new(&pp.generator, &ciphertext.0);
+    let statement = chaum_pedersen_dl_equality::Statement::new(&key_share.public_share, &share);
+    let proof = DLEquality::prove(rng, &parameters, &statement, &key_share.secret, transcript)?;
+
+    Ok(DecryptionShare {
+        index: key_share.index,
+        share,
+        proof,
+    })
+}
+
+/// Check a decryption share of `ciphertext` against the public share of the party which produced it.
+pub fn verify_decryption_share<C: ProjectiveCurve, T: Transcript>(
+    pp: &Parameters<C>,
+    public_share: &C::Affine,
+    ciphertext: &Ciphertext<C>,
+    decryption_share: &DecryptionShare<C>,
+    transcript: &mut T,
+) -> Result<(), CryptoError> {
+    transcript.domain_separator(PROTOCOL_NAME);
+    transcript.append(b"index", &(decryption_share.index as u32))?;
+
+    let parameters = chaum_pedersen_dl_equality::Parameters::new(&pp.generator, &ciphertext.0);
+    let statement =
+        chaum_pedersen_dl_equality::Statement::new(public_share, &decryption_share.share);
+
+    DLEquality::verify(&parameters, &statement, &decryption_share.proof, transcript)
+        .map_err(|_| CryptoError::InvalidDecryptionShare(decryption_share.index))
+}
+
+/// Recover the plaintext of `ciphertext` from at least `threshold` decryption shares by Lagrange interpolation.
+/// The shares are expected to have been checked with [`verify_decryption_share`].
+pub fn combine<C: ProjectiveCurve>(
+    parameters: &ThresholdParameters,
+    ciphertext: &Ciphertext<C>,
+    decryption_shares: &[DecryptionShare<C>],
+) -> Result<Plaintext<C>, CryptoError> {
+    if decryption_shares.len() < parameters.threshold {
+        return Err(CryptoError::NotEnoughDecryptionShares(
+            parameters.threshold,
+            decryption_shares.len(),
+        ));
+    }
+
+    let indices = decryption_shares
+        .iter()
+        .map(|decryption_share| decryption_share.index)
+        .collect::<Vec<_>>();
+
+    for (i, &index) in indices.iter().enumerate() {
+        parameters.check_index(index)?;
+        if indices[..i].contains(&index) {
+            return Err(CryptoError::DuplicatePartyIndex(index));
+        }
+    }
+
+    // s = sk * c1 = sum of lambda_i * sk_i * c1
+    let mut s = C::zero();
+    for decryption_share in decryption_shares {
+        let lambda: C::ScalarField = lagrange_coefficient(&indices, decryption_share.index)?;
+        s += decryption_share.share.mul(lambda.into_repr());
+    }
+
+    Ok(Plaintext(
+        (ciphertext.1.into_projective() - s).into_affine(),
+    ))
+}
@@ -0,0 +1,296 @@
+use super::ThresholdParameters;
+use crate::error::CryptoError;
+use crate::homomorphic_encryption::el_gamal::{Parameters, PublicKey, SecretKey};
+use crate::utils::rand::sample_vector;
+use crate::utils::validation::{
+    validate_non_identity_point, validate_point, Validate, ValidatePoint,
+};
+use crate::zkp::transcript::Transcript;
+use crate::zkp::{proofs::schnorr_identification, ArgumentOfKnowledge};
+
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{PrimeField, Zero};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::{
+    io::{Read, Write},
+    rand::Rng,
+};
+
+type SchnorrIdentification<C> = schnorr_identification::SchnorrIdentification<C>;
+
+/// Secret state of a party during the distributed key generation: a random polynomial of degree
+/// `threshold - 1` whose constant term is the party's contribution to the shared secret key.
+pub struct Participant<C: ProjectiveCurve> {
+    index: usize,
+    coefficients: Vec<C::ScalarField>,
+}
+
+/// Message broadcast by a party to all others. Contains Feldman commitments to the coefficients of the
+/// party's polynomial and a Schnorr proof of knowledge of its constant term, which prevents a party
+/// from choosing its contribution to the public key as a function of the others' (rogue-key attack).
+#[derive(Clone, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Broadcast<C: ProjectiveCurve> {
+    pub sender: usize,
+    pub commitments: Vec<C::Affine>,
+    pub proof_of_knowledge: schnorr_identification::proof::Proof<C>,
+}
+
+/// Evaluation of the polynomial of party `sender` at the index of party `recipient`. Must be sent over a
+/// private channel.
+#[derive(Copy, Clone, CanonicalSerialize, CanonicalDeserialize)]
+pub struct SecretShare<C: ProjectiveCurve> {
+    pub sender: usize,
+    pub recipient: usize,
+    pub value: C::ScalarField,
+}
+
+/// Output of the distributed key generation for a single party: its share of the secret key and the
+/// matching public share, which any other party can recompute from the broadcasts.
+#[derive(Copy, Clone, CanonicalSerialize, CanonicalDeserialize)]
+pub struct KeyShare<C: ProjectiveCurve> {
+    pub index: usize,
+    pub secret: SecretKey<C>,
+    pub public_share: C::Affine,
+}
+
+impl<C: ProjectiveCurve> Validate for Broadcast<C>
+where
+    C::Affine: ValidatePoint,
+{
+    fn validate(&self) -> Result<(), CryptoError> {
+        self.commitments.iter().try_for_each(validate_point)?;
+        self.proof_of_knowledge.validate()
+    }
+}
+
+impl<C: ProjectiveCurve> Validate for SecretShare<C> {
+    fn validate(&self) -> Result<(), CryptoError> {
+        Ok(())
+    }
+}
+
+impl<C: ProjectiveCurve> Validate for KeyShare<C>
+where
+    C::Affine: ValidatePoint,
+{
+    fn validate(&self) -> Result<(), CryptoError> {
+        validate_non_identity_point(&self.public_share)
+    }
+}
+
+impl<C: ProjectiveCurve> Participant<C> {
+    pub const PROTOCOL_NAME: &'static [u8] = b"distributed_key_generation";
+
+    pub fn new<R: Rng>(
+        rng: &mut R,
+        parameters: &ThresholdParameters,
+        index: usize,
+    ) -> Result<Self, CryptoError> {
+        parameters.check_index(index)?;
+
+        Ok(Self {
+            index,
+            coefficients: sample_vector(rng, parameters.threshold),
+        })
+    }
+
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Commit to the coefficients of the polynomial and prove knowledge of the constant term.
+    pub fn broadcast<R: Rng, T: Transcript>(
+        &self,
+        rng: &mut R,
+        pp: &Parameters<C>,
+        transcript: &mut T,
+    ) -> Result<Broadcast<C>, CryptoError> {
+        let commitments = C::batch_normalization_into_affine(
+            &self
+                .coefficients
+                .iter()
+                .map(|a_k| pp.generator.mul(a_k.into_repr()))
+                .collect::<Vec<_>>(),
+        );
+
+        transcript.domain_separator(Self::PROTOCOL_NAME);
+        transcript.append(b"sender", &(self.index as u32))?;
+
+        let proof_of_knowledge = SchnorrIdentification::prove(
+            rng,
+            &pp.generator,
+            &commitments[0],
+            &self.coefficients[0],
+            transcript,
+        )?;
+
+        Ok(Broadcast {
+            sender: self.index,
+            commitments,
+            proof_of_knowledge,
+        })
+    }
+
+    /// Evaluate the polynomial at the index of party `recipient`.
+    pub fn share_for(
+        &self,
+        parameters: &ThresholdParameters,
+        recipient: usize,
+    ) -> Result<SecretShare<C>, CryptoError> {
+        parameters.check_index(recipient)?;
+
+        let x = C::ScalarField::from(recipient as u64);
+        let value = self
+            .coefficients
+            .iter()
+            .rev()
+            .fold(C::ScalarField::zero(), |acc, &a_k| acc * x + a_k);
+
+        Ok(SecretShare {
+            sender: self.index,
+            recipient,
+            value,
+        })
+    }
+}
+
+/// Check that a broadcast commits to a polynomial of the right degree and carries a valid proof of
+/// knowledge of its constant term.
+pub fn verify_broadcast<C: ProjectiveCurve, T: Transcript>(
+    pp: &Parameters<C>,
+    parameters: &ThresholdParameters,
+    broadcast: &Broadcast<C>,
+    transcript: &mut T,
+) -> Result<(), CryptoError> {
+    parameters.check_index(broadcast.sender)?;
+
+    if broadcast.commitments.len() != parameters.threshold {
+        return Err(CryptoError::InvalidKeyGenerationBroadcast(broadcast.sender));
+    }
+
+    transcript.domain_separator(Participant::<C>::PROTOCOL_NAME);
+    transcript.append(b"sender", &(broadcast.sender as u32))?;
+
+    SchnorrIdentification::verify(
+        &pp.generator,
+        &broadcast.commitments[0],
+        &broadcast.proof_of_knowledge,
+        transcript,
+    )
+    .map_err(|_| CryptoError::InvalidKeyGenerationBroadcast(broadcast.sender))
+}
+
+/// Check a secret share received from `broadcast.sender` against the commitments it broadcast.
+pub fn verify_share<C: ProjectiveCurve>(
+    pp: &Parameters<C>,
+    broadcast: &Broadcast<C>,
+    share: &SecretShare<C>,
+) -> Result<(), CryptoError> {
+    if share.sender != broadcast.sender
+        || pp.generator.mul(share.value.into_repr())
+            != evaluate_commitments::<C>(&broadcast.commitments, share.recipient)
+    {
+        return Err(CryptoError::InvalidSecretShare(
+            share.sender,
+            share.recipient,
+        ));
+    }
+
+    Ok(())
+}
+
+/// Combine the shares received by party `index` from every party whose broadcast was accepted into a share
+/// of the joint secret key. `shares[i]` must have been sent by the author of `broadcasts[i]`.
+pub fn aggregate_key_share<C: ProjectiveCurve>(
+    pp: &Parameters<C>,
+    parameters: &ThresholdParameters,
+    index: usize,
+    broadcasts: &[Broadcast<C>],
+    shares: &[SecretShare<C>],
+) -> Result<KeyShare<C>, CryptoError> {
+    parameters.check_index(index)?;
+
+    if broadcasts.len() != shares.len() {
+        return Err(CryptoError::ShareCountError(broadcasts.len(), shares.len()));
+    }
+
+    check_senders(broadcasts)?;
+
+    let mut secret = C::ScalarField::zero();
+    for (broadcast, share) in broadcasts.iter().zip(shares.iter()) {
+        if share.recipient != index {
+            return Err(CryptoError::InvalidSecretShare(
+                share.sender,
+                share.recipient,
+            ));
+        }
+        verify_share(pp, broadcast, share)?;
+        secret += share.value;
+    }
+
+    Ok(KeyShare {
+        index,
+        secret,
+        public_share: pp.generator.mul(secret.into_repr()).into_affine(),
+    })
+}
+
+/// Compute the joint public key from the broadcasts of all parties.
+pub fn public_key<C: ProjectiveCurve>(
+    broadcasts: &[Broadcast<C>],
+) -> Result<PublicKey<C>, CryptoError> {
+    check_senders(broadcasts)?;
+
+    let mut public_key = C::zero();
+    for broadcast in broadcasts {
+        let constant_term = broadcast
+            .commitments
+            .first()
+            .ok_or(CryptoError::InvalidKeyGenerationBroadcast(broadcast.sender))?;
+        public_key.add_assign_mixed(constant_term);
+    }
+
+    Ok(public_key.into_affine())
+}
+
+/// Compute the public share of party `index`, i.e. its share of the secret key times the generator, from the
+/// broadcasts of all parties.
+pub fn public_share<C: ProjectiveCurve>(
+    broadcasts: &[Broadcast<C>],
+    index: usize,
+) -> Result<C::Affine, CryptoError> {
+    check_senders(broadcasts)?;
+
+    Ok(broadcasts
+        .iter()
+        .map(|broadcast| evaluate_commitments::<C>(&broadcast.commitments, index))
+        .sum::<C>()
+        .into_affine())
+}
+
+/// Check that there is at least one broadcast and that no party contributes twice, so that the key shares, the
+/// public key and the public shares computed from the same broadcasts agree.
+fn check_senders<C: ProjectiveCurve>(broadcasts: &[Broadcast<C>]) -> Result<(), CryptoError> {
+    if broadcasts.is_empty() {
+        return Err(CryptoError::NoKeyGenerationBroadcast);
+    }
+
+    for (i, broadcast) in broadcasts.iter().enumerate() {
+        if broadcasts[..i]
+            .iter()
+            .any(|other| other.sender == broadcast.sender)
+        {
+            return Err(CryptoError::DuplicatePartyIndex(broadcast.sender));
+        }
+    }
+
+    Ok(())
+}
+
+/// Evaluate "in the exponent" the polynomial committed to by `commitments` at `index`.
+fn evaluate_commitments<C: ProjectiveCurve>(commitments: &[C::Affine], index: usize) -> C {
+    let x = C::ScalarField::from(index as u64).into_repr();
+    commitments.iter().rev().fold(C::zero(), |acc, commitment| {
+        acc.mul(x).add_mixed(commitment)
+    })
+}
@@ -0,0 +1,57 @@
+use crate::error::CryptoError;
+
+use ark_ff::Field;
+
+pub mod decryption;
+pub mod dkg;
+mod tests;
+
+/// Parameters of a threshold setting: `num_parties` parties each hold a share of a secret key and any
+/// `threshold` of them can jointly use it. Parties are indexed from 1 to `num_parties` since the shares
+/// are evaluations of a polynomial whose value at 0 is the shared secret.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ThresholdParameters {
+    pub threshold: usize,
+    pub num_parties: usize,
+}
+
+impl ThresholdParameters {
+    pub fn new(threshold: usize, num_parties: usize) -> Result<Self, CryptoError> {
+        if threshold == 0 || threshold > num_parties {
+            return Err(CryptoError::InvalidThreshold(threshold, num_parties));
+        }
+
+        Ok(Self {
+            threshold,
+            num_parties,
+        })
+    }
+
+    pub fn check_index(&self, index: usize) -> Result<(), CryptoError> {
+        if index == 0 || index > self.num_parties {
+            return Err(CryptoError::InvalidPartyIndex(index, self.num_parties));
+        }
+
+        Ok(())
+    }
+}
+
+/// Compute the Lagrange coefficient of party `index` for interpolating the value at 0 of a polynomial
+/// from its evaluations at `indices`.
+pub fn lagrange_coefficient<F: Field>(indices: &[usize], index: usize) -> Result<F, CryptoError> {
+    let x_i = F::from(index as u64);
+
+    let mut numerator = F::one();
+    let mut denominator = F::one();
+    for &j in indices.iter().filter(|&&j| j != index) {
+        let x_j = F::from(j as u64);
+        numerator *= x_j;
+        denominator *= x_j - x_i;
+    }
+
+    let denominator_inverse = denominator
+        .inverse()
+        .ok_or(CryptoError::InvalidPartyIndex(index, indices.len()))?;
+
+    Ok(numerator * denominator_inverse)
+}
@@ -0,0 +1,349 @@
+#[cfg(test)]
+mod test {
+    use crate::error::CryptoError;
+    use crate::homomorphic_encryption::el_gamal::threshold::{
+        decryption, dkg, lagrange_coefficient, ThresholdParameters,
+    };
+    use crate::homomorphic_encryption::{el_gamal, HomomorphicEncryptionScheme};
+
+    use ark_ec::{AffineCurve, ProjectiveCurve};
+    use ark_marlin::rng::FiatShamirRng;
+    use ark_std::{rand::thread_rng, UniformRand};
+    use blake2::Blake2s;
+    use rand::Rng;
+    use starknet_curve;
+
+    type Curve = starknet_curve::Projective;
+    type Scalar = starknet_curve::Fr;
+    type ElGamal = el_gamal::ElGamal<Curve>;
+    type Plaintext = el_gamal::Plaintext<Curve>;
+    type Participant = dkg::Participant<Curve>;
+    type Broadcast = dkg::Broadcast<Curve>;
+    type KeyShare = dkg::KeyShare<Curve>;
+    type DecryptionShare = decryption::DecryptionShare<Curve>;
+    type FS = FiatShamirRng<Blake2s>;
+
+    /// Run a complete key generation ceremony in-process and return the broadcasts and the key share of
+    /// every party.
+    fn run_ceremony<R: Rng>(
+        rng: &mut R,
+        pp: &el_gamal::Parameters<Curve>,
+        parameters: &ThresholdParameters,
+    ) -> (Vec<Broadcast>, Vec<KeyShare>) {
+        let participants = (1..=parameters.num_parties)
+            .map(|i| Participant::new(rng, parameters, i).unwrap())
+            .collect::<Vec<_>>();
+
+        let broadcasts = participants
+            .iter()
+            .map(|participant| {
+                let mut fs_rng = FS::from_seed(b"Initialised with some input");
+                participant.broadcast(rng, pp, &mut fs_rng).unwrap()
+            })
+            .collect::<Vec<_>>();
+
+        for broadcast in broadcasts.iter() {
+            let mut fs_rng = FS::from_seed(b"Initialised with some input");
+            assert_eq!(
+                Ok(()),
+                dkg::verify_broadcast(pp, parameters, broadcast, &mut fs_rng)
+            );
+        }
+
+        let key_shares = (1..=parameters.num_parties)
+            .map(|recipient| {
+                let shares = participants
+                    .iter()
+                    .map(|participant| participant.share_for(parameters, recipient).unwrap())
+                    .collect::<Vec<_>>();
+
+                dkg::aggregate_key_share(pp, parameters, recipient, &broadcasts, &shares).unwrap()
+            })
+            .collect::<Vec<_>>();
+
+        (broadcasts, key_shares)
+    }
+
+    #[test]
+    fn test_key_generation_ceremony() {
+        let rng = &mut thread_rng();
+        let parameters = ThresholdParameters::new(3, 5).unwrap();
+        let pp = ElGamal::setup(rng).unwrap();
+
+        let (broadcasts, key_shares) = run_ceremony(rng, &pp, &parameters);
+        let pk = dkg::public_key(&broadcasts).unwrap();
+
+        // Every party can recompute the public share of every other party
+        for key_share in key_shares.iter() {
+            assert_eq!(
+                key_share.public_share,
+                dkg::public_share(&broadcasts, key_share.index).unwrap()
+            );
+        }
+
+        // Any `threshold` shares interpolate to the secret key matching the joint public key
+        for subset in [vec![1, 2, 3], vec![2, 4, 5], vec![1, 3, 5]] {
+            let sk: Scalar = subset
+                .iter()
+                .map(|&i| {
+                    lagrange_coefficient::<Scalar>(&subset, i).unwrap() * key_shares[i - 1].secret
+                })
+                .sum();
+
+            assert_eq!(pk, pp.generator.mul(sk).into_affine());
+
+            let message = Plaintext::rand(rng);
+            let r = Scalar::rand(rng);
+            let ciphertext = ElGamal::encrypt(&pp, &pk, &message, &r).unwrap();
+            assert_eq!(message, ElGamal::decrypt(&pp, &sk, &ciphertext).unwrap());
+        }
+
+        // Fewer than `threshold` shares do not
+        let subset = vec![1, 2];
+        let sk: Scalar = subset
+            .iter()
+            .map(|&i| {
+                lagrange_coefficient::<Scalar>(&subset, i).unwrap() * key_shares[i - 1].secret
+            })
+            .sum();
+        assert_ne!(pk, pp.generator.mul(sk).into_affine());
+    }
+
+    #[test]
+    fn test_invalid_share() {
+        let rng = &mut thread_rng();
+        let parameters = ThresholdParameters::new(2, 3).unwrap();
+        let pp = ElGamal::setup(rng).unwrap();
+
+        let dealer = Participant::new(rng, &parameters, 1).unwrap();
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let broadcast = dealer.broadcast(rng, &pp, &mut fs_rng).unwrap();
+
+        let share = dealer.share_for(&parameters, 2).unwrap();
+        assert_eq!(Ok(()), dkg::verify_share(&pp, &broadcast, &share));
+
+        let mut bad_share = share;
+        bad_share.value = Scalar::rand(rng);
+        assert_eq!(
+            dkg::verify_share(&pp, &broadcast, &bad_share),
+            Err(CryptoError::InvalidSecretShare(1, 2))
+        );
+        assert_eq!(
+            dkg::aggregate_key_share(&pp, &parameters, 2, &[broadcast], &[bad_share])
+                .map(|key_share| key_share.index),
+            Err(CryptoError::InvalidSecretShare(1, 2))
+        );
+    }
+
+    #[test]
+    fn test_malformed_broadcasts() {
+        let rng = &mut thread_rng();
+        let parameters = ThresholdParameters::new(2, 3).unwrap();
+        let pp = ElGamal::setup(rng).unwrap();
+
+        let dealer = Participant::new(rng, &parameters, 1).unwrap();
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let broadcast = dealer.broadcast(rng, &pp, &mut fs_rng).unwrap();
+        let share = dealer.share_for(&parameters, 2).unwrap();
+
+        // The same party cannot contribute twice to a key share
+        assert_eq!(
+            dkg::aggregate_key_share(
+                &pp,
+                &parameters,
+                2,
+                &[broadcast.clone(), broadcast.clone()],
+                &[share, share]
+            )
+            .map(|key_share| key_share.index),
+            Err(CryptoError::DuplicatePartyIndex(1))
+        );
+
+        // Nor to the public key and the public shares, which would then disagree with the key shares
+        assert_eq!(
+            dkg::public_key(&[broadcast.clone(), broadcast.clone()]),
+            Err(CryptoError::DuplicatePartyIndex(1))
+        );
+        assert_eq!(
+            dkg::public_share(&[broadcast.clone(), broadcast.clone()], 2),
+            Err(CryptoError::DuplicatePartyIndex(1))
+        );
+
+        // The public key and the public shares are not defined without broadcasts
+        assert_eq!(
+            dkg::public_key::<Curve>(&[]),
+            Err(CryptoError::NoKeyGenerationBroadcast)
+        );
+        assert_eq!(
+            dkg::public_share::<Curve>(&[], 2),
+            Err(CryptoError::NoKeyGenerationBroadcast)
+        );
+
+        // A broadcast without commitments is rejected instead of panicking
+        let mut empty_broadcast = broadcast;
+        empty_broadcast.commitments.clear();
+        assert_eq!(
+            dkg::public_key(&[empty_broadcast]),
+            Err(CryptoError::InvalidKeyGenerationBroadcast(1))
+        );
+    }
+
+    #[test]
+    fn test_invalid_broadcast() {
+        let rng = &mut thread_rng();
+        let parameters = ThresholdParameters::new(2, 3).unwrap();
+        let pp = ElGamal::setup(rng).unwrap();
+
+        let dealer = Participant::new(rng, &parameters, 1).unwrap();
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let broadcast = dealer.broadcast(rng, &pp, &mut fs_rng).unwrap();
+
+        // A proof of knowledge cannot be replayed by another party
+        let mut replayed = broadcast.clone();
+        replayed.sender = 2;
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            dkg::verify_broadcast(&pp, &parameters, &replayed, &mut fs_rng),
+            Err(CryptoError::InvalidKeyGenerationBroadcast(2))
+        );
+
+        // The polynomial must have degree threshold - 1
+        let mut too_short = broadcast;
+        too_short.commitments.pop();
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            dkg::verify_broadcast(&pp, &parameters, &too_short, &mut fs_rng),
+            Err(CryptoError::InvalidKeyGenerationBroadcast(1))
+        );
+    }
+
+    #[test]
+    fn test_invalid_parameters() {
+        assert_eq!(
+            ThresholdParameters::new(0, 3),
+            Err(CryptoError::InvalidThreshold(0, 3))
+        );
+        assert_eq!(
+            ThresholdParameters::new(4, 3),
+            Err(CryptoError::InvalidThreshold(4, 3))
+        );
+
+        let parameters = ThresholdParameters::new(2, 3).unwrap();
+        assert_eq!(
+            parameters.check_index(0),
+            Err(CryptoError::InvalidPartyIndex(0, 3))
+        );
+        assert_eq!(
+            parameters.check_index(4),
+            Err(CryptoError::InvalidPartyIndex(4, 3))
+        );
+    }
+
+    #[test]
+    fn test_threshold_decryption() {
+        let rng = &mut thread_rng();
+        let parameters = ThresholdParameters::new(3, 5).unwrap();
+        let pp = ElGamal::setup(rng).unwrap();
+
+        let (broadcasts, key_shares) = run_ceremony(rng, &pp, &parameters);
+        let pk = dkg::public_key(&broadcasts).unwrap();
+
+        let message = Plaintext::rand(rng);
+        let r = Scalar::rand(rng);
+        let ciphertext = ElGamal::encrypt(&pp, &pk, &message, &r).unwrap();
+
+        let decryption_shares = key_shares
+            .iter()
+            .map(|key_share| {
+                let mut fs_rng = FS::from_seed(b"Initialised with some input");
+                decryption::partial_decrypt(rng, &pp, key_share, &ciphertext, &mut fs_rng).unwrap()
+            })
+            .collect::<Vec<_>>();
+
+        for decryption_share in decryption_shares.iter() {
+            let public_share = dkg::public_share(&broadcasts, decryption_share.index).unwrap();
+            let mut fs_rng = FS::from_seed(b"Initialised with some input");
+            assert_eq!(
+                Ok(()),
+                decryption::verify_decryption_share(
+                    &pp,
+                    &public_share,
+                    &ciphertext,
+                    decryption_share,
+                    &mut fs_rng
+                )
+            );
+        }
+
+        // Any `threshold` or more shares recover the message
+        for subset in [vec![0, 1, 2], vec![4, 2, 0], vec![1, 2, 3, 4]] {
+            let shares = subset
+                .iter()
+                .map(|&i| decryption_shares[i])
+                .collect::<Vec<_>>();
+            assert_eq!(
+                Ok(message),
+                decryption::combine(&parameters, &ciphertext, &shares)
+            );
+        }
+
+        assert_eq!(
+            decryption::combine(&parameters, &ciphertext, &decryption_shares[..2]),
+            Err(CryptoError::NotEnoughDecryptionShares(3, 2))
+        );
+
+        let duplicated = vec![
+            decryption_shares[0],
+            decryption_shares[1],
+            decryption_shares[0],
+        ];
+        assert_eq!(
+            decryption::combine(&parameters, &ciphertext, &duplicated),
+            Err(CryptoError::DuplicatePartyIndex(1))
+        );
+    }
+
+    #[test]
+    fn test_invalid_decryption_share() {
+        let rng = &mut thread_rng();
+        let parameters = ThresholdParameters::new(2, 3).unwrap();
+        let pp = ElGamal::setup(rng).unwrap();
+
+        let (broadcasts, key_shares) = run_ceremony(rng, &pp, &parameters);
+        let pk = dkg::public_key(&broadcasts).unwrap();
+
+        let message = Plaintext::rand(rng);
+        let r = Scalar::rand(rng);
+        let ciphertext = ElGamal::encrypt(&pp, &pk, &message, &r).unwrap();
+
+        // A party using a secret which does not match its public share is caught
+        let mut dishonest_key_share = key_shares[1];
+        dishonest_key_share.secret = Scalar::rand(rng);
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let bad_share: DecryptionShare =
+            decryption::partial_decrypt(rng, &pp, &dishonest_key_share, &ciphertext, &mut fs_rng)
+                .unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            decryption::verify_decryption_share(
+                &pp,
+                &dkg::public_share(&broadcasts, 2).unwrap(),
+                &ciphertext,
+                &bad_share,
+                &mut fs_rng
+            ),
+            Err(CryptoError::InvalidDecryptionShare(2))
+        );
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let good_share =
+            decryption::partial_decrypt(rng, &pp, &key_shares[0], &ciphertext, &mut fs_rng)
+                .unwrap();
+        assert_ne!(
+            Ok(message),
+            decryption::combine(&parameters, &ciphertext, &[good_share, bad_share])
+        );
+    }
+}
@@ -0,0 +1,92 @@
+use crate::error::CryptoError;
+
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{PrimeField, Zero};
+use std::collections::HashMap;
+
+/// Precomputed baby steps for solving discrete logarithms in base `generator` with baby-step giant-step.
+/// Any `x` in `[0, bound)` is recovered from `x*generator` in at most `step` lookups, where `step` is the
+/// ceiling of the square root of `bound`. A table only depends on the generator and the bound, so it can
+/// be built once and reused for every decryption.
+#[derive(Clone)]
+pub struct DiscreteLogTable<C: ProjectiveCurve> {
+    generator: C::Affine,
+    bound: u64,
+    step: u64,
+    baby_steps: HashMap<C::Affine, u64>,
+    giant_step: C::Affine,
+}
+
+impl<C: ProjectiveCurve> DiscreteLogTable<C> {
+    pub fn new(generator: &C::Affine, bound: u64) -> Self {
+        let step = integer_sqrt_ceil(bound).max(1);
+
+        // j * generator for j in [0, step)
+        let mut acc = C::zero();
+        let mut projective_steps = Vec::with_capacity(step as usize);
+        for _ in 0..step {
+            projective_steps.push(acc);
+            acc.add_assign_mixed(generator);
+        }
+        let baby_steps = C::batch_normalization_into_affine(&projective_steps)
+            .into_iter()
+            .zip(0..step)
+            .collect();
+
+        let giant_step = -generator
+            .mul(C::ScalarField::from(step).into_repr())
+            .into_affine();
+
+        Self {
+            generator: *generator,
+            bound,
+            step,
+            baby_steps,
+            giant_step,
+        }
+    }
+
+    pub fn generator(&self) -> &C::Affine {
+        &self.generator
+    }
+
+    pub fn bound(&self) -> u64 {
+        self.bound
+    }
+
+    /// Find `x` in `[0, bound)` such that `point = x*generator`.
+    pub fn discrete_log(&self, point: &C::Affine) -> Result<u64, CryptoError> {
+        let mut current = point.into_projective();
+
+        // x = i*step + j: subtract step*generator until we land in the baby steps
+        for i in 0..self.step {
+            if let Some(j) = self.baby_steps.get(&current.into_affine()) {
+                let x = i * self.step + j;
+                if x < self.bound {
+                    return Ok(x);
+                }
+                break;
+            }
+            current.add_assign_mixed(&self.giant_step);
+        }
+
+        Err(CryptoError::DiscreteLogOutOfRange(self.bound))
+    }
+}
+
+/// Smallest `s` such that `s*s >= n`.
+pub(crate) fn integer_sqrt_ceil(n: u64) -> u64 {
+    if n.is_zero() {
+        return 0;
+    }
+
+    let mut s = (n as f64).sqrt() as u64;
+    while s.saturating_mul(s) < n {
+        s += 1;
+    }
+    while s > 0 && (s - 1).saturating_mul(s - 1) >= n {
+        s -= 1;
+    }
+
+    s
+}
@@ -0,0 +1,177 @@
+use crate::error::CryptoError;
+use crate::homomorphic_encryption::{el_gamal, HomomorphicEncryptionScheme};
+use crate::utils::validation::{validate_non_identity_point, Validate, ValidatePoint};
+
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{fields::PrimeField, One, UniformRand};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::{
+    io::{Read, Write},
+    marker::PhantomData,
+    rand::Rng,
+};
+
+pub mod discrete_log;
+mod tests;
+
+pub use discrete_log::DiscreteLogTable;
+
+/// Default upper bound (exclusive) on the messages which can be decrypted with parameters obtained from `setup`.
+pub const DEFAULT_MESSAGE_BOUND: u64 = 1 << 32;
+
+/// Largest bound accepted when deserializing parameters. The decryption table holds about the square root of the
+/// bound in points, so deserializing untrusted parameters with a larger bound could exhaust memory.
+pub const MAX_MESSAGE_BOUND: u64 = 1 << 40;
+
+/// ElGamal encryption "in the exponent": a message `m` is encrypted as (r*G, m*G + r*pk). The scheme is
+/// additively homomorphic over the messages themselves but decryption requires solving a discrete logarithm,
+/// so only messages in `[0, bound)` can be recovered.
+pub struct ExponentialElGamal<C: ProjectiveCurve> {
+    _group: PhantomData<C>,
+}
+
+/// Public parameters: the generator and a baby-step giant-step table for decryption. Only the generator and
+/// the bound are serialized, the table is rebuilt on deserialization if the bound is at most
+/// [`MAX_MESSAGE_BOUND`].
+#[derive(Clone)]
+pub struct Parameters<C: ProjectiveCurve> {
+    pub generator: C::Affine,
+    table: DiscreteLogTable<C>,
+}
+
+impl<C: ProjectiveCurve> Parameters<C> {
+    pub fn new(generator: &C::Affine, bound: u64) -> Self {
+        Self::from_table(DiscreteLogTable::new(generator, bound))
+    }
+
+    /// Reuse an existing lookup table. Its generator becomes the generator of the scheme.
+    pub fn from_table(table: DiscreteLogTable<C>) -> Self {
+        Self {
+            generator: *table.generator(),
+            table,
+        }
+    }
+
+    pub fn table(&self) -> &DiscreteLogTable<C> {
+        &self.table
+    }
+
+    fn el_gamal_parameters(&self) -> el_gamal::Parameters<C> {
+        el_gamal::Parameters {
+            generator: self.generator,
+        }
+    }
+}
+
+impl<C: ProjectiveCurve> Validate for Parameters<C>
+where
+    C::Affine: ValidatePoint,
+{
+    fn validate(&self) -> Result<(), CryptoError> {
+        validate_non_identity_point(&self.generator)
+    }
+}
+
+impl<C: ProjectiveCurve> CanonicalSerialize for Parameters<C> {
+    fn serialize<W: Write>(&self, mut writer: W) -> Result<(), SerializationError> {
+        self.generator.serialize(&mut writer)?;
+        self.table.bound().serialize(&mut writer)
+    }
+
+    fn serialized_size(&self) -> usize {
+        self.generator.serialized_size() + self.table.bound().serialized_size()
+    }
+}
+
+impl<C: ProjectiveCurve> CanonicalDeserialize for Parameters<C> {
+    fn deserialize<R: Read>(mut reader: R) -> Result<Self, SerializationError> {
+        let generator = C::Affine::deserialize(&mut reader)?;
+        let bound = u64::deserialize(&mut reader)?;
+        if bound > MAX_MESSAGE_BOUND {
+            return Err(SerializationError::InvalidData);
+        }
+
+        Ok(Self::new(&generator, bound))
+    }
+}
+
+pub type PublicKey<C> = el_gamal::PublicKey<C>;
+
+pub type SecretKey<C> = el_gamal::SecretKey<C>;
+
+pub type Plaintext<C> = <C as ProjectiveCurve>::ScalarField;
+
+pub type Generator<C> = Plaintext<C>;
+
+pub type Ciphertext<C> = el_gamal::Ciphertext<C>;
+
+impl<C: ProjectiveCurve> ExponentialElGamal<C> {
+    /// Generate parameters with a random generator which can decrypt messages in `[0, bound)`.
+    pub fn setup_with_bound<R: Rng>(rng: &mut R, bound: u64) -> Result<Parameters<C>, CryptoError> {
+        let generator = C::rand(rng).into_affine();
+
+        Ok(Parameters::new(&generator, bound))
+    }
+
+    /// Decrypt up to the encoding of the message, i.e. return `m*G`. Does not require the message to be in range.
+    pub fn decrypt_to_point(
+        pp: &Parameters<C>,
+        sk: &SecretKey<C>,
+        ciphertext: &Ciphertext<C>,
+    ) -> Result<C::Affine, CryptoError> {
+        let encoded = el_gamal::ElGamal::decrypt(&pp.el_gamal_parameters(), sk, ciphertext)?;
+
+        Ok(encoded.0)
+    }
+}
+
+impl<C: ProjectiveCurve> HomomorphicEncryptionScheme<C::ScalarField> for ExponentialElGamal<C> {
+    type Parameters = Parameters<C>;
+    type Generator = Generator<C>;
+    type PublicKey = PublicKey<C>;
+    type SecretKey = SecretKey<C>;
+    type Plaintext = Plaintext<C>;
+    type Ciphertext = Ciphertext<C>;
+    type Randomness = C::ScalarField;
+
+    fn setup<R: Rng>(rng: &mut R) -> Result<Self::Parameters, CryptoError> {
+        Self::setup_with_bound(rng, DEFAULT_MESSAGE_BOUND)
+    }
+
+    fn generator<R: Rng>(_rng: &mut R) -> Result<Self::Generator, CryptoError> {
+        Ok(Generator::<C>::one())
+    }
+
+    fn keygen<R: Rng>(
+        pp: &Self::Parameters,
+        rng: &mut R,
+    ) -> Result<(Self::PublicKey, Self::SecretKey), CryptoError> {
+        let secret_key = C::ScalarField::rand(rng);
+        let public_key = pp.generator.mul(secret_key.into_repr()).into_affine();
+
+        Ok((public_key, secret_key))
+    }
+
+    fn encrypt(
+        pp: &Self::Parameters,
+        pk: &Self::PublicKey,
+        message: &Self::Plaintext,
+        r: &C::ScalarField,
+    ) -> Result<Self::Ciphertext, CryptoError> {
+        // encode m as m*generator
+        let encoded = el_gamal::Plaintext(pp.generator.mul(message.into_repr()).into_affine());
+
+        el_gamal::ElGamal::encrypt(&pp.el_gamal_parameters(), pk, &encoded, r)
+    }
+
+    fn decrypt(
+        pp: &Self::Parameters,
+        sk: &Self::SecretKey,
+        ciphertext: &Self::Ciphertext,
+    ) -> Result<Self::Plaintext, CryptoError> {
+        let encoded = Self::decrypt_to_point(pp, sk, ciphertext)?;
+        let message = pp.table.discrete_log(&encoded)?;
+
+        Ok(C::ScalarField::from(message))
+    }
+}
@@ -0,0 +1,159 @@
+#[cfg(test)]
+mod test {
+    use crate::error::CryptoError;
+    use crate::homomorphic_encryption::{exponential_el_gamal, HomomorphicEncryptionScheme};
+    use crate::utils::rand::sample_vector;
+
+    use ark_ec::{AffineCurve, ProjectiveCurve};
+    use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+    use ark_std::{rand::thread_rng, UniformRand};
+    use rand::Rng;
+    use starknet_curve;
+
+    type Curve = starknet_curve::Projective;
+    type Scalar = starknet_curve::Fr;
+    type ExponentialElGamal = exponential_el_gamal::ExponentialElGamal<Curve>;
+    type Parameters = exponential_el_gamal::Parameters<Curve>;
+    type DiscreteLogTable = exponential_el_gamal::DiscreteLogTable<Curve>;
+
+    #[test]
+    fn valid_encrypt_decrypt() {
+        let rng = &mut thread_rng();
+        let bound = 1 << 16;
+
+        let parameters = ExponentialElGamal::setup_with_bound(rng, bound).unwrap();
+        let (pk, sk) = ExponentialElGamal::keygen(&parameters, rng).unwrap();
+
+        let mut messages = (0..20).map(|_| rng.gen_range(0..bound)).collect::<Vec<_>>();
+        messages.extend([0, 1, bound - 1]);
+
+        for m in messages {
+            let message = Scalar::from(m);
+            let r = Scalar::rand(rng);
+            let ciphertext = ExponentialElGamal::encrypt(&parameters, &pk, &message, &r).unwrap();
+            assert_eq!(
+                message,
+                ExponentialElGamal::decrypt(&parameters, &sk, &ciphertext).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    /// Verify that the sum of encrypted votes decrypts to the tally
+    fn homomorphic_tally() {
+        let rng = &mut thread_rng();
+        let n = 50;
+
+        let parameters = ExponentialElGamal::setup(rng).unwrap();
+        let (pk, sk) = ExponentialElGamal::keygen(&parameters, rng).unwrap();
+
+        let votes = (0..n)
+            .map(|_| rng.gen_range(0..1000u64))
+            .collect::<Vec<_>>();
+        let randoms: Vec<Scalar> = sample_vector(rng, n);
+
+        let tally = votes
+            .iter()
+            .zip(randoms.iter())
+            .map(|(&v, r)| {
+                ExponentialElGamal::encrypt(&parameters, &pk, &Scalar::from(v), r).unwrap()
+            })
+            .sum();
+
+        let weight = Scalar::from(3u64);
+        let expected = Scalar::from(votes.iter().sum::<u64>());
+        assert_eq!(
+            expected,
+            ExponentialElGamal::decrypt(&parameters, &sk, &tally).unwrap()
+        );
+        assert_eq!(
+            expected * weight,
+            ExponentialElGamal::decrypt(&parameters, &sk, &(tally * weight)).unwrap()
+        );
+    }
+
+    #[test]
+    fn out_of_range() {
+        let rng = &mut thread_rng();
+        let bound = 1000;
+
+        let parameters = ExponentialElGamal::setup_with_bound(rng, bound).unwrap();
+        let (pk, sk) = ExponentialElGamal::keygen(&parameters, rng).unwrap();
+
+        for m in [bound, bound + 1, 5000] {
+            let message = Scalar::from(m);
+            let ciphertext =
+                ExponentialElGamal::encrypt(&parameters, &pk, &message, &Scalar::rand(rng))
+                    .unwrap();
+
+            assert_eq!(
+                ExponentialElGamal::decrypt(&parameters, &sk, &ciphertext),
+                Err(CryptoError::DiscreteLogOutOfRange(bound))
+            );
+
+            // The encoded message can still be recovered
+            assert_eq!(
+                ExponentialElGamal::decrypt_to_point(&parameters, &sk, &ciphertext).unwrap(),
+                parameters.generator.mul(message).into_affine()
+            );
+        }
+    }
+
+    #[test]
+    fn reuse_table() {
+        let rng = &mut thread_rng();
+        let generator = Curve::rand(rng).into_affine();
+        let table = DiscreteLogTable::new(&generator, 1 << 12);
+
+        let x = 1234u64;
+        let point = generator.mul(Scalar::from(x)).into_affine();
+        assert_eq!(Ok(x), table.discrete_log(&point));
+
+        let parameters = Parameters::from_table(table.clone());
+        let (pk, sk) = ExponentialElGamal::keygen(&parameters, rng).unwrap();
+        let ciphertext =
+            ExponentialElGamal::encrypt(&parameters, &pk, &Scalar::from(x), &Scalar::rand(rng))
+                .unwrap();
+        assert_eq!(
+            Scalar::from(x),
+            ExponentialElGamal::decrypt(&parameters, &sk, &ciphertext).unwrap()
+        );
+
+        // Only the generator and the bound are serialized
+        let mut serialized = vec![0; parameters.serialized_size()];
+        parameters.serialize(&mut serialized[..]).unwrap();
+        let deserialized = Parameters::deserialize(&serialized[..]).unwrap();
+
+        assert_eq!(deserialized.generator, generator);
+        assert_eq!(deserialized.table().bound(), table.bound());
+        assert_eq!(
+            Scalar::from(x),
+            ExponentialElGamal::decrypt(&deserialized, &sk, &ciphertext).unwrap()
+        );
+    }
+
+    #[test]
+    fn reject_oversized_bound() {
+        let rng = &mut thread_rng();
+        let generator = Curve::rand(rng).into_affine();
+
+        // A crafted bound must not make the deserializer build a huge table
+        let mut serialized = Vec::new();
+        generator.serialize(&mut serialized).unwrap();
+        (exponential_el_gamal::MAX_MESSAGE_BOUND + 1)
+            .serialize(&mut serialized)
+            .unwrap();
+        assert!(Parameters::deserialize(&serialized[..]).is_err());
+    }
+
+    #[test]
+    fn integer_sqrt() {
+        use exponential_el_gamal::discrete_log::integer_sqrt_ceil;
+
+        assert_eq!(integer_sqrt_ceil(0), 0);
+        assert_eq!(integer_sqrt_ceil(1), 1);
+        assert_eq!(integer_sqrt_ceil(16), 4);
+        assert_eq!(integer_sqrt_ceil(17), 5);
+        assert_eq!(integer_sqrt_ceil(u64::MAX), 1 << 32);
+    }
+}
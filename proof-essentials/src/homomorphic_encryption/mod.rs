@@ -1,11 +1,15 @@
 use crate::error::CryptoError;
 use ark_ff::{Field, ToBytes, Zero};
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
-use ark_std::rand::Rng;
+use ark_std::{rand::Rng, UniformRand};
+use std::fmt::Debug;
 use std::iter::Sum;
 use std::ops;
 
 pub mod el_gamal;
+pub mod exponential_el_gamal;
+pub mod paillier;
+pub mod wide;
 
 /// Trait defining the types and functions needed for an additively homomorphic encryption scheme.
 /// The scheme is defined with respect to a finite field `F` for which scalar multiplication is preserved.
@@ -38,7 +42,20 @@ pub trait HomomorphicEncryptionScheme<Scalar: Field> {
         + CanonicalDeserialize
         + Sum
         + Zero
-        + ToBytes;
+        + ToBytes
+        + Accumulate<Scalar>;
+
+    /// Randomness used to encrypt a message. Re-encryption factors are combined linearly by the arguments, hence
+    /// the randomness must form a module over `Scalar`. For most schemes this is `Scalar` itself.
+    type Randomness: Copy
+        + ops::Add<Output = Self::Randomness>
+        + ops::Neg<Output = Self::Randomness>
+        + ops::Mul<Scalar, Output = Self::Randomness>
+        + CanonicalSerialize
+        + CanonicalDeserialize
+        + Sum
+        + Zero
+        + UniformRand;
 
     /// Generate the scheme's parameters.
     fn setup<R: Rng>(rng: &mut R) -> Result<Self::Parameters, CryptoError>;
@@ -57,7 +74,92 @@ pub trait HomomorphicEncryptionScheme<Scalar: Field> {
         pp: &Self::Parameters,
         pk: &Self::PublicKey,
         message: &Self::Plaintext,
-        r: &Scalar,
+        r: &Self::Randomness,
+    ) -> Result<Self::Ciphertext, CryptoError>;
+
+    /// Recover a message from the provided ciphertext using a private key.
+    fn decrypt(
+        pp: &Self::Parameters,
+        sk: &Self::SecretKey,
+        ciphertext: &Self::Ciphertext,
+    ) -> Result<Self::Plaintext, CryptoError>;
+}
+
+/// Values which can be accumulated in a representation where additions and scalar multiplications need no
+/// normalization, such as projective coordinates for elliptic curve points. Long linear combinations, e.g. dot
+/// products of ciphertexts or commitments, are computed on accumulators and converted back with a single batch
+/// normalization.
+pub trait Accumulate<Scalar: Field>: Sized {
+    type Accumulator: Copy
+        + PartialEq
+        + ops::Add<Output = Self::Accumulator>
+        + ops::Mul<Scalar, Output = Self::Accumulator>
+        + Sum
+        + Zero;
+
+    fn accumulator(&self) -> Self::Accumulator;
+
+    /// Compute `sum(scalars_i * values_i)`, ignoring the extra entries of the longer slice. The default
+    /// implementation performs one scalar multiplication per value: group-valued types override it with a
+    /// multi-scalar multiplication, whose cost grows sub-linearly in the number of values.
+    fn linear_combination(values: &[Self], scalars: &[Scalar]) -> Self::Accumulator {
+        values
+            .iter()
+            .zip(scalars.iter())
+            .map(|(value, &scalar)| value.accumulator() * scalar)
+            .sum()
+    }
+
+    /// Convert accumulators back, sharing the cost of the normalization between all of them.
+    fn normalize_batch(accumulators: &[Self::Accumulator]) -> Vec<Self>;
+
+    fn normalize(accumulator: &Self::Accumulator) -> Self {
+        Self::normalize_batch(std::slice::from_ref(accumulator)).remove(0)
+    }
+}
+
+pub type CiphertextAccumulator<Scalar, Enc> =
+    <<Enc as HomomorphicEncryptionScheme<Scalar>>::Ciphertext as Accumulate<Scalar>>::Accumulator;
+
+/// Additively homomorphic encryption scheme whose plaintexts live in a ring which is only known once keys are
+/// generated, such as Paillier where messages are integers modulo the RSA modulus of the public key.
+///
+/// [`HomomorphicEncryptionScheme`] cannot describe such schemes: it fixes the ring acting on ciphertexts to a
+/// `Field` chosen at compile time, and its arithmetic is implemented through `ops::Add` and `ops::Mul` which have
+/// no access to the public key. This trait lifts both restrictions: the ring is an associated type and the
+/// homomorphic operations receive the public key.
+///
+/// The zero-knowledge arguments of this crate remain bound to [`HomomorphicEncryptionScheme`], see the
+/// documentation of [`paillier`] for the reason.
+pub trait RingHomomorphicEncryptionScheme {
+    type Parameters;
+    type PublicKey;
+    type SecretKey;
+
+    /// Ring acting on ciphertexts by homomorphic scalar multiplication.
+    type Scalar: Clone;
+    type Plaintext: Clone + PartialEq + Debug;
+    type Randomness;
+    type Ciphertext: Clone + PartialEq + Debug;
+
+    /// Generate the scheme's parameters.
+    fn setup<R: Rng>(rng: &mut R) -> Result<Self::Parameters, CryptoError>;
+
+    /// Generate a public key and a private key.
+    fn keygen<R: Rng>(
+        pp: &Self::Parameters,
+        rng: &mut R,
+    ) -> Result<(Self::PublicKey, Self::SecretKey), CryptoError>;
+
+    /// Sample fresh encryption randomness for the provided public key.
+    fn sample_randomness<R: Rng>(pk: &Self::PublicKey, rng: &mut R) -> Self::Randomness;
+
+    /// Encrypt a message using the provided public key and randomness.
+    fn encrypt(
+        pp: &Self::Parameters,
+        pk: &Self::PublicKey,
+        message: &Self::Plaintext,
+        r: &Self::Randomness,
     ) -> Result<Self::Ciphertext, CryptoError>;
 
     /// Recover a message from the provided ciphertext using a private key.
@@ -66,4 +168,14 @@ pub trait HomomorphicEncryptionScheme<Scalar: Field> {
         sk: &Self::SecretKey,
         ciphertext: &Self::Ciphertext,
     ) -> Result<Self::Plaintext, CryptoError>;
+
+    /// Compute a ciphertext of the sum of the plaintexts of `a` and `b`.
+    fn add(pk: &Self::PublicKey, a: &Self::Ciphertext, b: &Self::Ciphertext) -> Self::Ciphertext;
+
+    /// Compute a ciphertext of the plaintext of `ciphertext` multiplied by `scalar`.
+    fn mul(
+        pk: &Self::PublicKey,
+        ciphertext: &Self::Ciphertext,
+        scalar: &Self::Scalar,
+    ) -> Self::Ciphertext;
 }
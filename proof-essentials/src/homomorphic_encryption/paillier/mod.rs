@@ -0,0 +1,205 @@
+//! Paillier encryption, an additively homomorphic scheme over the integers modulo an RSA modulus `n`.
+//!
+//! Paillier implements [`RingHomomorphicEncryptionScheme`] but not [`HomomorphicEncryptionScheme`]:
+//! - its plaintexts live in `Z_n`, which is not a field and whose modulus is only known after key generation,
+//!   so it cannot be the `Scalar: Field` type parameter of the trait;
+//! - homomorphic addition and scalar multiplication need `n^2`, which `ops::Add` and `ops::Mul` cannot access.
+//!
+//! As a consequence, the shuffle, multi-exponentiation and other arguments of this crate cannot run over Paillier
+//! ciphertexts, and reworking their trait bounds would not be enough. They are Bayer-Groth style arguments whose
+//! soundness relies on every relation holding modulo a single prime `q`: the scalar field of the curve, which is
+//! at the same time the message space of the Pedersen commitments, the exponent group of the ciphertexts and the
+//! field the challenges are drawn from. With Paillier the ciphertext exponents live modulo `n * phi(n)`, which is
+//! unknown to the prover and verifier and unrelated to `q`, so a relation proven over `Z_q` says nothing about the
+//! plaintexts. Arguing about Paillier ciphertexts requires integer commitments over a group of hidden order and
+//! range checks on the responses to control the slack (Damgard-Fujisaki), which is a different construction.
+//!
+//! [`HomomorphicEncryptionScheme`]: crate::homomorphic_encryption::HomomorphicEncryptionScheme
+
+use crate::error::CryptoError;
+use crate::homomorphic_encryption::RingHomomorphicEncryptionScheme;
+
+use ark_std::rand::Rng;
+use num_bigint::{BigUint, RandBigInt};
+use num_traits::One;
+
+pub mod prime;
+mod tests;
+
+/// Size of the RSA modulus generated by `setup`.
+pub const DEFAULT_MODULUS_BITS: usize = 2048;
+
+/// Smallest modulus accepted by [`Parameters::new`]. Far too small to be secure, but useful for testing.
+pub const MIN_MODULUS_BITS: usize = 64;
+
+pub struct Paillier;
+
+/// Size of the modulus to generate. Only built through [`Parameters::new`], so that key generation can rely on an
+/// even size of at least [`MIN_MODULUS_BITS`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Parameters {
+    modulus_bits: usize,
+}
+
+impl Parameters {
+    pub fn new(modulus_bits: usize) -> Result<Self, CryptoError> {
+        if modulus_bits < MIN_MODULUS_BITS || !modulus_bits.is_multiple_of(2) {
+            return Err(CryptoError::InvalidModulusSize(modulus_bits));
+        }
+
+        Ok(Self { modulus_bits })
+    }
+
+    pub fn modulus_bits(&self) -> usize {
+        self.modulus_bits
+    }
+}
+
+/// Public key: the RSA modulus `n`. The generator is fixed to `n + 1`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PublicKey {
+    n: BigUint,
+    n_squared: BigUint,
+}
+
+impl PublicKey {
+    pub fn new(n: BigUint) -> Self {
+        let n_squared = &n * &n;
+        Self { n, n_squared }
+    }
+
+    pub fn modulus(&self) -> &BigUint {
+        &self.n
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SecretKey {
+    public_key: PublicKey,
+    phi: BigUint,
+    mu: BigUint,
+}
+
+pub type Plaintext = BigUint;
+
+pub type Scalar = BigUint;
+
+pub type Randomness = BigUint;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Ciphertext(pub BigUint);
+
+impl Paillier {
+    /// Generate parameters for a modulus of `modulus_bits` bits.
+    pub fn setup_with_modulus_bits(modulus_bits: usize) -> Result<Parameters, CryptoError> {
+        Parameters::new(modulus_bits)
+    }
+}
+
+impl RingHomomorphicEncryptionScheme for Paillier {
+    type Parameters = Parameters;
+    type PublicKey = PublicKey;
+    type SecretKey = SecretKey;
+    type Scalar = Scalar;
+    type Plaintext = Plaintext;
+    type Randomness = Randomness;
+    type Ciphertext = Ciphertext;
+
+    fn setup<R: Rng>(_rng: &mut R) -> Result<Self::Parameters, CryptoError> {
+        Parameters::new(DEFAULT_MODULUS_BITS)
+    }
+
+    fn keygen<R: Rng>(
+        pp: &Self::Parameters,
+        rng: &mut R,
+    ) -> Result<(Self::PublicKey, Self::SecretKey), CryptoError> {
+        let prime_bits = (pp.modulus_bits / 2) as u64;
+
+        let (p, q) = loop {
+            let p = prime::random_prime(rng, prime_bits)?;
+            let q = prime::random_prime(rng, prime_bits)?;
+            if p != q {
+                break (p, q);
+            }
+        };
+
+        let public_key = PublicKey::new(&p * &q);
+
+        // With g = n + 1, decryption only needs phi(n) and its inverse modulo n
+        let phi = (p - 1u32) * (q - 1u32);
+        let mu = phi
+            .modinv(&public_key.n)
+            .ok_or(CryptoError::InvalidModulusSize(pp.modulus_bits))?;
+
+        let secret_key = SecretKey {
+            public_key: public_key.clone(),
+            phi,
+            mu,
+        };
+
+        Ok((public_key, secret_key))
+    }
+
+    fn sample_randomness<R: Rng>(pk: &Self::PublicKey, rng: &mut R) -> Self::Randomness {
+        loop {
+            let r = rng.gen_biguint_below(&pk.n);
+            if is_unit(&r, &pk.n) {
+                return r;
+            }
+        }
+    }
+
+    fn encrypt(
+        _pp: &Self::Parameters,
+        pk: &Self::PublicKey,
+        message: &Self::Plaintext,
+        r: &Self::Randomness,
+    ) -> Result<Self::Ciphertext, CryptoError> {
+        if *message >= pk.n {
+            return Err(CryptoError::PlaintextOutOfRange);
+        }
+        if !is_unit(r, &pk.n) {
+            return Err(CryptoError::InvalidRandomness);
+        }
+
+        // (n + 1)^m = 1 + m*n mod n^2
+        let g_m = (BigUint::one() + message * &pk.n) % &pk.n_squared;
+        let r_n = r.modpow(&pk.n, &pk.n_squared);
+
+        Ok(Ciphertext((g_m * r_n) % &pk.n_squared))
+    }
+
+    fn decrypt(
+        _pp: &Self::Parameters,
+        sk: &Self::SecretKey,
+        ciphertext: &Self::Ciphertext,
+    ) -> Result<Self::Plaintext, CryptoError> {
+        let pk = &sk.public_key;
+        if ciphertext.0 >= pk.n_squared || !is_unit(&ciphertext.0, &pk.n_squared) {
+            return Err(CryptoError::InvalidCiphertext);
+        }
+
+        // m = L(c^phi mod n^2) * phi^-1 mod n, with L(x) = (x - 1) / n
+        let u = ciphertext.0.modpow(&sk.phi, &pk.n_squared);
+        let l = (u - 1u32) / &pk.n;
+
+        Ok((l * &sk.mu) % &pk.n)
+    }
+
+    fn add(pk: &Self::PublicKey, a: &Self::Ciphertext, b: &Self::Ciphertext) -> Self::Ciphertext {
+        Ciphertext((&a.0 * &b.0) % &pk.n_squared)
+    }
+
+    fn mul(
+        pk: &Self::PublicKey,
+        ciphertext: &Self::Ciphertext,
+        scalar: &Self::Scalar,
+    ) -> Self::Ciphertext {
+        Ciphertext(ciphertext.0.modpow(scalar, &pk.n_squared))
+    }
+}
+
+/// Check that `x` is invertible modulo `modulus`.
+fn is_unit(x: &BigUint, modulus: &BigUint) -> bool {
+    x.modinv(modulus).is_some()
+}
@@ -0,0 +1,75 @@
+use crate::error::CryptoError;
+
+use ark_std::rand::Rng;
+use num_bigint::{BigUint, RandBigInt};
+use num_traits::{One, Zero};
+
+/// Primes used to discard most candidates before running Miller-Rabin.
+const SMALL_PRIMES: [u32; 25] = [
+    2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89, 97,
+];
+
+/// Number of Miller-Rabin rounds. A composite passes all of them with probability at most 4^-40.
+pub const MILLER_RABIN_ROUNDS: usize = 40;
+
+/// Probabilistic primality test: trial division by small primes followed by Miller-Rabin with random bases.
+pub fn is_probable_prime<R: Rng>(n: &BigUint, rounds: usize, rng: &mut R) -> bool {
+    let two = BigUint::from(2u32);
+    if *n < two {
+        return false;
+    }
+
+    for &p in SMALL_PRIMES.iter() {
+        let p = BigUint::from(p);
+        if *n == p {
+            return true;
+        }
+        if (n % &p).is_zero() {
+            return false;
+        }
+    }
+
+    // n - 1 = d * 2^s with d odd
+    let n_minus_one = n - 1u32;
+    let s = n_minus_one.trailing_zeros().unwrap_or(0);
+    let d = &n_minus_one >> s;
+
+    'witness: for _ in 0..rounds {
+        let a = rng.gen_biguint_range(&two, &n_minus_one);
+        let mut x = a.modpow(&d, n);
+
+        if x.is_one() || x == n_minus_one {
+            continue;
+        }
+
+        for _ in 1..s {
+            x = x.modpow(&two, n);
+            if x == n_minus_one {
+                continue 'witness;
+            }
+        }
+
+        return false;
+    }
+
+    true
+}
+
+/// Sample a random prime of exactly `bits` bits whose two most significant bits are set, so that the product of
+/// two such primes has exactly `2 * bits` bits. Fails if `bits < 2`.
+pub fn random_prime<R: Rng>(rng: &mut R, bits: u64) -> Result<BigUint, CryptoError> {
+    if bits < 2 {
+        return Err(CryptoError::InvalidPrimeSize(bits as usize));
+    }
+
+    loop {
+        let mut candidate = rng.gen_biguint(bits);
+        candidate.set_bit(bits - 1, true);
+        candidate.set_bit(bits - 2, true);
+        candidate.set_bit(0, true);
+
+        if is_probable_prime(&candidate, MILLER_RABIN_ROUNDS, rng) {
+            return Ok(candidate);
+        }
+    }
+}
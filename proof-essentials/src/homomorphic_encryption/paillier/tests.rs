@@ -0,0 +1,158 @@
+#[cfg(test)]
+mod test {
+    use crate::error::CryptoError;
+    use crate::homomorphic_encryption::paillier::prime;
+    use crate::homomorphic_encryption::{el_gamal, paillier, RingHomomorphicEncryptionScheme};
+
+    use ark_std::{rand::thread_rng, UniformRand};
+    use num_bigint::{BigUint, RandBigInt};
+    use rand::Rng;
+    use starknet_curve;
+
+    type Curve = starknet_curve::Projective;
+    type Scalar = starknet_curve::Fr;
+    type ElGamal = el_gamal::ElGamal<Curve>;
+    type Paillier = paillier::Paillier;
+
+    /// Check that Dec(alpha * Enc(m1) + Enc(m2)) = expected, for any scheme implementing the ring-based trait.
+    fn check_linear_combination<E: RingHomomorphicEncryptionScheme, R: Rng>(
+        rng: &mut R,
+        pp: &E::Parameters,
+        m1: &E::Plaintext,
+        m2: &E::Plaintext,
+        alpha: &E::Scalar,
+        expected: &E::Plaintext,
+    ) {
+        let (pk, sk) = E::keygen(pp, rng).unwrap();
+
+        let r1 = E::sample_randomness(&pk, rng);
+        let r2 = E::sample_randomness(&pk, rng);
+        let c1 = E::encrypt(pp, &pk, m1, &r1).unwrap();
+        let c2 = E::encrypt(pp, &pk, m2, &r2).unwrap();
+
+        assert_eq!(*m1, E::decrypt(pp, &sk, &c1).unwrap());
+
+        let c3 = E::add(&pk, &E::mul(&pk, &c1, alpha), &c2);
+        assert_eq!(*expected, E::decrypt(pp, &sk, &c3).unwrap());
+    }
+
+    #[test]
+    fn paillier_homomorphic_property() {
+        let rng = &mut thread_rng();
+        let pp = Paillier::setup_with_modulus_bits(512).unwrap();
+
+        let m1 = BigUint::from(1234u32);
+        let m2 = BigUint::from(5678u32);
+        let alpha = BigUint::from(42u32);
+        let expected = BigUint::from(1234u32 * 42 + 5678);
+
+        check_linear_combination::<Paillier, _>(rng, &pp, &m1, &m2, &alpha, &expected);
+    }
+
+    #[test]
+    fn el_gamal_homomorphic_property() {
+        let rng = &mut thread_rng();
+        let pp = <ElGamal as RingHomomorphicEncryptionScheme>::setup(rng).unwrap();
+
+        let m1 = el_gamal::Plaintext::rand(rng);
+        let m2 = el_gamal::Plaintext::rand(rng);
+        let alpha = Scalar::rand(rng);
+        let expected = m1 * alpha + m2;
+
+        check_linear_combination::<ElGamal, _>(rng, &pp, &m1, &m2, &alpha, &expected);
+    }
+
+    #[test]
+    fn paillier_wraps_around_modulus() {
+        let rng = &mut thread_rng();
+        let pp = Paillier::setup_with_modulus_bits(256).unwrap();
+        assert_eq!(pp.modulus_bits(), 256);
+        let (pk, sk) = Paillier::keygen(&pp, rng).unwrap();
+        assert_eq!(pk.modulus().bits(), 256);
+        let n = pk.modulus().clone();
+        assert_eq!(n.bits(), 256);
+
+        let m1 = rng.gen_biguint_below(&n);
+        let m2 = rng.gen_biguint_below(&n);
+        let c1 = Paillier::encrypt(&pp, &pk, &m1, &Paillier::sample_randomness(&pk, rng)).unwrap();
+        let c2 = Paillier::encrypt(&pp, &pk, &m2, &Paillier::sample_randomness(&pk, rng)).unwrap();
+
+        let sum = Paillier::decrypt(&pp, &sk, &Paillier::add(&pk, &c1, &c2)).unwrap();
+        assert_eq!(sum, (&m1 + &m2) % &n);
+
+        // Multiplying by n - 1 negates the plaintext
+        let negated = Paillier::mul(&pk, &c2, &(&n - 1u32));
+        let difference = Paillier::decrypt(&pp, &sk, &Paillier::add(&pk, &c1, &negated)).unwrap();
+        assert_eq!(
+            (difference + &m2) % &n,
+            Paillier::decrypt(&pp, &sk, &c1).unwrap()
+        );
+    }
+
+    #[test]
+    fn paillier_invalid_inputs() {
+        let rng = &mut thread_rng();
+
+        assert_eq!(
+            Paillier::setup_with_modulus_bits(32),
+            Err(CryptoError::InvalidModulusSize(32))
+        );
+        assert_eq!(
+            Paillier::setup_with_modulus_bits(257),
+            Err(CryptoError::InvalidModulusSize(257))
+        );
+        assert_eq!(
+            prime::random_prime(rng, 1),
+            Err(CryptoError::InvalidPrimeSize(1))
+        );
+
+        let pp = Paillier::setup_with_modulus_bits(256).unwrap();
+        assert_eq!(pp.modulus_bits(), 256);
+        let (pk, sk) = Paillier::keygen(&pp, rng).unwrap();
+        assert_eq!(pk.modulus().bits(), 256);
+        let n = pk.modulus().clone();
+        let r = Paillier::sample_randomness(&pk, rng);
+
+        assert_eq!(
+            Paillier::encrypt(&pp, &pk, &n, &r),
+            Err(CryptoError::PlaintextOutOfRange)
+        );
+        assert_eq!(
+            Paillier::encrypt(&pp, &pk, &BigUint::from(1u32), &BigUint::from(0u32)),
+            Err(CryptoError::InvalidRandomness)
+        );
+        assert_eq!(
+            Paillier::decrypt(&pp, &sk, &paillier::Ciphertext(n)),
+            Err(CryptoError::InvalidCiphertext)
+        );
+    }
+
+    #[test]
+    fn primality() {
+        let rng = &mut thread_rng();
+
+        let primes = [2u64, 3, 97, 101, 7919, 2147483647, 18446744073709551557];
+        for p in primes {
+            assert!(prime::is_probable_prime(&BigUint::from(p), 20, rng));
+        }
+
+        // Includes Carmichael numbers
+        let composites = [
+            0u64,
+            1,
+            4,
+            561,
+            1105,
+            8911,
+            2147483649,
+            18446744073709551555,
+        ];
+        for n in composites {
+            assert!(!prime::is_probable_prime(&BigUint::from(n), 20, rng));
+        }
+
+        let p = prime::random_prime(rng, 128).unwrap();
+        assert_eq!(p.bits(), 128);
+        assert!(prime::is_probable_prime(&p, 20, rng));
+    }
+}
@@ -0,0 +1,299 @@
+//! Encryption of fixed-size tuples of messages, such as the suit and the rank of a card encrypted separately.
+//!
+//! [`WideEncryption`] encrypts each component of a [`Wide`] plaintext with the underlying scheme, using independent
+//! randomness per component. Since tuples of ciphertexts, plaintexts and randomness are themselves modules over the
+//! scalar field, the wide scheme implements [`HomomorphicEncryptionScheme`] and every argument of this crate can
+//! run over it. In particular, the shuffle argument permutes tuples as a whole, i.e. all the components of the
+//! tuples are shuffled by the same hidden permutation, and produces a single proof.
+
+use crate::error::CryptoError;
+use crate::homomorphic_encryption::{Accumulate, HomomorphicEncryptionScheme};
+use crate::utils::validation::Validate;
+
+use ark_ff::{Field, ToBytes, Zero};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::{
+    io::{Read, Write},
+    marker::PhantomData,
+    rand::Rng,
+    UniformRand,
+};
+use std::array;
+use std::iter::Sum;
+use std::ops;
+
+mod tests;
+
+/// Wide encryption scheme: encrypts tuples of `K` messages of the scheme `Enc`.
+pub struct WideEncryption<Enc, const K: usize> {
+    _encryption_scheme: PhantomData<Enc>,
+}
+
+/// Tuple of `K` values on which arithmetic operations are performed component-wise.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Wide<T, const K: usize>(pub [T; K]);
+
+pub type Plaintext<Scalar, Enc, const K: usize> =
+    Wide<<Enc as HomomorphicEncryptionScheme<Scalar>>::Plaintext, K>;
+
+pub type Generator<Scalar, Enc, const K: usize> =
+    Wide<<Enc as HomomorphicEncryptionScheme<Scalar>>::Generator, K>;
+
+pub type Ciphertext<Scalar, Enc, const K: usize> =
+    Wide<<Enc as HomomorphicEncryptionScheme<Scalar>>::Ciphertext, K>;
+
+pub type Randomness<Scalar, Enc, const K: usize> =
+    Wide<<Enc as HomomorphicEncryptionScheme<Scalar>>::Randomness, K>;
+
+impl<T, const K: usize> Wide<T, K> {
+    pub fn new(components: [T; K]) -> Self {
+        Self(components)
+    }
+
+    pub fn components(&self) -> &[T; K] {
+        &self.0
+    }
+
+    /// Transpose `K` columns of equal length into a vector of tuples: the i-th tuple contains the i-th element of
+    /// every column.
+    pub fn from_columns(columns: &[Vec<T>; K]) -> Result<Vec<Self>, CryptoError>
+    where
+        T: Copy,
+    {
+        let len = columns.first().map_or(0, |column| column.len());
+        if let Some(column) = columns.iter().find(|column| column.len() != len) {
+            return Err(CryptoError::WideLengthError(len, column.len()));
+        }
+
+        Ok((0..len)
+            .map(|i| Self(array::from_fn(|j| columns[j][i])))
+            .collect())
+    }
+
+    /// Inverse of [`Wide::from_columns`].
+    pub fn to_columns(tuples: &[Self]) -> [Vec<T>; K]
+    where
+        T: Copy,
+    {
+        array::from_fn(|j| tuples.iter().map(|tuple| tuple.0[j]).collect())
+    }
+}
+
+impl<T: ops::Add, const K: usize> ops::Add for Wide<T, K> {
+    type Output = Wide<T::Output, K>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let mut rhs = rhs.0.into_iter();
+        Wide(self.0.map(|lhs| lhs + rhs.next().unwrap()))
+    }
+}
+
+impl<T: ops::Neg<Output = T>, const K: usize> ops::Neg for Wide<T, K> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(self.0.map(|x| -x))
+    }
+}
+
+impl<T: ops::Mul<S>, S: Copy, const K: usize> ops::Mul<S> for Wide<T, K> {
+    type Output = Wide<T::Output, K>;
+
+    fn mul(self, x: S) -> Self::Output {
+        Wide(self.0.map(|component| component * x))
+    }
+}
+
+impl<T: Zero, const K: usize> Zero for Wide<T, K> {
+    fn zero() -> Self {
+        Self(array::from_fn(|_| T::zero()))
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0.iter().all(|component| component.is_zero())
+    }
+}
+
+impl<T: Zero, const K: usize> Sum for Wide<T, K> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |a, b| a + b)
+    }
+}
+
+impl<T: UniformRand, const K: usize> UniformRand for Wide<T, K> {
+    fn rand<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Self(array::from_fn(|_| T::rand(rng)))
+    }
+}
+
+impl<T: ToBytes, const K: usize> ToBytes for Wide<T, K> {
+    fn write<W: Write>(&self, mut w: W) -> ark_std::io::Result<()> {
+        self.0
+            .iter()
+            .try_for_each(|component| component.write(&mut w))
+    }
+}
+
+impl<T: CanonicalSerialize, const K: usize> CanonicalSerialize for Wide<T, K> {
+    fn serialize<W: Write>(&self, mut writer: W) -> Result<(), SerializationError> {
+        self.0
+            .iter()
+            .try_for_each(|component| component.serialize(&mut writer))
+    }
+
+    fn serialized_size(&self) -> usize {
+        self.0
+            .iter()
+            .map(|component| component.serialized_size())
+            .sum()
+    }
+
+    fn serialize_uncompressed<W: Write>(&self, mut writer: W) -> Result<(), SerializationError> {
+        self.0
+            .iter()
+            .try_for_each(|component| component.serialize_uncompressed(&mut writer))
+    }
+
+    fn serialize_unchecked<W: Write>(&self, mut writer: W) -> Result<(), SerializationError> {
+        self.0
+            .iter()
+            .try_for_each(|component| component.serialize_unchecked(&mut writer))
+    }
+
+    fn uncompressed_size(&self) -> usize {
+        self.0
+            .iter()
+            .map(|component| component.uncompressed_size())
+            .sum()
+    }
+}
+
+impl<T: CanonicalDeserialize, const K: usize> CanonicalDeserialize for Wide<T, K> {
+    fn deserialize<R: Read>(mut reader: R) -> Result<Self, SerializationError> {
+        Self::deserialize_components(|| T::deserialize(&mut reader))
+    }
+
+    fn deserialize_uncompressed<R: Read>(mut reader: R) -> Result<Self, SerializationError> {
+        Self::deserialize_components(|| T::deserialize_uncompressed(&mut reader))
+    }
+
+    fn deserialize_unchecked<R: Read>(mut reader: R) -> Result<Self, SerializationError> {
+        Self::deserialize_components(|| T::deserialize_unchecked(&mut reader))
+    }
+}
+
+impl<T, const K: usize> Wide<T, K> {
+    fn deserialize_components<F>(mut read_component: F) -> Result<Self, SerializationError>
+    where
+        F: FnMut() -> Result<T, SerializationError>,
+    {
+        let components = (0..K)
+            .map(|_| read_component())
+            .collect::<Result<Vec<T>, SerializationError>>()?;
+
+        match components.try_into() {
+            Ok(components) => Ok(Self(components)),
+            Err(_) => Err(SerializationError::InvalidData),
+        }
+    }
+}
+
+impl<Scalar: Field, T: Accumulate<Scalar> + Copy, const K: usize> Accumulate<Scalar>
+    for Wide<T, K>
+{
+    type Accumulator = Wide<T::Accumulator, K>;
+
+    fn accumulator(&self) -> Self::Accumulator {
+        Wide(array::from_fn(|j| self.0[j].accumulator()))
+    }
+
+    fn linear_combination(values: &[Self], scalars: &[Scalar]) -> Self::Accumulator {
+        Wide(array::from_fn(|j| {
+            let components = values.iter().map(|value| value.0[j]).collect::<Vec<_>>();
+            T::linear_combination(&components, scalars)
+        }))
+    }
+
+    fn normalize_batch(accumulators: &[Self::Accumulator]) -> Vec<Self> {
+        let components = accumulators
+            .iter()
+            .flat_map(|accumulator| accumulator.0)
+            .collect::<Vec<_>>();
+
+        let mut components = T::normalize_batch(&components).into_iter();
+
+        accumulators
+            .iter()
+            .map(|_| Wide(array::from_fn(|_| components.next().unwrap())))
+            .collect()
+    }
+}
+
+impl<T: Validate, const K: usize> Validate for Wide<T, K> {
+    fn validate(&self) -> Result<(), CryptoError> {
+        self.0.iter().try_for_each(|component| component.validate())
+    }
+}
+
+impl<Scalar, Enc, const K: usize> HomomorphicEncryptionScheme<Scalar> for WideEncryption<Enc, K>
+where
+    Scalar: Field,
+    Enc: HomomorphicEncryptionScheme<Scalar>,
+{
+    type Parameters = Enc::Parameters;
+    type PublicKey = Enc::PublicKey;
+    type SecretKey = Enc::SecretKey;
+    type Generator = Generator<Scalar, Enc, K>;
+    type Plaintext = Plaintext<Scalar, Enc, K>;
+    type Ciphertext = Ciphertext<Scalar, Enc, K>;
+    type Randomness = Randomness<Scalar, Enc, K>;
+
+    fn setup<R: Rng>(rng: &mut R) -> Result<Self::Parameters, CryptoError> {
+        Enc::setup(rng)
+    }
+
+    fn generator<R: Rng>(rng: &mut R) -> Result<Self::Generator, CryptoError> {
+        let generators = (0..K)
+            .map(|_| Enc::generator(rng))
+            .collect::<Result<Vec<_>, CryptoError>>()?;
+
+        Ok(Wide(array::from_fn(|j| generators[j])))
+    }
+
+    fn keygen<R: Rng>(
+        pp: &Self::Parameters,
+        rng: &mut R,
+    ) -> Result<(Self::PublicKey, Self::SecretKey), CryptoError> {
+        Enc::keygen(pp, rng)
+    }
+
+    fn encrypt(
+        pp: &Self::Parameters,
+        pk: &Self::PublicKey,
+        message: &Self::Plaintext,
+        r: &Self::Randomness,
+    ) -> Result<Self::Ciphertext, CryptoError> {
+        let components = message
+            .0
+            .iter()
+            .zip(r.0.iter())
+            .map(|(m, r)| Enc::encrypt(pp, pk, m, r))
+            .collect::<Result<Vec<_>, CryptoError>>()?;
+
+        Ok(Wide(array::from_fn(|j| components[j])))
+    }
+
+    fn decrypt(
+        pp: &Self::Parameters,
+        sk: &Self::SecretKey,
+        ciphertext: &Self::Ciphertext,
+    ) -> Result<Self::Plaintext, CryptoError> {
+        let components = ciphertext
+            .0
+            .iter()
+            .map(|c| Enc::decrypt(pp, sk, c))
+            .collect::<Result<Vec<_>, CryptoError>>()?;
+
+        Ok(Wide(array::from_fn(|j| components[j])))
+    }
+}
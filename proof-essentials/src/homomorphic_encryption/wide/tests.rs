@@ -0,0 +1,86 @@
+#[cfg(test)]
+mod test {
+    use crate::error::CryptoError;
+    use crate::homomorphic_encryption::{el_gamal, wide, HomomorphicEncryptionScheme};
+    use crate::utils::rand::sample_vector;
+
+    use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+    use ark_std::{rand::thread_rng, UniformRand};
+    use starknet_curve;
+
+    type Curve = starknet_curve::Projective;
+    type Scalar = starknet_curve::Fr;
+    type ElGamal = el_gamal::ElGamal<Curve>;
+    type Plaintext = el_gamal::Plaintext<Curve>;
+    type WideElGamal = wide::WideEncryption<ElGamal, 3>;
+    type WidePlaintext = wide::Plaintext<Scalar, ElGamal, 3>;
+    type WideCiphertext = wide::Ciphertext<Scalar, ElGamal, 3>;
+    type WideRandomness = wide::Randomness<Scalar, ElGamal, 3>;
+
+    #[test]
+    fn homomorphic_property() {
+        let rng = &mut thread_rng();
+
+        let parameters = WideElGamal::setup(rng).unwrap();
+        let (pk, sk) = WideElGamal::keygen(&parameters, rng).unwrap();
+
+        let m1 = WidePlaintext::rand(rng);
+        let c1 = WideElGamal::encrypt(&parameters, &pk, &m1, &WideRandomness::rand(rng)).unwrap();
+        let m2 = WidePlaintext::rand(rng);
+        let c2 = WideElGamal::encrypt(&parameters, &pk, &m2, &WideRandomness::rand(rng)).unwrap();
+
+        assert_eq!(m1, WideElGamal::decrypt(&parameters, &sk, &c1).unwrap());
+
+        let alpha = Scalar::rand(rng);
+        let c3 = c1 * alpha + c2;
+        assert_eq!(
+            m1 * alpha + m2,
+            WideElGamal::decrypt(&parameters, &sk, &c3).unwrap()
+        );
+
+        // Each component is an encryption under the underlying scheme
+        for (m, c) in m1.components().iter().zip(c1.components()) {
+            assert_eq!(*m, ElGamal::decrypt(&parameters, &sk, c).unwrap());
+        }
+    }
+
+    #[test]
+    fn serialize_deserialize() {
+        let rng = &mut thread_rng();
+        let ciphertext = WideCiphertext::rand(rng);
+
+        let mut serialized = Vec::new();
+        ciphertext.serialize(&mut serialized).unwrap();
+        assert_eq!(serialized.len(), ciphertext.serialized_size());
+
+        assert_eq!(
+            ciphertext,
+            WideCiphertext::deserialize(&serialized[..]).unwrap()
+        );
+        assert!(WideCiphertext::deserialize(&serialized[1..]).is_err());
+    }
+
+    #[test]
+    fn columns() {
+        let rng = &mut thread_rng();
+        let suits: Vec<Plaintext> = sample_vector(rng, 5);
+        let ranks: Vec<Plaintext> = sample_vector(rng, 5);
+        let ids: Vec<Plaintext> = sample_vector(rng, 5);
+
+        let columns = [suits, ranks, ids];
+        let tuples = WidePlaintext::from_columns(&columns).unwrap();
+        assert_eq!(tuples.len(), 5);
+        assert_eq!(tuples[2].components()[1], columns[1][2]);
+        assert_eq!(WidePlaintext::to_columns(&tuples), columns);
+
+        let uneven = [
+            columns[0].clone(),
+            columns[1][..4].to_vec(),
+            columns[2].clone(),
+        ];
+        assert_eq!(
+            WidePlaintext::from_columns(&uneven),
+            Err(CryptoError::WideLengthError(5, 4))
+        );
+    }
+}
@@ -1,3 +1,4 @@
+pub mod curves;
 pub mod error;
 pub mod homomorphic_encryption;
 pub mod utils;
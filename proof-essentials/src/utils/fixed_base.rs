@@ -0,0 +1,79 @@
+use ark_ec::{msm::FixedBaseMSM, ProjectiveCurve};
+use ark_ff::{FpParameters, PrimeField};
+
+/// Window size used when none is specified. A table then holds `2^8` points per 8 bits of scalar.
+pub const DEFAULT_WINDOW_SIZE: usize = 8;
+
+/// Windowed precomputation of the multiples of a fixed base, which speeds up repeated scalar multiplications of
+/// that base. Building a table costs about as much as `2^window / window` variable-base multiplications.
+#[derive(Clone)]
+pub struct FixedBaseTable<C: ProjectiveCurve> {
+    window: usize,
+    scalar_size: usize,
+    table: Vec<Vec<C::Affine>>,
+}
+
+impl<C: ProjectiveCurve> FixedBaseTable<C> {
+    pub fn new(base: &C::Affine) -> Self {
+        Self::with_window_size(base, DEFAULT_WINDOW_SIZE)
+    }
+
+    pub fn with_window_size(base: &C::Affine, window: usize) -> Self {
+        let scalar_size = <C::ScalarField as PrimeField>::Params::MODULUS_BITS as usize;
+        let table = FixedBaseMSM::get_window_table(scalar_size, window, C::from(*base));
+
+        Self {
+            window,
+            scalar_size,
+            table,
+        }
+    }
+
+    pub fn window_size(&self) -> usize {
+        self.window
+    }
+
+    /// Return `scalar * base`.
+    pub fn mul(&self, scalar: &C::ScalarField) -> C {
+        let outerc = self.scalar_size.div_ceil(self.window);
+        FixedBaseMSM::windowed_mul::<C>(outerc, self.window, &self.table, scalar)
+    }
+
+    /// Return `scalar * base` for each scalar, in projective coordinates.
+    pub fn batch_mul(&self, scalars: &[C::ScalarField]) -> Vec<C> {
+        FixedBaseMSM::multi_scalar_mul::<C>(self.scalar_size, self.window, &self.table, scalars)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::rand::sample_vector;
+    use ark_ec::AffineCurve;
+    use ark_ff::Zero;
+    use ark_std::{rand::thread_rng, UniformRand};
+
+    type Curve = starknet_curve::Projective;
+    type Scalar = starknet_curve::Fr;
+
+    #[test]
+    fn fixed_base_mul() {
+        let rng = &mut thread_rng();
+        let base = Curve::rand(rng).into_affine();
+
+        for window in [3, DEFAULT_WINDOW_SIZE, 11] {
+            let table = FixedBaseTable::<Curve>::with_window_size(&base, window);
+
+            let scalars: Vec<Scalar> = sample_vector(rng, 5);
+            let expected = scalars
+                .iter()
+                .map(|s| base.mul(s.into_repr()))
+                .collect::<Vec<_>>();
+
+            assert_eq!(table.batch_mul(&scalars), expected);
+            assert_eq!(table.mul(&scalars[0]), expected[0]);
+            assert!(table.mul(&Scalar::zero()).is_zero());
+            assert_eq!(table.mul(&-Scalar::from(1u64)), -base.into_projective());
+        }
+    }
+}
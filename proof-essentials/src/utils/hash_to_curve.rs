@@ -0,0 +1,64 @@
+use ark_ec::ProjectiveCurve;
+
+/// Curves for which a hash-to-curve function is available. Points obtained this way have no known discrete
+/// logarithm relation to each other, which allows deriving public parameters without a trusted setup.
+pub trait HashToCurve: ProjectiveCurve {
+    /// Hash `msg` to a point of the prime order subgroup, using `dst` as domain separation tag.
+    fn hash_to_curve(msg: &[u8], dst: &[u8]) -> Self::Affine;
+}
+
+impl HashToCurve for starknet_curve::Projective {
+    fn hash_to_curve(msg: &[u8], dst: &[u8]) -> Self::Affine {
+        starknet_curve::hash_to_curve::hash_to_curve(msg, dst)
+    }
+}
+
+/// Deterministically derive `count` points from a label ("nothing-up-my-sleeve" generators). The i-th point
+/// is the hash of the length of the label, the label and i, so that labels cannot collide and the points
+/// derived for a given label are the same regardless of `count`.
+pub fn derive_generators<C: HashToCurve>(dst: &[u8], label: &[u8], count: usize) -> Vec<C::Affine> {
+    (0..count)
+        .map(|i| {
+            let msg = [
+                &(label.len() as u64).to_le_bytes()[..],
+                label,
+                &(i as u64).to_le_bytes()[..],
+            ]
+            .concat();
+            C::hash_to_curve(&msg, dst)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ff::Zero;
+
+    type Curve = starknet_curve::Projective;
+
+    #[test]
+    fn derive_generators_test() {
+        let points = derive_generators::<Curve>(b"test", b"label", 10);
+
+        assert_eq!(points, derive_generators::<Curve>(b"test", b"label", 10));
+        assert_eq!(
+            points[..4],
+            derive_generators::<Curve>(b"test", b"label", 4)[..]
+        );
+        assert_ne!(
+            points,
+            derive_generators::<Curve>(b"test", b"other label", 10)
+        );
+        assert_ne!(
+            points,
+            derive_generators::<Curve>(b"other test", b"label", 10)
+        );
+
+        for (i, point) in points.iter().enumerate() {
+            assert!(!point.is_zero());
+            assert!(point.is_in_correct_subgroup_assuming_on_curve());
+            assert!(!points[..i].contains(point));
+        }
+    }
+}
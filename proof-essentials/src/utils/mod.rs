@@ -1,3 +1,6 @@
+pub mod fixed_base;
+pub mod hash_to_curve;
 pub mod permutation;
 pub mod rand;
+pub mod validation;
 pub mod vector_arithmetic;
@@ -1,6 +1,7 @@
 use rand::{seq::SliceRandom, Rng};
 
 /// Represent a permutation pi as a vector such that for all indices i, vec(i) = pi(i)
+#[derive(Clone)]
 pub struct Permutation {
     pub mapping: Vec<usize>,
     pub size: usize,
@@ -0,0 +1,200 @@
+//! Validation of points received from untrusted parties.
+//!
+//! The `CanonicalDeserialize` implementations of this crate's types only perform the checks of the underlying
+//! curve implementation, which differ between formats: `deserialize_unchecked` performs none, and identity points
+//! are always accepted. Values obtained from untrusted peers should either go through [`deserialize_validated`]
+//! or be checked with [`Validate::validate`] before use.
+
+use crate::error::CryptoError;
+
+use ark_ec::{
+    short_weierstrass_jacobian, twisted_edwards_extended, AffineCurve, ProjectiveCurve,
+    SWModelParameters, TEModelParameters,
+};
+use ark_serialize::CanonicalDeserialize;
+use ark_std::io::Read;
+
+/// Membership checks for affine points of the supported curve models.
+pub trait ValidatePoint: AffineCurve {
+    fn is_on_curve(&self) -> bool;
+
+    fn is_in_correct_subgroup_assuming_on_curve(&self) -> bool;
+}
+
+impl<P: SWModelParameters> ValidatePoint for short_weierstrass_jacobian::GroupAffine<P> {
+    fn is_on_curve(&self) -> bool {
+        short_weierstrass_jacobian::GroupAffine::is_on_curve(self)
+    }
+
+    fn is_in_correct_subgroup_assuming_on_curve(&self) -> bool {
+        short_weierstrass_jacobian::GroupAffine::is_in_correct_subgroup_assuming_on_curve(self)
+    }
+}
+
+impl<P: TEModelParameters> ValidatePoint for twisted_edwards_extended::GroupAffine<P> {
+    fn is_on_curve(&self) -> bool {
+        twisted_edwards_extended::GroupAffine::is_on_curve(self)
+    }
+
+    fn is_in_correct_subgroup_assuming_on_curve(&self) -> bool {
+        twisted_edwards_extended::GroupAffine::is_in_correct_subgroup_assuming_on_curve(self)
+    }
+}
+
+/// Check that a value only contains points which are on the curve and in the prime order subgroup, and no
+/// identity point where it is forbidden.
+pub trait Validate {
+    fn validate(&self) -> Result<(), CryptoError>;
+}
+
+/// Check that `point` is on the curve and in the prime order subgroup. The identity is accepted.
+pub fn validate_point<A: ValidatePoint>(point: &A) -> Result<(), CryptoError> {
+    if !point.is_on_curve() {
+        return Err(CryptoError::PointNotOnCurve);
+    }
+
+    if !point.is_in_correct_subgroup_assuming_on_curve() {
+        return Err(CryptoError::PointNotInSubgroup);
+    }
+
+    Ok(())
+}
+
+/// Check that `point` is on the curve, in the prime order subgroup and is not the identity.
+pub fn validate_non_identity_point<A: ValidatePoint>(point: &A) -> Result<(), CryptoError> {
+    validate_point(point)?;
+
+    if point.is_zero() {
+        return Err(CryptoError::IdentityPoint);
+    }
+
+    Ok(())
+}
+
+/// Check a point given in projective coordinates.
+pub fn validate_projective_point<C>(point: &C) -> Result<(), CryptoError>
+where
+    C: ProjectiveCurve,
+    C::Affine: ValidatePoint,
+{
+    validate_point(&point.into_affine())
+}
+
+impl<P: SWModelParameters> Validate for short_weierstrass_jacobian::GroupAffine<P> {
+    fn validate(&self) -> Result<(), CryptoError> {
+        validate_point(self)
+    }
+}
+
+impl<P: TEModelParameters> Validate for twisted_edwards_extended::GroupAffine<P> {
+    fn validate(&self) -> Result<(), CryptoError> {
+        validate_point(self)
+    }
+}
+
+impl<T: Validate> Validate for Vec<T> {
+    fn validate(&self) -> Result<(), CryptoError> {
+        self.iter().try_for_each(|value| value.validate())
+    }
+}
+
+/// Deserialize a value written with `serialize_uncompressed` (or `serialize_unchecked`) and validate it. The
+/// curve implementation's own checks are skipped, so that every rejection is reported with a dedicated error.
+pub fn deserialize_validated<T, R>(reader: R) -> Result<T, CryptoError>
+where
+    T: CanonicalDeserialize + Validate,
+    R: Read,
+{
+    let value = T::deserialize_unchecked(reader)?;
+    value.validate()?;
+
+    Ok(value)
+}
+
+/// Deserialize a value written with `serialize` and validate it. Points which cannot be decompressed are
+/// rejected by the curve implementation with [`CryptoError::DeserializationError`].
+pub fn deserialize_compressed_validated<T, R>(reader: R) -> Result<T, CryptoError>
+where
+    T: CanonicalDeserialize + Validate,
+    R: Read,
+{
+    let value = T::deserialize(reader)?;
+    value.validate()?;
+
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ec::short_weierstrass_jacobian::GroupAffine;
+    use ark_ff::{One, UniformRand};
+    use ark_serialize::CanonicalSerialize;
+    use ark_std::rand::thread_rng;
+    use ark_std::Zero;
+
+    type StarkAffine = starknet_curve::Affine;
+    type StarkCurve = starknet_curve::Projective;
+
+    fn serialize_unchecked<T: CanonicalSerialize>(value: &T) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        value.serialize_unchecked(&mut bytes).unwrap();
+        bytes
+    }
+
+    #[test]
+    fn valid_points() {
+        let rng = &mut thread_rng();
+        let point = StarkCurve::rand(rng).into_affine();
+
+        assert_eq!(
+            Ok(point),
+            deserialize_validated(&serialize_unchecked(&point)[..])
+        );
+        assert_eq!(
+            Ok(StarkAffine::zero()),
+            deserialize_validated(&serialize_unchecked(&StarkAffine::zero())[..])
+        );
+        assert_eq!(
+            validate_non_identity_point(&StarkAffine::zero()),
+            Err(CryptoError::IdentityPoint)
+        );
+    }
+
+    #[test]
+    fn point_not_on_curve() {
+        let rng = &mut thread_rng();
+        let point = StarkCurve::rand(rng).into_affine();
+        let off_curve = StarkAffine::new(point.x, point.y + starknet_curve::Fq::one(), false);
+
+        assert_eq!(
+            deserialize_validated::<StarkAffine, _>(&serialize_unchecked(&off_curve)[..]),
+            Err(CryptoError::PointNotOnCurve)
+        );
+        assert_eq!(
+            deserialize_validated::<Vec<StarkAffine>, _>(
+                &serialize_unchecked(&vec![point, off_curve])[..]
+            ),
+            Err(CryptoError::PointNotOnCurve)
+        );
+    }
+
+    #[test]
+    fn point_not_in_subgroup() {
+        // BLS12-381 G1 has a large cofactor: a random point of the curve is not in the prime order subgroup
+        let rng = &mut thread_rng();
+        let point = loop {
+            let x = ark_bls12_381::Fq::rand(rng);
+            if let Some(point) =
+                GroupAffine::<ark_bls12_381::g1::Parameters>::get_point_from_x(x, false)
+            {
+                break point;
+            }
+        };
+
+        assert_eq!(
+            deserialize_validated::<ark_bls12_381::G1Affine, _>(&serialize_unchecked(&point)[..]),
+            Err(CryptoError::PointNotInSubgroup)
+        );
+    }
+}
@@ -1,10 +1,11 @@
 use crate::error::CryptoError;
+use crate::homomorphic_encryption::Accumulate;
 use ark_ff::Field;
 use std::iter::Sum;
 use std::ops::Mul;
 
 /// Compute the dot product (inner product) of two vectors
-pub fn dot_product<S, T>(scalars: &Vec<S>, rhs: &Vec<T>) -> Result<T, CryptoError>
+pub fn dot_product<S, T>(scalars: &[S], rhs: &[T]) -> Result<T, CryptoError>
 where
     S: Field,
     T: Copy + Sum<T> + Mul<S, Output = T>,
@@ -20,6 +21,33 @@ where
         .sum())
 }
 
+/// Compute the dot product of a vector of scalars and a vector of values without normalizing the result, see
+/// [`Accumulate`]
+pub fn accumulated_dot_product<S, T>(
+    scalars: &[S],
+    rhs: &[T],
+) -> Result<T::Accumulator, CryptoError>
+where
+    S: Field,
+    T: Accumulate<S>,
+{
+    if scalars.len() != rhs.len() {
+        return Err(CryptoError::DotProductLengthError(scalars.len(), rhs.len()));
+    }
+
+    Ok(T::linear_combination(rhs, scalars))
+}
+
+/// Compute the dot product of a vector of scalars and a vector of group-valued entries, such as ciphertexts or
+/// commitments, with a multi-scalar multiplication
+pub fn msm_dot_product<S, T>(scalars: &[S], rhs: &[T]) -> Result<T, CryptoError>
+where
+    S: Field,
+    T: Accumulate<S>,
+{
+    Ok(T::normalize(&accumulated_dot_product(scalars, rhs)?))
+}
+
 // Compute the Hadamard product (elemet-wise multiplication) of two vectors
 pub fn hadamard_product<S: Field>(scalars: &Vec<S>, rhs: &Vec<S>) -> Result<Vec<S>, CryptoError> {
     if scalars.len() != rhs.len() {
@@ -54,6 +82,7 @@ mod tests {
     use super::*;
     use crate::homomorphic_encryption::el_gamal;
     use crate::utils::rand::sample_vector;
+    use crate::vector_commitment::pedersen;
     use ark_ff::{One, Zero};
     use ark_std::rand::thread_rng;
     use starknet_curve;
@@ -61,6 +90,7 @@ mod tests {
     type Scalar = starknet_curve::Fr;
     type Curve = starknet_curve::Projective;
     type Ciphertext = el_gamal::Ciphertext<Curve>;
+    type Commitment = pedersen::Commitment<Curve>;
 
     #[test]
     fn dot_product_test() {
@@ -73,6 +103,33 @@ mod tests {
         dot_product(&scalars, &ciphers).unwrap();
     }
 
+    #[test]
+    fn msm_dot_product_test() {
+        let rng = &mut thread_rng();
+
+        for n in [1, 5, 40] {
+            let scalars: Vec<Scalar> = sample_vector(rng, n);
+            let ciphers: Vec<Ciphertext> = sample_vector(rng, n);
+            let commitments: Vec<Commitment> = sample_vector(rng, n);
+
+            assert_eq!(
+                msm_dot_product(&scalars, &ciphers).unwrap(),
+                dot_product(&scalars, &ciphers).unwrap()
+            );
+            assert_eq!(
+                msm_dot_product(&scalars, &commitments).unwrap(),
+                dot_product(&scalars, &commitments).unwrap()
+            );
+        }
+
+        let scalars: Vec<Scalar> = sample_vector(rng, 3);
+        let ciphers: Vec<Ciphertext> = sample_vector(rng, 4);
+        assert_eq!(
+            msm_dot_product(&scalars, &ciphers),
+            Err(CryptoError::DotProductLengthError(3, 4))
+        );
+    }
+
     #[test]
     fn hadamard_product_test() {
         let rng = &mut thread_rng();
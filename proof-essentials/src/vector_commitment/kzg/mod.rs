@@ -0,0 +1,244 @@
+use crate::error::CryptoError;
+use crate::utils::fixed_base::FixedBaseTable;
+use crate::utils::validation::{validate_non_identity_point, Validate, ValidatePoint};
+use crate::vector_commitment::{pedersen, HomomorphicCommitmentScheme};
+
+use ark_ec::{msm::VariableBaseMSM, AffineCurve, PairingEngine, ProjectiveCurve};
+use ark_ff::{One, PrimeField, ToBytes, Zero};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::{
+    io::{Read, Write},
+    marker::PhantomData,
+    UniformRand,
+};
+use rand::Rng;
+
+mod tests;
+
+/// KZG commitments to the coefficients of a polynomial over the scalar field of a pairing-friendly curve. A
+/// vector x is committed as `C = sum x_i*tau^i*g + r*h`, which is homomorphic and has the size of a single point
+/// whatever the length of x, and can be opened at any point z to the evaluation of `p(X) = sum x_i*X^i`.
+///
+/// The points are the same as those of Pedersen commitments, with the structured generators `tau^i*g` in place of
+/// independent ones, so commitments of both schemes share the [`pedersen::Commitment`] type.
+pub struct KZGCommitment<E: PairingEngine> {
+    _engine: PhantomData<E>,
+}
+
+pub type Commitment<E> = pedersen::Commitment<<E as PairingEngine>::G1Projective>;
+
+/// Commit key for KZG commitments: the powers of a secret tau in G1, an independent point h for blinding and the
+/// points g2 and tau*g2 of G2 needed to verify openings.
+#[derive(Clone, CanonicalSerialize, CanonicalDeserialize, Debug)]
+pub struct CommitKey<E: PairingEngine> {
+    powers_of_g: Vec<E::G1Affine>,
+    h: E::G1Affine,
+    g2: E::G2Affine,
+    tau_g2: E::G2Affine,
+}
+
+impl<E: PairingEngine> CommitKey<E> {
+    /// Build a commit key from the output of a trusted setup ceremony.
+    pub fn new(
+        powers_of_g: Vec<E::G1Affine>,
+        h: E::G1Affine,
+        g2: E::G2Affine,
+        tau_g2: E::G2Affine,
+    ) -> Self {
+        Self {
+            powers_of_g,
+            h,
+            g2,
+            tau_g2,
+        }
+    }
+
+    pub fn powers_of_g(&self) -> &[E::G1Affine] {
+        &self.powers_of_g
+    }
+
+    pub fn h(&self) -> &E::G1Affine {
+        &self.h
+    }
+}
+
+impl<E: PairingEngine> ToBytes for CommitKey<E> {
+    fn write<W: Write>(&self, mut w: W) -> ark_std::io::Result<()> {
+        self.powers_of_g.write(&mut w)?;
+        self.h.write(&mut w)?;
+        self.g2.write(&mut w)?;
+        self.tau_g2.write(&mut w)?;
+
+        Ok(())
+    }
+}
+
+impl<E: PairingEngine> Validate for CommitKey<E>
+where
+    E::G1Affine: ValidatePoint,
+    E::G2Affine: ValidatePoint,
+{
+    fn validate(&self) -> Result<(), CryptoError> {
+        self.powers_of_g
+            .iter()
+            .try_for_each(validate_non_identity_point)?;
+        validate_non_identity_point(&self.h)?;
+        validate_non_identity_point(&self.g2)?;
+        validate_non_identity_point(&self.tau_g2)
+    }
+}
+
+/// Proof that a commitment opens to a polynomial taking a given value at a given point. The blinding factor of
+/// the commitment is revealed, hence an opened commitment is no longer hiding.
+#[derive(Clone, Copy, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct Opening<E: PairingEngine> {
+    pub witness: E::G1Affine,
+    pub blinding: E::Fr,
+}
+
+impl<E: PairingEngine> Validate for Opening<E>
+where
+    E::G1Affine: ValidatePoint,
+{
+    fn validate(&self) -> Result<(), CryptoError> {
+        crate::utils::validation::validate_point(&self.witness)
+    }
+}
+
+impl<E: PairingEngine> HomomorphicCommitmentScheme<E::Fr> for KZGCommitment<E> {
+    type CommitKey = CommitKey<E>;
+    type Commitment = Commitment<E>;
+
+    /// Run the trusted setup in process. The secret tau goes through this process' memory, so anyone who can
+    /// observe it can break the binding of the commitments: this setup is only meant for tests.
+    fn setup<R: Rng>(public_randomess: &mut R, len: usize) -> CommitKey<E> {
+        let tau = E::Fr::rand(public_randomess);
+        let g = E::G1Projective::rand(public_randomess).into_affine();
+        let h = E::G1Projective::rand(public_randomess).into_affine();
+        let g2 = E::G2Projective::rand(public_randomess).into_affine();
+
+        let powers_of_tau = (0..len)
+            .scan(E::Fr::one(), |power, _| {
+                let current = *power;
+                *power *= tau;
+                Some(current)
+            })
+            .collect::<Vec<_>>();
+        let powers_of_g = E::G1Projective::batch_normalization_into_affine(
+            &FixedBaseTable::<E::G1Projective>::new(&g).batch_mul(&powers_of_tau),
+        );
+
+        CommitKey {
+            powers_of_g,
+            h,
+            g2,
+            tau_g2: g2.mul(tau).into_affine(),
+        }
+    }
+
+    fn commit(
+        commit_key: &CommitKey<E>,
+        x: &[E::Fr],
+        r: E::Fr,
+    ) -> Result<Self::Commitment, CryptoError> {
+        if x.len() > commit_key.powers_of_g.len() {
+            return Err(CryptoError::CommitmentLengthError(
+                String::from("KZG"),
+                x.len(),
+                commit_key.powers_of_g.len(),
+            ));
+        }
+
+        let scalars = [&[r], x]
+            .concat()
+            .iter()
+            .map(|x| x.into_repr())
+            .collect::<Vec<_>>();
+
+        let bases = [&[commit_key.h], &commit_key.powers_of_g[..x.len()]].concat();
+
+        Ok(pedersen::Commitment(
+            VariableBaseMSM::multi_scalar_mul(&bases, &scalars).into_affine(),
+        ))
+    }
+}
+
+impl<E: PairingEngine> KZGCommitment<E> {
+    /// Evaluate the polynomial with coefficients x at `point`, and prove the evaluation for the commitment to x
+    /// with randomness r.
+    pub fn open(
+        commit_key: &CommitKey<E>,
+        x: &[E::Fr],
+        r: E::Fr,
+        point: E::Fr,
+    ) -> Result<(E::Fr, Opening<E>), CryptoError> {
+        if x.len() > commit_key.powers_of_g.len() {
+            return Err(CryptoError::CommitmentLengthError(
+                String::from("KZG"),
+                x.len(),
+                commit_key.powers_of_g.len(),
+            ));
+        }
+
+        // Divide p(X) by (X - point) with Horner's method: the last value is the remainder p(point) and the
+        // others are the coefficients of the quotient, from the highest degree down.
+        let mut quotient = Vec::with_capacity(x.len());
+        let mut value = E::Fr::zero();
+        for coefficient in x.iter().rev() {
+            quotient.push(value);
+            value = value * point + coefficient;
+        }
+        let quotient = quotient.into_iter().skip(1).rev().collect::<Vec<_>>();
+
+        let scalars = quotient.iter().map(|q| q.into_repr()).collect::<Vec<_>>();
+        let witness =
+            VariableBaseMSM::multi_scalar_mul(&commit_key.powers_of_g[..quotient.len()], &scalars);
+
+        Ok((
+            value,
+            Opening {
+                witness: witness.into_affine(),
+                blinding: r,
+            },
+        ))
+    }
+
+    /// Check that `commitment` opens to a polynomial which evaluates to `value` at `point`, i.e. that
+    /// `e(C - value*g - r*h, g2) == e(W, tau*g2 - point*g2)`.
+    pub fn verify_opening(
+        commit_key: &CommitKey<E>,
+        commitment: &Commitment<E>,
+        point: E::Fr,
+        value: E::Fr,
+        opening: &Opening<E>,
+    ) -> Result<(), CryptoError> {
+        let g = commit_key
+            .powers_of_g
+            .first()
+            .ok_or(CryptoError::CommitmentLengthError(
+                String::from("KZG"),
+                1,
+                0,
+            ))?;
+
+        let mut evaluation = commitment.0.into_projective();
+        evaluation -= g.mul(value);
+        evaluation -= commit_key.h.mul(opening.blinding);
+
+        let mut shifted_tau = commit_key.tau_g2.into_projective();
+        shifted_tau -= commit_key.g2.mul(point);
+
+        let pairs = [
+            (evaluation.into_affine().into(), commit_key.g2.into()),
+            ((-opening.witness).into(), shifted_tau.into_affine().into()),
+        ];
+
+        if !E::product_of_pairings(pairs.iter()).is_one() {
+            return Err(CryptoError::ProofVerificationError(String::from(
+                "KZG Opening",
+            )));
+        }
+
+        Ok(())
+    }
+}
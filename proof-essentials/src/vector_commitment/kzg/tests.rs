@@ -0,0 +1,267 @@
+#[cfg(test)]
+mod test {
+    use crate::error::CryptoError;
+    use crate::utils::rand::sample_vector;
+    use crate::utils::validation::Validate;
+    use crate::utils::vector_arithmetic::hadamard_product;
+    use crate::vector_commitment::{kzg, HomomorphicCommitmentScheme};
+    use crate::zkp::arguments::{hadamard_product, matrix_elements_product};
+    use crate::zkp::ArgumentOfKnowledge;
+    use ark_ff::{One, Zero};
+    use ark_marlin::rng::FiatShamirRng;
+    use ark_std::{rand::thread_rng, UniformRand};
+    use blake2::Blake2s;
+
+    // Define type aliases for succinctness
+    type Engine = ark_bls12_381::Bls12_381;
+    type Scalar = ark_bls12_381::Fr;
+    type Kzg = kzg::KZGCommitment<Engine>;
+
+    type FS = FiatShamirRng<Blake2s>;
+
+    fn commit_columns(
+        commit_key: &kzg::CommitKey<Engine>,
+        columns: &[Vec<Scalar>],
+        randoms: &[Scalar],
+    ) -> Vec<kzg::Commitment<Engine>> {
+        columns
+            .iter()
+            .zip(randoms.iter())
+            .map(|(column, &random)| Kzg::commit(commit_key, column, random).unwrap())
+            .collect()
+    }
+
+    fn evaluate(x: &[Scalar], point: Scalar) -> Scalar {
+        x.iter().rev().fold(Scalar::zero(), |value, coefficient| {
+            value * point + coefficient
+        })
+    }
+
+    #[test]
+    fn additive_homomorphism() {
+        let rng = &mut thread_rng();
+        let n = 17;
+
+        let commit_key = Kzg::setup(rng, n);
+        assert_eq!(Ok(()), commit_key.validate());
+
+        let r1 = Scalar::rand(rng);
+        let r2 = Scalar::rand(rng);
+
+        let v1: Vec<Scalar> = sample_vector(rng, n);
+        let v2: Vec<Scalar> = sample_vector(rng, n);
+
+        let alpha = Scalar::rand(rng);
+        let beta = Scalar::rand(rng);
+
+        let v3 = v1
+            .iter()
+            .zip(v2.iter())
+            .map(|(&a, &b)| a * alpha + b * beta)
+            .collect::<Vec<_>>();
+        let r3 = alpha * r1 + beta * r2;
+
+        let commit_v1 = Kzg::commit(&commit_key, &v1, r1).unwrap();
+        let commit_v2 = Kzg::commit(&commit_key, &v2, r2).unwrap();
+        let commit_v3 = Kzg::commit(&commit_key, &v3, r3).unwrap();
+
+        assert_eq!(commit_v1 * alpha + commit_v2 * beta, commit_v3);
+
+        assert_eq!(
+            Err(CryptoError::CommitmentLengthError(
+                String::from("KZG"),
+                n + 1,
+                n
+            )),
+            Kzg::commit(&commit_key, &vec![Scalar::one(); n + 1], r1)
+        );
+    }
+
+    #[test]
+    fn open_and_verify() {
+        let rng = &mut thread_rng();
+        let commit_key = Kzg::setup(rng, 17);
+
+        // Polynomials shorter than the key, of degree 0 and with no coefficient
+        for n in [17, 5, 1, 0] {
+            let x: Vec<Scalar> = sample_vector(rng, n);
+            let r = Scalar::rand(rng);
+            let commitment = Kzg::commit(&commit_key, &x, r).unwrap();
+
+            let point = Scalar::rand(rng);
+            let (value, opening) = Kzg::open(&commit_key, &x, r, point).unwrap();
+            assert_eq!(value, evaluate(&x, point));
+            assert_eq!(
+                Ok(()),
+                Kzg::verify_opening(&commit_key, &commitment, point, value, &opening)
+            );
+
+            let failure = Err(CryptoError::ProofVerificationError(String::from(
+                "KZG Opening",
+            )));
+            assert_eq!(
+                failure,
+                Kzg::verify_opening(
+                    &commit_key,
+                    &commitment,
+                    point,
+                    value + Scalar::one(),
+                    &opening
+                )
+            );
+            // Constant polynomials take the same value everywhere
+            if n > 1 {
+                assert_eq!(
+                    failure,
+                    Kzg::verify_opening(
+                        &commit_key,
+                        &commitment,
+                        point + Scalar::one(),
+                        value,
+                        &opening
+                    )
+                );
+            }
+            let other_commitment = Kzg::commit(&commit_key, &x, r + Scalar::one()).unwrap();
+            assert_eq!(
+                failure,
+                Kzg::verify_opening(&commit_key, &other_commitment, point, value, &opening)
+            );
+        }
+    }
+
+    #[test]
+    fn product_argument() {
+        type Statement<'a> = matrix_elements_product::Statement<'a, Scalar, Kzg>;
+        type Parameters<'a> = matrix_elements_product::Parameters<'a, Scalar, Kzg>;
+        type ProductArgument<'a> = matrix_elements_product::ProductArgument<'a, Scalar, Kzg>;
+
+        let m = 4;
+        let n = 13;
+
+        let rng = &mut thread_rng();
+        let commit_key = Kzg::setup(rng, n);
+
+        let random_scalars: Vec<Scalar> = sample_vector(rng, m * n);
+        let a_chunks = random_scalars
+            .chunks(n)
+            .map(|c| c.to_vec())
+            .collect::<Vec<_>>();
+        let r: Vec<Scalar> = sample_vector(rng, m);
+        let a_commits = commit_columns(&commit_key, &a_chunks, &r);
+
+        let product = random_scalars.iter().fold(Scalar::one(), |x, y| x * y);
+
+        let proof_parameters = Parameters::new(m, n, &commit_key);
+        let statement = Statement::new(&a_commits, product);
+        let witness = matrix_elements_product::Witness::new(&a_chunks, &r);
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let proof =
+            ProductArgument::prove(rng, &proof_parameters, &statement, &witness, &mut fs_rng)
+                .unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            Ok(()),
+            ProductArgument::verify(&proof_parameters, &statement, &proof, &mut fs_rng)
+        );
+
+        let wrong_statement = Statement::new(&a_commits, product + Scalar::one());
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let invalid_proof = ProductArgument::prove(
+            rng,
+            &proof_parameters,
+            &wrong_statement,
+            &witness,
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_ne!(
+            Ok(()),
+            ProductArgument::verify(
+                &proof_parameters,
+                &wrong_statement,
+                &invalid_proof,
+                &mut fs_rng
+            )
+        );
+    }
+
+    #[test]
+    fn hadamard_product_argument() {
+        type Statement<'a> = hadamard_product::Statement<'a, Scalar, Kzg>;
+        type Parameters<'a> = hadamard_product::Parameters<'a, Scalar, Kzg>;
+        type HadamardProductArgument<'a> =
+            hadamard_product::HadamardProductArgument<'a, Scalar, Kzg>;
+
+        let m = 4;
+        let n = 13;
+
+        let rng = &mut thread_rng();
+        let commit_key = Kzg::setup(rng, n);
+
+        let random_scalars: Vec<Scalar> = sample_vector(rng, m * n);
+        let a_chunks = random_scalars
+            .chunks(n)
+            .map(|c| c.to_vec())
+            .collect::<Vec<_>>();
+        let r: Vec<Scalar> = sample_vector(rng, m);
+        let a_commits = commit_columns(&commit_key, &a_chunks, &r);
+
+        let b = a_chunks.iter().fold(vec![Scalar::one(); n], |x, y| {
+            hadamard_product(&x, y).unwrap()
+        });
+        let s = Scalar::rand(rng);
+        let b_commit = Kzg::commit(&commit_key, &b, s).unwrap();
+
+        let proof_parameters = Parameters::new(m, n, &commit_key);
+        let statement = Statement::new(&a_commits, b_commit);
+        let witness = hadamard_product::Witness::new(&a_chunks, &r, &b, s);
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let proof = HadamardProductArgument::prove(
+            rng,
+            &proof_parameters,
+            &statement,
+            &witness,
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            Ok(()),
+            HadamardProductArgument::verify(&proof_parameters, &statement, &proof, &mut fs_rng)
+        );
+
+        let bad_b: Vec<Scalar> = sample_vector(rng, n);
+        let invalid_witness = hadamard_product::Witness::new(&a_chunks, &r, &bad_b, s);
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let invalid_proof = HadamardProductArgument::prove(
+            rng,
+            &proof_parameters,
+            &statement,
+            &invalid_witness,
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            Err(CryptoError::ProofVerificationError(String::from(
+                "Hadamard Product (5.1)",
+            ))),
+            HadamardProductArgument::verify(
+                &proof_parameters,
+                &statement,
+                &invalid_proof,
+                &mut fs_rng
+            )
+        );
+    }
+}
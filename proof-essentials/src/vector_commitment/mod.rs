@@ -1,6 +1,8 @@
+pub mod kzg;
 pub mod pedersen;
 
 use crate::error::CryptoError;
+use crate::homomorphic_encryption::Accumulate;
 use ark_ff::{Field, ToBytes, Zero};
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
 use rand::Rng;
@@ -21,7 +23,8 @@ pub trait HomomorphicCommitmentScheme<Scalar: Field> {
         + CanonicalDeserialize
         + Zero
         + Sum
-        + ToBytes;
+        + ToBytes
+        + Accumulate<Scalar>;
 
     /// Generate a commit key using the provided length
     fn setup<R: Rng>(public_randomess: &mut R, len: usize) -> Self::CommitKey;
@@ -29,7 +32,26 @@ pub trait HomomorphicCommitmentScheme<Scalar: Field> {
     /// Commit to a vector of scalars using the commit key
     fn commit(
         commit_key: &Self::CommitKey,
-        x: &Vec<Scalar>,
+        x: &[Scalar],
         r: Scalar,
     ) -> Result<Self::Commitment, CryptoError>;
+
+    /// Commit to each column of a matrix with the matching randomness. The default implementation commits to the
+    /// columns one by one: schemes which can share work between the columns override it. The columns can be owned
+    /// vectors or borrowed slices, so that callers do not copy vectors they keep using.
+    fn commit_columns<V: AsRef<[Scalar]> + Sync>(
+        commit_key: &Self::CommitKey,
+        columns: &[V],
+        r: &[Scalar],
+    ) -> Result<Vec<Self::Commitment>, CryptoError> {
+        if columns.len() != r.len() {
+            return Err(CryptoError::BlindingLengthError(columns.len(), r.len()));
+        }
+
+        columns
+            .iter()
+            .zip(r.iter())
+            .map(|(column, &random)| Self::commit(commit_key, column.as_ref(), random))
+            .collect()
+    }
 }
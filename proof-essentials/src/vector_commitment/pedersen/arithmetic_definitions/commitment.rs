@@ -1,6 +1,7 @@
-use super::super::Commitment;
-use ark_ec::{AffineCurve, ProjectiveCurve};
-use ark_ff::Zero;
+use super::super::{Commitment, ProjectiveCommitment};
+use crate::homomorphic_encryption::Accumulate;
+use ark_ec::{msm::VariableBaseMSM, AffineCurve, ProjectiveCurve};
+use ark_ff::{PrimeField, Zero};
 use ark_std::UniformRand;
 use rand::Rng;
 use std::ops::Mul;
@@ -41,3 +42,60 @@ impl<C: ProjectiveCurve> UniformRand for Commitment<C> {
         Self(C::rand(rng).into_affine())
     }
 }
+
+impl<C: ProjectiveCurve> std::ops::Add for ProjectiveCommitment<C> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl<C: ProjectiveCurve> Mul<C::ScalarField> for ProjectiveCommitment<C> {
+    type Output = Self;
+
+    fn mul(mut self, x: C::ScalarField) -> Self::Output {
+        self.0 *= x;
+        self
+    }
+}
+
+impl<C: ProjectiveCurve> std::iter::Sum for ProjectiveCommitment<C> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        Self(iter.map(|c| c.0).sum())
+    }
+}
+
+impl<C: ProjectiveCurve> Zero for ProjectiveCommitment<C> {
+    fn zero() -> Self {
+        Self(C::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+}
+
+impl<C: ProjectiveCurve> Accumulate<C::ScalarField> for Commitment<C> {
+    type Accumulator = ProjectiveCommitment<C>;
+
+    fn accumulator(&self) -> Self::Accumulator {
+        ProjectiveCommitment(self.0.into_projective())
+    }
+
+    fn linear_combination(values: &[Self], scalars: &[C::ScalarField]) -> Self::Accumulator {
+        let bases = values.iter().map(|c| c.0).collect::<Vec<_>>();
+        let scalars = scalars.iter().map(|s| s.into_repr()).collect::<Vec<_>>();
+
+        ProjectiveCommitment(VariableBaseMSM::multi_scalar_mul(&bases, &scalars))
+    }
+
+    fn normalize_batch(accumulators: &[Self::Accumulator]) -> Vec<Self> {
+        let points = accumulators.iter().map(|c| c.0).collect::<Vec<_>>();
+
+        C::batch_normalization_into_affine(&points)
+            .into_iter()
+            .map(Self)
+            .collect()
+    }
+}
@@ -1,4 +1,9 @@
 use crate::error::CryptoError;
+use crate::utils::fixed_base::FixedBaseTable;
+use crate::utils::hash_to_curve::{derive_generators, HashToCurve};
+use crate::utils::validation::{
+    validate_non_identity_point, validate_point, Validate, ValidatePoint,
+};
 use crate::vector_commitment::HomomorphicCommitmentScheme;
 
 use ark_ec::{msm::VariableBaseMSM, ProjectiveCurve};
@@ -9,6 +14,9 @@ use ark_std::{
     marker::PhantomData,
 };
 use rand::Rng;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use std::iter;
 
 pub mod arithmetic_definitions;
 mod tests;
@@ -27,6 +35,14 @@ impl<C: ProjectiveCurve> CommitKey<C> {
     pub fn new(g: Vec<C::Affine>, h: C::Affine) -> Self {
         Self { g, h }
     }
+
+    pub fn g(&self) -> &[C::Affine] {
+        &self.g
+    }
+
+    pub fn h(&self) -> &C::Affine {
+        &self.h
+    }
 }
 
 impl<C: ProjectiveCurve> ToBytes for CommitKey<C> {
@@ -38,9 +54,92 @@ impl<C: ProjectiveCurve> ToBytes for CommitKey<C> {
     }
 }
 
+/// Commit key along with a fixed-base table for `h`, which speeds up committing when the key is used many times:
+/// the blinding term then costs table lookups instead of a scalar multiplication.
+#[derive(Clone)]
+pub struct PreparedCommitKey<C: ProjectiveCurve> {
+    commit_key: CommitKey<C>,
+    h_table: FixedBaseTable<C>,
+}
+
+impl<C: ProjectiveCurve> PreparedCommitKey<C> {
+    pub fn new(commit_key: &CommitKey<C>) -> Self {
+        Self {
+            commit_key: commit_key.clone(),
+            h_table: FixedBaseTable::new(&commit_key.h),
+        }
+    }
+
+    pub fn with_window_size(commit_key: &CommitKey<C>, window: usize) -> Self {
+        Self {
+            commit_key: commit_key.clone(),
+            h_table: FixedBaseTable::with_window_size(&commit_key.h, window),
+        }
+    }
+
+    pub fn commit_key(&self) -> &CommitKey<C> {
+        &self.commit_key
+    }
+
+    /// Commit to `x` with randomness `r`, with the same output as `PedersenCommitment::commit`.
+    pub fn commit(
+        &self,
+        x: &[C::ScalarField],
+        r: &C::ScalarField,
+    ) -> Result<Commitment<C>, CryptoError> {
+        let mut commitment = self.commit_without_blinding(x)?;
+        commitment += self.h_table.mul(r);
+
+        Ok(Commitment(commitment.into_affine()))
+    }
+
+    /// Commit to each vector with the matching randomness.
+    pub fn batch_commit(
+        &self,
+        x: &[Vec<C::ScalarField>],
+        r: &[C::ScalarField],
+    ) -> Result<Vec<Commitment<C>>, CryptoError> {
+        if x.len() != r.len() {
+            return Err(CryptoError::BlindingLengthError(x.len(), r.len()));
+        }
+
+        let commitments = x
+            .iter()
+            .zip(self.h_table.batch_mul(r))
+            .map(|(x, blinding)| Ok(self.commit_without_blinding(x)? + blinding))
+            .collect::<Result<Vec<_>, CryptoError>>()?;
+
+        Ok(C::batch_normalization_into_affine(&commitments)
+            .into_iter()
+            .map(Commitment)
+            .collect())
+    }
+
+    fn commit_without_blinding(&self, x: &[C::ScalarField]) -> Result<C, CryptoError> {
+        if x.len() > self.commit_key.g.len() {
+            return Err(CryptoError::CommitmentLengthError(
+                String::from("Pedersen"),
+                x.len(),
+                self.commit_key.g.len(),
+            ));
+        }
+
+        let scalars = x.iter().map(|x| x.into_repr()).collect::<Vec<_>>();
+
+        Ok(VariableBaseMSM::multi_scalar_mul(
+            &self.commit_key.g[..x.len()],
+            &scalars,
+        ))
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
 pub struct Commitment<C: ProjectiveCurve>(pub C::Affine);
 
+/// Commitment in projective coordinates, used to accumulate linear combinations of commitments.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ProjectiveCommitment<C: ProjectiveCurve>(pub C);
+
 impl<C: ProjectiveCurve> ToBytes for Commitment<C> {
     fn write<W: Write>(&self, mut w: W) -> ark_std::io::Result<()> {
         self.0.write(&mut w)?;
@@ -49,6 +148,39 @@ impl<C: ProjectiveCurve> ToBytes for Commitment<C> {
     }
 }
 
+impl<C: ProjectiveCurve> Validate for CommitKey<C>
+where
+    C::Affine: ValidatePoint,
+{
+    fn validate(&self) -> Result<(), CryptoError> {
+        self.g.iter().try_for_each(validate_non_identity_point)?;
+        validate_non_identity_point(&self.h)
+    }
+}
+
+impl<C: ProjectiveCurve> Validate for Commitment<C>
+where
+    C::Affine: ValidatePoint,
+{
+    fn validate(&self) -> Result<(), CryptoError> {
+        validate_point(&self.0)
+    }
+}
+
+impl<C: HashToCurve> PedersenCommitment<C> {
+    pub const TRANSPARENT_SETUP_DST: &'static [u8] = b"PROOF-ESSENTIALS-V01-PEDERSEN-COMMIT-KEY";
+
+    /// Derive a commit key of length `len` from `label` by hashing to the curve, so that nobody knows discrete
+    /// logarithm relations between its points. `h` is the first derived point and `g` the following ones, hence
+    /// the key for a shorter length is a prefix of the key for a longer one.
+    pub fn transparent_setup(label: &[u8], len: usize) -> CommitKey<C> {
+        let mut points = derive_generators::<C>(Self::TRANSPARENT_SETUP_DST, label, len + 1);
+        let h = points.remove(0);
+
+        CommitKey::<C> { g: points, h }
+    }
+}
+
 impl<C: ProjectiveCurve> HomomorphicCommitmentScheme<C::ScalarField> for PedersenCommitment<C> {
     type CommitKey = CommitKey<C>;
     type Commitment = Commitment<C>;
@@ -64,7 +196,7 @@ impl<C: ProjectiveCurve> HomomorphicCommitmentScheme<C::ScalarField> for Pederse
 
     fn commit(
         commit_key: &CommitKey<C>,
-        x: &Vec<C::ScalarField>,
+        x: &[C::ScalarField],
         r: C::ScalarField,
     ) -> Result<Self::Commitment, CryptoError> {
         if x.len() > commit_key.g.len() {
@@ -75,7 +207,7 @@ impl<C: ProjectiveCurve> HomomorphicCommitmentScheme<C::ScalarField> for Pederse
             ));
         }
 
-        let scalars = [&[r], x.as_slice()]
+        let scalars = [&[r], x]
             .concat()
             .iter()
             .map(|x| x.into_repr())
@@ -87,4 +219,58 @@ impl<C: ProjectiveCurve> HomomorphicCommitmentScheme<C::ScalarField> for Pederse
             VariableBaseMSM::multi_scalar_mul(&bases, &scalars[..]).into_affine(),
         ))
     }
+
+    /// Commit to the columns with one multi-scalar multiplication each, which share the bases and run in
+    /// parallel with the `parallel` feature, and normalize all the commitments at once.
+    fn commit_columns<V: AsRef<[C::ScalarField]> + Sync>(
+        commit_key: &CommitKey<C>,
+        columns: &[V],
+        r: &[C::ScalarField],
+    ) -> Result<Vec<Self::Commitment>, CryptoError> {
+        if columns.len() != r.len() {
+            return Err(CryptoError::BlindingLengthError(columns.len(), r.len()));
+        }
+
+        let n = columns
+            .iter()
+            .map(|column| column.as_ref().len())
+            .max()
+            .unwrap_or(0);
+        if n > commit_key.g.len() {
+            return Err(CryptoError::CommitmentLengthError(
+                String::from("Pedersen"),
+                n,
+                commit_key.g.len(),
+            ));
+        }
+
+        let bases = [&[commit_key.h], &commit_key.g[..n]].concat();
+        let commit_column = |(column, r): (&V, &C::ScalarField)| {
+            let scalars = iter::once(r)
+                .chain(column.as_ref().iter())
+                .map(|x| x.into_repr())
+                .collect::<Vec<_>>();
+
+            VariableBaseMSM::multi_scalar_mul(&bases[..scalars.len()], &scalars)
+        };
+
+        #[cfg(feature = "parallel")]
+        let commitments = columns
+            .par_iter()
+            .zip(r.par_iter())
+            .map(commit_column)
+            .collect::<Vec<_>>();
+
+        #[cfg(not(feature = "parallel"))]
+        let commitments = columns
+            .iter()
+            .zip(r.iter())
+            .map(commit_column)
+            .collect::<Vec<_>>();
+
+        Ok(C::batch_normalization_into_affine(&commitments)
+            .into_iter()
+            .map(Commitment)
+            .collect())
+    }
 }
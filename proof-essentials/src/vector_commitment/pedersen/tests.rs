@@ -1,8 +1,9 @@
 #[cfg(test)]
 mod test {
+    use crate::error::CryptoError;
     use crate::utils::rand::sample_vector;
     use crate::vector_commitment::{pedersen, HomomorphicCommitmentScheme};
-    use ark_ff::Zero;
+    use ark_ff::{to_bytes, Zero};
     use ark_std::{rand::thread_rng, UniformRand};
     use starknet_curve;
     use std::ops::Mul;
@@ -60,7 +61,7 @@ mod test {
 
         let commit_v1 = Pedersen::commit(&commit_key, &v1, r).unwrap();
 
-        let commit_s1 = Pedersen::commit(&commit_key, &vec![s1], r).unwrap();
+        let commit_s1 = Pedersen::commit(&commit_key, &[s1], r).unwrap();
 
         assert_eq!(v1[0], s1);
         assert_eq!(commit_v1, commit_s1);
@@ -82,4 +83,117 @@ mod test {
 
         let _commit = Pedersen::commit(&commit_key, &too_long, r).unwrap();
     }
+
+    #[test]
+    fn transparent_setup() {
+        let rng = &mut thread_rng();
+        let n = 10;
+
+        let commit_key = Pedersen::transparent_setup(b"test", n);
+        assert_eq!(
+            to_bytes![commit_key].unwrap(),
+            to_bytes![Pedersen::transparent_setup(b"test", n)].unwrap()
+        );
+
+        // Keys of different lengths agree on their common prefix
+        let shorter_key = Pedersen::transparent_setup(b"test", n - 3);
+        let v: Vec<Scalar> = sample_vector(rng, n - 3);
+        let r = Scalar::rand(rng);
+        assert_eq!(
+            Pedersen::commit(&commit_key, &v, r).unwrap(),
+            Pedersen::commit(&shorter_key, &v, r).unwrap()
+        );
+
+        let other_key = Pedersen::transparent_setup(b"other test", n);
+        assert_ne!(
+            Pedersen::commit(&commit_key, &v, r).unwrap(),
+            Pedersen::commit(&other_key, &v, r).unwrap()
+        );
+    }
+
+    #[test]
+    fn prepared_commit_key() {
+        let rng = &mut thread_rng();
+        let n = 13;
+
+        let commit_key = Pedersen::setup(rng, n);
+        let prepared = pedersen::PreparedCommitKey::new(&commit_key);
+
+        let vectors = (0..4)
+            .map(|i| sample_vector(rng, n - i))
+            .collect::<Vec<Vec<Scalar>>>();
+        let randomness: Vec<Scalar> = sample_vector(rng, 4);
+
+        let expected = vectors
+            .iter()
+            .zip(randomness.iter())
+            .map(|(v, &r)| Pedersen::commit(&commit_key, v, r).unwrap())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            prepared.batch_commit(&vectors, &randomness).unwrap(),
+            expected
+        );
+        assert_eq!(
+            prepared.commit(&vectors[0], &randomness[0]).unwrap(),
+            expected[0]
+        );
+
+        let too_long: Vec<Scalar> = sample_vector(rng, n + 1);
+        assert!(prepared.commit(&too_long, &randomness[0]).is_err());
+        assert_eq!(
+            prepared.batch_commit(&vectors, &randomness[1..]),
+            Err(CryptoError::BlindingLengthError(4, 3))
+        );
+    }
+
+    #[test]
+    fn commit_columns() {
+        let rng = &mut thread_rng();
+        let n = 13;
+
+        let commit_key = Pedersen::setup(rng, n);
+
+        // Columns of different lengths, including an empty one
+        let columns = (0..5)
+            .map(|i| sample_vector(rng, n - 3 * i + 1))
+            .chain(std::iter::once(Vec::new()))
+            .collect::<Vec<Vec<Scalar>>>();
+        let randomness: Vec<Scalar> = sample_vector(rng, columns.len());
+
+        // The first column is too long for the key
+        let expected = columns[1..]
+            .iter()
+            .zip(randomness[1..].iter())
+            .map(|(v, &r)| Pedersen::commit(&commit_key, v, r).unwrap())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            Pedersen::commit_columns(&commit_key, &columns[1..], &randomness[1..]).unwrap(),
+            expected
+        );
+        assert_eq!(
+            Pedersen::commit_columns(&commit_key, &columns[..0], &[]).unwrap(),
+            Vec::new()
+        );
+
+        // Borrowed columns commit the same as owned ones
+        let borrowed = columns[1..].iter().map(Vec::as_slice).collect::<Vec<_>>();
+        assert_eq!(
+            Pedersen::commit_columns(&commit_key, &borrowed, &randomness[1..]).unwrap(),
+            expected
+        );
+        assert_eq!(
+            Pedersen::commit_columns(&commit_key, &columns[1..], &randomness),
+            Err(CryptoError::BlindingLengthError(5, 6))
+        );
+        assert_eq!(
+            Pedersen::commit_columns(&commit_key, &columns, &randomness),
+            Err(CryptoError::CommitmentLengthError(
+                String::from("Pedersen"),
+                n + 1,
+                n
+            ))
+        );
+    }
 }
@@ -0,0 +1,232 @@
+//! Group equations checked at the end of the arguments. A verifier can either check them one at a time or collect
+//! them over several proofs and check a random linear combination of all of them at once, which replaces many
+//! small multi-scalar multiplications by a single large one.
+
+use crate::error::CryptoError;
+use crate::homomorphic_encryption::{Accumulate, HomomorphicEncryptionScheme};
+use crate::vector_commitment::HomomorphicCommitmentScheme;
+
+use ark_ff::{Field, Zero};
+use ark_std::rand::Rng;
+
+/// Equation `commitment == commit(values, random)`.
+pub(crate) struct CommitmentEquation<Scalar, Comm>
+where
+    Scalar: Field,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+{
+    commitment: Comm::Commitment,
+    values: Vec<Scalar>,
+    random: Scalar,
+}
+
+impl<Scalar, Comm> CommitmentEquation<Scalar, Comm>
+where
+    Scalar: Field,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+{
+    pub(crate) fn new(commitment: Comm::Commitment, values: Vec<Scalar>, random: Scalar) -> Self {
+        Self {
+            commitment,
+            values,
+            random,
+        }
+    }
+
+    fn holds(&self, commit_key: &Comm::CommitKey) -> Result<bool, CryptoError> {
+        Ok(self.commitment == Comm::commit(commit_key, &self.values, self.random)?)
+    }
+}
+
+/// Equation `sum(scalars_i * ciphertexts_i) == Enc(generator * exponent, randomness)`.
+pub(crate) struct CiphertextEquation<Scalar, Enc>
+where
+    Scalar: Field,
+    Enc: HomomorphicEncryptionScheme<Scalar>,
+{
+    ciphertexts: Vec<Enc::Ciphertext>,
+    scalars: Vec<Scalar>,
+    exponent: Scalar,
+    randomness: Enc::Randomness,
+}
+
+impl<Scalar, Enc> CiphertextEquation<Scalar, Enc>
+where
+    Scalar: Field,
+    Enc: HomomorphicEncryptionScheme<Scalar>,
+{
+    pub(crate) fn new(
+        ciphertexts: Vec<Enc::Ciphertext>,
+        scalars: Vec<Scalar>,
+        exponent: Scalar,
+        randomness: Enc::Randomness,
+    ) -> Self {
+        Self {
+            ciphertexts,
+            scalars,
+            exponent,
+            randomness,
+        }
+    }
+
+    fn holds(&self, keys: &EncryptionKeys<Scalar, Enc>) -> Result<bool, CryptoError> {
+        let left = Enc::Ciphertext::linear_combination(&self.ciphertexts, &self.scalars);
+        let right = Enc::encrypt(
+            keys.encrypt_parameters,
+            keys.public_key,
+            &(*keys.generator * self.exponent),
+            &self.randomness,
+        )?;
+
+        Ok(left == right.accumulator())
+    }
+}
+
+/// Public parameters needed to check ciphertext equations.
+pub(crate) struct EncryptionKeys<'a, Scalar, Enc>
+where
+    Scalar: Field,
+    Enc: HomomorphicEncryptionScheme<Scalar>,
+{
+    pub(crate) encrypt_parameters: &'a Enc::Parameters,
+    pub(crate) public_key: &'a Enc::PublicKey,
+    pub(crate) generator: &'a Enc::Generator,
+}
+
+/// Equations left to check by the verifier of an argument.
+pub(crate) struct Equations<Scalar, Enc, Comm>
+where
+    Scalar: Field,
+    Enc: HomomorphicEncryptionScheme<Scalar>,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+{
+    pub(crate) commitments: Vec<CommitmentEquation<Scalar, Comm>>,
+    pub(crate) ciphertexts: Vec<CiphertextEquation<Scalar, Enc>>,
+}
+
+impl<Scalar, Enc, Comm> Equations<Scalar, Enc, Comm>
+where
+    Scalar: Field,
+    Enc: HomomorphicEncryptionScheme<Scalar>,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+{
+    pub(crate) fn new(
+        commitments: Vec<CommitmentEquation<Scalar, Comm>>,
+        ciphertexts: Vec<CiphertextEquation<Scalar, Enc>>,
+    ) -> Self {
+        Self {
+            commitments,
+            ciphertexts,
+        }
+    }
+
+    pub(crate) fn extend(&mut self, other: Self) {
+        self.commitments.extend(other.commitments);
+        self.ciphertexts.extend(other.ciphertexts);
+    }
+
+    /// Check the equations one at a time.
+    pub(crate) fn holds(
+        &self,
+        commit_key: &Comm::CommitKey,
+        keys: &EncryptionKeys<Scalar, Enc>,
+    ) -> Result<bool, CryptoError> {
+        for equation in &self.commitments {
+            if !equation.holds(commit_key)? {
+                return Ok(false);
+            }
+        }
+
+        for equation in &self.ciphertexts {
+            if !equation.holds(keys)? {
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+
+    /// Check a random linear combination of the equations of several proofs. Commitments to vectors of different
+    /// lengths are combined by padding the shorter vectors with zeros, which leaves the commitments unchanged for
+    /// the schemes of this crate. The combination holds with negligible probability if any equation does not.
+    pub(crate) fn batch_holds<R: Rng>(
+        rng: &mut R,
+        batch: &[Self],
+        commit_key: &Comm::CommitKey,
+        keys: &EncryptionKeys<Scalar, Enc>,
+    ) -> Result<bool, CryptoError> {
+        let mut commitments = Vec::new();
+        let mut commitment_weights = Vec::new();
+        let mut values: Vec<Scalar> = Vec::new();
+        let mut random = Scalar::zero();
+
+        let mut ciphertexts = Vec::new();
+        let mut ciphertext_scalars = Vec::new();
+        let mut exponent = Scalar::zero();
+        let mut randomness = Enc::Randomness::zero();
+
+        for equations in batch {
+            for equation in &equations.commitments {
+                let weight = Scalar::rand(rng);
+
+                commitments.push(equation.commitment);
+                commitment_weights.push(weight);
+                if values.len() < equation.values.len() {
+                    values.resize(equation.values.len(), Scalar::zero());
+                }
+                for (value, &v) in values.iter_mut().zip(equation.values.iter()) {
+                    *value += weight * v;
+                }
+                random += weight * equation.random;
+            }
+
+            for equation in &equations.ciphertexts {
+                let weight = Scalar::rand(rng);
+
+                ciphertexts.extend_from_slice(&equation.ciphertexts);
+                ciphertext_scalars.extend(equation.scalars.iter().map(|&s| weight * s));
+                exponent += weight * equation.exponent;
+                randomness = randomness + equation.randomness * weight;
+            }
+        }
+
+        if !commitments.is_empty() {
+            let left = Comm::Commitment::linear_combination(&commitments, &commitment_weights);
+            let right = Comm::commit(commit_key, &values, random)?;
+            if left != right.accumulator() {
+                return Ok(false);
+            }
+        }
+
+        if !ciphertexts.is_empty() {
+            let equation = CiphertextEquation::<Scalar, Enc>::new(
+                ciphertexts,
+                ciphertext_scalars,
+                exponent,
+                randomness,
+            );
+            return equation.holds(keys);
+        }
+
+        Ok(true)
+    }
+}
+
+/// Check equations on commitments one at a time, failing with the name of the argument they come from.
+pub(crate) fn check_commitment_equations<Scalar, Comm>(
+    equations: &[CommitmentEquation<Scalar, Comm>],
+    commit_key: &Comm::CommitKey,
+    argument: &str,
+) -> Result<(), CryptoError>
+where
+    Scalar: Field,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+{
+    for equation in equations {
+        if !equation.holds(commit_key)? {
+            return Err(CryptoError::ProofVerificationError(String::from(argument)));
+        }
+    }
+
+    Ok(())
+}
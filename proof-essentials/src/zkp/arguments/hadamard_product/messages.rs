@@ -0,0 +1,46 @@
+use crate::error::CryptoError;
+use crate::utils::validation::Validate;
+use crate::vector_commitment::HomomorphicCommitmentScheme;
+
+use ark_ff::Field;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::io::{Read, Write};
+use ark_std::{rand::Rng, UniformRand};
+
+/// First message of the prover: commitments to the partial products of the columns of A. The first one is the
+/// commitment to the first column and the last one is the commitment to b.
+#[derive(CanonicalDeserialize, CanonicalSerialize)]
+pub struct Round1Message<Scalar, Comm>
+where
+    Scalar: Field,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+{
+    pub b_commits: Vec<Comm::Commitment>,
+}
+
+/// Challenges of the verifier: x combines the partial products and y defines the bilinear map of the zero argument.
+#[derive(Clone, Copy, Debug, PartialEq, CanonicalDeserialize, CanonicalSerialize)]
+pub struct Round1Challenge<Scalar: Field> {
+    pub x: Scalar,
+    pub y: Scalar,
+}
+
+impl<Scalar: Field> UniformRand for Round1Challenge<Scalar> {
+    fn rand<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Self {
+            x: Scalar::rand(rng),
+            y: Scalar::rand(rng),
+        }
+    }
+}
+
+impl<Scalar, Comm> Validate for Round1Message<Scalar, Comm>
+where
+    Scalar: Field,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+    Comm::Commitment: Validate,
+{
+    fn validate(&self) -> Result<(), CryptoError> {
+        self.b_commits.validate()
+    }
+}
@@ -1,14 +1,14 @@
+pub mod messages;
 pub mod proof;
 pub mod prover;
 mod tests;
+pub mod verifier;
 
 use crate::error::CryptoError;
 use crate::vector_commitment::HomomorphicCommitmentScheme;
-use crate::zkp::ArgumentOfKnowledge;
+use crate::zkp::{transcript::Transcript, ArgumentOfKnowledge};
 use ark_ff::Field;
-use ark_marlin::rng::FiatShamirRng;
 use ark_std::{marker::PhantomData, rand::Rng};
-use digest::Digest;
 
 pub struct HadamardProductArgument<'a, F, Comm>
 where
@@ -29,26 +29,26 @@ where
     type Witness = Witness<'a, Scalar>;
     type Proof = proof::Proof<Scalar, Comm>;
 
-    fn prove<R: Rng, D: Digest>(
+    fn prove<R: Rng, T: Transcript>(
         rng: &mut R,
         common_reference_string: &Self::CommonReferenceString,
         statement: &Self::Statement,
         witness: &Self::Witness,
-        fs_rng: &mut FiatShamirRng<D>,
+        transcript: &mut T,
     ) -> Result<Self::Proof, CryptoError> {
         let prover = prover::Prover::new(common_reference_string, statement, witness);
-        let proof = prover.prove(rng, fs_rng)?;
+        let proof = prover.prove(rng, transcript)?;
 
         Ok(proof)
     }
 
-    fn verify<D: Digest>(
+    fn verify<T: Transcript>(
         common_reference_string: &Self::CommonReferenceString,
         statement: &Self::Statement,
         proof: &Self::Proof,
-        fs_rng: &mut FiatShamirRng<D>,
+        transcript: &mut T,
     ) -> Result<(), CryptoError> {
-        proof.verify(&common_reference_string, &statement, fs_rng)
+        proof.verify(&common_reference_string, &statement, transcript)
     }
 }
 
@@ -80,9 +80,9 @@ pub struct Witness<'a, Scalar>
 where
     Scalar: Field,
 {
-    pub matrix_a: &'a Vec<Vec<Scalar>>,
-    pub randoms_for_a_commit: &'a Vec<Scalar>,
-    pub vector_b: &'a Vec<Scalar>,
+    pub matrix_a: &'a [Vec<Scalar>],
+    pub randoms_for_a_commit: &'a [Scalar],
+    pub vector_b: &'a [Scalar],
     pub random_for_b_commit: Scalar,
 }
 
@@ -91,9 +91,9 @@ where
     Scalar: Field,
 {
     pub fn new(
-        matrix_a: &'a Vec<Vec<Scalar>>,
-        randoms_for_a_commit: &'a Vec<Scalar>,
-        vector_b: &'a Vec<Scalar>,
+        matrix_a: &'a [Vec<Scalar>],
+        randoms_for_a_commit: &'a [Scalar],
+        vector_b: &'a [Scalar],
         random_for_b_commit: Scalar,
     ) -> Self {
         Self {
@@ -113,7 +113,7 @@ where
     Scalar: Field,
     Comm: HomomorphicCommitmentScheme<Scalar>,
 {
-    pub commitment_to_a: &'a Vec<Comm::Commitment>,
+    pub commitment_to_a: &'a [Comm::Commitment],
     pub commitment_to_b: Comm::Commitment,
 }
 
@@ -122,13 +122,38 @@ where
     Scalar: Field,
     Comm: HomomorphicCommitmentScheme<Scalar>,
 {
-    pub fn new(
-        commitment_to_a: &'a Vec<Comm::Commitment>,
-        commitment_to_b: Comm::Commitment,
-    ) -> Self {
+    pub fn new(commitment_to_a: &'a [Comm::Commitment], commitment_to_b: Comm::Commitment) -> Self {
         Self {
             commitment_to_a,
             commitment_to_b,
         }
     }
 }
+
+/// Absorb the public parameters, the statement and the commitments to the partial products of the columns of A,
+/// and derive the challenges x and y.
+pub(crate) fn round_1_challenges<Scalar, Comm, T>(
+    parameters: &Parameters<Scalar, Comm>,
+    statement: &Statement<Scalar, Comm>,
+    message: &messages::Round1Message<Scalar, Comm>,
+    transcript: &mut T,
+) -> Result<messages::Round1Challenge<Scalar>, CryptoError>
+where
+    Scalar: Field,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+    T: Transcript,
+{
+    transcript.domain_separator(b"Hadamard Product Argument");
+    transcript.round(1);
+    transcript.append(b"commit key", parameters.commit_key)?;
+    transcript.append(b"m", &(parameters.m as u32))?;
+    transcript.append(b"n", &(parameters.n as u32))?;
+    transcript.append_slice(b"commitments to a", statement.commitment_to_a)?;
+    transcript.append(b"commitment to b", &statement.commitment_to_b)?;
+    transcript.append(b"b commits", &message.b_commits)?;
+
+    Ok(messages::Round1Challenge {
+        x: transcript.challenge_scalar(b"x"),
+        y: transcript.challenge_scalar(b"y"),
+    })
+}
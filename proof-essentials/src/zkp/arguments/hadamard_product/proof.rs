@@ -1,15 +1,15 @@
-use super::{Parameters, Statement};
+use super::messages::Round1Message;
+use super::{verifier::Verifier, Parameters, Statement};
 
 use crate::error::CryptoError;
+use crate::utils::validation::Validate;
 use crate::vector_commitment::HomomorphicCommitmentScheme;
-use crate::zkp::arguments::{zero_value_bilinear_map, zero_value_bilinear_map::YMapping};
-use crate::zkp::{arguments::scalar_powers, ArgumentOfKnowledge};
+use crate::zkp::arguments::zero_value_bilinear_map;
+use crate::zkp::transcript::Transcript;
 
-use ark_ff::{to_bytes, Field, Zero};
-use ark_marlin::rng::FiatShamirRng;
+use ark_ff::Field;
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
 use ark_std::io::{Read, Write};
-use digest::Digest;
 
 #[derive(CanonicalDeserialize, CanonicalSerialize)]
 pub struct Proof<Scalar, Comm>
@@ -17,10 +17,7 @@ where
     Scalar: Field,
     Comm: HomomorphicCommitmentScheme<Scalar>,
 {
-    // Round 1
-    pub b_commits: Vec<Comm::Commitment>,
-
-    // Round 2
+    pub round_1: Round1Message<Scalar, Comm>,
     pub zero_arg_proof: zero_value_bilinear_map::proof::Proof<Scalar, Comm>,
 }
 
@@ -29,97 +26,26 @@ where
     Scalar: Field,
     Comm: HomomorphicCommitmentScheme<Scalar>,
 {
-    pub fn verify<D: Digest>(
+    pub fn verify<T: Transcript>(
         &self,
         proof_parameters: &Parameters<Scalar, Comm>,
         statement: &Statement<Scalar, Comm>,
-        fs_rng: &mut FiatShamirRng<D>,
+        transcript: &mut T,
     ) -> Result<(), CryptoError> {
-        fs_rng.absorb(&to_bytes![b"hadamard_product_argument"]?);
-
-        // check c_b_1 = c_a_1
-        if statement.commitment_to_a[0] != self.b_commits[0] {
-            return Err(CryptoError::ProofVerificationError(String::from(
-                "Hadamard Product (5.1)",
-            )));
-        }
-
-        // check c_b_m = c_b
-        if statement.commitment_to_b != self.b_commits[proof_parameters.m - 1] {
-            return Err(CryptoError::ProofVerificationError(String::from(
-                "Hadamard Product (5.1)",
-            )));
-        }
-
-        // Public parameters
-        fs_rng.absorb(&to_bytes![
-            proof_parameters.commit_key,
-            proof_parameters.m as u32,
-            proof_parameters.n as u32
-        ]?);
-
-        // Committed values
-        fs_rng.absorb(&to_bytes![self.b_commits]?);
-
-        // Extract challenges
-        let x = Scalar::rand(fs_rng);
-        let y = Scalar::rand(fs_rng);
-
-        // Precompute all powers of the x challenge from 0 to m-1
-        let x_challenge_powers = scalar_powers(x, proof_parameters.m - 1);
-
-        // Use the second challenge to define our bilinear mapping
-        let prover_mapping = YMapping::new(y, proof_parameters.n);
-
-        let mut c_d_i = self
-            .b_commits
-            .iter()
-            .zip(x_challenge_powers.iter().skip(1))
-            .map(|(&b_i_commit, &x_power_i)| b_i_commit * x_power_i)
-            .collect::<Vec<Comm::Commitment>>();
-
-        let temp_x_c_d_shifted = self
-            .b_commits
-            .iter()
-            .skip(1)
-            .zip(x_challenge_powers.iter().skip(1))
-            .map(|(&b_i_commit, &x_power_i)| b_i_commit * x_power_i)
-            .collect::<Vec<Comm::Commitment>>();
-
-        let final_cd: Comm::Commitment = temp_x_c_d_shifted
-            .iter()
-            .fold(Comm::Commitment::zero(), |acc, &x| acc + x);
-        c_d_i.push(final_cd);
-
-        // Engage in zero argument
-        let zero_arg_parameters = zero_value_bilinear_map::Parameters::new(
-            proof_parameters.m,
-            proof_parameters.n,
-            proof_parameters.commit_key,
-        );
-
-        let minus_one = -Scalar::one();
-        let vec_minus_ones = vec![minus_one; proof_parameters.n];
-        let minus_ones_commit =
-            Comm::commit(proof_parameters.commit_key, &vec_minus_ones, Scalar::zero())?;
-        let vec_commits_to_a: Vec<Comm::Commitment> =
-            [&statement.commitment_to_a[1..], &[minus_ones_commit]]
-                .concat()
-                .to_vec();
+        Verifier::new(proof_parameters, statement).verify(self, transcript)
+    }
+}
 
-        let zero_arg_statement =
-            zero_value_bilinear_map::Statement::new(&vec_commits_to_a, &c_d_i, &prover_mapping);
+impl<Scalar, Comm> Validate for Proof<Scalar, Comm>
+where
+    Scalar: Field,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+    Comm::Commitment: Validate,
+{
+    fn validate(&self) -> Result<(), CryptoError> {
+        self.round_1.validate()?;
+        self.zero_arg_proof.validate()?;
 
-        match zero_value_bilinear_map::ZeroValueArgument::verify(
-            &zero_arg_parameters,
-            &zero_arg_statement,
-            &self.zero_arg_proof,
-            fs_rng,
-        ) {
-            Ok(_) => Ok(()),
-            Err(_) => Err(CryptoError::ProofVerificationError(String::from(
-                "Hadamard Product (5.1)",
-            ))),
-        }
+        Ok(())
     }
 }
@@ -1,26 +1,47 @@
-use super::{proof::Proof, Parameters, Statement, Witness};
+use super::messages::{Round1Challenge, Round1Message};
+use super::{proof::Proof, round_1_challenges, Parameters, Statement, Witness};
 
 use crate::error::CryptoError;
 use crate::utils::rand::sample_vector;
 use crate::utils::vector_arithmetic::{dot_product, hadamard_product};
 use crate::vector_commitment::HomomorphicCommitmentScheme;
 use crate::zkp::arguments::{zero_value_bilinear_map, zero_value_bilinear_map::YMapping};
-use crate::zkp::ArgumentOfKnowledge;
+use crate::zkp::transcript::Transcript;
 
-use ark_ff::{to_bytes, Field, Zero};
-use ark_marlin::rng::FiatShamirRng;
-use digest::Digest;
+use ark_ff::{Field, Zero};
 use rand::Rng;
-use std::iter;
+use std::{borrow::Cow, iter};
 
+/// Prover waiting for the first challenge, with the first message to send to the verifier.
+type Round1<'a, Scalar, Comm> = (ProverRound1<'a, Scalar, Comm>, Round1Message<Scalar, Comm>);
+
+/// Prover of the Hadamard product argument. It borrows the statement and the witness, except when another argument
+/// hands over to it with values derived during its own rounds, which the prover then owns.
 pub struct Prover<'a, Scalar, Comm>
 where
     Scalar: Field,
     Comm: HomomorphicCommitmentScheme<Scalar>,
 {
-    parameters: &'a Parameters<'a, Scalar, Comm>,
-    statement: &'a Statement<'a, Scalar, Comm>,
-    witness: &'a Witness<'a, Scalar>,
+    pub(crate) parameters: Parameters<'a, Scalar, Comm>,
+    pub(crate) commitment_to_a: Cow<'a, [Comm::Commitment]>,
+    pub(crate) commitment_to_b: Comm::Commitment,
+    pub(crate) matrix_a: Cow<'a, [Vec<Scalar>]>,
+    pub(crate) randoms_for_a_commit: Cow<'a, [Scalar]>,
+    pub(crate) vector_b: Cow<'a, [Scalar]>,
+    pub(crate) random_for_b_commit: Scalar,
+}
+
+/// Prover of the Hadamard product argument once the commitments to the partial products are sent, waiting for
+/// the challenges x and y.
+pub struct ProverRound1<'a, Scalar, Comm>
+where
+    Scalar: Field,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+{
+    prover: Prover<'a, Scalar, Comm>,
+    b: Vec<Vec<Scalar>>,
+    b_commits: Vec<Comm::Commitment>,
+    s: Vec<Scalar>,
 }
 
 impl<'a, Scalar, Comm> Prover<'a, Scalar, Comm>
@@ -29,28 +50,30 @@ where
     Comm: HomomorphicCommitmentScheme<Scalar>,
 {
     pub fn new(
-        parameters: &'a Parameters<'a, Scalar, Comm>,
-        statement: &'a Statement<'a, Scalar, Comm>,
-        witness: &'a Witness<'a, Scalar>,
+        parameters: &Parameters<'a, Scalar, Comm>,
+        statement: &Statement<'a, Scalar, Comm>,
+        witness: &Witness<'a, Scalar>,
     ) -> Self {
         Self {
-            parameters,
-            statement,
-            witness,
+            parameters: Parameters::new(parameters.m, parameters.n, parameters.commit_key),
+            commitment_to_a: Cow::Borrowed(statement.commitment_to_a),
+            commitment_to_b: statement.commitment_to_b,
+            matrix_a: Cow::Borrowed(witness.matrix_a),
+            randoms_for_a_commit: Cow::Borrowed(witness.randoms_for_a_commit),
+            vector_b: Cow::Borrowed(witness.vector_b),
+            random_for_b_commit: witness.random_for_b_commit,
         }
     }
 
-    pub fn prove<R: Rng, D: Digest>(
-        &self,
-        rng: &mut R,
-        fs_rng: &mut FiatShamirRng<D>,
-    ) -> Result<Proof<Scalar, Comm>, CryptoError> {
-        fs_rng.absorb(&to_bytes![b"hadamard_product_argument"]?);
+    fn statement(&self) -> Statement<'_, Scalar, Comm> {
+        Statement::new(&self.commitment_to_a, self.commitment_to_b)
+    }
 
+    pub fn round_1<R: Rng>(self, rng: &mut R) -> Result<Round1<'a, Scalar, Comm>, CryptoError> {
         // Compute intermediate products (b values). Final b should be the one from the witness
         let mut acc = vec![Scalar::one(); self.parameters.n];
 
-        let b = self.witness.matrix_a[..self.witness.matrix_a.len() - 1]
+        let b = self.matrix_a[..self.matrix_a.len() - 1]
             .iter()
             .map(|x| {
                 acc = acc
@@ -60,63 +83,98 @@ where
                     .collect();
                 acc.clone()
             })
-            .chain(iter::once(self.witness.vector_b.to_vec()))
+            .chain(iter::once(self.vector_b.to_vec()))
             .collect::<Vec<_>>();
 
         let mut s: Vec<Scalar> = sample_vector(rng, self.parameters.m - 2);
 
-        let b_commit_middle = b
-            .iter()
-            .take(b.len() - 1)
-            .skip(1)
-            .zip(s.iter())
-            .map(|(b_i, &s_i)| -> Result<Comm::Commitment, CryptoError> {
-                Comm::commit(self.parameters.commit_key, b_i, s_i)
-            })
-            .collect::<Result<Vec<_>, _>>()?;
+        let b_commit_middle =
+            Comm::commit_columns(self.parameters.commit_key, &b[1..b.len() - 1], &s)?;
 
-        let b_commits = iter::once(self.statement.commitment_to_a[0])
+        let b_commits = iter::once(self.commitment_to_a[0])
             .chain(b_commit_middle.into_iter())
-            .chain(iter::once(self.statement.commitment_to_b))
+            .chain(iter::once(self.commitment_to_b))
             .collect::<Vec<Comm::Commitment>>();
 
-        s.insert(0, self.witness.randoms_for_a_commit[0]);
-        s.push(self.witness.random_for_b_commit);
+        s.insert(0, self.randoms_for_a_commit[0]);
+        s.push(self.random_for_b_commit);
 
-        // Public parameters
-        fs_rng.absorb(&to_bytes![
-            self.parameters.commit_key,
-            self.parameters.m as u32,
-            self.parameters.n as u32
-        ]?);
+        let message = Round1Message {
+            b_commits: b_commits.clone(),
+        };
 
-        // Commited values
-        fs_rng.absorb(&to_bytes![b_commits]?);
+        let state = ProverRound1 {
+            prover: self,
+            b,
+            b_commits,
+            s,
+        };
+
+        Ok((state, message))
+    }
+
+    pub fn prove<R: Rng, T: Transcript>(
+        self,
+        rng: &mut R,
+        transcript: &mut T,
+    ) -> Result<Proof<Scalar, Comm>, CryptoError> {
+        let (prover, round_1) = self.round_1(rng)?;
 
         // Challenges
-        let x = Scalar::rand(fs_rng);
-        let y = Scalar::rand(fs_rng);
+        let challenge = round_1_challenges(
+            &prover.prover.parameters,
+            &prover.prover.statement(),
+            &round_1,
+            transcript,
+        )?;
+
+        let zero_arg_proof = prover.zero_argument(&challenge)?.prove(rng, transcript)?;
+
+        Ok(Proof {
+            round_1,
+            zero_arg_proof,
+        })
+    }
+}
+
+impl<'a, Scalar, Comm> ProverRound1<'a, Scalar, Comm>
+where
+    Scalar: Field,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+{
+    /// Reduce the Hadamard product to a zero argument for the bilinear map defined by the challenge y and hand over
+    /// to its prover.
+    pub fn zero_argument(
+        self,
+        challenge: &Round1Challenge<Scalar>,
+    ) -> Result<zero_value_bilinear_map::prover::Prover<'a, Scalar, Comm>, CryptoError> {
+        let Round1Challenge { x, y } = *challenge;
+        let prover = &self.prover;
+        let parameters = &prover.parameters;
+        let b = &self.b;
+        let b_commits = &self.b_commits;
+        let s = &self.s;
 
         // Precompute all powers of the x challenge
         let x_challenge_powers = iter::once(Scalar::one())
             .chain(iter::once(x))
-            .chain((1..self.parameters.m - 1).scan(x, |current_power, _exp| {
+            .chain((1..parameters.m - 1).scan(x, |current_power, _exp| {
                 *current_power *= x;
                 Some(*current_power)
             }))
             .collect::<Vec<_>>();
 
         // Use the second challenge to define our bilinear mapping
-        let prover_mapping = YMapping::new(y, self.parameters.n);
+        let prover_mapping = YMapping::new(y, parameters.n);
 
         // Prepare statement
         let minus_one = -Scalar::one();
-        let vec_minus_ones = vec![minus_one; self.parameters.n];
+        let vec_minus_ones = vec![minus_one; parameters.n];
         let minus_ones_commit =
-            Comm::commit(self.parameters.commit_key, &vec_minus_ones, Scalar::zero())?;
+            Comm::commit(parameters.commit_key, &vec_minus_ones, Scalar::zero())?;
 
         let vec_commits_to_a: Vec<Comm::Commitment> =
-            [&self.statement.commitment_to_a[..], &[minus_ones_commit]]
+            [&prover.commitment_to_a[..], &[minus_ones_commit]]
                 .concat()
                 .to_vec();
 
@@ -139,16 +197,14 @@ where
         c_d_i.push(final_cd);
 
         // prepare witness
-        let vec_openings_to_a = [&self.witness.matrix_a[1..], &[vec_minus_ones]]
-            .concat()
-            .to_vec();
-        let vec_randoms_for_a = [&self.witness.randoms_for_a_commit[1..], &[Scalar::zero()]]
+        let vec_openings_to_a = [&prover.matrix_a[1..], &[vec_minus_ones]].concat().to_vec();
+        let vec_randoms_for_a = [&prover.randoms_for_a_commit[1..], &[Scalar::zero()]]
             .concat()
             .to_vec();
 
         let final_t = dot_product(
-            &x_challenge_powers[1..=self.parameters.m - 1].to_vec(),
-            &s[1..=self.parameters.m - 1].to_vec(),
+            &x_challenge_powers[1..=parameters.m - 1],
+            &s[1..=parameters.m - 1],
         )?;
 
         let vec_randoms_for_d = x_challenge_powers
@@ -159,12 +215,12 @@ where
             .chain(iter::once(final_t))
             .collect::<Vec<Scalar>>();
 
-        let temp_x_b = b[1..=self.parameters.m - 1]
+        let temp_x_b = b[1..=parameters.m - 1]
             .to_vec()
             .iter()
             .zip(x_challenge_powers.iter().skip(1))
             .map(|(b_chunk, &x_power_i)| {
-                let x_power_i_vector = vec![x_power_i; self.parameters.n];
+                let x_power_i_vector = vec![x_power_i; parameters.n];
                 hadamard_product(b_chunk, &x_power_i_vector)
             })
             .collect::<Result<Vec<Vec<Scalar>>, CryptoError>>()?;
@@ -183,7 +239,7 @@ where
             .iter()
             .zip(x_challenge_powers.iter().skip(1))
             .map(|(b_chunk, &x_power_i)| {
-                let x_power_i_vector = vec![x_power_i; self.parameters.n];
+                let x_power_i_vector = vec![x_power_i; parameters.n];
                 hadamard_product(b_chunk, &x_power_i_vector)
             })
             .collect::<Result<Vec<_>, CryptoError>>()?
@@ -191,42 +247,21 @@ where
             .chain(iter::once(final_d))
             .collect::<Vec<_>>();
 
-        let vec_commits_to_a_shifted = vec_commits_to_a[1..].to_vec();
-        let zero_arg_statement = zero_value_bilinear_map::Statement::new(
-            &vec_commits_to_a_shifted,
-            &c_d_i,
-            &prover_mapping,
-        );
-
         let zero_arg_params = zero_value_bilinear_map::Parameters::new(
-            self.parameters.m,
-            self.parameters.n,
-            self.parameters.commit_key,
-        );
-
-        let zero_arg_witness = zero_value_bilinear_map::Witness::new(
-            &vec_openings_to_a,
-            &vec_randoms_for_a,
-            &vec_openings_to_d,
-            &vec_randoms_for_d,
+            parameters.m,
+            parameters.n,
+            parameters.commit_key,
         );
 
-        let zero_arg_proof = zero_value_bilinear_map::ZeroValueArgument::prove(
-            rng,
-            &zero_arg_params,
-            &zero_arg_statement,
-            &zero_arg_witness,
-            fs_rng,
-        )?;
-
-        let proof = Proof {
-            // Round 1
-            b_commits,
-
-            // Round 2
-            zero_arg_proof,
-        };
-
-        Ok(proof)
+        Ok(zero_value_bilinear_map::prover::Prover {
+            parameters: zero_arg_params,
+            commitment_to_a: Cow::Owned(vec_commits_to_a[1..].to_vec()),
+            commitment_to_b: Cow::Owned(c_d_i),
+            bilinear_map: Cow::Owned(prover_mapping),
+            matrix_a: Cow::Owned(vec_openings_to_a),
+            randoms_for_a_commit: Cow::Owned(vec_randoms_for_a),
+            matrix_b: Cow::Owned(vec_openings_to_d),
+            randoms_for_b_commit: Cow::Owned(vec_randoms_for_d),
+        })
     }
 }
@@ -0,0 +1,165 @@
+use super::messages::{Round1Challenge, Round1Message};
+use super::{proof::Proof, round_1_challenges, Parameters, Statement};
+
+use crate::error::CryptoError;
+use crate::vector_commitment::HomomorphicCommitmentScheme;
+use crate::zkp::arguments::batch::CommitmentEquation;
+use crate::zkp::arguments::{
+    scalar_powers, zero_value_bilinear_map, zero_value_bilinear_map::YMapping,
+};
+use crate::zkp::transcript::Transcript;
+
+use ark_ff::{Field, Zero};
+use std::borrow::Cow;
+
+/// Verifier of the Hadamard product argument. It borrows the statement, except when another argument hands over to
+/// it with commitments derived during its own rounds, which the verifier then owns.
+pub struct Verifier<'a, Scalar, Comm>
+where
+    Scalar: Field,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+{
+    pub(crate) parameters: Parameters<'a, Scalar, Comm>,
+    pub(crate) commitment_to_a: Cow<'a, [Comm::Commitment]>,
+    pub(crate) commitment_to_b: Comm::Commitment,
+}
+
+impl<'a, Scalar, Comm> Verifier<'a, Scalar, Comm>
+where
+    Scalar: Field,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+{
+    pub fn new(
+        parameters: &Parameters<'a, Scalar, Comm>,
+        statement: &Statement<'a, Scalar, Comm>,
+    ) -> Self {
+        Self {
+            parameters: Parameters::new(parameters.m, parameters.n, parameters.commit_key),
+            commitment_to_a: Cow::Borrowed(statement.commitment_to_a),
+            commitment_to_b: statement.commitment_to_b,
+        }
+    }
+
+    fn statement(&self) -> Statement<'_, Scalar, Comm> {
+        Statement::new(&self.commitment_to_a, self.commitment_to_b)
+    }
+
+    /// Check the commitments to the partial products and hand over to the verifier of the zero argument for the
+    /// bilinear map defined by the challenge y.
+    pub fn round_1(
+        self,
+        message: &Round1Message<Scalar, Comm>,
+        challenge: &Round1Challenge<Scalar>,
+    ) -> Result<zero_value_bilinear_map::verifier::Verifier<'a, Scalar, Comm>, CryptoError> {
+        let parameters = &self.parameters;
+        let b_commits = &message.b_commits;
+
+        if b_commits.len() != parameters.m {
+            return Err(CryptoError::ProofVerificationError(String::from(
+                "Hadamard Product (5.1)",
+            )));
+        }
+
+        // check c_b_1 = c_a_1
+        if self.commitment_to_a[0] != b_commits[0] {
+            return Err(CryptoError::ProofVerificationError(String::from(
+                "Hadamard Product (5.1)",
+            )));
+        }
+
+        // check c_b_m = c_b
+        if self.commitment_to_b != b_commits[parameters.m - 1] {
+            return Err(CryptoError::ProofVerificationError(String::from(
+                "Hadamard Product (5.1)",
+            )));
+        }
+
+        // Precompute all powers of the x challenge from 0 to m-1
+        let x_challenge_powers = scalar_powers(challenge.x, parameters.m - 1);
+
+        // Use the second challenge to define our bilinear mapping
+        let prover_mapping = YMapping::new(challenge.y, parameters.n);
+
+        let mut c_d_i = b_commits
+            .iter()
+            .zip(x_challenge_powers.iter().skip(1))
+            .map(|(&b_i_commit, &x_power_i)| b_i_commit * x_power_i)
+            .collect::<Vec<Comm::Commitment>>();
+
+        let temp_x_c_d_shifted = b_commits
+            .iter()
+            .skip(1)
+            .zip(x_challenge_powers.iter().skip(1))
+            .map(|(&b_i_commit, &x_power_i)| b_i_commit * x_power_i)
+            .collect::<Vec<Comm::Commitment>>();
+
+        let final_cd: Comm::Commitment = temp_x_c_d_shifted
+            .iter()
+            .fold(Comm::Commitment::zero(), |acc, &x| acc + x);
+        c_d_i.push(final_cd);
+
+        // Engage in zero argument
+        let zero_arg_parameters = zero_value_bilinear_map::Parameters::new(
+            parameters.m,
+            parameters.n,
+            parameters.commit_key,
+        );
+
+        let minus_one = -Scalar::one();
+        let vec_minus_ones = vec![minus_one; parameters.n];
+        let minus_ones_commit =
+            Comm::commit(parameters.commit_key, &vec_minus_ones, Scalar::zero())?;
+        let vec_commits_to_a: Vec<Comm::Commitment> =
+            [&self.commitment_to_a[1..], &[minus_ones_commit]]
+                .concat()
+                .to_vec();
+
+        Ok(zero_value_bilinear_map::verifier::Verifier {
+            parameters: zero_arg_parameters,
+            commitment_to_a: Cow::Owned(vec_commits_to_a),
+            commitment_to_b: Cow::Owned(c_d_i),
+            bilinear_map: Cow::Owned(prover_mapping),
+        })
+    }
+
+    pub fn verify<T: Transcript>(
+        self,
+        proof: &Proof<Scalar, Comm>,
+        transcript: &mut T,
+    ) -> Result<(), CryptoError> {
+        // Extract challenges
+        let challenge = round_1_challenges(
+            &self.parameters,
+            &self.statement(),
+            &proof.round_1,
+            transcript,
+        )?;
+
+        match self
+            .round_1(&proof.round_1, &challenge)?
+            .verify(&proof.zero_arg_proof, transcript)
+        {
+            Ok(_) => Ok(()),
+            Err(_) => Err(CryptoError::ProofVerificationError(String::from(
+                "Hadamard Product (5.1)",
+            ))),
+        }
+    }
+
+    /// Run the verifier and return the equations of the zero argument instead of checking them.
+    pub(crate) fn equations<T: Transcript>(
+        self,
+        proof: &Proof<Scalar, Comm>,
+        transcript: &mut T,
+    ) -> Result<Vec<CommitmentEquation<Scalar, Comm>>, CryptoError> {
+        let challenge = round_1_challenges(
+            &self.parameters,
+            &self.statement(),
+            &proof.round_1,
+            transcript,
+        )?;
+
+        self.round_1(&proof.round_1, &challenge)?
+            .equations(&proof.zero_arg_proof, transcript)
+    }
+}
@@ -0,0 +1,68 @@
+use crate::error::CryptoError;
+use crate::utils::validation::{validate_point, Validate, ValidatePoint};
+
+use ark_ec::ProjectiveCurve;
+use ark_ff::Field;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::io::{Read, Write};
+use ark_std::{rand::Rng, UniformRand};
+
+/// Message of a folding round: the cross terms of the commitments to a and b and of their inner product.
+#[derive(Clone, Debug, PartialEq, CanonicalDeserialize, CanonicalSerialize)]
+pub struct RoundMessage<C>
+where
+    C: ProjectiveCurve,
+{
+    pub l_a: C::Affine,
+    pub r_a: C::Affine,
+    pub l_b: C::Affine,
+    pub r_b: C::Affine,
+    pub l_c: C::ScalarField,
+    pub r_c: C::ScalarField,
+}
+
+/// Challenge x used to fold the vectors and the generators at the end of a round.
+#[derive(Clone, Copy, Debug, PartialEq, CanonicalDeserialize, CanonicalSerialize)]
+pub struct RoundChallenge<F: Field> {
+    pub x: F,
+}
+
+/// Last message of the prover: the vectors folded down to a single element.
+#[derive(Clone, Debug, PartialEq, CanonicalDeserialize, CanonicalSerialize)]
+pub struct FinalMessage<C>
+where
+    C: ProjectiveCurve,
+{
+    pub a: C::ScalarField,
+    pub b: C::ScalarField,
+}
+
+/// Weight rho used by the verifier to check both folded commitments at once. It is never sent to the prover.
+#[derive(Clone, Copy, Debug, PartialEq, CanonicalDeserialize, CanonicalSerialize)]
+pub struct FinalChallenge<F: Field> {
+    pub rho: F,
+}
+
+impl<F: Field> UniformRand for RoundChallenge<F> {
+    fn rand<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Self { x: F::rand(rng) }
+    }
+}
+
+impl<F: Field> UniformRand for FinalChallenge<F> {
+    fn rand<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Self { rho: F::rand(rng) }
+    }
+}
+
+impl<C> Validate for RoundMessage<C>
+where
+    C: ProjectiveCurve,
+    C::Affine: ValidatePoint,
+{
+    fn validate(&self) -> Result<(), CryptoError> {
+        [&self.l_a, &self.r_a, &self.l_b, &self.r_b]
+            .iter()
+            .try_for_each(|point| validate_point(*point))
+    }
+}
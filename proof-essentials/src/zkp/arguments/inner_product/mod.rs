@@ -0,0 +1,171 @@
+pub mod messages;
+pub mod proof;
+pub mod prover;
+mod tests;
+pub mod verifier;
+
+use crate::error::CryptoError;
+use crate::vector_commitment::pedersen::{CommitKey, Commitment};
+use crate::zkp::{transcript::Transcript, ArgumentOfKnowledge};
+use ark_ec::ProjectiveCurve;
+use ark_ff::{batch_inversion, Field, Zero};
+use ark_std::{marker::PhantomData, rand::Rng};
+
+/// Logarithmic-size argument of knowledge of two vectors a and b of length n, committed as `<a, g>` and `<b, g>`
+/// with the generators of a Pedersen commit key, such that the weighted inner product `sum a_i * b_i * w_i` equals
+/// a public value. Each round halves both vectors along with their generators, as in the inner product argument
+/// of Bulletproofs, so the proof contains `4 * ceil(log2(n))` points and `2 * ceil(log2(n)) + 2` scalars.
+///
+/// The commitments are not blinded and the argument is not zero-knowledge: it is meant to replace vectors that
+/// would otherwise be sent in the clear, e.g. the blinded openings of the last round of a Sigma protocol.
+pub struct InnerProductArgument<'a, C: ProjectiveCurve> {
+    _group: PhantomData<&'a C>,
+}
+
+pub type Parameters<C> = CommitKey<C>;
+
+/// Statement for the inner product argument: commitments `<a, g>` and `<b, g>` to two vectors, non-zero weights w
+/// of the same length as the vectors and the claimed value of `sum a_i * b_i * w_i`.
+pub struct Statement<'a, C: ProjectiveCurve> {
+    pub commitment_to_a: &'a Commitment<C>,
+    pub commitment_to_b: &'a Commitment<C>,
+    pub weights: &'a [C::ScalarField],
+    pub value: C::ScalarField,
+}
+
+impl<'a, C: ProjectiveCurve> Statement<'a, C> {
+    pub fn new(
+        commitment_to_a: &'a Commitment<C>,
+        commitment_to_b: &'a Commitment<C>,
+        weights: &'a [C::ScalarField],
+        value: C::ScalarField,
+    ) -> Self {
+        Self {
+            commitment_to_a,
+            commitment_to_b,
+            weights,
+            value,
+        }
+    }
+
+    /// Check that the vectors are not empty and fit in the commit key, and return the inverses of the weights.
+    pub(crate) fn inverse_weights(
+        &self,
+        parameters: &Parameters<C>,
+    ) -> Result<Vec<C::ScalarField>, CryptoError> {
+        let n = self.weights.len();
+        if n == 0 || n > parameters.g().len() {
+            return Err(CryptoError::CommitmentLengthError(
+                String::from("Inner Product"),
+                n,
+                parameters.g().len(),
+            ));
+        }
+
+        if self.weights.iter().any(|w| w.is_zero()) {
+            return Err(CryptoError::ZeroInnerProductWeight);
+        }
+
+        let mut inverses = self.weights.to_vec();
+        batch_inversion(&mut inverses);
+
+        Ok(inverses)
+    }
+}
+
+pub struct Witness<'a, Scalar: Field> {
+    pub a: &'a [Scalar],
+    pub b: &'a [Scalar],
+}
+
+impl<'a, Scalar: Field> Witness<'a, Scalar> {
+    pub fn new(a: &'a [Scalar], b: &'a [Scalar]) -> Self {
+        Self { a, b }
+    }
+}
+
+impl<'a, C: ProjectiveCurve> ArgumentOfKnowledge for InnerProductArgument<'a, C> {
+    type CommonReferenceString = Parameters<C>;
+    type Statement = Statement<'a, C>;
+    type Witness = Witness<'a, C::ScalarField>;
+    type Proof = proof::Proof<C>;
+
+    fn prove<R: Rng, T: Transcript>(
+        _rng: &mut R,
+        common_reference_string: &Self::CommonReferenceString,
+        statement: &Self::Statement,
+        witness: &Self::Witness,
+        transcript: &mut T,
+    ) -> Result<Self::Proof, CryptoError> {
+        let prover = prover::Prover::new(common_reference_string, statement, witness);
+        prover.prove(transcript)
+    }
+
+    fn verify<T: Transcript>(
+        common_reference_string: &Self::CommonReferenceString,
+        statement: &Self::Statement,
+        proof: &Self::Proof,
+        transcript: &mut T,
+    ) -> Result<(), CryptoError> {
+        proof.verify(common_reference_string, statement, transcript)
+    }
+}
+
+/// Absorb the public parameters and the statement.
+pub(crate) fn absorb_statement<C: ProjectiveCurve, T: Transcript>(
+    parameters: &Parameters<C>,
+    statement: &Statement<C>,
+    transcript: &mut T,
+) -> Result<(), CryptoError> {
+    transcript.domain_separator(b"Inner Product Argument");
+    transcript.append(b"commit key", parameters)?;
+    transcript.append(b"commitment to a", statement.commitment_to_a)?;
+    transcript.append(b"commitment to b", statement.commitment_to_b)?;
+    transcript.append(b"weights", &statement.weights)?;
+    transcript.append(b"value", &statement.value)?;
+
+    Ok(())
+}
+
+/// Absorb the cross terms of a folding round, and derive the folding challenge.
+pub(crate) fn round_challenge<C: ProjectiveCurve, T: Transcript>(
+    round: usize,
+    message: &messages::RoundMessage<C>,
+    transcript: &mut T,
+) -> Result<messages::RoundChallenge<C::ScalarField>, CryptoError> {
+    transcript.round(round as u64);
+    transcript.append(b"l_a", &message.l_a)?;
+    transcript.append(b"r_a", &message.r_a)?;
+    transcript.append(b"l_b", &message.l_b)?;
+    transcript.append(b"r_b", &message.r_b)?;
+    transcript.append(b"l_c", &message.l_c)?;
+    transcript.append(b"r_c", &message.r_c)?;
+
+    Ok(messages::RoundChallenge {
+        x: transcript.challenge_scalar(b"x"),
+    })
+}
+
+/// Absorb the folded vectors, and derive the weight used to check both folded commitments at once.
+pub(crate) fn final_challenge<C: ProjectiveCurve, T: Transcript>(
+    message: &messages::FinalMessage<C>,
+    transcript: &mut T,
+) -> Result<messages::FinalChallenge<C::ScalarField>, CryptoError> {
+    transcript.append(b"a", &message.a)?;
+    transcript.append(b"b", &message.b)?;
+
+    Ok(messages::FinalChallenge {
+        rho: transcript.challenge_scalar(b"rho"),
+    })
+}
+
+/// Lengths of the vectors before each round, down to the final length of 1. A round maps a vector of length m to
+/// one of length `ceil(m / 2)`: when m is odd, the last element of the lower half has no counterpart.
+pub(crate) fn round_lengths(n: usize) -> Vec<usize> {
+    let mut lengths = vec![n];
+    while let Some(&m) = lengths.last().filter(|&&m| m > 1) {
+        lengths.push(m.div_ceil(2));
+    }
+
+    lengths
+}
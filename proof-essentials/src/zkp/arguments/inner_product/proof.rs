@@ -0,0 +1,43 @@
+use super::messages::{FinalMessage, RoundMessage};
+use super::{verifier::Verifier, Parameters, Statement};
+
+use crate::error::CryptoError;
+use crate::utils::validation::{Validate, ValidatePoint};
+use crate::zkp::transcript::Transcript;
+
+use ark_ec::ProjectiveCurve;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::io::{Read, Write};
+
+#[derive(Clone, Debug, PartialEq, CanonicalDeserialize, CanonicalSerialize)]
+pub struct Proof<C>
+where
+    C: ProjectiveCurve,
+{
+    // One message per round
+    pub(crate) rounds: Vec<RoundMessage<C>>,
+
+    // Folded vectors
+    pub(crate) folded: FinalMessage<C>,
+}
+
+impl<C: ProjectiveCurve> Proof<C> {
+    pub fn verify<T: Transcript>(
+        &self,
+        parameters: &Parameters<C>,
+        statement: &Statement<C>,
+        transcript: &mut T,
+    ) -> Result<(), CryptoError> {
+        Verifier::new(parameters, statement).verify(self, transcript)
+    }
+}
+
+impl<C> Validate for Proof<C>
+where
+    C: ProjectiveCurve,
+    C::Affine: ValidatePoint,
+{
+    fn validate(&self) -> Result<(), CryptoError> {
+        self.rounds.validate()
+    }
+}
@@ -0,0 +1,202 @@
+use super::messages::{FinalMessage, RoundChallenge, RoundMessage};
+use super::{
+    absorb_statement, final_challenge, proof::Proof, round_challenge, Parameters, Statement,
+    Witness,
+};
+
+use crate::error::CryptoError;
+use crate::vector_commitment::pedersen::Commitment;
+use crate::zkp::transcript::Transcript;
+use ark_ec::{msm::VariableBaseMSM, AffineCurve, ProjectiveCurve};
+use ark_ff::{PrimeField, Zero};
+use std::borrow::Cow;
+
+/// Prover of the inner product argument. It borrows the commit key, the weights and the witness, except when another
+/// argument hands over to it with values derived during its own rounds, which the prover then owns.
+pub struct Prover<'a, C: ProjectiveCurve> {
+    pub(crate) parameters: Cow<'a, Parameters<C>>,
+    pub(crate) commitment_to_a: Commitment<C>,
+    pub(crate) commitment_to_b: Commitment<C>,
+    pub(crate) weights: Cow<'a, [C::ScalarField]>,
+    pub(crate) value: C::ScalarField,
+    pub(crate) a: Cow<'a, [C::ScalarField]>,
+    pub(crate) b: Cow<'a, [C::ScalarField]>,
+}
+
+impl<'a, C: ProjectiveCurve> Prover<'a, C> {
+    pub fn new(
+        parameters: &'a Parameters<C>,
+        statement: &Statement<'a, C>,
+        witness: &Witness<'a, C::ScalarField>,
+    ) -> Self {
+        Self {
+            parameters: Cow::Borrowed(parameters),
+            commitment_to_a: *statement.commitment_to_a,
+            commitment_to_b: *statement.commitment_to_b,
+            weights: Cow::Borrowed(statement.weights),
+            value: statement.value,
+            a: Cow::Borrowed(witness.a),
+            b: Cow::Borrowed(witness.b),
+        }
+    }
+
+    fn statement(&self) -> Statement<'_, C> {
+        Statement::new(
+            &self.commitment_to_a,
+            &self.commitment_to_b,
+            &self.weights,
+            self.value,
+        )
+    }
+
+    /// Weight the vectors and the generators, after which the prover is ready for the folding rounds.
+    pub fn start(self) -> Result<FoldingProver<C>, CryptoError> {
+        let inverse_weights = self.statement().inverse_weights(&self.parameters)?;
+        let n = inverse_weights.len();
+
+        if self.a.len() != n || self.b.len() != n {
+            return Err(CryptoError::DotProductLengthError(
+                self.a.len(),
+                self.b.len(),
+            ));
+        }
+
+        // Fold b∘w under the generators g∘w^(-1), so that the commitment to b is unchanged and the weighted inner
+        // product of a and b is the plain inner product of a and b∘w
+        let b = self
+            .b
+            .iter()
+            .zip(self.weights.iter())
+            .map(|(b_i, w_i)| *b_i * w_i)
+            .collect::<Vec<_>>();
+        let g = self.parameters.g()[..n].to_vec();
+        let h = C::batch_normalization_into_affine(
+            &g.iter()
+                .zip(inverse_weights.iter())
+                .map(|(g_i, w_i)| g_i.mul(w_i.into_repr()))
+                .collect::<Vec<_>>(),
+        );
+
+        Ok(FoldingProver {
+            a: self.a.into_owned(),
+            b,
+            g,
+            h,
+        })
+    }
+
+    pub fn prove<T: Transcript>(self, transcript: &mut T) -> Result<Proof<C>, CryptoError> {
+        absorb_statement(&self.parameters, &self.statement(), transcript)?;
+
+        let mut prover = self.start()?;
+        let mut rounds = Vec::new();
+        while let Some(message) = prover.round_message() {
+            let challenge = round_challenge(rounds.len() + 1, &message, transcript)?;
+            prover.fold(&challenge);
+            rounds.push(message);
+        }
+
+        let folded = prover.final_message();
+
+        // The verifier derives one more challenge, which keeps both transcripts in the same state
+        final_challenge(&folded, transcript)?;
+
+        Ok(Proof { rounds, folded })
+    }
+}
+
+/// Prover of the inner product argument during the folding rounds.
+pub struct FoldingProver<C: ProjectiveCurve> {
+    a: Vec<C::ScalarField>,
+    b: Vec<C::ScalarField>,
+    g: Vec<C::Affine>,
+    h: Vec<C::Affine>,
+}
+
+impl<C: ProjectiveCurve> FoldingProver<C> {
+    /// Cross terms of the next folding round, or `None` once the vectors are folded down to a single element.
+    pub fn round_message(&self) -> Option<RoundMessage<C>> {
+        if self.a.len() <= 1 {
+            return None;
+        }
+
+        let half = self.a.len().div_ceil(2);
+        let (a_lo, a_hi) = self.a.split_at(half);
+        let (b_lo, b_hi) = self.b.split_at(half);
+        let (g_lo, g_hi) = self.g.split_at(half);
+        let (h_lo, h_hi) = self.h.split_at(half);
+        let k = a_hi.len();
+
+        let l_a = Self::msm(g_hi, &a_lo[..k]);
+        let r_a = Self::msm(&g_lo[..k], a_hi);
+        let l_b = Self::msm(h_hi, &b_lo[..k]);
+        let r_b = Self::msm(&h_lo[..k], b_hi);
+        let points = C::batch_normalization_into_affine(&[l_a, r_a, l_b, r_b]);
+
+        Some(RoundMessage {
+            l_a: points[0],
+            r_a: points[1],
+            l_b: points[2],
+            r_b: points[3],
+            l_c: Self::inner_product(&a_lo[..k], b_hi),
+            r_c: Self::inner_product(a_hi, &b_lo[..k]),
+        })
+    }
+
+    /// Fold the vectors and the generators with the challenge of the current round.
+    pub fn fold(&mut self, challenge: &RoundChallenge<C::ScalarField>) {
+        let x = challenge.x;
+        let half = self.a.len().div_ceil(2);
+        let (a_lo, a_hi) = self.a.split_at(half);
+        let (b_lo, b_hi) = self.b.split_at(half);
+        let (g_lo, g_hi) = self.g.split_at(half);
+        let (h_lo, h_hi) = self.h.split_at(half);
+
+        // a' = a_lo + x*a_hi and g' = x*g_lo + g_hi, b' = x*b_lo + b_hi and h' = h_lo + x*h_hi
+        let x_repr = x.into_repr();
+        let folded_a = (0..half)
+            .map(|i| a_lo[i] + a_hi.get(i).map_or(C::ScalarField::zero(), |a| x * a))
+            .collect();
+        let folded_b = (0..half)
+            .map(|i| x * b_lo[i] + b_hi.get(i).copied().unwrap_or_else(Zero::zero))
+            .collect();
+        let folded_g = (0..half)
+            .map(|i| {
+                let mut point = g_lo[i].mul(x_repr);
+                if let Some(g_hi) = g_hi.get(i) {
+                    point.add_assign_mixed(g_hi);
+                }
+                point
+            })
+            .collect::<Vec<_>>();
+        let folded_h = (0..half)
+            .map(|i| {
+                let mut point = h_hi.get(i).map_or(C::zero(), |h| h.mul(x_repr));
+                point.add_assign_mixed(&h_lo[i]);
+                point
+            })
+            .collect::<Vec<_>>();
+
+        self.a = folded_a;
+        self.b = folded_b;
+        self.g = C::batch_normalization_into_affine(&folded_g);
+        self.h = C::batch_normalization_into_affine(&folded_h);
+    }
+
+    /// Folded vectors, sent once no folding round is left.
+    pub fn final_message(&self) -> FinalMessage<C> {
+        FinalMessage {
+            a: self.a[0],
+            b: self.b[0],
+        }
+    }
+
+    fn msm(bases: &[C::Affine], scalars: &[C::ScalarField]) -> C {
+        let scalars = scalars.iter().map(|s| s.into_repr()).collect::<Vec<_>>();
+        VariableBaseMSM::multi_scalar_mul(bases, &scalars)
+    }
+
+    fn inner_product(a: &[C::ScalarField], b: &[C::ScalarField]) -> C::ScalarField {
+        a.iter().zip(b.iter()).map(|(a_i, b_i)| *a_i * b_i).sum()
+    }
+}
@@ -0,0 +1,158 @@
+#[cfg(test)]
+mod test {
+    use crate::error::CryptoError;
+    use crate::utils::rand::sample_vector;
+    use crate::utils::validation::deserialize_validated;
+    use crate::vector_commitment::{pedersen, HomomorphicCommitmentScheme};
+    use crate::zkp::{arguments::inner_product, ArgumentOfKnowledge};
+
+    use ark_ff::{One, Zero};
+    use ark_marlin::rng::FiatShamirRng;
+    use ark_serialize::CanonicalSerialize;
+    use ark_std::{rand::thread_rng, UniformRand};
+    use blake2::Blake2s;
+    use starknet_curve;
+
+    // Choose elliptic curve setting
+    type Curve = starknet_curve::Projective;
+    type Scalar = starknet_curve::Fr;
+
+    // Type aliases for concrete instances using the chosen EC.
+    type Comm = pedersen::PedersenCommitment<Curve>;
+    type Witness<'a> = inner_product::Witness<'a, Scalar>;
+    type Statement<'a> = inner_product::Statement<'a, Curve>;
+    type InnerProductArgument<'a> = inner_product::InnerProductArgument<'a, Curve>;
+
+    // Fiat Shamir
+    type FS = FiatShamirRng<Blake2s>;
+
+    fn weighted_inner_product(a: &[Scalar], b: &[Scalar], weights: &[Scalar]) -> Scalar {
+        a.iter()
+            .zip(b.iter())
+            .zip(weights.iter())
+            .map(|((a_i, b_i), w_i)| *a_i * b_i * w_i)
+            .sum()
+    }
+
+    #[test]
+    fn test_inner_product_argument() {
+        let rng = &mut thread_rng();
+        let commit_key = Comm::setup(rng, 16);
+
+        // Lengths which are and are not powers of two, with and without weights
+        for n in [1, 2, 5, 13, 16] {
+            let a: Vec<Scalar> = sample_vector(rng, n);
+            let b: Vec<Scalar> = sample_vector(rng, n);
+            let commitment_to_a = Comm::commit(&commit_key, &a, Scalar::zero()).unwrap();
+            let commitment_to_b = Comm::commit(&commit_key, &b, Scalar::zero()).unwrap();
+
+            for weights in [vec![Scalar::one(); n], sample_vector(rng, n)] {
+                let value = weighted_inner_product(&a, &b, &weights);
+                let statement = Statement::new(&commitment_to_a, &commitment_to_b, &weights, value);
+                let witness = Witness::new(&a, &b);
+
+                let mut fs_rng = FS::from_seed(b"Initialised with some input");
+                let proof = InnerProductArgument::prove(
+                    rng,
+                    &commit_key,
+                    &statement,
+                    &witness,
+                    &mut fs_rng,
+                )
+                .unwrap();
+                assert_eq!(
+                    proof.rounds.len(),
+                    n.next_power_of_two().trailing_zeros() as usize
+                );
+
+                let mut bytes = Vec::new();
+                proof.serialize_unchecked(&mut bytes).unwrap();
+                let received_proof = deserialize_validated(&bytes[..]).unwrap();
+
+                let mut fs_rng = FS::from_seed(b"Initialised with some input");
+                assert_eq!(
+                    Ok(()),
+                    InnerProductArgument::verify(
+                        &commit_key,
+                        &statement,
+                        &received_proof,
+                        &mut fs_rng
+                    )
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_wrong_value() {
+        let rng = &mut thread_rng();
+        let n = 13;
+        let commit_key = Comm::setup(rng, n);
+
+        let a: Vec<Scalar> = sample_vector(rng, n);
+        let b: Vec<Scalar> = sample_vector(rng, n);
+        let weights: Vec<Scalar> = sample_vector(rng, n);
+        let commitment_to_a = Comm::commit(&commit_key, &a, Scalar::zero()).unwrap();
+        let commitment_to_b = Comm::commit(&commit_key, &b, Scalar::zero()).unwrap();
+
+        let value = weighted_inner_product(&a, &b, &weights) + Scalar::one();
+        let statement = Statement::new(&commitment_to_a, &commitment_to_b, &weights, value);
+        let witness = Witness::new(&a, &b);
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let proof =
+            InnerProductArgument::prove(rng, &commit_key, &statement, &witness, &mut fs_rng)
+                .unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            Err(CryptoError::ProofVerificationError(String::from(
+                "Inner Product Argument"
+            ))),
+            InnerProductArgument::verify(&commit_key, &statement, &proof, &mut fs_rng)
+        );
+    }
+
+    #[test]
+    fn test_wrong_opening() {
+        let rng = &mut thread_rng();
+        let n = 13;
+        let commit_key = Comm::setup(rng, n);
+
+        let a: Vec<Scalar> = sample_vector(rng, n);
+        let b: Vec<Scalar> = sample_vector(rng, n);
+        let weights: Vec<Scalar> = sample_vector(rng, n);
+        let commitment_to_a = Comm::commit(&commit_key, &a, Scalar::zero()).unwrap();
+        let commitment_to_b = Comm::commit(&commit_key, &b, Scalar::zero()).unwrap();
+
+        // The value is consistent with the witness, but the witness does not open the commitment to b
+        let mut another_b = b.clone();
+        another_b[12] = Scalar::rand(rng);
+        let value = weighted_inner_product(&a, &another_b, &weights);
+        let statement = Statement::new(&commitment_to_a, &commitment_to_b, &weights, value);
+        let witness = Witness::new(&a, &another_b);
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let proof =
+            InnerProductArgument::prove(rng, &commit_key, &statement, &witness, &mut fs_rng)
+                .unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            Err(CryptoError::ProofVerificationError(String::from(
+                "Inner Product Argument"
+            ))),
+            InnerProductArgument::verify(&commit_key, &statement, &proof, &mut fs_rng)
+        );
+
+        // Weights must be invertible
+        let mut zero_weights = weights.clone();
+        zero_weights[3] = Scalar::zero();
+        let statement = Statement::new(&commitment_to_a, &commitment_to_b, &zero_weights, value);
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            Err(CryptoError::ZeroInnerProductWeight),
+            InnerProductArgument::verify(&commit_key, &statement, &proof, &mut fs_rng)
+        );
+    }
+}
@@ -0,0 +1,177 @@
+use super::messages::{FinalChallenge, FinalMessage, RoundChallenge, RoundMessage};
+use super::{
+    absorb_statement, final_challenge, proof::Proof, round_challenge, round_lengths, Parameters,
+    Statement,
+};
+
+use crate::error::CryptoError;
+use crate::vector_commitment::pedersen::Commitment;
+use crate::zkp::transcript::Transcript;
+
+use ark_ec::{msm::VariableBaseMSM, ProjectiveCurve};
+use ark_ff::{Field, One, PrimeField};
+use std::borrow::Cow;
+
+/// Verifier of the inner product argument. It borrows the commit key and the weights, except when another argument
+/// hands over to it with values derived during its own rounds, which the verifier then owns.
+pub struct Verifier<'a, C: ProjectiveCurve> {
+    pub(crate) parameters: Cow<'a, Parameters<C>>,
+    pub(crate) commitment_to_a: Commitment<C>,
+    pub(crate) commitment_to_b: Commitment<C>,
+    pub(crate) weights: Cow<'a, [C::ScalarField]>,
+    pub(crate) value: C::ScalarField,
+    pub(crate) rounds: Vec<RoundMessage<C>>,
+    pub(crate) challenges: Vec<C::ScalarField>,
+}
+
+impl<'a, C: ProjectiveCurve> Verifier<'a, C> {
+    pub fn new(parameters: &'a Parameters<C>, statement: &Statement<'a, C>) -> Self {
+        Self {
+            parameters: Cow::Borrowed(parameters),
+            commitment_to_a: *statement.commitment_to_a,
+            commitment_to_b: *statement.commitment_to_b,
+            weights: Cow::Borrowed(statement.weights),
+            value: statement.value,
+            rounds: Vec::new(),
+            challenges: Vec::new(),
+        }
+    }
+
+    fn statement(&self) -> Statement<'_, C> {
+        Statement::new(
+            &self.commitment_to_a,
+            &self.commitment_to_b,
+            &self.weights,
+            self.value,
+        )
+    }
+
+    /// Number of folding rounds expected before the final message, i.e. `ceil(log2(n))` for vectors of length n.
+    pub fn rounds(&self) -> usize {
+        round_lengths(self.weights.len()).len() - 1
+    }
+
+    /// Receive the message of a folding round along with the challenge sent back to the prover.
+    pub fn fold(&mut self, message: &RoundMessage<C>, challenge: &RoundChallenge<C::ScalarField>) {
+        self.rounds.push(message.clone());
+        self.challenges.push(challenge.x);
+    }
+
+    /// Receive the folded vectors and check them against the statement and the messages of all the rounds.
+    pub fn finish(
+        self,
+        message: &FinalMessage<C>,
+        challenge: &FinalChallenge<C::ScalarField>,
+    ) -> Result<(), CryptoError> {
+        let inverse_weights = self.statement().inverse_weights(&self.parameters)?;
+        let lengths = round_lengths(inverse_weights.len());
+        let rounds = lengths.len() - 1;
+
+        if self.rounds.len() != rounds {
+            return Err(CryptoError::ProofVerificationError(String::from(
+                "Inner Product Argument",
+            )));
+        }
+
+        let challenges = &self.challenges;
+
+        // suffix[j] is the product of the challenges of the rounds after j. Each round maps the commitments and
+        // the inner product c to
+        //   P_a' = L_a + x*P_a + x^2*R_a,  P_b' = R_b + x*P_b + x^2*L_b,  c' = L_c + x*c + x^2*R_c
+        let mut suffix = vec![C::ScalarField::one(); rounds + 1];
+        for j in (0..rounds).rev() {
+            suffix[j] = suffix[j + 1] * challenges[j];
+        }
+        let product = suffix[0];
+
+        let folded_value = product * self.value
+            + (0..rounds)
+                .map(|j| {
+                    suffix[j + 1]
+                        * (self.rounds[j].l_c + challenges[j].square() * self.rounds[j].r_c)
+                })
+                .sum::<C::ScalarField>();
+
+        if folded_value != message.a * message.b {
+            return Err(CryptoError::ProofVerificationError(String::from(
+                "Inner Product Argument",
+            )));
+        }
+
+        // Coefficients of the original generators in the folded ones, obtained by unfolding the rounds backwards:
+        // g' = x*g_lo + g_hi and h' = h_lo + x*h_hi
+        let mut g_coefficients = vec![C::ScalarField::one()];
+        let mut h_coefficients = vec![C::ScalarField::one()];
+        for j in (0..rounds).rev() {
+            let half = lengths[j + 1];
+            let x = challenges[j];
+            g_coefficients = (0..lengths[j])
+                .map(|p| {
+                    if p < half {
+                        x * g_coefficients[p]
+                    } else {
+                        g_coefficients[p - half]
+                    }
+                })
+                .collect();
+            h_coefficients = (0..lengths[j])
+                .map(|p| {
+                    if p < half {
+                        h_coefficients[p]
+                    } else {
+                        x * h_coefficients[p - half]
+                    }
+                })
+                .collect();
+        }
+
+        // Check both folded commitments at once using a random linear combination:
+        // a*<s, g> + rho*b*<t, g∘w^(-1)> - P_a' - rho*P_b' ==? 0
+        let rho = challenge.rho;
+        let rho_b = rho * message.b;
+
+        let mut bases = self.parameters.g()[..inverse_weights.len()].to_vec();
+        let mut scalars = g_coefficients
+            .iter()
+            .zip(h_coefficients.iter().zip(inverse_weights.iter()))
+            .map(|(s, (t, w_inv))| message.a * s + rho_b * t * w_inv)
+            .collect::<Vec<_>>();
+
+        bases.extend(self.rounds.iter().map(|round| round.l_a));
+        scalars.extend((0..rounds).map(|j| -suffix[j + 1]));
+        bases.extend(self.rounds.iter().map(|round| round.r_a));
+        scalars.extend((0..rounds).map(|j| -suffix[j + 1] * challenges[j].square()));
+        bases.extend(self.rounds.iter().map(|round| round.l_b));
+        scalars.extend((0..rounds).map(|j| -rho * suffix[j + 1] * challenges[j].square()));
+        bases.extend(self.rounds.iter().map(|round| round.r_b));
+        scalars.extend((0..rounds).map(|j| -rho * suffix[j + 1]));
+
+        bases.extend_from_slice(&[self.commitment_to_a.0, self.commitment_to_b.0]);
+        scalars.extend_from_slice(&[-product, -rho * product]);
+
+        let scalars = scalars.iter().map(|s| s.into_repr()).collect::<Vec<_>>();
+        if !VariableBaseMSM::multi_scalar_mul(&bases, &scalars).is_zero() {
+            return Err(CryptoError::ProofVerificationError(String::from(
+                "Inner Product Argument",
+            )));
+        }
+
+        Ok(())
+    }
+
+    pub fn verify<T: Transcript>(
+        mut self,
+        proof: &Proof<C>,
+        transcript: &mut T,
+    ) -> Result<(), CryptoError> {
+        absorb_statement(&self.parameters, &self.statement(), transcript)?;
+
+        for (j, message) in proof.rounds.iter().enumerate() {
+            let challenge = round_challenge(j + 1, message, transcript)?;
+            self.fold(message, &challenge);
+        }
+
+        let challenge = final_challenge(&proof.folded, transcript)?;
+        self.finish(&proof.folded, &challenge)
+    }
+}
@@ -0,0 +1,50 @@
+use crate::error::CryptoError;
+use crate::utils::validation::Validate;
+use crate::vector_commitment::HomomorphicCommitmentScheme;
+
+use ark_ff::Field;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::io::{Read, Write};
+use ark_std::{rand::Rng, UniformRand};
+
+/// First message of the prover: commitments to the random masks u and their image A*u.
+#[derive(CanonicalDeserialize, CanonicalSerialize)]
+pub struct Round1Message<Scalar, Comm>
+where
+    Scalar: Field,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+{
+    pub vector_of_committed_masks: Vec<Comm::Commitment>,
+    pub masks_image: Vec<Scalar>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, CanonicalDeserialize, CanonicalSerialize)]
+pub struct Round1Challenge<Scalar: Field> {
+    pub x: Scalar,
+}
+
+/// Second message of the prover: the committed vectors and their randoms, blinded with the masks.
+#[derive(CanonicalDeserialize, CanonicalSerialize)]
+pub struct Round2Message<Scalar: Field> {
+    pub vectors_blinded: Vec<Vec<Scalar>>,
+    pub randoms_blinded: Vec<Scalar>,
+}
+
+impl<Scalar: Field> UniformRand for Round1Challenge<Scalar> {
+    fn rand<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Self {
+            x: Scalar::rand(rng),
+        }
+    }
+}
+
+impl<Scalar, Comm> Validate for Round1Message<Scalar, Comm>
+where
+    Scalar: Field,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+    Comm::Commitment: Validate,
+{
+    fn validate(&self) -> Result<(), CryptoError> {
+        self.vector_of_committed_masks.validate()
+    }
+}
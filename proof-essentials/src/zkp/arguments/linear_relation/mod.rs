@@ -0,0 +1,199 @@
+pub mod messages;
+pub mod proof;
+pub mod prover;
+mod tests;
+pub mod verifier;
+
+use crate::error::CryptoError;
+use crate::utils::vector_arithmetic::dot_product;
+use crate::vector_commitment::HomomorphicCommitmentScheme;
+use crate::zkp::{transcript::Transcript, ArgumentOfKnowledge};
+use ark_ff::Field;
+use ark_std::{marker::PhantomData, rand::Rng};
+
+/// Argument that committed vectors satisfy a system of public linear equations. The prover commits to random
+/// masks u and sends their image A*u, then answers a challenge c with `z = u + c*x`. The verifier checks the
+/// openings of the commitments homomorphically and that `A*z == A*u + c*y`.
+pub struct LinearRelationArgument<'a, F, Comm>
+where
+    F: Field,
+    Comm: HomomorphicCommitmentScheme<F>,
+{
+    _field: PhantomData<&'a F>,
+    _commitment_scheme: PhantomData<&'a Comm>,
+}
+
+impl<'a, Scalar, Comm> ArgumentOfKnowledge for LinearRelationArgument<'a, Scalar, Comm>
+where
+    Scalar: Field,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+{
+    type CommonReferenceString = Parameters<'a, Scalar, Comm>;
+    type Statement = Statement<'a, Scalar, Comm>;
+    type Witness = Witness<'a, Scalar>;
+    type Proof = proof::Proof<Scalar, Comm>;
+
+    fn prove<R: Rng, T: Transcript>(
+        rng: &mut R,
+        common_reference_string: &Self::CommonReferenceString,
+        statement: &Self::Statement,
+        witness: &Self::Witness,
+        transcript: &mut T,
+    ) -> Result<Self::Proof, CryptoError> {
+        let prover = prover::Prover::new(common_reference_string, statement, witness);
+        let proof = prover.prove(rng, transcript)?;
+
+        Ok(proof)
+    }
+
+    fn verify<T: Transcript>(
+        common_reference_string: &Self::CommonReferenceString,
+        statement: &Self::Statement,
+        proof: &Self::Proof,
+        transcript: &mut T,
+    ) -> Result<(), CryptoError> {
+        proof.verify(common_reference_string, statement, transcript)
+    }
+}
+
+/// Parameters for the linear relation argument. Contains a commitment key, the number m of committed vectors and
+/// their length n.
+pub struct Parameters<'a, Scalar, Comm>
+where
+    Scalar: Field,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+{
+    pub commit_key: &'a Comm::CommitKey,
+    pub m: usize,
+    pub n: usize,
+}
+
+impl<'a, Scalar, Comm> Parameters<'a, Scalar, Comm>
+where
+    Scalar: Field,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+{
+    pub fn new(m: usize, n: usize, commit_key: &'a Comm::CommitKey) -> Self {
+        Self { commit_key, m, n }
+    }
+}
+
+/// Witness for the linear relation argument. Contains m vectors x_1, ..., x_m and a vector r such that
+/// `commitments` (see `Statement`) are commitments to the vectors using the randoms r and `A*(x_1 || ... || x_m) = y`.
+pub struct Witness<'a, Scalar>
+where
+    Scalar: Field,
+{
+    pub vectors: &'a Vec<Vec<Scalar>>,
+    pub randoms: &'a Vec<Scalar>,
+}
+
+impl<'a, Scalar> Witness<'a, Scalar>
+where
+    Scalar: Field,
+{
+    pub fn new(vectors: &'a Vec<Vec<Scalar>>, randoms: &'a Vec<Scalar>) -> Self {
+        Self { vectors, randoms }
+    }
+}
+
+/// Statement for the linear relation argument. Contains a vector of `commitments` to m vectors of length n, a
+/// public matrix A with `m*n` columns and a public vector y with one entry per row of A. Each row of A applies to
+/// the concatenation of the committed vectors, e.g. `[a_1, ..., a_m]` on commitments to single scalars encodes
+/// `sum a_i*x_i = y` and `[1, 1, -1]` encodes that the third commitment opens to the sum of the first two.
+pub struct Statement<'a, Scalar, Comm>
+where
+    Scalar: Field,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+{
+    pub commitments: &'a Vec<Comm::Commitment>,
+    pub matrix: &'a Vec<Vec<Scalar>>,
+    pub vector: &'a Vec<Scalar>,
+}
+
+impl<'a, Scalar, Comm> Statement<'a, Scalar, Comm>
+where
+    Scalar: Field,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+{
+    pub fn new(
+        commitments: &'a Vec<Comm::Commitment>,
+        matrix: &'a Vec<Vec<Scalar>>,
+        vector: &'a Vec<Scalar>,
+    ) -> Self {
+        Self {
+            commitments,
+            matrix,
+            vector,
+        }
+    }
+
+    /// Check the dimensions of the statement, including the width of each row of the matrix, against the parameters.
+    pub(crate) fn check(&self, parameters: &Parameters<Scalar, Comm>) -> Result<(), CryptoError> {
+        if self.commitments.len() != parameters.m {
+            return Err(CryptoError::LinearRelationLengthError(
+                parameters.m,
+                self.commitments.len(),
+            ));
+        }
+
+        if self.matrix.len() != self.vector.len() {
+            return Err(CryptoError::LinearRelationLengthError(
+                self.matrix.len(),
+                self.vector.len(),
+            ));
+        }
+
+        // Each row applies to the concatenation of the m committed vectors
+        let width = parameters.m * parameters.n;
+        if let Some(row) = self.matrix.iter().find(|row| row.len() != width) {
+            return Err(CryptoError::LinearRelationLengthError(width, row.len()));
+        }
+
+        Ok(())
+    }
+}
+
+/// Apply a matrix to the concatenation of the given vectors.
+pub(crate) fn linear_map<Scalar: Field>(
+    matrix: &[Vec<Scalar>],
+    vectors: &[Vec<Scalar>],
+) -> Result<Vec<Scalar>, CryptoError> {
+    let concatenated = vectors.concat();
+    matrix
+        .iter()
+        .map(|row| dot_product(row, &concatenated))
+        .collect()
+}
+
+/// Absorb the public parameters, the statement and the messages of the first round, and derive the challenge x.
+pub(crate) fn round_1_challenge<Scalar, Comm, T>(
+    parameters: &Parameters<Scalar, Comm>,
+    statement: &Statement<Scalar, Comm>,
+    message: &messages::Round1Message<Scalar, Comm>,
+    transcript: &mut T,
+) -> Result<messages::Round1Challenge<Scalar>, CryptoError>
+where
+    Scalar: Field,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+    T: Transcript,
+{
+    transcript.domain_separator(b"Linear Relation Argument");
+
+    // Public information
+    transcript.append(b"commit key", parameters.commit_key)?;
+    transcript.append(b"m", &(parameters.m as u32))?;
+    transcript.append(b"n", &(parameters.n as u32))?;
+    transcript.append(b"commitments", statement.commitments)?;
+    transcript.append(b"matrix", statement.matrix)?;
+    transcript.append(b"vector", statement.vector)?;
+
+    // Commitments
+    transcript.round(1);
+    transcript.append(b"mask commits", &message.vector_of_committed_masks)?;
+    transcript.append(b"masks image", &message.masks_image)?;
+
+    Ok(messages::Round1Challenge {
+        x: transcript.challenge_scalar(b"x"),
+    })
+}
@@ -0,0 +1,47 @@
+use super::messages::{Round1Message, Round2Message};
+use super::{verifier::Verifier, Parameters, Statement};
+
+use crate::error::CryptoError;
+use crate::utils::validation::Validate;
+use crate::vector_commitment::HomomorphicCommitmentScheme;
+use crate::zkp::transcript::Transcript;
+
+use ark_ff::Field;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::io::{Read, Write};
+
+#[derive(CanonicalDeserialize, CanonicalSerialize)]
+pub struct Proof<Scalar, Comm>
+where
+    Scalar: Field,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+{
+    pub(crate) round_1: Round1Message<Scalar, Comm>,
+    pub(crate) round_2: Round2Message<Scalar>,
+}
+
+impl<Scalar, Comm> Proof<Scalar, Comm>
+where
+    Scalar: Field,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+{
+    pub fn verify<T: Transcript>(
+        &self,
+        proof_parameters: &Parameters<Scalar, Comm>,
+        statement: &Statement<Scalar, Comm>,
+        transcript: &mut T,
+    ) -> Result<(), CryptoError> {
+        Verifier::new(proof_parameters, statement).verify(self, transcript)
+    }
+}
+
+impl<Scalar, Comm> Validate for Proof<Scalar, Comm>
+where
+    Scalar: Field,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+    Comm::Commitment: Validate,
+{
+    fn validate(&self) -> Result<(), CryptoError> {
+        self.round_1.validate()
+    }
+}
@@ -0,0 +1,160 @@
+use super::messages::{Round1Challenge, Round1Message, Round2Message};
+use super::{linear_map, proof::Proof, round_1_challenge, Parameters, Statement, Witness};
+
+use crate::error::CryptoError;
+use crate::utils::rand::sample_vector;
+use crate::vector_commitment::HomomorphicCommitmentScheme;
+use crate::zkp::transcript::Transcript;
+
+use ark_ff::Field;
+use rand::Rng;
+
+/// Prover waiting for the first challenge, with the first message to send to the verifier.
+type Round1<'a, Scalar, Comm> = (ProverRound1<'a, Scalar, Comm>, Round1Message<Scalar, Comm>);
+
+pub struct Prover<'a, Scalar, Comm>
+where
+    Scalar: Field,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+{
+    parameters: Parameters<'a, Scalar, Comm>,
+    commitments: &'a Vec<Comm::Commitment>,
+    matrix: &'a Vec<Vec<Scalar>>,
+    vector: &'a Vec<Scalar>,
+    vectors: &'a Vec<Vec<Scalar>>,
+    randoms: &'a Vec<Scalar>,
+}
+
+/// Prover of the linear relation argument once the masks are committed, waiting for the challenge x.
+pub struct ProverRound1<'a, Scalar, Comm>
+where
+    Scalar: Field,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+{
+    prover: Prover<'a, Scalar, Comm>,
+    masks: Vec<Vec<Scalar>>,
+    randoms_for_masks: Vec<Scalar>,
+}
+
+impl<'a, Scalar, Comm> Prover<'a, Scalar, Comm>
+where
+    Scalar: Field,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+{
+    pub fn new(
+        parameters: &Parameters<'a, Scalar, Comm>,
+        statement: &Statement<'a, Scalar, Comm>,
+        witness: &Witness<'a, Scalar>,
+    ) -> Self {
+        Self {
+            parameters: Parameters::new(parameters.m, parameters.n, parameters.commit_key),
+            commitments: statement.commitments,
+            matrix: statement.matrix,
+            vector: statement.vector,
+            vectors: witness.vectors,
+            randoms: witness.randoms,
+        }
+    }
+
+    fn statement(&self) -> Statement<'a, Scalar, Comm> {
+        Statement::new(self.commitments, self.matrix, self.vector)
+    }
+
+    pub fn round_1<R: Rng>(self, rng: &mut R) -> Result<Round1<'a, Scalar, Comm>, CryptoError> {
+        self.statement().check(&self.parameters)?;
+
+        let m = self.parameters.m;
+        let n = self.parameters.n;
+
+        if self.vectors.len() != m {
+            return Err(CryptoError::LinearRelationLengthError(
+                m,
+                self.vectors.len(),
+            ));
+        }
+
+        if self.randoms.len() != m {
+            return Err(CryptoError::LinearRelationLengthError(
+                m,
+                self.randoms.len(),
+            ));
+        }
+
+        if let Some(vector) = self.vectors.iter().find(|vector| vector.len() != n) {
+            return Err(CryptoError::LinearRelationLengthError(n, vector.len()));
+        }
+
+        let masks = (0..m)
+            .map(|_| sample_vector(rng, n))
+            .collect::<Vec<Vec<Scalar>>>();
+        let randoms_for_masks: Vec<Scalar> = sample_vector(rng, m);
+
+        let vector_of_committed_masks =
+            Comm::commit_columns(self.parameters.commit_key, &masks, &randoms_for_masks)?;
+        let masks_image = linear_map(self.matrix, &masks)?;
+
+        let message = Round1Message {
+            vector_of_committed_masks,
+            masks_image,
+        };
+
+        let state = ProverRound1 {
+            prover: self,
+            masks,
+            randoms_for_masks,
+        };
+
+        Ok((state, message))
+    }
+
+    pub fn prove<R: Rng, T: Transcript>(
+        self,
+        rng: &mut R,
+        transcript: &mut T,
+    ) -> Result<Proof<Scalar, Comm>, CryptoError> {
+        let (prover, round_1) = self.round_1(rng)?;
+        let x = round_1_challenge(
+            &prover.prover.parameters,
+            &prover.prover.statement(),
+            &round_1,
+            transcript,
+        )?;
+        let round_2 = prover.round_2(&x);
+
+        Ok(Proof { round_1, round_2 })
+    }
+}
+
+impl<'a, Scalar, Comm> ProverRound1<'a, Scalar, Comm>
+where
+    Scalar: Field,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+{
+    pub fn round_2(self, challenge: &Round1Challenge<Scalar>) -> Round2Message<Scalar> {
+        let x = challenge.x;
+
+        let vectors_blinded = self
+            .masks
+            .iter()
+            .zip(self.prover.vectors.iter())
+            .map(|(mask, vector)| {
+                mask.iter()
+                    .zip(vector.iter())
+                    .map(|(&u, &v)| u + x * v)
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let randoms_blinded = self
+            .randoms_for_masks
+            .iter()
+            .zip(self.prover.randoms.iter())
+            .map(|(&rho, &r)| rho + x * r)
+            .collect::<Vec<_>>();
+
+        Round2Message {
+            vectors_blinded,
+            randoms_blinded,
+        }
+    }
+}
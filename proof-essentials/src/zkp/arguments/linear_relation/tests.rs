@@ -0,0 +1,215 @@
+#[cfg(test)]
+mod test {
+    use crate::error::CryptoError;
+    use crate::utils::rand::sample_vector;
+    use crate::utils::validation::deserialize_validated;
+    use crate::utils::vector_arithmetic::dot_product;
+    use crate::vector_commitment::{pedersen, HomomorphicCommitmentScheme};
+    use crate::zkp::{arguments::linear_relation, ArgumentOfKnowledge};
+
+    use ark_ff::{One, Zero};
+    use ark_marlin::rng::FiatShamirRng;
+    use ark_serialize::CanonicalSerialize;
+    use ark_std::{rand::thread_rng, UniformRand};
+    use blake2::Blake2s;
+    use starknet_curve;
+
+    // Choose elliptic curve setting
+    type Curve = starknet_curve::Projective;
+    type Scalar = starknet_curve::Fr;
+
+    // Type aliases for concrete instances using the chosen EC.
+    type Comm = pedersen::PedersenCommitment<Curve>;
+    type Witness<'a> = linear_relation::Witness<'a, Scalar>;
+    type Statement<'a> = linear_relation::Statement<'a, Scalar, Comm>;
+    type LinearRelationArgument<'a> = linear_relation::LinearRelationArgument<'a, Scalar, Comm>;
+    type Parameters<'a> = linear_relation::Parameters<'a, Scalar, Comm>;
+
+    // Fiat Shamir
+    type FS = FiatShamirRng<Blake2s>;
+
+    #[test]
+    fn test_weighted_sum() {
+        // sum a_i*x_i = y with commitments to single scalars x_i
+        let m = 5;
+        let n = 1;
+
+        let rng = &mut thread_rng();
+        let commit_key = Comm::setup(rng, n);
+
+        let x: Vec<Scalar> = sample_vector(rng, m);
+        let vectors = x.iter().map(|x_i| vec![*x_i]).collect::<Vec<_>>();
+        let r: Vec<Scalar> = sample_vector(rng, m);
+        let commitments = vectors
+            .iter()
+            .zip(r.iter())
+            .map(|(vector, &random)| Comm::commit(&commit_key, vector, random).unwrap())
+            .collect::<Vec<_>>();
+
+        let a: Vec<Scalar> = sample_vector(rng, m);
+        let y = vec![dot_product(&a, &x).unwrap()];
+        let matrix = vec![a];
+
+        let parameters = Parameters::new(m, n, &commit_key);
+        let statement = Statement::new(&commitments, &matrix, &y);
+        let witness = Witness::new(&vectors, &r);
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let proof =
+            LinearRelationArgument::prove(rng, &parameters, &statement, &witness, &mut fs_rng)
+                .unwrap();
+
+        let mut bytes = Vec::new();
+        proof.serialize_unchecked(&mut bytes).unwrap();
+        let received_proof = deserialize_validated(&bytes[..]).unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            Ok(()),
+            LinearRelationArgument::verify(&parameters, &statement, &received_proof, &mut fs_rng)
+        );
+
+        // The proof does not hold for another value
+        let wrong_y = vec![y[0] + Scalar::one()];
+        let wrong_statement = Statement::new(&commitments, &matrix, &wrong_y);
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            Err(CryptoError::ProofVerificationError(String::from(
+                "Linear Relation Argument"
+            ))),
+            LinearRelationArgument::verify(&parameters, &wrong_statement, &proof, &mut fs_rng)
+        );
+    }
+
+    #[test]
+    fn test_sum_of_commitments() {
+        // The third commitment opens to the sum of the first two: x_1[j] + x_2[j] - x_3[j] = 0 for every j
+        let m = 3;
+        let n = 4;
+
+        let rng = &mut thread_rng();
+        let commit_key = Comm::setup(rng, n);
+
+        let x_1: Vec<Scalar> = sample_vector(rng, n);
+        let x_2: Vec<Scalar> = sample_vector(rng, n);
+        let x_3 = x_1
+            .iter()
+            .zip(x_2.iter())
+            .map(|(a, b)| *a + b)
+            .collect::<Vec<_>>();
+        let vectors = vec![x_1, x_2, x_3];
+        let r: Vec<Scalar> = sample_vector(rng, m);
+        let commitments = vectors
+            .iter()
+            .zip(r.iter())
+            .map(|(vector, &random)| Comm::commit(&commit_key, vector, random).unwrap())
+            .collect::<Vec<_>>();
+
+        let matrix = (0..n)
+            .map(|j| {
+                let mut row = vec![Scalar::zero(); m * n];
+                row[j] = Scalar::one();
+                row[n + j] = Scalar::one();
+                row[2 * n + j] = -Scalar::one();
+                row
+            })
+            .collect::<Vec<_>>();
+        let y = vec![Scalar::zero(); n];
+
+        let parameters = Parameters::new(m, n, &commit_key);
+        let statement = Statement::new(&commitments, &matrix, &y);
+        let witness = Witness::new(&vectors, &r);
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let proof =
+            LinearRelationArgument::prove(rng, &parameters, &statement, &witness, &mut fs_rng)
+                .unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            Ok(()),
+            LinearRelationArgument::verify(&parameters, &statement, &proof, &mut fs_rng)
+        );
+
+        // A witness which does not satisfy the relation
+        let mut bad_vectors = vectors.clone();
+        bad_vectors[2][1] = Scalar::rand(rng);
+        let bad_commitments = bad_vectors
+            .iter()
+            .zip(r.iter())
+            .map(|(vector, &random)| Comm::commit(&commit_key, vector, random).unwrap())
+            .collect::<Vec<_>>();
+        let bad_statement = Statement::new(&bad_commitments, &matrix, &y);
+        let bad_witness = Witness::new(&bad_vectors, &r);
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let invalid_proof = LinearRelationArgument::prove(
+            rng,
+            &parameters,
+            &bad_statement,
+            &bad_witness,
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            Err(CryptoError::ProofVerificationError(String::from(
+                "Linear Relation Argument"
+            ))),
+            LinearRelationArgument::verify(
+                &parameters,
+                &bad_statement,
+                &invalid_proof,
+                &mut fs_rng
+            )
+        );
+
+        // A statement with one value per row is required
+        let short_y = vec![Scalar::zero(); n - 1];
+        let short_statement = Statement::new(&commitments, &matrix, &short_y);
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            Err(CryptoError::LinearRelationLengthError(n, n - 1)),
+            LinearRelationArgument::verify(&parameters, &short_statement, &proof, &mut fs_rng)
+        );
+
+        // Every row applies to the concatenation of the m committed vectors
+        let mut narrow_matrix = matrix.clone();
+        narrow_matrix[1].pop();
+        let narrow_statement = Statement::new(&commitments, &narrow_matrix, &y);
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            Err(CryptoError::LinearRelationLengthError(m * n, m * n - 1)),
+            LinearRelationArgument::verify(&parameters, &narrow_statement, &proof, &mut fs_rng)
+        );
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            Some(CryptoError::LinearRelationLengthError(m * n, m * n - 1)),
+            LinearRelationArgument::prove(
+                rng,
+                &parameters,
+                &narrow_statement,
+                &witness,
+                &mut fs_rng
+            )
+            .err()
+        );
+
+        // The witness holds one random per committed vector
+        let short_r = r[1..].to_vec();
+        let short_witness = Witness::new(&vectors, &short_r);
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            Some(CryptoError::LinearRelationLengthError(m, m - 1)),
+            LinearRelationArgument::prove(
+                rng,
+                &parameters,
+                &statement,
+                &short_witness,
+                &mut fs_rng
+            )
+            .err()
+        );
+    }
+}
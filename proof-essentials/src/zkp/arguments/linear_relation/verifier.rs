@@ -0,0 +1,153 @@
+use super::messages::{Round1Challenge, Round1Message, Round2Message};
+use super::{linear_map, proof::Proof, round_1_challenge, Parameters, Statement};
+
+use crate::error::CryptoError;
+use crate::vector_commitment::HomomorphicCommitmentScheme;
+use crate::zkp::transcript::Transcript;
+
+use ark_ff::Field;
+
+pub struct Verifier<'a, Scalar, Comm>
+where
+    Scalar: Field,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+{
+    parameters: Parameters<'a, Scalar, Comm>,
+    commitments: &'a Vec<Comm::Commitment>,
+    matrix: &'a Vec<Vec<Scalar>>,
+    vector: &'a Vec<Scalar>,
+}
+
+/// Verifier of the linear relation argument once the masks are received. Holds the blinded commitments and the
+/// blinded image that the second round is checked against.
+pub struct VerifierRound1<'a, Scalar, Comm>
+where
+    Scalar: Field,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+{
+    verifier: Verifier<'a, Scalar, Comm>,
+    commitments_blinded: Vec<Comm::Commitment>,
+    image_blinded: Vec<Scalar>,
+}
+
+impl<'a, Scalar, Comm> Verifier<'a, Scalar, Comm>
+where
+    Scalar: Field,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+{
+    pub fn new(
+        parameters: &Parameters<'a, Scalar, Comm>,
+        statement: &Statement<'a, Scalar, Comm>,
+    ) -> Self {
+        Self {
+            parameters: Parameters::new(parameters.m, parameters.n, parameters.commit_key),
+            commitments: statement.commitments,
+            matrix: statement.matrix,
+            vector: statement.vector,
+        }
+    }
+
+    fn statement(&self) -> Statement<'a, Scalar, Comm> {
+        Statement::new(self.commitments, self.matrix, self.vector)
+    }
+
+    pub fn round_1(
+        self,
+        message: &Round1Message<Scalar, Comm>,
+        challenge: &Round1Challenge<Scalar>,
+    ) -> Result<VerifierRound1<'a, Scalar, Comm>, CryptoError> {
+        self.statement().check(&self.parameters)?;
+
+        if message.vector_of_committed_masks.len() != self.parameters.m
+            || message.masks_image.len() != self.vector.len()
+        {
+            return Err(CryptoError::ProofVerificationError(String::from(
+                "Linear Relation Argument",
+            )));
+        }
+
+        let x = challenge.x;
+
+        let commitments_blinded = message
+            .vector_of_committed_masks
+            .iter()
+            .zip(self.commitments.iter())
+            .map(|(&mask_commit, &commitment)| commitment * x + mask_commit)
+            .collect::<Vec<_>>();
+
+        let image_blinded = message
+            .masks_image
+            .iter()
+            .zip(self.vector.iter())
+            .map(|(&image, &y)| image + x * y)
+            .collect::<Vec<_>>();
+
+        Ok(VerifierRound1 {
+            verifier: self,
+            commitments_blinded,
+            image_blinded,
+        })
+    }
+
+    pub fn verify<T: Transcript>(
+        self,
+        proof: &Proof<Scalar, Comm>,
+        transcript: &mut T,
+    ) -> Result<(), CryptoError> {
+        let x = round_1_challenge(
+            &self.parameters,
+            &self.statement(),
+            &proof.round_1,
+            transcript,
+        )?;
+
+        self.round_1(&proof.round_1, &x)?.round_2(&proof.round_2)
+    }
+}
+
+impl<'a, Scalar, Comm> VerifierRound1<'a, Scalar, Comm>
+where
+    Scalar: Field,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+{
+    pub fn round_2(self, message: &Round2Message<Scalar>) -> Result<(), CryptoError> {
+        let parameters = &self.verifier.parameters;
+
+        if message.randoms_blinded.len() != parameters.m
+            || message.vectors_blinded.len() != parameters.m
+            || message
+                .vectors_blinded
+                .iter()
+                .any(|vector| vector.len() != parameters.n)
+        {
+            return Err(CryptoError::ProofVerificationError(String::from(
+                "Linear Relation Argument",
+            )));
+        }
+
+        // Verify that the blinded vectors open the blinded commitments
+        for ((&left, vector), &random) in self
+            .commitments_blinded
+            .iter()
+            .zip(message.vectors_blinded.iter())
+            .zip(message.randoms_blinded.iter())
+        {
+            let right = Comm::commit(parameters.commit_key, vector, random)?;
+            if left != right {
+                return Err(CryptoError::ProofVerificationError(String::from(
+                    "Linear Relation Argument",
+                )));
+            }
+        }
+
+        // Verify that the blinded vectors satisfy the blinded relation
+        let left = linear_map(self.verifier.matrix, &message.vectors_blinded)?;
+        if left != self.image_blinded {
+            return Err(CryptoError::ProofVerificationError(String::from(
+                "Linear Relation Argument",
+            )));
+        }
+
+        Ok(())
+    }
+}
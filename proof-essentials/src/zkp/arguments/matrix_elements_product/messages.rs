@@ -0,0 +1,28 @@
+use crate::error::CryptoError;
+use crate::utils::validation::Validate;
+use crate::vector_commitment::HomomorphicCommitmentScheme;
+
+use ark_ff::Field;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::io::{Read, Write};
+
+/// Only message of the prover before the sub-arguments: a commitment to the product of the columns of A.
+#[derive(CanonicalDeserialize, CanonicalSerialize)]
+pub struct Round1Message<Scalar, Comm>
+where
+    Scalar: Field,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+{
+    pub b_commit: Comm::Commitment,
+}
+
+impl<Scalar, Comm> Validate for Round1Message<Scalar, Comm>
+where
+    Scalar: Field,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+    Comm::Commitment: Validate,
+{
+    fn validate(&self) -> Result<(), CryptoError> {
+        self.b_commit.validate()
+    }
+}
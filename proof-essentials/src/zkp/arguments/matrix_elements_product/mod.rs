@@ -1,14 +1,14 @@
+pub mod messages;
 pub mod proof;
 pub mod prover;
 mod tests;
+pub mod verifier;
 
 use crate::error::CryptoError;
 use crate::vector_commitment::HomomorphicCommitmentScheme;
-use crate::zkp::ArgumentOfKnowledge;
+use crate::zkp::{transcript::Transcript, ArgumentOfKnowledge};
 use ark_ff::Field;
-use ark_marlin::rng::FiatShamirRng;
 use ark_std::{marker::PhantomData, rand::Rng};
-use digest::Digest;
 
 pub struct ProductArgument<'a, F, Comm>
 where
@@ -29,26 +29,26 @@ where
     type Witness = Witness<'a, Scalar>;
     type Proof = proof::Proof<Scalar, Comm>;
 
-    fn prove<R: Rng, D: Digest>(
+    fn prove<R: Rng, T: Transcript>(
         rng: &mut R,
         common_reference_string: &Self::CommonReferenceString,
         statement: &Self::Statement,
         witness: &Self::Witness,
-        fs_rng: &mut FiatShamirRng<D>,
+        transcript: &mut T,
     ) -> Result<Self::Proof, CryptoError> {
         let prover = prover::Prover::new(common_reference_string, statement, witness);
-        let proof = prover.prove(rng, fs_rng)?;
+        let proof = prover.prove(rng, transcript)?;
 
         Ok(proof)
     }
 
-    fn verify<D: Digest>(
+    fn verify<T: Transcript>(
         common_reference_string: &Self::CommonReferenceString,
         statement: &Self::Statement,
         proof: &Self::Proof,
-        fs_rng: &mut FiatShamirRng<D>,
+        transcript: &mut T,
     ) -> Result<(), CryptoError> {
-        proof.verify(&common_reference_string, &statement, fs_rng)
+        proof.verify(&common_reference_string, &statement, transcript)
     }
 }
 
@@ -76,15 +76,15 @@ where
 /// Witness for the product argument. Contains a matrix A for which we want to claim the product b (see [Statement])
 /// and randoms which will have been used to commit to each column of A.
 pub struct Witness<'a, Scalar: Field> {
-    pub matrix_a: &'a Vec<Vec<Scalar>>,
-    pub randoms_for_a_commit: &'a Vec<Scalar>,
+    pub matrix_a: &'a [Vec<Scalar>],
+    pub randoms_for_a_commit: &'a [Scalar],
 }
 
 impl<'a, Scalar> Witness<'a, Scalar>
 where
     Scalar: Field,
 {
-    pub fn new(matrix_a: &'a Vec<Vec<Scalar>>, randoms_for_a_commit: &'a Vec<Scalar>) -> Self {
+    pub fn new(matrix_a: &'a [Vec<Scalar>], randoms_for_a_commit: &'a [Scalar]) -> Self {
         Self {
             matrix_a,
             randoms_for_a_commit,
@@ -99,7 +99,7 @@ where
     Scalar: Field,
     Comm: HomomorphicCommitmentScheme<Scalar>,
 {
-    pub commitments_to_a: &'a Vec<Comm::Commitment>,
+    pub commitments_to_a: &'a [Comm::Commitment],
     pub b: Scalar,
 }
 
@@ -108,7 +108,7 @@ where
     Scalar: Field,
     Comm: HomomorphicCommitmentScheme<Scalar>,
 {
-    pub fn new(commitments_to_a: &'a Vec<Comm::Commitment>, b: Scalar) -> Self {
+    pub fn new(commitments_to_a: &'a [Comm::Commitment], b: Scalar) -> Self {
         Self {
             commitments_to_a,
             b,
@@ -122,3 +122,24 @@ where
         Ok(())
     }
 }
+
+/// Absorb the public parameters and the statement. The sub-arguments absorb their own messages.
+pub(crate) fn absorb_statement<Scalar, Comm, T>(
+    parameters: &Parameters<Scalar, Comm>,
+    statement: &Statement<Scalar, Comm>,
+    transcript: &mut T,
+) -> Result<(), CryptoError>
+where
+    Scalar: Field,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+    T: Transcript,
+{
+    transcript.domain_separator(b"Matrix Elements Product Argument");
+    transcript.append(b"commit key", parameters.commit_key)?;
+    transcript.append(b"m", &(parameters.m as u32))?;
+    transcript.append(b"n", &(parameters.n as u32))?;
+    transcript.append_slice(b"commitments to a", statement.commitments_to_a)?;
+    transcript.append(b"b", &statement.b)?;
+
+    Ok(())
+}
@@ -1,14 +1,15 @@
-use super::{Parameters, Statement};
+use super::messages::Round1Message;
+use super::{verifier::Verifier, Parameters, Statement};
 
 use crate::error::CryptoError;
+use crate::utils::validation::Validate;
 use crate::vector_commitment::HomomorphicCommitmentScheme;
 use crate::zkp::arguments::{hadamard_product, single_value_product};
+use crate::zkp::transcript::Transcript;
 
-use ark_ff::{to_bytes, Field};
-use ark_marlin::rng::FiatShamirRng;
+use ark_ff::Field;
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
 use ark_std::io::{Read, Write};
-use digest::Digest;
 
 #[derive(CanonicalDeserialize, CanonicalSerialize)]
 pub struct Proof<Scalar, Comm>
@@ -16,7 +17,7 @@ where
     Scalar: Field,
     Comm: HomomorphicCommitmentScheme<Scalar>,
 {
-    pub b_commit: Comm::Commitment,
+    pub round_1: Round1Message<Scalar, Comm>,
     pub hadamard_product_proof: hadamard_product::proof::Proof<Scalar, Comm>,
     pub single_value_proof: single_value_product::proof::Proof<Scalar, Comm>,
 }
@@ -26,42 +27,26 @@ where
     Scalar: Field,
     Comm: HomomorphicCommitmentScheme<Scalar>,
 {
-    pub fn verify<D: Digest>(
+    pub fn verify<T: Transcript>(
         &self,
         proof_parameters: &Parameters<Scalar, Comm>,
         statement: &Statement<Scalar, Comm>,
-        fs_rng: &mut FiatShamirRng<D>,
+        transcript: &mut T,
     ) -> Result<(), CryptoError> {
-        statement.is_valid(proof_parameters)?;
-        fs_rng.absorb(&to_bytes![b"matrix_elements_product"]?);
-
-        // Verifiy hadamrd product argument
-        let hadamard_product_parameters = hadamard_product::Parameters::new(
-            proof_parameters.m,
-            proof_parameters.n,
-            proof_parameters.commit_key,
-        );
-
-        let hadamard_product_statement =
-            hadamard_product::Statement::new(statement.commitments_to_a, self.b_commit);
-
-        self.hadamard_product_proof.verify(
-            &hadamard_product_parameters,
-            &hadamard_product_statement,
-            fs_rng,
-        )?;
-        // verify single value product argument
-        let single_value_product_parameters =
-            single_value_product::Parameters::new(proof_parameters.n, proof_parameters.commit_key);
-
-        let single_value_product_statement =
-            single_value_product::Statement::new(&self.b_commit, statement.b);
+        Verifier::new(proof_parameters, statement).verify(self, transcript)
+    }
+}
 
-        self.single_value_proof.verify(
-            &single_value_product_parameters,
-            &single_value_product_statement,
-            fs_rng,
-        )?;
+impl<Scalar, Comm> Validate for Proof<Scalar, Comm>
+where
+    Scalar: Field,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+    Comm::Commitment: Validate,
+{
+    fn validate(&self) -> Result<(), CryptoError> {
+        self.round_1.validate()?;
+        self.hadamard_product_proof.validate()?;
+        self.single_value_proof.validate()?;
 
         Ok(())
     }
@@ -1,26 +1,35 @@
-use super::{proof::Proof, Parameters, Statement, Witness};
+use super::messages::Round1Message;
+use super::{absorb_statement, proof::Proof, Parameters, Statement, Witness};
 
 use crate::error::CryptoError;
 use crate::utils::vector_arithmetic::hadamard_product as compute_hadamard_product;
 use crate::vector_commitment::HomomorphicCommitmentScheme;
-use crate::zkp::{
-    arguments::{hadamard_product, single_value_product},
-    ArgumentOfKnowledge,
-};
+use crate::zkp::arguments::{hadamard_product, single_value_product};
+use crate::zkp::transcript::Transcript;
 
-use ark_ff::{to_bytes, Field};
-use ark_marlin::rng::FiatShamirRng;
+use ark_ff::Field;
 use ark_std::rand::Rng;
-use digest::Digest;
+use std::borrow::Cow;
 
+/// Message committing to the products of the rows, with the provers of the two sub-arguments.
+type Round1<'a, Scalar, Comm> = (
+    Round1Message<Scalar, Comm>,
+    hadamard_product::prover::Prover<'a, Scalar, Comm>,
+    single_value_product::prover::Prover<'a, Scalar, Comm>,
+);
+
+/// Prover of the product argument. It borrows the statement and the witness, except when another argument hands over
+/// to it with values derived during its own rounds, which the prover then owns.
 pub struct Prover<'a, Scalar, Comm>
 where
     Scalar: Field,
     Comm: HomomorphicCommitmentScheme<Scalar>,
 {
-    parameters: &'a Parameters<'a, Scalar, Comm>,
-    statement: &'a Statement<'a, Scalar, Comm>,
-    witness: &'a Witness<'a, Scalar>,
+    pub(crate) parameters: Parameters<'a, Scalar, Comm>,
+    pub(crate) commitments_to_a: Cow<'a, [Comm::Commitment]>,
+    pub(crate) b: Scalar,
+    pub(crate) matrix_a: Cow<'a, [Vec<Scalar>]>,
+    pub(crate) randoms_for_a_commit: Cow<'a, [Scalar]>,
 }
 
 impl<'a, Scalar, Comm> Prover<'a, Scalar, Comm>
@@ -29,85 +38,89 @@ where
     Comm: HomomorphicCommitmentScheme<Scalar>,
 {
     pub fn new(
-        parameters: &'a Parameters<'a, Scalar, Comm>,
-        statement: &'a Statement<'a, Scalar, Comm>,
-        witness: &'a Witness<'a, Scalar>,
+        parameters: &Parameters<'a, Scalar, Comm>,
+        statement: &Statement<'a, Scalar, Comm>,
+        witness: &Witness<'a, Scalar>,
     ) -> Self {
         Self {
-            parameters,
-            statement,
-            witness,
+            parameters: Parameters::new(parameters.m, parameters.n, parameters.commit_key),
+            commitments_to_a: Cow::Borrowed(statement.commitments_to_a),
+            b: statement.b,
+            matrix_a: Cow::Borrowed(witness.matrix_a),
+            randoms_for_a_commit: Cow::Borrowed(witness.randoms_for_a_commit),
         }
     }
 
-    pub fn prove<R: Rng, D: Digest>(
-        &self,
-        rng: &mut R,
-        fs_rng: &mut FiatShamirRng<D>,
-    ) -> Result<Proof<Scalar, Comm>, CryptoError> {
-        fs_rng.absorb(&to_bytes![b"matrix_elements_product"]?);
+    fn statement(&self) -> Statement<'_, Scalar, Comm> {
+        Statement::new(&self.commitments_to_a, self.b)
+    }
 
+    /// Commit to the product of the columns of A and hand over to the provers of the Hadamard product argument,
+    /// showing that the committed vector is computed correctly, and of the single value product argument, showing
+    /// that the product of its entries is b.
+    pub fn round_1<R: Rng>(self, rng: &mut R) -> Result<Round1<'a, Scalar, Comm>, CryptoError> {
         let s = Scalar::rand(rng);
 
         let mut product_along_rows = vec![Scalar::one(); self.parameters.n];
-        for x in self.witness.matrix_a {
-            product_along_rows = compute_hadamard_product(&x, &product_along_rows)?;
+        for x in self.matrix_a.iter() {
+            product_along_rows = compute_hadamard_product(x, &product_along_rows)?;
         }
 
         let b_commit = Comm::commit(self.parameters.commit_key, &product_along_rows, s)?;
 
-        // Engage in Hadamard Product Argument for b_commit and the `product_along_rows` as its witness:
-        // This will show that each entry in `product_along_rows` is computed correctly
-        let hadamard_product_parameters = hadamard_product::Parameters::new(
-            self.parameters.m,
-            self.parameters.n,
-            self.parameters.commit_key,
-        );
-
-        let hadamard_product_statement =
-            hadamard_product::Statement::new(&self.statement.commitments_to_a, b_commit);
-
-        let hadamard_product_witness = hadamard_product::Witness::new(
-            self.witness.matrix_a,
-            self.witness.randoms_for_a_commit,
-            &product_along_rows,
-            s,
-        );
-
-        let hadamard_product_proof = hadamard_product::HadamardProductArgument::prove(
-            rng,
-            &hadamard_product_parameters,
-            &hadamard_product_statement,
-            &hadamard_product_witness,
-            fs_rng,
-        )?;
-
         // Engage in single value product argument for b_commit and b as a statement:
         // This will show that our claimed product b is indeed the product of the values in
         // `product_along_rows`
-        let single_value_product_parameters =
-            single_value_product::Parameters::new(self.parameters.n, self.parameters.commit_key);
+        let single_value_product_prover = single_value_product::prover::Prover {
+            parameters: single_value_product::Parameters::new(
+                self.parameters.n,
+                self.parameters.commit_key,
+            ),
+            a_commit: b_commit,
+            b: self.b,
+            a: Cow::Owned(product_along_rows.clone()),
+            random_for_a_commit: s,
+        };
+
+        // Engage in Hadamard Product Argument for b_commit and the `product_along_rows` as its witness:
+        // This will show that each entry in `product_along_rows` is computed correctly
+        let hadamard_product_prover = hadamard_product::prover::Prover {
+            parameters: hadamard_product::Parameters::new(
+                self.parameters.m,
+                self.parameters.n,
+                self.parameters.commit_key,
+            ),
+            commitment_to_a: self.commitments_to_a,
+            commitment_to_b: b_commit,
+            matrix_a: self.matrix_a,
+            randoms_for_a_commit: self.randoms_for_a_commit,
+            vector_b: Cow::Owned(product_along_rows),
+            random_for_b_commit: s,
+        };
 
-        let single_value_product_witness =
-            single_value_product::Witness::new(&product_along_rows, &s);
+        Ok((
+            Round1Message { b_commit },
+            hadamard_product_prover,
+            single_value_product_prover,
+        ))
+    }
 
-        let single_value_product_statement =
-            single_value_product::Statement::new(&b_commit, self.statement.b);
+    pub fn prove<R: Rng, T: Transcript>(
+        self,
+        rng: &mut R,
+        transcript: &mut T,
+    ) -> Result<Proof<Scalar, Comm>, CryptoError> {
+        absorb_statement(&self.parameters, &self.statement(), transcript)?;
+
+        let (round_1, hadamard_product_prover, single_value_product_prover) = self.round_1(rng)?;
 
-        let single_value_proof = single_value_product::SingleValueProductArgument::prove(
-            rng,
-            &single_value_product_parameters,
-            &single_value_product_statement,
-            &single_value_product_witness,
-            fs_rng,
-        )?;
+        let hadamard_product_proof = hadamard_product_prover.prove(rng, transcript)?;
+        let single_value_proof = single_value_product_prover.prove(rng, transcript)?;
 
-        let proof = Proof {
-            b_commit,
+        Ok(Proof {
+            round_1,
             hadamard_product_proof,
             single_value_proof,
-        };
-
-        Ok(proof)
+        })
     }
 }
@@ -0,0 +1,123 @@
+use super::messages::Round1Message;
+use super::{absorb_statement, proof::Proof, Parameters, Statement};
+
+use crate::error::CryptoError;
+use crate::vector_commitment::HomomorphicCommitmentScheme;
+use crate::zkp::arguments::batch::CommitmentEquation;
+use crate::zkp::arguments::{hadamard_product, single_value_product};
+use crate::zkp::transcript::Transcript;
+
+use ark_ff::Field;
+use std::borrow::Cow;
+
+/// Verifiers of the Hadamard product and single value product arguments.
+type SubArguments<'a, Scalar, Comm> = (
+    hadamard_product::verifier::Verifier<'a, Scalar, Comm>,
+    single_value_product::verifier::Verifier<'a, Scalar, Comm>,
+);
+
+/// Verifier of the product argument. It borrows the statement, except when another argument hands over to it with
+/// commitments derived during its own rounds, which the verifier then owns.
+pub struct Verifier<'a, Scalar, Comm>
+where
+    Scalar: Field,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+{
+    pub(crate) parameters: Parameters<'a, Scalar, Comm>,
+    pub(crate) commitments_to_a: Cow<'a, [Comm::Commitment]>,
+    pub(crate) b: Scalar,
+}
+
+impl<'a, Scalar, Comm> Verifier<'a, Scalar, Comm>
+where
+    Scalar: Field,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+{
+    pub fn new(
+        parameters: &Parameters<'a, Scalar, Comm>,
+        statement: &Statement<'a, Scalar, Comm>,
+    ) -> Self {
+        Self {
+            parameters: Parameters::new(parameters.m, parameters.n, parameters.commit_key),
+            commitments_to_a: Cow::Borrowed(statement.commitments_to_a),
+            b: statement.b,
+        }
+    }
+
+    fn statement(&self) -> Statement<'_, Scalar, Comm> {
+        Statement::new(&self.commitments_to_a, self.b)
+    }
+
+    /// Receive the commitment to the product of the columns of A and hand over to the verifiers of the Hadamard
+    /// product and single value product arguments.
+    pub fn round_1(
+        self,
+        message: &Round1Message<Scalar, Comm>,
+    ) -> Result<SubArguments<'a, Scalar, Comm>, CryptoError> {
+        self.statement().is_valid(&self.parameters)?;
+
+        let hadamard_product_parameters = hadamard_product::Parameters::new(
+            self.parameters.m,
+            self.parameters.n,
+            self.parameters.commit_key,
+        );
+
+        let single_value_product_parameters =
+            single_value_product::Parameters::new(self.parameters.n, self.parameters.commit_key);
+
+        let single_value_product_statement =
+            single_value_product::Statement::new(&message.b_commit, self.b);
+
+        Ok((
+            hadamard_product::verifier::Verifier {
+                parameters: hadamard_product_parameters,
+                commitment_to_a: self.commitments_to_a,
+                commitment_to_b: message.b_commit,
+            },
+            single_value_product::verifier::Verifier::new(
+                &single_value_product_parameters,
+                &single_value_product_statement,
+            ),
+        ))
+    }
+
+    pub fn verify<T: Transcript>(
+        self,
+        proof: &Proof<Scalar, Comm>,
+        transcript: &mut T,
+    ) -> Result<(), CryptoError> {
+        absorb_statement(&self.parameters, &self.statement(), transcript)?;
+
+        let (hadamard_product_verifier, single_value_product_verifier) =
+            self.round_1(&proof.round_1)?;
+
+        // Verifiy hadamrd product argument
+        hadamard_product_verifier.verify(&proof.hadamard_product_proof, transcript)?;
+
+        // verify single value product argument
+        single_value_product_verifier.verify(&proof.single_value_proof, transcript)?;
+
+        Ok(())
+    }
+
+    /// Run the verifier and return the equations of the Hadamard product and single value product arguments
+    /// instead of checking them.
+    pub(crate) fn equations<T: Transcript>(
+        self,
+        proof: &Proof<Scalar, Comm>,
+        transcript: &mut T,
+    ) -> Result<Vec<CommitmentEquation<Scalar, Comm>>, CryptoError> {
+        absorb_statement(&self.parameters, &self.statement(), transcript)?;
+
+        let (hadamard_product_verifier, single_value_product_verifier) =
+            self.round_1(&proof.round_1)?;
+
+        let mut equations =
+            hadamard_product_verifier.equations(&proof.hadamard_product_proof, transcript)?;
+        equations.extend(
+            single_value_product_verifier.equations(&proof.single_value_proof, transcript)?,
+        );
+
+        Ok(equations)
+    }
+}
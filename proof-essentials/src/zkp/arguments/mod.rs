@@ -1,10 +1,15 @@
 pub mod hadamard_product;
+pub mod inner_product;
+pub mod linear_relation;
 pub mod matrix_elements_product;
 pub mod multi_exponentiation;
+pub mod range_proof;
 pub mod shuffle;
 pub mod single_value_product;
 pub mod zero_value_bilinear_map;
 
+mod batch;
+
 use ark_ff::Field;
 use std::iter;
 
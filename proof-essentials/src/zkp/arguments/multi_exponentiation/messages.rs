@@ -0,0 +1,68 @@
+use crate::error::CryptoError;
+use crate::homomorphic_encryption::HomomorphicEncryptionScheme;
+use crate::utils::validation::Validate;
+use crate::vector_commitment::HomomorphicCommitmentScheme;
+
+use ark_ff::Field;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::io::{Read, Write};
+use ark_std::{rand::Rng, UniformRand};
+
+/// First message of the prover: a commitment to the random column a_0, commitments to the random values b_k and
+/// the masked encryptions of the diagonals of the product of the ciphertexts with the exponents.
+#[derive(CanonicalDeserialize, CanonicalSerialize)]
+pub struct Round1Message<Scalar, Enc, Comm>
+where
+    Scalar: Field,
+    Enc: HomomorphicEncryptionScheme<Scalar>,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+{
+    pub a_0_commit: Comm::Commitment,
+    pub commit_b_k: Vec<Comm::Commitment>,
+    pub vector_e_k: Vec<Enc::Ciphertext>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, CanonicalDeserialize, CanonicalSerialize)]
+pub struct Round1Challenge<Scalar: Field> {
+    pub x: Scalar,
+}
+
+/// Second message of the prover: the exponents, the masking values and their randoms, blinded with the powers of
+/// the challenge.
+#[derive(CanonicalDeserialize, CanonicalSerialize)]
+pub struct Round2Message<Scalar, Enc>
+where
+    Scalar: Field,
+    Enc: HomomorphicEncryptionScheme<Scalar>,
+{
+    pub r_blinded: Scalar,
+    pub b_blinded: Scalar,
+    pub s_blinded: Scalar,
+    pub tau_blinded: Enc::Randomness,
+    pub a_blinded: Vec<Scalar>,
+}
+
+impl<Scalar: Field> UniformRand for Round1Challenge<Scalar> {
+    fn rand<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Self {
+            x: Scalar::rand(rng),
+        }
+    }
+}
+
+impl<Scalar, Enc, Comm> Validate for Round1Message<Scalar, Enc, Comm>
+where
+    Scalar: Field,
+    Enc: HomomorphicEncryptionScheme<Scalar>,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+    Enc::Ciphertext: Validate,
+    Comm::Commitment: Validate,
+{
+    fn validate(&self) -> Result<(), CryptoError> {
+        self.a_0_commit.validate()?;
+        self.commit_b_k.validate()?;
+        self.vector_e_k.validate()?;
+
+        Ok(())
+    }
+}
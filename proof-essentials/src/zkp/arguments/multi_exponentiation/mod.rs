@@ -1,15 +1,15 @@
+pub mod messages;
 pub mod proof;
 pub mod prover;
 mod tests;
+pub mod verifier;
 
 use crate::error::CryptoError;
 use crate::homomorphic_encryption::HomomorphicEncryptionScheme;
 use crate::vector_commitment::HomomorphicCommitmentScheme;
-use crate::zkp::ArgumentOfKnowledge;
+use crate::zkp::{transcript::Transcript, ArgumentOfKnowledge};
 use ark_ff::Field;
-use ark_marlin::rng::FiatShamirRng;
 use ark_std::{marker::PhantomData, rand::Rng};
-use digest::Digest;
 
 pub struct MultiExponentiation<
     'a,
@@ -30,29 +30,29 @@ where
 {
     type CommonReferenceString = Parameters<'a, F, Enc, Comm>;
     type Statement = Statement<'a, F, Enc, Comm>;
-    type Witness = Witness<'a, F>;
+    type Witness = Witness<'a, F, Enc::Randomness>;
     type Proof = proof::Proof<F, Enc, Comm>;
 
-    fn prove<R: Rng, D: Digest>(
+    fn prove<R: Rng, T: Transcript>(
         rng: &mut R,
         common_reference_string: &Self::CommonReferenceString,
         statement: &Self::Statement,
         witness: &Self::Witness,
-        fs_rng: &mut FiatShamirRng<D>,
+        transcript: &mut T,
     ) -> Result<Self::Proof, CryptoError> {
         let prover = prover::Prover::new(&common_reference_string, &statement, &witness);
-        let proof = prover.prove(rng, fs_rng)?;
+        let proof = prover.prove(rng, transcript)?;
 
         Ok(proof)
     }
 
-    fn verify<D: Digest>(
+    fn verify<T: Transcript>(
         common_reference_string: &Self::CommonReferenceString,
         statement: &Self::Statement,
         proof: &Self::Proof,
-        fs_rng: &mut FiatShamirRng<D>,
+        transcript: &mut T,
     ) -> Result<(), CryptoError> {
-        proof.verify(&common_reference_string, &statement, fs_rng)
+        proof.verify(&common_reference_string, &statement, transcript)
     }
 }
 
@@ -92,24 +92,25 @@ where
 }
 
 /// Witness for the multi-exponentiation argument. Contains a hidden n-by-m matrix A, a vector of randoms r used to commit to
-/// the columns of A and an aggregate re-encryption factor rho
-pub struct Witness<'a, Scalar>
+/// the columns of A and an aggregate re-encryption factor rho. The re-encryption factor has the type of the
+/// encryption scheme's randomness, which defaults to the scalar field.
+pub struct Witness<'a, Scalar, Randomness = Scalar>
 where
     Scalar: Field,
 {
-    pub matrix_a: &'a Vec<Vec<Scalar>>,
-    pub matrix_blinders: &'a Vec<Scalar>,
-    pub rho: Scalar,
+    pub matrix_a: &'a [Vec<Scalar>],
+    pub matrix_blinders: &'a [Scalar],
+    pub rho: Randomness,
 }
 
-impl<'a, Scalar> Witness<'a, Scalar>
+impl<'a, Scalar, Randomness> Witness<'a, Scalar, Randomness>
 where
     Scalar: Field,
 {
     pub fn new(
-        matrix_a: &'a Vec<Vec<Scalar>>,
-        matrix_blinders: &'a Vec<Scalar>,
-        rho: Scalar,
+        matrix_a: &'a [Vec<Scalar>],
+        matrix_blinders: &'a [Scalar],
+        rho: Randomness,
     ) -> Self {
         Self {
             matrix_a,
@@ -128,9 +129,9 @@ where
     Enc: HomomorphicEncryptionScheme<Scalar>,
     Comm: HomomorphicCommitmentScheme<Scalar>,
 {
-    pub shuffled_ciphers: &'a Vec<Vec<Enc::Ciphertext>>,
+    pub shuffled_ciphers: &'a [Vec<Enc::Ciphertext>],
     pub product: Enc::Ciphertext,
-    pub commitments_to_exponents: &'a Vec<Comm::Commitment>,
+    pub commitments_to_exponents: &'a [Comm::Commitment],
 }
 
 impl<'a, Scalar, Enc, Comm> Statement<'a, Scalar, Enc, Comm>
@@ -140,9 +141,9 @@ where
     Comm: HomomorphicCommitmentScheme<Scalar>,
 {
     pub fn new(
-        shuffled_ciphers: &'a Vec<Vec<Enc::Ciphertext>>,
+        shuffled_ciphers: &'a [Vec<Enc::Ciphertext>],
         product: Enc::Ciphertext,
-        commitments_to_exponents: &'a Vec<Comm::Commitment>,
+        commitments_to_exponents: &'a [Comm::Commitment],
     ) -> Self {
         Self {
             shuffled_ciphers,
@@ -151,3 +152,57 @@ where
         }
     }
 }
+
+/// Absorb the public parameters and the statement, along with the dimensions of the matrix of ciphertexts.
+pub(crate) fn absorb_statement<Scalar, Enc, Comm, T>(
+    parameters: &Parameters<Scalar, Enc, Comm>,
+    statement: &Statement<Scalar, Enc, Comm>,
+    transcript: &mut T,
+) -> Result<(), CryptoError>
+where
+    Scalar: Field,
+    Enc: HomomorphicEncryptionScheme<Scalar>,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+    T: Transcript,
+{
+    let m = statement.shuffled_ciphers.len();
+    let n = statement
+        .shuffled_ciphers
+        .first()
+        .map_or(0, |row| row.len());
+
+    transcript.domain_separator(b"Multi-Exponentiation Argument");
+    transcript.append(b"public key", parameters.public_key)?;
+    transcript.append(b"commit key", parameters.commit_key)?;
+    transcript.append_slice(
+        b"commitments to exponents",
+        statement.commitments_to_exponents,
+    )?;
+    transcript.append(b"product", &statement.product)?;
+    transcript.append_slice(b"shuffled ciphers", statement.shuffled_ciphers)?;
+    transcript.append(b"m", &(m as u32))?;
+    transcript.append(b"n", &(n as u32))?;
+
+    Ok(())
+}
+
+/// Absorb the commitments and the encrypted diagonals of the first round, and derive the challenge x.
+pub(crate) fn round_1_challenge<Scalar, Enc, Comm, T>(
+    message: &messages::Round1Message<Scalar, Enc, Comm>,
+    transcript: &mut T,
+) -> Result<messages::Round1Challenge<Scalar>, CryptoError>
+where
+    Scalar: Field,
+    Enc: HomomorphicEncryptionScheme<Scalar>,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+    T: Transcript,
+{
+    transcript.round(1);
+    transcript.append(b"a_0 commit", &message.a_0_commit)?;
+    transcript.append(b"b_k commits", &message.commit_b_k)?;
+    transcript.append(b"e_k", &message.vector_e_k)?;
+
+    Ok(messages::Round1Challenge {
+        x: transcript.challenge_scalar(b"x"),
+    })
+}
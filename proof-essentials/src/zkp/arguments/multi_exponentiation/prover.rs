@@ -1,29 +1,59 @@
+use super::messages::{Round1Challenge, Round1Message, Round2Message};
 use super::proof::Proof;
-use super::{Parameters, Statement, Witness};
+use super::{absorb_statement, round_1_challenge, Parameters, Statement, Witness};
 
 use crate::error::CryptoError;
-use crate::homomorphic_encryption::HomomorphicEncryptionScheme;
-use crate::utils::{rand::sample_vector, vector_arithmetic::dot_product};
+use crate::homomorphic_encryption::{
+    Accumulate, CiphertextAccumulator, HomomorphicEncryptionScheme,
+};
+use crate::utils::{
+    rand::sample_vector,
+    vector_arithmetic::{accumulated_dot_product, dot_product},
+};
 use crate::vector_commitment::HomomorphicCommitmentScheme;
 use crate::zkp::arguments::scalar_powers;
+use crate::zkp::transcript::Transcript;
 
-use ark_ff::{to_bytes, Field, Zero};
-use ark_marlin::rng::FiatShamirRng;
+use ark_ff::{Field, Zero};
 use ark_std::rand::Rng;
-use digest::Digest;
-use std::marker::PhantomData;
+use std::{borrow::Cow, iter, slice};
 
+/// Prover waiting for the challenge x, with the first message to send to the verifier.
+type Round1<'a, Scalar, Enc, Comm> = (
+    ProverRound1<'a, Scalar, Enc, Comm>,
+    Round1Message<Scalar, Enc, Comm>,
+);
+
+/// Prover of the multi-exponentiation argument. It borrows the statement and the witness, except when another
+/// argument hands over to it with values derived during its own rounds, which the prover then owns.
 pub struct Prover<'a, Scalar, Enc, Comm>
 where
     Scalar: Field,
     Enc: HomomorphicEncryptionScheme<Scalar>,
     Comm: HomomorphicCommitmentScheme<Scalar>,
 {
-    parameters: &'a Parameters<'a, Scalar, Enc, Comm>,
-    statement: &'a Statement<'a, Scalar, Enc, Comm>,
-    witness: &'a Witness<'a, Scalar>,
-    _encryption_scheme: PhantomData<Enc>,
-    _commitment_scheme: PhantomData<Comm>,
+    pub(crate) parameters: Parameters<'a, Scalar, Enc, Comm>,
+    pub(crate) shuffled_ciphers: Cow<'a, [Vec<Enc::Ciphertext>]>,
+    pub(crate) product: Enc::Ciphertext,
+    pub(crate) commitments_to_exponents: Cow<'a, [Comm::Commitment]>,
+    pub(crate) matrix_a: Cow<'a, [Vec<Scalar>]>,
+    pub(crate) matrix_blinders: Cow<'a, [Scalar]>,
+    pub(crate) rho: Enc::Randomness,
+}
+
+/// Prover of the multi-exponentiation argument once the first message is sent, waiting for the challenge x.
+pub struct ProverRound1<'a, Scalar, Enc, Comm>
+where
+    Scalar: Field,
+    Enc: HomomorphicEncryptionScheme<Scalar>,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+{
+    prover: Prover<'a, Scalar, Enc, Comm>,
+    a_0: Vec<Scalar>,
+    r_0: Scalar,
+    b: Vec<Scalar>,
+    s: Vec<Scalar>,
+    tau: Vec<Enc::Randomness>,
 }
 
 impl<'a, Scalar, Enc, Comm> Prover<'a, Scalar, Enc, Comm>
@@ -33,71 +63,64 @@ where
     Comm: HomomorphicCommitmentScheme<Scalar>,
 {
     pub fn new(
-        parameters: &'a Parameters<'a, Scalar, Enc, Comm>,
-        statement: &'a Statement<'a, Scalar, Enc, Comm>,
-        witness: &'a Witness<'a, Scalar>,
+        parameters: &Parameters<'a, Scalar, Enc, Comm>,
+        statement: &Statement<'a, Scalar, Enc, Comm>,
+        witness: &Witness<'a, Scalar, Enc::Randomness>,
     ) -> Self {
         //TODO add dimension assertions
         Self {
-            parameters,
-            statement,
-            witness,
-            _encryption_scheme: PhantomData::<Enc>,
-            _commitment_scheme: PhantomData::<Comm>,
+            parameters: Parameters::new(
+                parameters.encrypt_parameters,
+                parameters.public_key,
+                parameters.commit_key,
+                parameters.generator,
+            ),
+            shuffled_ciphers: Cow::Borrowed(statement.shuffled_ciphers),
+            product: statement.product,
+            commitments_to_exponents: Cow::Borrowed(statement.commitments_to_exponents),
+            matrix_a: Cow::Borrowed(witness.matrix_a),
+            matrix_blinders: Cow::Borrowed(witness.matrix_blinders),
+            rho: witness.rho,
         }
     }
 
-    pub fn prove<R: Rng, D: Digest>(
-        &self,
+    fn statement(&self) -> Statement<'_, Scalar, Enc, Comm> {
+        Statement::new(
+            &self.shuffled_ciphers,
+            self.product,
+            &self.commitments_to_exponents,
+        )
+    }
+
+    pub fn round_1<R: Rng>(
+        self,
         rng: &mut R,
-        fs_rng: &mut FiatShamirRng<D>,
-    ) -> Result<Proof<Scalar, Enc, Comm>, CryptoError> {
-        fs_rng.absorb(
-            &to_bytes![
-                b"multi-exponentiation",
-                self.parameters.public_key,
-                self.parameters.commit_key,
-                self.statement.commitments_to_exponents,
-                &self.statement.product,
-                self.statement.shuffled_ciphers
-            ]
-            .unwrap(),
-        );
-
-        let m = self.witness.matrix_a.len();
-        let n = self.witness.matrix_a[0].len();
+    ) -> Result<Round1<'a, Scalar, Enc, Comm>, CryptoError> {
+        let m = self.matrix_a.len();
+        let n = self.matrix_a[0].len();
         let num_of_diagonals = 2 * m - 1;
 
-        fs_rng.absorb(&to_bytes![m as u32, n as u32, num_of_diagonals as u32]?);
-
         let a_0: Vec<Scalar> = sample_vector(rng, n);
         let r_0 = Scalar::rand(rng);
 
         let mut b: Vec<Scalar> = sample_vector(rng, num_of_diagonals + 1);
         let mut s: Vec<Scalar> = sample_vector(rng, num_of_diagonals + 1);
-        let mut tau: Vec<Scalar> = sample_vector(rng, num_of_diagonals + 1);
+        let mut tau: Vec<Enc::Randomness> = sample_vector(rng, num_of_diagonals + 1);
 
         b[m] = Scalar::zero();
         s[m] = Scalar::zero();
-        tau[m] = self.witness.rho;
+        tau[m] = self.rho;
 
-        let a_0_commit = Comm::commit(&self.parameters.commit_key, &a_0, r_0)?;
+        // Commit to a_0 and to each b_k at once
+        let columns = iter::once(&a_0[..])
+            .chain(b.iter().map(slice::from_ref))
+            .collect::<Vec<_>>();
+        let randoms = iter::once(r_0).chain(s.iter().copied()).collect::<Vec<_>>();
+        let mut commit_b_k = Comm::commit_columns(self.parameters.commit_key, &columns, &randoms)?;
+        let a_0_commit = commit_b_k.remove(0);
 
-        let commit_b_k = b
-            .iter()
-            .zip(s.iter())
-            .map(|(&b_k, &s_k)| {
-                let commit = Comm::commit(self.parameters.commit_key, &vec![b_k], s_k)?;
-                Ok(commit)
-            })
-            .collect::<Result<Vec<Comm::Commitment>, CryptoError>>()?;
-
-        let diagonals = Self::diagonals_from_chunks(
-            &self.statement.shuffled_ciphers,
-            &self.witness.matrix_a,
-            &a_0,
-        )
-        .unwrap();
+        let diagonals =
+            Self::diagonals_from_chunks(&self.shuffled_ciphers, &self.matrix_a, &a_0).unwrap();
 
         let vector_e_k = b
             .iter()
@@ -107,104 +130,73 @@ where
                 let message = *self.parameters.generator * b_k;
 
                 let encrypted_random = Enc::encrypt(
-                    &self.parameters.encrypt_parameters,
+                    self.parameters.encrypt_parameters,
                     self.parameters.public_key,
                     &message,
                     tau_k,
-                );
-
-                // let encrypted_random = DiscreteLogVTMF::<C>::mask(
-                //     encryption_parameters,
-                //     self.parameters.public_key,
-                //     &self.parameters.masking_generator.mul(*b_k).into_affine(),
-                //     &Randomness::<C>(*tau_k),
-                // );
-                encrypted_random.unwrap() + d_k
-            })
-            .collect::<Vec<Enc::Ciphertext>>();
-
-        fs_rng.absorb(&to_bytes![a_0_commit, commit_b_k, vector_e_k]?);
-
-        let challenge = Scalar::rand(fs_rng);
-
-        // Precompute all powers of the challenge from 0 to number_of_diagonals
-        let challenge_powers = scalar_powers(challenge, num_of_diagonals);
+                )?;
 
-        // take vector x: x, x^2, x^3, ..., x^m
-        let x_array = challenge_powers[1..m + 1].to_vec();
-
-        let scalar_products_ax = self
-            .witness
-            .matrix_a
-            .iter()
-            .enumerate()
-            .map(|(i, chunk)| {
-                chunk
-                    .iter()
-                    .map(|scalar| x_array[i] * scalar)
-                    .collect::<Vec<_>>()
+                Ok(encrypted_random.accumulator() + d_k)
             })
-            .collect::<Vec<Vec<Scalar>>>();
-
-        let mut a_blinded: Vec<Scalar> = Vec::with_capacity(n);
-
-        // c0[0]x + c1[0]x^2 ... cm[0]x^m = b[0]
-        // c0[1]x + c1[1]x^2 ... cm[1]x^m = b[1]
-        // c0[2]x + c1[2]x^2 ... cm[2]x^m
-        // c0[3]x + c1[3]x^2 ... cm[3]x^m
-        // ...
-        // c0[n]x + c1[n]x^2 ... cm[n]x^m = b[n]
-        for i in 0..n {
-            let mut poly = a_0[i];
-            for j in 0..m {
-                poly = poly + scalar_products_ax[j][i];
-            }
-            a_blinded.push(poly);
-        }
+            .collect::<Result<Vec<_>, CryptoError>>()?;
+        let vector_e_k = Enc::Ciphertext::normalize_batch(&vector_e_k);
 
-        let r_blinded = r_0 + dot_product(&self.witness.matrix_blinders, &x_array)?;
-        let b_blinded = dot_product(&b, &challenge_powers)?;
-        let s_blinded = dot_product(&s, &challenge_powers)?;
-        let tau_blinded = dot_product(&tau, &challenge_powers)?;
-
-        let proof = Proof {
-            // Round 1
+        let message = Round1Message {
             a_0_commit,
             commit_b_k,
             vector_e_k,
+        };
 
-            // Round 2
-            r_blinded,
-            b_blinded,
-            s_blinded,
-            tau_blinded,
-            a_blinded,
+        let state = ProverRound1 {
+            prover: self,
+            a_0,
+            r_0,
+            b,
+            s,
+            tau,
         };
 
-        Ok(proof)
+        Ok((state, message))
     }
 
+    pub fn prove<R: Rng, T: Transcript>(
+        self,
+        rng: &mut R,
+        transcript: &mut T,
+    ) -> Result<Proof<Scalar, Enc, Comm>, CryptoError> {
+        absorb_statement(&self.parameters, &self.statement(), transcript)?;
+
+        let (prover, round_1) = self.round_1(rng)?;
+        let challenge = round_1_challenge(&round_1, transcript)?;
+        let round_2 = prover.round_2(&challenge)?;
+
+        Ok(Proof { round_1, round_2 })
+    }
+
+    /// Compute the sums of the diagonals of the matrix of dot products between the ciphertext and scalar chunks.
+    /// The sums are left as accumulators, to be normalized once they are masked.
     fn diagonals_from_chunks(
-        cipher_chunks: &Vec<Vec<Enc::Ciphertext>>,
-        scalar_chunks: &Vec<Vec<Scalar>>,
-        a_0_randomness: &Vec<Scalar>,
-    ) -> Result<Vec<Enc::Ciphertext>, CryptoError> {
+        cipher_chunks: &[Vec<Enc::Ciphertext>],
+        scalar_chunks: &[Vec<Scalar>],
+        a_0_randomness: &[Scalar],
+    ) -> Result<Vec<CiphertextAccumulator<Scalar, Enc>>, CryptoError> {
         let m = cipher_chunks.len();
         let num_of_diagonals = 2 * m - 1;
 
-        let mut diagonal_sums: Vec<Enc::Ciphertext> =
-            vec![Enc::Ciphertext::zero(); num_of_diagonals];
+        let mut diagonal_sums: Vec<CiphertextAccumulator<Scalar, Enc>> =
+            vec![Zero::zero(); num_of_diagonals];
         let center = num_of_diagonals / 2 as usize;
 
         for d in 1..m {
-            let additional_randomness = dot_product(&a_0_randomness, &cipher_chunks[d - 1])?;
-            let mut tmp_product1 = Enc::Ciphertext::zero();
-            let mut tmp_product2 = Enc::Ciphertext::zero();
+            let additional_randomness =
+                accumulated_dot_product(a_0_randomness, &cipher_chunks[d - 1])?;
+            let mut tmp_product1 = CiphertextAccumulator::<Scalar, Enc>::zero();
+            let mut tmp_product2 = CiphertextAccumulator::<Scalar, Enc>::zero();
             for i in d..m {
-                let dot = dot_product(&scalar_chunks[i - d], &cipher_chunks[i])?;
+                let dot = accumulated_dot_product(&scalar_chunks[i - d], &cipher_chunks[i])?;
                 tmp_product1 = tmp_product1 + dot;
 
-                let dot = dot_product(&scalar_chunks[i], &cipher_chunks[i - d])?;
+                let dot = accumulated_dot_product(&scalar_chunks[i], &cipher_chunks[i - d])?;
                 tmp_product2 = tmp_product2 + dot;
             }
 
@@ -220,25 +212,82 @@ where
         //     Ok(commit)
         // }).collect()?;
 
-        let product: Result<Vec<Enc::Ciphertext>, CryptoError> = cipher_chunks
+        let product = cipher_chunks
             .iter()
             .zip(scalar_chunks.iter())
-            .map(|(c_i, a_i)| {
-                let dot_p = dot_product(a_i, c_i)?;
-                Ok(dot_p)
-            })
-            .collect();
-
-        let product = product?
-            .iter()
-            .fold(Enc::Ciphertext::zero(), |acc, &x| acc + x);
-        // .sum();
+            .map(|(c_i, a_i)| accumulated_dot_product(a_i, c_i))
+            .sum::<Result<CiphertextAccumulator<Scalar, Enc>, CryptoError>>()?;
 
         diagonal_sums[center] = product;
 
-        let zeroth_diagonal = dot_product(&a_0_randomness, &cipher_chunks.last().unwrap())?;
+        let zeroth_diagonal =
+            accumulated_dot_product(a_0_randomness, cipher_chunks.last().unwrap())?;
         diagonal_sums.insert(0, zeroth_diagonal);
 
         Ok(diagonal_sums)
     }
 }
+
+impl<'a, Scalar, Enc, Comm> ProverRound1<'a, Scalar, Enc, Comm>
+where
+    Scalar: Field,
+    Enc: HomomorphicEncryptionScheme<Scalar>,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+{
+    pub fn round_2(
+        self,
+        challenge: &Round1Challenge<Scalar>,
+    ) -> Result<Round2Message<Scalar, Enc>, CryptoError> {
+        let m = self.prover.matrix_a.len();
+        let n = self.prover.matrix_a[0].len();
+        let num_of_diagonals = 2 * m - 1;
+
+        // Precompute all powers of the challenge from 0 to number_of_diagonals
+        let challenge_powers = scalar_powers(challenge.x, num_of_diagonals);
+
+        // take vector x: x, x^2, x^3, ..., x^m
+        let x_array = challenge_powers[1..m + 1].to_vec();
+
+        let scalar_products_ax = self
+            .prover
+            .matrix_a
+            .iter()
+            .enumerate()
+            .map(|(i, chunk)| {
+                chunk
+                    .iter()
+                    .map(|scalar| x_array[i] * scalar)
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<Vec<Scalar>>>();
+
+        let mut a_blinded: Vec<Scalar> = Vec::with_capacity(n);
+
+        // c0[0]x + c1[0]x^2 ... cm[0]x^m = b[0]
+        // c0[1]x + c1[1]x^2 ... cm[1]x^m = b[1]
+        // c0[2]x + c1[2]x^2 ... cm[2]x^m
+        // c0[3]x + c1[3]x^2 ... cm[3]x^m
+        // ...
+        // c0[n]x + c1[n]x^2 ... cm[n]x^m = b[n]
+        for i in 0..n {
+            let mut poly = self.a_0[i];
+            for j in 0..m {
+                poly = poly + scalar_products_ax[j][i];
+            }
+            a_blinded.push(poly);
+        }
+
+        let r_blinded = self.r_0 + dot_product(&self.prover.matrix_blinders, &x_array)?;
+        let b_blinded = dot_product(&self.b, &challenge_powers)?;
+        let s_blinded = dot_product(&self.s, &challenge_powers)?;
+        let tau_blinded = dot_product(&challenge_powers, &self.tau)?;
+
+        Ok(Round2Message {
+            r_blinded,
+            b_blinded,
+            s_blinded,
+            tau_blinded,
+            a_blinded,
+        })
+    }
+}
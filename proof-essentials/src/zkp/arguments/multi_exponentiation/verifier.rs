@@ -0,0 +1,255 @@
+use super::messages::{Round1Challenge, Round1Message, Round2Message};
+use super::{absorb_statement, proof::Proof, round_1_challenge, Parameters, Statement};
+
+use crate::error::CryptoError;
+use crate::homomorphic_encryption::HomomorphicEncryptionScheme;
+use crate::utils::vector_arithmetic::msm_dot_product;
+use crate::vector_commitment::HomomorphicCommitmentScheme;
+use crate::zkp::arguments::batch::{
+    CiphertextEquation, CommitmentEquation, EncryptionKeys, Equations,
+};
+use crate::zkp::arguments::scalar_powers;
+use crate::zkp::transcript::Transcript;
+
+use ark_ff::Field;
+use std::borrow::Cow;
+
+/// Verifier of the multi-exponentiation argument. It borrows the statement, except when another argument hands over
+/// to it with values derived during its own rounds, which the verifier then owns.
+pub struct Verifier<'a, Scalar, Enc, Comm>
+where
+    Scalar: Field,
+    Enc: HomomorphicEncryptionScheme<Scalar>,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+{
+    pub(crate) parameters: Parameters<'a, Scalar, Enc, Comm>,
+    pub(crate) shuffled_ciphers: Cow<'a, [Vec<Enc::Ciphertext>]>,
+    pub(crate) product: Enc::Ciphertext,
+    pub(crate) commitments_to_exponents: Cow<'a, [Comm::Commitment]>,
+}
+
+/// Verifier of the multi-exponentiation argument once the first message is received. Holds the combinations of
+/// the commitments and ciphertexts that the openings of the second round are checked against.
+pub struct VerifierRound1<'a, Scalar, Enc, Comm>
+where
+    Scalar: Field,
+    Enc: HomomorphicEncryptionScheme<Scalar>,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+{
+    verifier: Verifier<'a, Scalar, Enc, Comm>,
+    challenge_powers: Vec<Scalar>,
+    commitment_to_a_blinded: Comm::Commitment,
+    commitment_to_b_blinded: Comm::Commitment,
+    vector_e_k: Vec<Enc::Ciphertext>,
+}
+
+impl<'a, Scalar, Enc, Comm> Verifier<'a, Scalar, Enc, Comm>
+where
+    Scalar: Field,
+    Enc: HomomorphicEncryptionScheme<Scalar>,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+{
+    pub fn new(
+        parameters: &Parameters<'a, Scalar, Enc, Comm>,
+        statement: &Statement<'a, Scalar, Enc, Comm>,
+    ) -> Self {
+        Self {
+            parameters: Parameters::new(
+                parameters.encrypt_parameters,
+                parameters.public_key,
+                parameters.commit_key,
+                parameters.generator,
+            ),
+            shuffled_ciphers: Cow::Borrowed(statement.shuffled_ciphers),
+            product: statement.product,
+            commitments_to_exponents: Cow::Borrowed(statement.commitments_to_exponents),
+        }
+    }
+
+    fn statement(&self) -> Statement<'_, Scalar, Enc, Comm> {
+        Statement::new(
+            &self.shuffled_ciphers,
+            self.product,
+            &self.commitments_to_exponents,
+        )
+    }
+
+    pub fn round_1(
+        self,
+        message: &Round1Message<Scalar, Enc, Comm>,
+        challenge: &Round1Challenge<Scalar>,
+    ) -> Result<VerifierRound1<'a, Scalar, Enc, Comm>, CryptoError> {
+        let m = self.shuffled_ciphers.len();
+        let num_of_diagonals = 2 * m - 1;
+
+        if message.commit_b_k.len() != num_of_diagonals + 1
+            || message.vector_e_k.len() != num_of_diagonals + 1
+        {
+            return Err(CryptoError::ProofVerificationError(String::from(
+                "Multi Exponentiation",
+            )));
+        }
+
+        // Precompute all powers of the challenge from 0 to number_of_diagonals
+        let challenge_powers = scalar_powers(challenge.x, num_of_diagonals);
+
+        // take vector x: x, x^2, x^3, ..., x^m
+        let x_array = challenge_powers[1..m + 1].to_vec();
+
+        let left = message.commit_b_k[m];
+        let right = Comm::commit(
+            self.parameters.commit_key,
+            &[Scalar::zero()],
+            Scalar::zero(),
+        )?;
+
+        if left != right {
+            return Err(CryptoError::ProofVerificationError(String::from(
+                "Multi Exponentiation",
+            )));
+        }
+
+        if message.vector_e_k[m] != self.product {
+            return Err(CryptoError::ProofVerificationError(String::from(
+                "Multi Exponentiation",
+            )));
+        }
+
+        let c_a_x = msm_dot_product(&x_array, &self.commitments_to_exponents)?;
+        let commitment_to_a_blinded = c_a_x + message.a_0_commit;
+        let commitment_to_b_blinded = msm_dot_product(&challenge_powers, &message.commit_b_k)?;
+
+        Ok(VerifierRound1 {
+            verifier: self,
+            challenge_powers,
+            commitment_to_a_blinded,
+            commitment_to_b_blinded,
+            vector_e_k: message.vector_e_k.clone(),
+        })
+    }
+
+    pub fn verify<T: Transcript>(
+        self,
+        proof: &Proof<Scalar, Enc, Comm>,
+        transcript: &mut T,
+    ) -> Result<(), CryptoError> {
+        absorb_statement(&self.parameters, &self.statement(), transcript)?;
+
+        let challenge = round_1_challenge(&proof.round_1, transcript)?;
+
+        self.round_1(&proof.round_1, &challenge)?
+            .round_2(&proof.round_2)
+    }
+
+    /// Run the verifier and return the equations of the last round instead of checking them.
+    pub(crate) fn equations<T: Transcript>(
+        self,
+        proof: &Proof<Scalar, Enc, Comm>,
+        transcript: &mut T,
+    ) -> Result<Equations<Scalar, Enc, Comm>, CryptoError> {
+        absorb_statement(&self.parameters, &self.statement(), transcript)?;
+
+        let challenge = round_1_challenge(&proof.round_1, transcript)?;
+
+        self.round_1(&proof.round_1, &challenge)?
+            .round_2_equations(&proof.round_2)
+    }
+}
+
+impl<'a, Scalar, Enc, Comm> VerifierRound1<'a, Scalar, Enc, Comm>
+where
+    Scalar: Field,
+    Enc: HomomorphicEncryptionScheme<Scalar>,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+{
+    pub fn round_2(self, message: &Round2Message<Scalar, Enc>) -> Result<(), CryptoError> {
+        let parameters = &self.verifier.parameters;
+        let commit_key = parameters.commit_key;
+        let keys = EncryptionKeys {
+            encrypt_parameters: parameters.encrypt_parameters,
+            public_key: parameters.public_key,
+            generator: parameters.generator,
+        };
+
+        let equations = self.round_2_equations(message)?;
+        if !equations.holds(commit_key, &keys)? {
+            return Err(CryptoError::ProofVerificationError(String::from(
+                "Multi Exponentiation",
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Equations checked by the second round: the blinded vectors open the combinations of the commitments
+    /// computed in the first round, and the combination of the ciphertexts E_k matches the blinded
+    /// multi-exponentiation of the shuffled ciphertexts.
+    pub(crate) fn round_2_equations(
+        self,
+        message: &Round2Message<Scalar, Enc>,
+    ) -> Result<Equations<Scalar, Enc, Comm>, CryptoError> {
+        let m = self.verifier.shuffled_ciphers.len();
+        let num_of_diagonals = 2 * m - 1;
+
+        if self
+            .verifier
+            .shuffled_ciphers
+            .iter()
+            .any(|chunk| chunk.len() != message.a_blinded.len())
+        {
+            return Err(CryptoError::ProofVerificationError(String::from(
+                "Multi Exponentiation",
+            )));
+        }
+
+        let commitments = vec![
+            CommitmentEquation::new(
+                self.commitment_to_a_blinded,
+                message.a_blinded.clone(),
+                message.r_blinded,
+            ),
+            CommitmentEquation::new(
+                self.commitment_to_b_blinded,
+                vec![message.b_blinded],
+                message.s_blinded,
+            ),
+        ];
+
+        /*
+            sum(E_k * x^k) - sum(C_i * x^(m-1-i) * a) = Enc(G * b, tau), i.e.
+            c1 * x^m-1; x[m-1]
+            c2 * x^m-2; x[m-2]
+            c3 * x^m-3; x[m-3]
+            ...
+            cm * x^m-m; x[0]
+        */
+        let mut ciphertexts = self.vector_e_k;
+        let mut scalars = self.challenge_powers[..num_of_diagonals + 1].to_vec();
+
+        for (power_of_x, cipher_chunk) in self
+            .challenge_powers
+            .iter()
+            .take(m)
+            .rev()
+            .zip(self.verifier.shuffled_ciphers.iter())
+        {
+            // x^m - i * a_vec
+            ciphertexts.extend_from_slice(cipher_chunk);
+            scalars.extend(
+                message
+                    .a_blinded
+                    .iter()
+                    .map(|element_of_a| -(*element_of_a * *power_of_x)),
+            );
+        }
+
+        let ciphertexts = vec![CiphertextEquation::new(
+            ciphertexts,
+            scalars,
+            message.b_blinded,
+            message.tau_blinded,
+        )];
+
+        Ok(Equations::new(commitments, ciphertexts))
+    }
+}
@@ -0,0 +1,85 @@
+use crate::error::CryptoError;
+use crate::utils::validation::{Validate, ValidatePoint};
+use crate::vector_commitment::pedersen::Commitment;
+
+use ark_ec::ProjectiveCurve;
+use ark_ff::Field;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::io::{Read, Write};
+use ark_std::{rand::Rng, UniformRand};
+
+/// First message of the prover: commitments to the bits a_L of the values, to a_R = a_L - 1 and to their masks.
+#[derive(Clone, Debug, PartialEq, CanonicalDeserialize, CanonicalSerialize)]
+pub struct Round1Message<C: ProjectiveCurve> {
+    pub a_l_commit: Commitment<C>,
+    pub a_r_commit: Commitment<C>,
+    pub s_l_commit: Commitment<C>,
+    pub s_r_commit: Commitment<C>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, CanonicalDeserialize, CanonicalSerialize)]
+pub struct Round1Challenge<F: Field> {
+    pub y: F,
+    pub z: F,
+}
+
+/// Second message of the prover: commitments to the coefficients t_1 and t_2 of t(X).
+#[derive(Clone, Debug, PartialEq, CanonicalDeserialize, CanonicalSerialize)]
+pub struct Round2Message<C: ProjectiveCurve> {
+    pub t_1_commit: Commitment<C>,
+    pub t_2_commit: Commitment<C>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, CanonicalDeserialize, CanonicalSerialize)]
+pub struct Round2Challenge<F: Field> {
+    pub x: F,
+}
+
+/// Third message of the prover: the evaluation of t(X) at x and the blinding factors of the commitments to t(x),
+/// l(x) and r(x). The prover then hands over to an inner product argument on l(x) and r(x).
+#[derive(Clone, Debug, PartialEq, CanonicalDeserialize, CanonicalSerialize)]
+pub struct Round3Message<F: Field> {
+    pub t_hat: F,
+    pub tau_x: F,
+    pub mu_l: F,
+    pub mu_r: F,
+}
+
+impl<F: Field> UniformRand for Round1Challenge<F> {
+    fn rand<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Self {
+            y: F::rand(rng),
+            z: F::rand(rng),
+        }
+    }
+}
+
+impl<F: Field> UniformRand for Round2Challenge<F> {
+    fn rand<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Self { x: F::rand(rng) }
+    }
+}
+
+impl<C> Validate for Round1Message<C>
+where
+    C: ProjectiveCurve,
+    C::Affine: ValidatePoint,
+{
+    fn validate(&self) -> Result<(), CryptoError> {
+        self.a_l_commit.validate()?;
+        self.a_r_commit.validate()?;
+        self.s_l_commit.validate()?;
+        self.s_r_commit.validate()
+    }
+}
+
+impl<C> Validate for Round2Message<C>
+where
+    C: ProjectiveCurve,
+    C::Affine: ValidatePoint,
+{
+    fn validate(&self) -> Result<(), CryptoError> {
+        self.t_1_commit.validate()?;
+        self.t_2_commit.validate()
+    }
+}
@@ -0,0 +1,178 @@
+pub mod messages;
+pub mod proof;
+pub mod prover;
+mod tests;
+pub mod verifier;
+
+use crate::error::CryptoError;
+use crate::vector_commitment::pedersen::{CommitKey, Commitment};
+use crate::zkp::{arguments::scalar_powers, transcript::Transcript, ArgumentOfKnowledge};
+use ark_ec::ProjectiveCurve;
+use ark_ff::{Field, FpParameters, PrimeField};
+use ark_std::{marker::PhantomData, rand::Rng};
+
+/// Aggregated range proof in the style of Bulletproofs: proves that each of m Pedersen commitments
+/// `V_j = v_j*g_0 + gamma_j*h` opens to a value in `[0, 2^k)`. The bits of all the values are committed with the
+/// generators `g_1, ..., g_(k*m)` of the commit key and the final inner product is proven with the
+/// [`InnerProductArgument`](crate::zkp::arguments::inner_product::InnerProductArgument), so the proof contains
+/// `4 * ceil(log2(k*m)) + 6` points.
+pub struct RangeProof<'a, C: ProjectiveCurve> {
+    _group: PhantomData<&'a C>,
+}
+
+/// Parameters for the range proof. Contains a commit key with at least `k*m + 1` generators and the number of
+/// bits k of the range.
+pub struct Parameters<'a, C: ProjectiveCurve> {
+    pub commit_key: &'a CommitKey<C>,
+    pub bits: usize,
+}
+
+impl<'a, C: ProjectiveCurve> Parameters<'a, C> {
+    pub fn new(bits: usize, commit_key: &'a CommitKey<C>) -> Self {
+        Self { commit_key, bits }
+    }
+
+    /// Check that the range fits in the scalar field and that the commit key is long enough for m values, and
+    /// return the commit key for the vectors of bits, made of the generators following the one used for values.
+    pub(crate) fn vector_key(&self, m: usize) -> Result<CommitKey<C>, CryptoError> {
+        let modulus_bits = <C::ScalarField as PrimeField>::Params::MODULUS_BITS as usize;
+        if self.bits == 0 || self.bits >= modulus_bits {
+            return Err(CryptoError::InvalidRangeSize(self.bits));
+        }
+
+        let len = self.bits * m;
+        let g = self.commit_key.g();
+        if m == 0 || len + 1 > g.len() {
+            return Err(CryptoError::CommitmentLengthError(
+                String::from("Range"),
+                len + 1,
+                g.len(),
+            ));
+        }
+
+        Ok(CommitKey::new(g[1..len + 1].to_vec(), *self.commit_key.h()))
+    }
+}
+
+/// Statement for the range proof: Pedersen commitments to single values.
+pub struct Statement<'a, C: ProjectiveCurve> {
+    pub commitments: &'a [Commitment<C>],
+}
+
+impl<'a, C: ProjectiveCurve> Statement<'a, C> {
+    pub fn new(commitments: &'a [Commitment<C>]) -> Self {
+        Self { commitments }
+    }
+}
+
+/// Witness for the range proof: the committed values and their blinding factors.
+pub struct Witness<'a, Scalar: PrimeField> {
+    pub values: &'a [Scalar],
+    pub blindings: &'a [Scalar],
+}
+
+impl<'a, Scalar: PrimeField> Witness<'a, Scalar> {
+    pub fn new(values: &'a [Scalar], blindings: &'a [Scalar]) -> Self {
+        Self { values, blindings }
+    }
+}
+
+impl<'a, C: ProjectiveCurve> ArgumentOfKnowledge for RangeProof<'a, C> {
+    type CommonReferenceString = Parameters<'a, C>;
+    type Statement = Statement<'a, C>;
+    type Witness = Witness<'a, C::ScalarField>;
+    type Proof = proof::Proof<C>;
+
+    fn prove<R: Rng, T: Transcript>(
+        rng: &mut R,
+        common_reference_string: &Self::CommonReferenceString,
+        statement: &Self::Statement,
+        witness: &Self::Witness,
+        transcript: &mut T,
+    ) -> Result<Self::Proof, CryptoError> {
+        let prover = prover::Prover::new(common_reference_string, statement, witness);
+        prover.prove(rng, transcript)
+    }
+
+    fn verify<T: Transcript>(
+        common_reference_string: &Self::CommonReferenceString,
+        statement: &Self::Statement,
+        proof: &Self::Proof,
+        transcript: &mut T,
+    ) -> Result<(), CryptoError> {
+        proof.verify(common_reference_string, statement, transcript)
+    }
+}
+
+/// Absorb the public parameters and the statement.
+pub(crate) fn absorb_statement<C: ProjectiveCurve, T: Transcript>(
+    parameters: &Parameters<C>,
+    statement: &Statement<C>,
+    transcript: &mut T,
+) -> Result<(), CryptoError> {
+    transcript.domain_separator(b"Range Proof");
+    transcript.append(b"commit key", parameters.commit_key)?;
+    transcript.append(b"bits", &(parameters.bits as u32))?;
+    transcript.append(b"commitments", &statement.commitments)?;
+
+    Ok(())
+}
+
+/// Absorb the commitments to the bits and to their masks, and derive the challenges y and z.
+pub(crate) fn round_1_challenges<C: ProjectiveCurve, T: Transcript>(
+    message: &messages::Round1Message<C>,
+    transcript: &mut T,
+) -> Result<messages::Round1Challenge<C::ScalarField>, CryptoError> {
+    transcript.round(1);
+    transcript.append(b"a_l commit", &message.a_l_commit)?;
+    transcript.append(b"a_r commit", &message.a_r_commit)?;
+    transcript.append(b"s_l commit", &message.s_l_commit)?;
+    transcript.append(b"s_r commit", &message.s_r_commit)?;
+
+    Ok(messages::Round1Challenge {
+        y: transcript.challenge_scalar(b"y"),
+        z: transcript.challenge_scalar(b"z"),
+    })
+}
+
+/// Absorb the commitments to the coefficients of t(X), and derive the challenge x.
+pub(crate) fn round_2_challenge<C: ProjectiveCurve, T: Transcript>(
+    message: &messages::Round2Message<C>,
+    transcript: &mut T,
+) -> Result<messages::Round2Challenge<C::ScalarField>, CryptoError> {
+    transcript.round(2);
+    transcript.append(b"t_1 commit", &message.t_1_commit)?;
+    transcript.append(b"t_2 commit", &message.t_2_commit)?;
+
+    Ok(messages::Round2Challenge {
+        x: transcript.challenge_scalar(b"x"),
+    })
+}
+
+/// Absorb the evaluation of t(X) and the blinding factors, before the inner product argument.
+pub(crate) fn absorb_round_3<F: Field, T: Transcript>(
+    message: &messages::Round3Message<F>,
+    transcript: &mut T,
+) -> Result<(), CryptoError> {
+    transcript.round(3);
+    transcript.append(b"t_hat", &message.t_hat)?;
+    transcript.append(b"tau_x", &message.tau_x)?;
+    transcript.append(b"mu_l", &message.mu_l)?;
+    transcript.append(b"mu_r", &message.mu_r)?;
+
+    Ok(())
+}
+
+/// The vector `z^2*2^k || z^3*2^k || ... || z^(m+1)*2^k`, which ties the bits of the j-th value to the j-th
+/// commitment.
+pub(crate) fn powers_of_two_scaled<F: PrimeField>(z: F, bits: usize, m: usize) -> Vec<F> {
+    let powers_of_two = &scalar_powers(F::from(2u64), bits)[..bits];
+    let mut z_power = z;
+    (0..m)
+        .flat_map(|_| {
+            z_power *= z;
+            let scale = z_power;
+            powers_of_two.iter().map(move |p| scale * p)
+        })
+        .collect()
+}
@@ -0,0 +1,45 @@
+use super::messages::{Round1Message, Round2Message, Round3Message};
+use super::{verifier::Verifier, Parameters, Statement};
+
+use crate::error::CryptoError;
+use crate::utils::validation::{Validate, ValidatePoint};
+use crate::zkp::arguments::inner_product;
+use crate::zkp::transcript::Transcript;
+
+use ark_ec::ProjectiveCurve;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::io::{Read, Write};
+
+#[derive(CanonicalDeserialize, CanonicalSerialize)]
+pub struct Proof<C>
+where
+    C: ProjectiveCurve,
+{
+    pub(crate) round_1: Round1Message<C>,
+    pub(crate) round_2: Round2Message<C>,
+    pub(crate) round_3: Round3Message<C::ScalarField>,
+    pub(crate) inner_product_proof: inner_product::proof::Proof<C>,
+}
+
+impl<C: ProjectiveCurve> Proof<C> {
+    pub fn verify<T: Transcript>(
+        &self,
+        parameters: &Parameters<C>,
+        statement: &Statement<C>,
+        transcript: &mut T,
+    ) -> Result<(), CryptoError> {
+        Verifier::new(parameters, statement).verify(self, transcript)
+    }
+}
+
+impl<C> Validate for Proof<C>
+where
+    C: ProjectiveCurve,
+    C::Affine: ValidatePoint,
+{
+    fn validate(&self) -> Result<(), CryptoError> {
+        self.round_1.validate()?;
+        self.round_2.validate()?;
+        self.inner_product_proof.validate()
+    }
+}
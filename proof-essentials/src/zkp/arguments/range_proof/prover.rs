@@ -0,0 +1,304 @@
+use super::messages::{
+    Round1Challenge, Round1Message, Round2Challenge, Round2Message, Round3Message,
+};
+use super::{
+    absorb_round_3, absorb_statement, powers_of_two_scaled, proof::Proof, round_1_challenges,
+    round_2_challenge, Parameters, Statement, Witness,
+};
+
+use crate::error::CryptoError;
+use crate::utils::{rand::sample_vector, vector_arithmetic::dot_product};
+use crate::vector_commitment::pedersen::{CommitKey, Commitment, PedersenCommitment};
+use crate::vector_commitment::HomomorphicCommitmentScheme;
+use crate::zkp::arguments::{inner_product, scalar_powers};
+use crate::zkp::transcript::Transcript;
+use ark_ec::ProjectiveCurve;
+use ark_ff::{batch_inversion, BigInteger, One, PrimeField, Zero};
+use ark_std::{rand::Rng, UniformRand};
+use std::borrow::Cow;
+
+pub struct Prover<'a, C: ProjectiveCurve> {
+    parameters: Parameters<'a, C>,
+    commitments: &'a [Commitment<C>],
+    values: &'a [C::ScalarField],
+    blindings: &'a [C::ScalarField],
+}
+
+/// Prover of the range proof once the bits and their masks are committed, waiting for the challenges y and z.
+pub struct ProverRound1<'a, C: ProjectiveCurve> {
+    prover: Prover<'a, C>,
+    vector_key: CommitKey<C>,
+    a_l: Vec<C::ScalarField>,
+    a_r: Vec<C::ScalarField>,
+    s_l: Vec<C::ScalarField>,
+    s_r: Vec<C::ScalarField>,
+    alpha_l: C::ScalarField,
+    alpha_r: C::ScalarField,
+    rho_l: C::ScalarField,
+    rho_r: C::ScalarField,
+}
+
+/// Prover of the range proof once the coefficients of t(X) are committed, waiting for the challenge x.
+pub struct ProverRound2<'a, C: ProjectiveCurve> {
+    prover: Prover<'a, C>,
+    vector_key: CommitKey<C>,
+    powers_of_y: Vec<C::ScalarField>,
+    z: C::ScalarField,
+    l_0: Vec<C::ScalarField>,
+    r_0: Vec<C::ScalarField>,
+    s_l: Vec<C::ScalarField>,
+    s_r: Vec<C::ScalarField>,
+    alpha_l: C::ScalarField,
+    alpha_r: C::ScalarField,
+    rho_l: C::ScalarField,
+    rho_r: C::ScalarField,
+    tau_1: C::ScalarField,
+    tau_2: C::ScalarField,
+}
+
+impl<'a, C: ProjectiveCurve> Prover<'a, C> {
+    pub fn new(
+        parameters: &Parameters<'a, C>,
+        statement: &Statement<'a, C>,
+        witness: &Witness<'a, C::ScalarField>,
+    ) -> Self {
+        Self {
+            parameters: Parameters::new(parameters.bits, parameters.commit_key),
+            commitments: statement.commitments,
+            values: witness.values,
+            blindings: witness.blindings,
+        }
+    }
+
+    fn statement(&self) -> Statement<'a, C> {
+        Statement::new(self.commitments)
+    }
+
+    pub fn round_1<R: Rng>(
+        self,
+        rng: &mut R,
+    ) -> Result<(ProverRound1<'a, C>, Round1Message<C>), CryptoError> {
+        let m = self.commitments.len();
+        for openings in [self.values.len(), self.blindings.len()] {
+            if openings != m {
+                return Err(CryptoError::RangeProofLengthError(m, openings));
+            }
+        }
+
+        let vector_key = self.parameters.vector_key(m)?;
+        let bits = self.parameters.bits;
+        let len = bits * m;
+        let one = C::ScalarField::one();
+
+        // Bits a_L of the values and a_R = a_L - 1. Values out of range are truncated and the proof will not verify.
+        let a_l = self
+            .values
+            .iter()
+            .flat_map(|value| {
+                let repr = value.into_repr();
+                (0..bits).map(move |i| C::ScalarField::from(repr.get_bit(i) as u64))
+            })
+            .collect::<Vec<_>>();
+        let a_r = a_l.iter().map(|bit| *bit - one).collect::<Vec<_>>();
+
+        let alpha_l = C::ScalarField::rand(rng);
+        let alpha_r = C::ScalarField::rand(rng);
+
+        let s_l: Vec<C::ScalarField> = sample_vector(rng, len);
+        let s_r: Vec<C::ScalarField> = sample_vector(rng, len);
+        let rho_l = C::ScalarField::rand(rng);
+        let rho_r = C::ScalarField::rand(rng);
+
+        let commits = PedersenCommitment::commit_columns(
+            &vector_key,
+            &[&a_l[..], &a_r[..], &s_l[..], &s_r[..]],
+            &[alpha_l, alpha_r, rho_l, rho_r],
+        )?;
+
+        let message = Round1Message {
+            a_l_commit: commits[0],
+            a_r_commit: commits[1],
+            s_l_commit: commits[2],
+            s_r_commit: commits[3],
+        };
+
+        let state = ProverRound1 {
+            prover: self,
+            vector_key,
+            a_l,
+            a_r,
+            s_l,
+            s_r,
+            alpha_l,
+            alpha_r,
+            rho_l,
+            rho_r,
+        };
+
+        Ok((state, message))
+    }
+
+    pub fn prove<R: Rng, T: Transcript>(
+        self,
+        rng: &mut R,
+        transcript: &mut T,
+    ) -> Result<Proof<C>, CryptoError> {
+        absorb_statement(&self.parameters, &self.statement(), transcript)?;
+
+        let (prover, round_1) = self.round_1(rng)?;
+        let y_z = round_1_challenges(&round_1, transcript)?;
+
+        let (prover, round_2) = prover.round_2(rng, &y_z)?;
+        let x = round_2_challenge(&round_2, transcript)?;
+
+        let (round_3, inner_product_prover) = prover.round_3(&x)?;
+        absorb_round_3(&round_3, transcript)?;
+
+        let inner_product_proof = inner_product_prover.prove(transcript)?;
+
+        Ok(Proof {
+            round_1,
+            round_2,
+            round_3,
+            inner_product_proof,
+        })
+    }
+
+    fn weighted_inner_product(
+        a: &[C::ScalarField],
+        b: &[C::ScalarField],
+        weights: &[C::ScalarField],
+    ) -> C::ScalarField {
+        a.iter()
+            .zip(b.iter())
+            .zip(weights.iter())
+            .map(|((a_i, b_i), w_i)| *a_i * b_i * w_i)
+            .sum()
+    }
+}
+
+impl<'a, C: ProjectiveCurve> ProverRound1<'a, C> {
+    pub fn round_2<R: Rng>(
+        self,
+        rng: &mut R,
+        challenge: &Round1Challenge<C::ScalarField>,
+    ) -> Result<(ProverRound2<'a, C>, Round2Message<C>), CryptoError> {
+        let Round1Challenge { y, z } = *challenge;
+        let bits = self.prover.parameters.bits;
+        let m = self.prover.commitments.len();
+        let len = bits * m;
+
+        // l(X) = a_L - z + s_L*X and r'(X) = a_R + z + s_R*X + y^(-N)∘d, where d are the scaled powers of two. The
+        // polynomial t(X) = t_0 + t_1*X + t_2*X^2 is the inner product of l(X) and r'(X) weighted by y^N.
+        let powers_of_y = scalar_powers(y, len)[..len].to_vec();
+        let mut inverse_powers_of_y = powers_of_y.clone();
+        batch_inversion(&mut inverse_powers_of_y);
+        let d = powers_of_two_scaled(z, bits, m);
+
+        let l_0 = self.a_l.iter().map(|a| *a - z).collect::<Vec<_>>();
+        let r_0 = self
+            .a_r
+            .iter()
+            .zip(d.iter().zip(inverse_powers_of_y.iter()))
+            .map(|(a, (d_i, y_inv))| *a + z + *d_i * y_inv)
+            .collect::<Vec<_>>();
+
+        let t_1 = Prover::<C>::weighted_inner_product(&l_0, &self.s_r, &powers_of_y)
+            + Prover::<C>::weighted_inner_product(&self.s_l, &r_0, &powers_of_y);
+        let t_2 = Prover::<C>::weighted_inner_product(&self.s_l, &self.s_r, &powers_of_y);
+
+        let tau_1 = C::ScalarField::rand(rng);
+        let tau_2 = C::ScalarField::rand(rng);
+        let commits = PedersenCommitment::commit_columns(
+            self.prover.parameters.commit_key,
+            &[vec![t_1], vec![t_2]],
+            &[tau_1, tau_2],
+        )?;
+
+        let message = Round2Message {
+            t_1_commit: commits[0],
+            t_2_commit: commits[1],
+        };
+
+        let state = ProverRound2 {
+            prover: self.prover,
+            vector_key: self.vector_key,
+            powers_of_y,
+            z,
+            l_0,
+            r_0,
+            s_l: self.s_l,
+            s_r: self.s_r,
+            alpha_l: self.alpha_l,
+            alpha_r: self.alpha_r,
+            rho_l: self.rho_l,
+            rho_r: self.rho_r,
+            tau_1,
+            tau_2,
+        };
+
+        Ok((state, message))
+    }
+}
+
+impl<'a, C: ProjectiveCurve> ProverRound2<'a, C> {
+    /// Evaluate l(X), r'(X) and t(X) at x and hand over to the prover of the inner product argument on l(x) and
+    /// r'(x).
+    pub fn round_3(
+        self,
+        challenge: &Round2Challenge<C::ScalarField>,
+    ) -> Result<
+        (
+            Round3Message<C::ScalarField>,
+            inner_product::prover::Prover<'a, C>,
+        ),
+        CryptoError,
+    > {
+        let x = challenge.x;
+        let m = self.prover.commitments.len();
+
+        let l = self
+            .l_0
+            .iter()
+            .zip(self.s_l.iter())
+            .map(|(l_i, s_i)| *l_i + x * s_i)
+            .collect::<Vec<_>>();
+        let r = self
+            .r_0
+            .iter()
+            .zip(self.s_r.iter())
+            .map(|(r_i, s_i)| *r_i + x * s_i)
+            .collect::<Vec<_>>();
+        let t_hat = Prover::<C>::weighted_inner_product(&l, &r, &self.powers_of_y);
+
+        let z_powers = scalar_powers(self.z, m + 1)[2..].to_vec();
+        let tau_x =
+            self.tau_1 * x + self.tau_2 * x * x + dot_product(&z_powers, self.prover.blindings)?;
+        let mu_l = self.alpha_l + self.rho_l * x;
+        let mu_r = self.alpha_r + self.rho_r * x;
+
+        // Prove the value of the inner product on the unblinded commitments to l and r'
+        let zero = C::ScalarField::zero();
+        let commits =
+            PedersenCommitment::commit_columns(&self.vector_key, &[&l[..], &r[..]], &[zero, zero])?;
+        let (commitment_to_l, commitment_to_r) = (commits[0], commits[1]);
+        let inner_product_prover = inner_product::prover::Prover {
+            parameters: Cow::Owned(self.vector_key),
+            commitment_to_a: commitment_to_l,
+            commitment_to_b: commitment_to_r,
+            weights: Cow::Owned(self.powers_of_y),
+            value: t_hat,
+            a: Cow::Owned(l),
+            b: Cow::Owned(r),
+        };
+
+        let message = Round3Message {
+            t_hat,
+            tau_x,
+            mu_l,
+            mu_r,
+        };
+
+        Ok((message, inner_product_prover))
+    }
+}
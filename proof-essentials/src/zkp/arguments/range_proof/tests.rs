@@ -0,0 +1,168 @@
+#[cfg(test)]
+mod test {
+    use crate::error::CryptoError;
+    use crate::utils::rand::sample_vector;
+    use crate::utils::validation::deserialize_validated;
+    use crate::vector_commitment::{pedersen, HomomorphicCommitmentScheme};
+    use crate::zkp::{arguments::range_proof, ArgumentOfKnowledge};
+
+    use ark_ff::{Field, One, Zero};
+    use ark_marlin::rng::FiatShamirRng;
+    use ark_serialize::CanonicalSerialize;
+    use ark_std::rand::thread_rng;
+    use blake2::Blake2s;
+    use starknet_curve;
+
+    // Choose elliptic curve setting
+    type Curve = starknet_curve::Projective;
+    type Scalar = starknet_curve::Fr;
+
+    // Type aliases for concrete instances using the chosen EC.
+    type Comm = pedersen::PedersenCommitment<Curve>;
+    type CommitKey = pedersen::CommitKey<Curve>;
+    type Commitment = pedersen::Commitment<Curve>;
+    type Witness<'a> = range_proof::Witness<'a, Scalar>;
+    type Statement<'a> = range_proof::Statement<'a, Curve>;
+    type Parameters<'a> = range_proof::Parameters<'a, Curve>;
+    type RangeProof<'a> = range_proof::RangeProof<'a, Curve>;
+
+    // Fiat Shamir
+    type FS = FiatShamirRng<Blake2s>;
+
+    fn commit(commit_key: &CommitKey, values: &[Scalar], blindings: &[Scalar]) -> Vec<Commitment> {
+        values
+            .iter()
+            .zip(blindings.iter())
+            .map(|(value, blinding)| Comm::commit(commit_key, &[*value], *blinding).unwrap())
+            .collect()
+    }
+
+    fn prove_and_verify(
+        commit_key: &CommitKey,
+        bits: usize,
+        values: &[Scalar],
+    ) -> Result<(), CryptoError> {
+        let rng = &mut thread_rng();
+        let blindings: Vec<Scalar> = sample_vector(rng, values.len());
+        let commitments = commit(commit_key, values, &blindings);
+
+        let parameters = Parameters::new(bits, commit_key);
+        let statement = Statement::new(&commitments);
+        let witness = Witness::new(values, &blindings);
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let proof = RangeProof::prove(rng, &parameters, &statement, &witness, &mut fs_rng)?;
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        RangeProof::verify(&parameters, &statement, &proof, &mut fs_rng)
+    }
+
+    #[test]
+    fn test_range_proof() {
+        let rng = &mut thread_rng();
+        let bits = 8;
+        let commit_key = Comm::setup(rng, 3 * bits + 1);
+
+        let values = vec![
+            Scalar::from(0u64),
+            Scalar::from(255u64),
+            Scalar::from(42u64),
+        ];
+        let blindings: Vec<Scalar> = sample_vector(rng, values.len());
+        let commitments = commit(&commit_key, &values, &blindings);
+
+        let parameters = Parameters::new(bits, &commit_key);
+        let statement = Statement::new(&commitments);
+        let witness = Witness::new(&values, &blindings);
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let proof = RangeProof::prove(rng, &parameters, &statement, &witness, &mut fs_rng).unwrap();
+
+        let mut bytes = Vec::new();
+        proof.serialize_unchecked(&mut bytes).unwrap();
+        let received_proof = deserialize_validated(&bytes[..]).unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            Ok(()),
+            RangeProof::verify(&parameters, &statement, &received_proof, &mut fs_rng)
+        );
+
+        // The proof does not hold for other commitments
+        let other_blindings: Vec<Scalar> = sample_vector(rng, values.len());
+        let other_commitments = commit(&commit_key, &values, &other_blindings);
+        let other_statement = Statement::new(&other_commitments);
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            Err(CryptoError::ProofVerificationError(String::from(
+                "Range Proof"
+            ))),
+            RangeProof::verify(&parameters, &other_statement, &proof, &mut fs_rng)
+        );
+    }
+
+    #[test]
+    fn test_range_boundaries() {
+        let rng = &mut thread_rng();
+        let commit_key = Comm::setup(rng, 2 * 64 + 1);
+
+        for bits in [1, 2, 7, 64] {
+            let max = Scalar::from(2u64).pow([bits as u64]) - Scalar::one();
+
+            // Single values and aggregated values on both ends of the range
+            assert_eq!(
+                Ok(()),
+                prove_and_verify(&commit_key, bits, &[Scalar::zero()])
+            );
+            assert_eq!(Ok(()), prove_and_verify(&commit_key, bits, &[max]));
+            assert_eq!(
+                Ok(()),
+                prove_and_verify(&commit_key, bits, &[max, Scalar::zero()])
+            );
+
+            // Values just outside of the range, alone or next to a valid value
+            let outside_of_range = Err(CryptoError::ProofVerificationError(String::from(
+                "Range Proof",
+            )));
+            assert_eq!(
+                outside_of_range,
+                prove_and_verify(&commit_key, bits, &[max + Scalar::one()])
+            );
+            assert_eq!(
+                outside_of_range,
+                prove_and_verify(&commit_key, bits, &[Scalar::zero(), -Scalar::one()])
+            );
+        }
+    }
+
+    #[test]
+    fn test_invalid_parameters() {
+        let rng = &mut thread_rng();
+        let commit_key = Comm::setup(rng, 16);
+        let values = vec![Scalar::one(); 2];
+
+        assert_eq!(
+            Err(CryptoError::CommitmentLengthError(
+                String::from("Range"),
+                17,
+                16
+            )),
+            prove_and_verify(&commit_key, 8, &values)
+        );
+        assert_eq!(
+            Err(CryptoError::InvalidRangeSize(0)),
+            prove_and_verify(&commit_key, 0, &values)
+        );
+
+        let blindings: Vec<Scalar> = sample_vector(rng, 1);
+        let commitments = commit(&commit_key, &values, &[blindings[0], blindings[0]]);
+        let parameters = Parameters::new(4, &commit_key);
+        let statement = Statement::new(&commitments);
+        let witness = Witness::new(&values, &blindings);
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            Err(CryptoError::RangeProofLengthError(2, 1)),
+            RangeProof::prove(rng, &parameters, &statement, &witness, &mut fs_rng).map(|_| ())
+        );
+    }
+}
@@ -0,0 +1,189 @@
+use super::messages::{
+    Round1Challenge, Round1Message, Round2Challenge, Round2Message, Round3Message,
+};
+use super::{
+    absorb_round_3, absorb_statement, powers_of_two_scaled, proof::Proof, round_1_challenges,
+    round_2_challenge, Parameters, Statement,
+};
+
+use crate::error::CryptoError;
+use crate::utils::vector_arithmetic::msm_dot_product;
+use crate::vector_commitment::pedersen::{CommitKey, Commitment, PedersenCommitment};
+use crate::vector_commitment::HomomorphicCommitmentScheme;
+use crate::zkp::arguments::{inner_product, scalar_powers};
+use crate::zkp::transcript::Transcript;
+
+use ark_ec::ProjectiveCurve;
+use ark_ff::{batch_inversion, Field, One, Zero};
+use std::borrow::Cow;
+
+pub struct Verifier<'a, C: ProjectiveCurve> {
+    parameters: Parameters<'a, C>,
+    commitments: &'a [Commitment<C>],
+}
+
+/// Verifier of the range proof once the commitments to the bits and the challenges y and z are exchanged.
+pub struct VerifierRound1<'a, C: ProjectiveCurve> {
+    verifier: Verifier<'a, C>,
+    vector_key: CommitKey<C>,
+    commitments: Round1Message<C>,
+    y: C::ScalarField,
+    z: C::ScalarField,
+}
+
+/// Verifier of the range proof once the challenge x is sent. Holds the commitment that t(x) is checked against
+/// and the blinded commitments to l(x) and r'(x).
+pub struct VerifierRound2<'a, C: ProjectiveCurve> {
+    verifier: Verifier<'a, C>,
+    vector_key: CommitKey<C>,
+    powers_of_y: Vec<C::ScalarField>,
+    z: C::ScalarField,
+    r_offset: Vec<C::ScalarField>,
+    commitment_to_t: Commitment<C>,
+    commitment_to_l_blinded: Commitment<C>,
+    commitment_to_r_blinded: Commitment<C>,
+}
+
+impl<'a, C: ProjectiveCurve> Verifier<'a, C> {
+    pub fn new(parameters: &Parameters<'a, C>, statement: &Statement<'a, C>) -> Self {
+        Self {
+            parameters: Parameters::new(parameters.bits, parameters.commit_key),
+            commitments: statement.commitments,
+        }
+    }
+
+    fn statement(&self) -> Statement<'a, C> {
+        Statement::new(self.commitments)
+    }
+
+    pub fn round_1(
+        self,
+        message: &Round1Message<C>,
+        challenge: &Round1Challenge<C::ScalarField>,
+    ) -> Result<VerifierRound1<'a, C>, CryptoError> {
+        let vector_key = self.parameters.vector_key(self.commitments.len())?;
+
+        Ok(VerifierRound1 {
+            verifier: self,
+            vector_key,
+            commitments: message.clone(),
+            y: challenge.y,
+            z: challenge.z,
+        })
+    }
+
+    pub fn verify<T: Transcript>(
+        self,
+        proof: &Proof<C>,
+        transcript: &mut T,
+    ) -> Result<(), CryptoError> {
+        absorb_statement(&self.parameters, &self.statement(), transcript)?;
+
+        let y_z = round_1_challenges(&proof.round_1, transcript)?;
+        let x = round_2_challenge(&proof.round_2, transcript)?;
+
+        let verifier = self
+            .round_1(&proof.round_1, &y_z)?
+            .round_2(&proof.round_2, &x)?;
+
+        absorb_round_3(&proof.round_3, transcript)?;
+
+        verifier
+            .round_3(&proof.round_3)?
+            .verify(&proof.inner_product_proof, transcript)
+            .map_err(|_| CryptoError::ProofVerificationError(String::from("Range Proof")))
+    }
+}
+
+impl<'a, C: ProjectiveCurve> VerifierRound1<'a, C> {
+    pub fn round_2(
+        self,
+        message: &Round2Message<C>,
+        challenge: &Round2Challenge<C::ScalarField>,
+    ) -> Result<VerifierRound2<'a, C>, CryptoError> {
+        let (y, z, x) = (self.y, self.z, challenge.x);
+        let m = self.verifier.commitments.len();
+        let bits = self.verifier.parameters.bits;
+        let len = bits * m;
+        let commit_key = self.verifier.parameters.commit_key;
+
+        // Commitment to t(x) with t_0 = sum z^(j+2)*v_j + delta(y, z), where
+        // delta(y, z) = (z - z^2)*<1, y^N> - sum z^(j+3)*<1, 2^k>
+        let powers_of_y = scalar_powers(y, len)[..len].to_vec();
+        let z_powers = scalar_powers(z, m + 2);
+        let sum_of_powers_of_y: C::ScalarField = powers_of_y.iter().sum();
+        let sum_of_powers_of_two =
+            C::ScalarField::from(2u64).pow([bits as u64]) - C::ScalarField::one();
+        let sum_of_z_powers: C::ScalarField = z_powers[3..].iter().sum();
+        let delta = (z - z_powers[2]) * sum_of_powers_of_y - sum_of_z_powers * sum_of_powers_of_two;
+
+        let mut scalars = z_powers[2..m + 2].to_vec();
+        scalars.extend_from_slice(&[C::ScalarField::one(), x, x * x]);
+        let mut commitments = self.verifier.commitments.to_vec();
+        commitments.extend_from_slice(&[
+            PedersenCommitment::commit(commit_key, &[delta], C::ScalarField::zero())?,
+            message.t_1_commit,
+            message.t_2_commit,
+        ]);
+        let commitment_to_t = msm_dot_product(&scalars, &commitments)?;
+
+        // Offset of r' = a_R + z + s_R*x + y^(-N)∘d
+        let mut inverse_powers_of_y = powers_of_y.clone();
+        batch_inversion(&mut inverse_powers_of_y);
+        let r_offset = powers_of_two_scaled(z, bits, m)
+            .iter()
+            .zip(inverse_powers_of_y.iter())
+            .map(|(d_i, y_inv)| z + *d_i * y_inv)
+            .collect::<Vec<_>>();
+
+        let commitment_to_l_blinded = self.commitments.a_l_commit + self.commitments.s_l_commit * x;
+        let commitment_to_r_blinded = self.commitments.a_r_commit + self.commitments.s_r_commit * x;
+
+        Ok(VerifierRound2 {
+            verifier: self.verifier,
+            vector_key: self.vector_key,
+            powers_of_y,
+            z,
+            r_offset,
+            commitment_to_t,
+            commitment_to_l_blinded,
+            commitment_to_r_blinded,
+        })
+    }
+}
+
+impl<'a, C: ProjectiveCurve> VerifierRound2<'a, C> {
+    /// Check the evaluation of t(X) at x and hand over to the verifier of the inner product argument on the
+    /// unblinded commitments to l(x) and r'(x).
+    pub fn round_3(
+        self,
+        message: &Round3Message<C::ScalarField>,
+    ) -> Result<inner_product::verifier::Verifier<'a, C>, CryptoError> {
+        if PedersenCommitment::commit(
+            self.verifier.parameters.commit_key,
+            &[message.t_hat],
+            message.tau_x,
+        )? != self.commitment_to_t
+        {
+            return Err(CryptoError::ProofVerificationError(String::from(
+                "Range Proof",
+            )));
+        }
+
+        let len = self.powers_of_y.len();
+        let commitment_to_l = self.commitment_to_l_blinded
+            + PedersenCommitment::commit(&self.vector_key, &vec![-self.z; len], -message.mu_l)?;
+        let commitment_to_r = self.commitment_to_r_blinded
+            + PedersenCommitment::commit(&self.vector_key, &self.r_offset, -message.mu_r)?;
+
+        Ok(inner_product::verifier::Verifier {
+            parameters: Cow::Owned(self.vector_key),
+            commitment_to_a: commitment_to_l,
+            commitment_to_b: commitment_to_r,
+            weights: Cow::Owned(self.powers_of_y),
+            value: message.t_hat,
+            rounds: Vec::new(),
+            challenges: Vec::new(),
+        })
+    }
+}
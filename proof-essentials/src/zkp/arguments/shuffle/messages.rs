@@ -0,0 +1,79 @@
+use crate::error::CryptoError;
+use crate::utils::validation::Validate;
+use crate::vector_commitment::HomomorphicCommitmentScheme;
+
+use ark_ff::Field;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::io::{Read, Write};
+use ark_std::{rand::Rng, UniformRand};
+
+/// First message of the prover: commitments to the permuted indices 1, ..., mn.
+#[derive(CanonicalDeserialize, CanonicalSerialize)]
+pub struct Round1Message<Scalar, Comm>
+where
+    Scalar: Field,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+{
+    pub a_commits: Vec<Comm::Commitment>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, CanonicalDeserialize, CanonicalSerialize)]
+pub struct Round1Challenge<Scalar: Field> {
+    pub x: Scalar,
+}
+
+/// Second message of the prover: commitments to the permuted powers x, ..., x^mn of the challenge.
+#[derive(CanonicalDeserialize, CanonicalSerialize)]
+pub struct Round2Message<Scalar, Comm>
+where
+    Scalar: Field,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+{
+    pub b_commits: Vec<Comm::Commitment>,
+}
+
+/// Challenges of the verifier combining the permuted indices and powers of x into the product argument.
+#[derive(Clone, Copy, Debug, PartialEq, CanonicalDeserialize, CanonicalSerialize)]
+pub struct Round2Challenge<Scalar: Field> {
+    pub y: Scalar,
+    pub z: Scalar,
+}
+
+impl<Scalar: Field> UniformRand for Round1Challenge<Scalar> {
+    fn rand<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Self {
+            x: Scalar::rand(rng),
+        }
+    }
+}
+
+impl<Scalar: Field> UniformRand for Round2Challenge<Scalar> {
+    fn rand<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Self {
+            y: Scalar::rand(rng),
+            z: Scalar::rand(rng),
+        }
+    }
+}
+
+impl<Scalar, Comm> Validate for Round1Message<Scalar, Comm>
+where
+    Scalar: Field,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+    Comm::Commitment: Validate,
+{
+    fn validate(&self) -> Result<(), CryptoError> {
+        self.a_commits.validate()
+    }
+}
+
+impl<Scalar, Comm> Validate for Round2Message<Scalar, Comm>
+where
+    Scalar: Field,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+    Comm::Commitment: Validate,
+{
+    fn validate(&self) -> Result<(), CryptoError> {
+        self.b_commits.validate()
+    }
+}
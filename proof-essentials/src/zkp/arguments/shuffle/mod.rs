@@ -1,17 +1,17 @@
+pub mod messages;
 pub mod proof;
 pub mod prover;
 mod tests;
+pub mod verifier;
 
 use crate::error::CryptoError;
 use crate::homomorphic_encryption::HomomorphicEncryptionScheme;
 use crate::utils::permutation::Permutation;
 use crate::vector_commitment::HomomorphicCommitmentScheme;
-use crate::zkp::ArgumentOfKnowledge;
+use crate::zkp::{transcript::Transcript, ArgumentOfKnowledge};
 
 use ark_ff::Field;
-use ark_marlin::rng::FiatShamirRng;
 use ark_std::rand::Rng;
-use digest::Digest;
 use std::marker::PhantomData;
 
 pub struct ShuffleArgument<
@@ -33,29 +33,29 @@ where
 {
     type CommonReferenceString = Parameters<'a, F, Enc, Comm>;
     type Statement = Statement<'a, F, Enc>;
-    type Witness = Witness<'a, F>;
+    type Witness = Witness<'a, Enc::Randomness>;
     type Proof = proof::Proof<F, Enc, Comm>;
 
-    fn prove<R: Rng, D: Digest>(
+    fn prove<R: Rng, T: Transcript>(
         rng: &mut R,
         common_reference_string: &Self::CommonReferenceString,
         statement: &Self::Statement,
         witness: &Self::Witness,
-        fs_rng: &mut FiatShamirRng<D>,
+        transcript: &mut T,
     ) -> Result<Self::Proof, CryptoError> {
         let prover = prover::Prover::new(&common_reference_string, &statement, &witness);
-        let proof = prover.prove(rng, fs_rng)?;
+        let proof = prover.prove(rng, transcript)?;
 
         Ok(proof)
     }
 
-    fn verify<D: Digest>(
+    fn verify<T: Transcript>(
         common_reference_string: &Self::CommonReferenceString,
         statement: &Self::Statement,
         proof: &Self::Proof,
-        fs_rng: &mut FiatShamirRng<D>,
+        transcript: &mut T,
     ) -> Result<(), CryptoError> {
-        proof.verify(&common_reference_string, &statement, fs_rng)
+        proof.verify(&common_reference_string, &statement, transcript)
     }
 }
 
@@ -136,14 +136,67 @@ where
     }
 }
 
-/// Witness
-pub struct Witness<'a, Scalar: Field> {
+/// Witness of a shuffle: the permutation and the re-encryption factors of the shuffled ciphertexts.
+pub struct Witness<'a, Randomness> {
     pub permutation: &'a Permutation,
-    pub rho: &'a Vec<Scalar>,
+    pub rho: &'a Vec<Randomness>,
 }
 
-impl<'a, Scalar: Field> Witness<'a, Scalar> {
-    pub fn new(permutation: &'a Permutation, rho: &'a Vec<Scalar>) -> Self {
+impl<'a, Randomness> Witness<'a, Randomness> {
+    pub fn new(permutation: &'a Permutation, rho: &'a Vec<Randomness>) -> Self {
         Self { permutation, rho }
     }
 }
+
+/// Absorb the public parameters, the statement and the commitments to the permuted indices, and derive the
+/// challenge x.
+pub(crate) fn round_1_challenge<Scalar, Enc, Comm, T>(
+    parameters: &Parameters<Scalar, Enc, Comm>,
+    statement: &Statement<Scalar, Enc>,
+    message: &messages::Round1Message<Scalar, Comm>,
+    transcript: &mut T,
+) -> Result<messages::Round1Challenge<Scalar>, CryptoError>
+where
+    Scalar: Field,
+    Enc: HomomorphicEncryptionScheme<Scalar>,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+    T: Transcript,
+{
+    transcript.domain_separator(b"Shuffle Argument");
+
+    // Public data
+    transcript.append(b"public key", parameters.public_key)?;
+    transcript.append(b"commit key", parameters.commit_key)?;
+
+    // Statement
+    transcript.append(b"input ciphers", statement.input_ciphers)?;
+    transcript.append(b"shuffled ciphers", statement.shuffled_ciphers)?;
+    transcript.append(b"m", &(statement.m as u32))?;
+    transcript.append(b"n", &(statement.n as u32))?;
+
+    transcript.round(1);
+    transcript.append(b"a commits", &message.a_commits)?;
+
+    Ok(messages::Round1Challenge {
+        x: transcript.challenge_scalar(b"x"),
+    })
+}
+
+/// Absorb the commitments to the permuted powers of x, and derive the challenges y and z.
+pub(crate) fn round_2_challenges<Scalar, Comm, T>(
+    message: &messages::Round2Message<Scalar, Comm>,
+    transcript: &mut T,
+) -> Result<messages::Round2Challenge<Scalar>, CryptoError>
+where
+    Scalar: Field,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+    T: Transcript,
+{
+    transcript.round(2);
+    transcript.append(b"b commits", &message.b_commits)?;
+
+    Ok(messages::Round2Challenge {
+        y: transcript.challenge_scalar(b"y"),
+        z: transcript.challenge_scalar(b"z"),
+    })
+}
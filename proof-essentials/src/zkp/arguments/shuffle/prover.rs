@@ -1,18 +1,37 @@
-use super::{proof::Proof, Parameters, Statement, Witness};
+use super::messages::{Round1Challenge, Round1Message, Round2Challenge, Round2Message};
+use super::{proof::Proof, round_1_challenge, round_2_challenges, Parameters, Statement, Witness};
 
 use crate::error::CryptoError;
-use crate::homomorphic_encryption::HomomorphicEncryptionScheme;
+use crate::homomorphic_encryption::{Accumulate, HomomorphicEncryptionScheme};
+use crate::utils::permutation::Permutation;
 use crate::utils::rand::sample_vector;
-use crate::utils::vector_arithmetic::{dot_product, reshape};
+use crate::utils::vector_arithmetic::{accumulated_dot_product, dot_product, reshape};
 use crate::vector_commitment::HomomorphicCommitmentScheme;
 use crate::zkp::arguments::scalar_powers;
 use crate::zkp::arguments::{matrix_elements_product as product_argument, multi_exponentiation};
-use crate::zkp::ArgumentOfKnowledge;
+use crate::zkp::transcript::Transcript;
 
-use ark_ff::{to_bytes, Field, Zero};
-use ark_marlin::rng::FiatShamirRng;
-use digest::Digest;
+use ark_ff::{Field, Zero};
 use rand::Rng;
+use std::borrow::Cow;
+
+/// Prover waiting for the challenge x, with the commitments to the permuted indices.
+type Round1<'a, Scalar, Enc, Comm> = (
+    ProverRound1<'a, Scalar, Enc, Comm>,
+    Round1Message<Scalar, Comm>,
+);
+
+/// Prover waiting for the challenges y and z, with the commitments to the permuted powers of x.
+type Round2<'a, Scalar, Enc, Comm> = (
+    ProverRound2<'a, Scalar, Enc, Comm>,
+    Round2Message<Scalar, Comm>,
+);
+
+/// Provers of the product and multi-exponentiation arguments.
+type SubArguments<'a, Scalar, Enc, Comm> = (
+    product_argument::prover::Prover<'a, Scalar, Comm>,
+    multi_exponentiation::prover::Prover<'a, Scalar, Enc, Comm>,
+);
 
 pub struct Prover<'a, Scalar, Enc, Comm>
 where
@@ -20,9 +39,42 @@ where
     Enc: HomomorphicEncryptionScheme<Scalar>,
     Comm: HomomorphicCommitmentScheme<Scalar>,
 {
-    parameters: &'a Parameters<'a, Scalar, Enc, Comm>,
-    statement: &'a Statement<'a, Scalar, Enc>,
-    witness: &'a Witness<'a, Scalar>,
+    parameters: Parameters<'a, Scalar, Enc, Comm>,
+    input_ciphers: &'a Vec<Enc::Ciphertext>,
+    shuffled_ciphers: &'a Vec<Enc::Ciphertext>,
+    m: usize,
+    n: usize,
+    permutation: &'a Permutation,
+    rho: &'a Vec<Enc::Randomness>,
+}
+
+/// Prover of the shuffle argument once the commitments to the permuted indices are sent, waiting for the
+/// challenge x.
+pub struct ProverRound1<'a, Scalar, Enc, Comm>
+where
+    Scalar: Field,
+    Enc: HomomorphicEncryptionScheme<Scalar>,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+{
+    prover: Prover<'a, Scalar, Enc, Comm>,
+    a: Vec<Scalar>,
+    r: Vec<Scalar>,
+}
+
+/// Prover of the shuffle argument once the commitments to the permuted powers of x are sent, waiting for the
+/// challenges y and z.
+pub struct ProverRound2<'a, Scalar, Enc, Comm>
+where
+    Scalar: Field,
+    Enc: HomomorphicEncryptionScheme<Scalar>,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+{
+    prover: Prover<'a, Scalar, Enc, Comm>,
+    a: Vec<Scalar>,
+    r: Vec<Scalar>,
+    b: Vec<Scalar>,
+    s: Vec<Scalar>,
+    b_commits: Vec<Comm::Commitment>,
 }
 
 impl<'a, Scalar, Enc, Comm> Prover<'a, Scalar, Enc, Comm>
@@ -32,178 +84,222 @@ where
     Comm: HomomorphicCommitmentScheme<Scalar>,
 {
     pub fn new(
-        parameters: &'a Parameters<'a, Scalar, Enc, Comm>,
-        statement: &'a Statement<'a, Scalar, Enc>,
-        witness: &'a Witness<'a, Scalar>,
+        parameters: &Parameters<'a, Scalar, Enc, Comm>,
+        statement: &Statement<'a, Scalar, Enc>,
+        witness: &Witness<'a, Enc::Randomness>,
     ) -> Self {
         //TODO add dimension assertions
         Self {
-            parameters,
-            statement,
-            witness,
+            parameters: Parameters::new(
+                parameters.encrypt_parameters,
+                parameters.public_key,
+                parameters.commit_key,
+                parameters.generator,
+            ),
+            input_ciphers: statement.input_ciphers,
+            shuffled_ciphers: statement.shuffled_ciphers,
+            m: statement.m,
+            n: statement.n,
+            permutation: witness.permutation,
+            rho: witness.rho,
         }
     }
 
-    pub fn prove<R: Rng, D: Digest>(
-        &self,
-        rng: &mut R,
-        fs_rng: &mut FiatShamirRng<D>,
-    ) -> Result<Proof<Scalar, Enc, Comm>, CryptoError> {
-        fs_rng.absorb(&to_bytes![b"shuffle_argument"]?);
+    fn statement(&self) -> Statement<'a, Scalar, Enc> {
+        Statement::new(self.input_ciphers, self.shuffled_ciphers, self.m, self.n)
+    }
 
-        let r: Vec<Scalar> = sample_vector(rng, self.statement.m);
+    pub fn round_1<R: Rng>(
+        self,
+        rng: &mut R,
+    ) -> Result<Round1<'a, Scalar, Enc, Comm>, CryptoError> {
+        let r: Vec<Scalar> = sample_vector(rng, self.m);
 
-        let index = (1..=self.statement.m * self.statement.n)
+        let index = (1..=self.m * self.n)
             .map(|x| Scalar::from(x as u64))
             .collect::<Vec<_>>();
 
-        let a = self.witness.permutation.permute_array(&index);
+        let a = self.permutation.permute_array(&index);
 
-        let a_chunks = reshape(&a, self.statement.m, self.statement.n)?;
+        let a_chunks = reshape(&a, self.m, self.n)?;
 
-        let a_commits = a_chunks
-            .iter()
-            .zip(r.iter())
-            .map(|(chunk, &r)| Comm::commit(self.parameters.commit_key, chunk, r))
-            .collect::<Result<Vec<_>, CryptoError>>()?;
-
-        // Public data
-        fs_rng.absorb(&to_bytes![
-            self.parameters.public_key,
-            self.parameters.commit_key
-        ]?);
-
-        // statement
-        fs_rng.absorb(
-            &to_bytes![
-                self.statement.input_ciphers,
-                self.statement.shuffled_ciphers,
-                self.statement.m as u32,
-                self.statement.n as u32
-            ]
-            .unwrap(),
-        );
+        let a_commits = Comm::commit_columns(self.parameters.commit_key, &a_chunks, &r)?;
 
+        Ok((
+            ProverRound1 { prover: self, a, r },
+            Round1Message { a_commits },
+        ))
+    }
+
+    pub fn prove<R: Rng, T: Transcript>(
+        self,
+        rng: &mut R,
+        transcript: &mut T,
+    ) -> Result<Proof<Scalar, Enc, Comm>, CryptoError> {
         // round 1
-        fs_rng.absorb(&to_bytes![a_commits]?);
-        let x = Scalar::rand(fs_rng);
+        let (prover, round_1) = self.round_1(rng)?;
+        let x = round_1_challenge(
+            &prover.prover.parameters,
+            &prover.prover.statement(),
+            &round_1,
+            transcript,
+        )?;
 
-        let challenge_powers = scalar_powers(x, self.witness.permutation.size)[1..].to_vec();
+        //round 2
+        let (prover, round_2) = prover.round_2(rng, &x)?;
+        let y_z = round_2_challenges(&round_2, transcript)?;
 
-        let b = self.witness.permutation.permute_array(&challenge_powers);
-        let s: Vec<Scalar> = sample_vector(rng, self.statement.m);
+        let (product_argument_prover, multi_exp_prover) = prover.sub_arguments(&y_z)?;
+        let product_argument_proof = product_argument_prover.prove(rng, transcript)?;
+        let multi_exp_proof = multi_exp_prover.prove(rng, transcript)?;
 
-        let b_chunks = b
-            .chunks(self.statement.n)
-            .map(|c| c.to_vec())
-            .collect::<Vec<_>>();
+        // Produce proof
+        let proof = Proof {
+            round_1,
+            round_2,
+            product_argument_proof,
+            multi_exp_proof,
+        };
 
-        let b_commits = b_chunks
-            .iter()
-            .zip(s.iter())
-            .map(|(b, &s)| Comm::commit(self.parameters.commit_key, b, s))
-            .collect::<Result<Vec<_>, CryptoError>>()?;
+        Ok(proof)
+    }
+}
 
-        //round 2
-        fs_rng.absorb(&to_bytes![b_commits]?);
-        let y = Scalar::rand(fs_rng);
-        let z = Scalar::rand(fs_rng);
+impl<'a, Scalar, Enc, Comm> ProverRound1<'a, Scalar, Enc, Comm>
+where
+    Scalar: Field,
+    Enc: HomomorphicEncryptionScheme<Scalar>,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+{
+    pub fn round_2<R: Rng>(
+        self,
+        rng: &mut R,
+        challenge: &Round1Challenge<Scalar>,
+    ) -> Result<Round2<'a, Scalar, Enc, Comm>, CryptoError> {
+        let prover = self.prover;
+
+        let challenge_powers = scalar_powers(challenge.x, prover.permutation.size)[1..].to_vec();
+
+        let b = prover.permutation.permute_array(&challenge_powers);
+        let s: Vec<Scalar> = sample_vector(rng, prover.m);
+
+        let b_chunks = b.chunks(prover.n).map(|c| c.to_vec()).collect::<Vec<_>>();
+
+        let b_commits = Comm::commit_columns(prover.parameters.commit_key, &b_chunks, &s)?;
+
+        let message = Round2Message {
+            b_commits: b_commits.clone(),
+        };
 
-        let d = a
+        let state = ProverRound2 {
+            prover,
+            a: self.a,
+            r: self.r,
+            b,
+            s,
+            b_commits,
+        };
+
+        Ok((state, message))
+    }
+}
+
+impl<'a, Scalar, Enc, Comm> ProverRound2<'a, Scalar, Enc, Comm>
+where
+    Scalar: Field,
+    Enc: HomomorphicEncryptionScheme<Scalar>,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+{
+    /// Hand over to the provers of the product argument, showing that the committed values are a permutation of
+    /// the indices and of the powers of x, and of the multi-exponentiation argument, showing that the shuffled
+    /// ciphertexts are re-encrypted with the permuted powers of x.
+    pub fn sub_arguments(
+        self,
+        challenge: &Round2Challenge<Scalar>,
+    ) -> Result<SubArguments<'a, Scalar, Enc, Comm>, CryptoError> {
+        let Round2Challenge { y, z } = *challenge;
+        let prover = &self.prover;
+        let parameters = &prover.parameters;
+
+        let d = self
+            .a
             .iter()
-            .zip(b.iter())
+            .zip(self.b.iter())
             .map(|(&a, &b)| y * a + b)
             .collect::<Vec<_>>();
-        let t = r
+        let t = self
+            .r
             .iter()
-            .zip(s.iter())
+            .zip(self.s.iter())
             .map(|(&r, &s)| y * r + s)
             .collect::<Vec<_>>();
 
         // Engage in product argument ---------------------------------------------------------------------
-        let product_argument_parameters = product_argument::Parameters::new(
-            self.statement.m,
-            self.statement.n,
-            self.parameters.commit_key,
-        );
+        let product_argument_parameters =
+            product_argument::Parameters::new(prover.m, prover.n, parameters.commit_key);
 
         let d_minus_z = d.iter().map(|&x| x - z).collect::<Vec<Scalar>>();
         let d_minus_z_chunks = d_minus_z
-            .chunks(self.statement.n)
+            .chunks(prover.n)
             .map(|c| c.to_vec())
             .collect::<Vec<_>>();
 
-        let d_minus_z_commits = d_minus_z_chunks
-            .iter()
-            .zip(t.iter())
-            .map(|(chunk, &random)| Comm::commit(self.parameters.commit_key, chunk, random))
-            .collect::<Result<Vec<_>, CryptoError>>()?;
+        let d_minus_z_commits = Comm::commit_columns(parameters.commit_key, &d_minus_z_chunks, &t)?;
 
         let claimed_product = d_minus_z.iter().product();
 
-        let product_argument_statement =
-            product_argument::Statement::new(&d_minus_z_commits, claimed_product);
-
-        let product_argument_witness = product_argument::Witness::new(&d_minus_z_chunks, &t);
-
-        let product_argument_prover = product_argument::prover::Prover::new(
-            &product_argument_parameters,
-            &product_argument_statement,
-            &product_argument_witness,
-        );
-
-        let product_argument_proof = product_argument_prover.prove(rng, fs_rng)?;
+        let product_argument_prover = product_argument::prover::Prover {
+            parameters: product_argument_parameters,
+            commitments_to_a: Cow::Owned(d_minus_z_commits),
+            b: claimed_product,
+            matrix_a: Cow::Owned(d_minus_z_chunks),
+            randoms_for_a_commit: Cow::Owned(t),
+        };
 
         // Engage in multi-exponentation argument ----------------------------------------------------------
         let multi_exp_parameters = multi_exponentiation::Parameters::new(
-            self.parameters.encrypt_parameters,
-            self.parameters.public_key,
-            self.parameters.commit_key,
-            self.parameters.generator,
+            parameters.encrypt_parameters,
+            parameters.public_key,
+            parameters.commit_key,
+            parameters.generator,
         );
 
-        let minus_rho_witness = self.witness.rho.iter().map(|&x| -x).collect::<Vec<_>>();
-        let rho = dot_product(&minus_rho_witness, &b)?;
+        let minus_rho_witness = prover.rho.iter().map(|&x| -x).collect::<Vec<_>>();
+        let rho = dot_product(&self.b, &minus_rho_witness)?;
 
-        let temp = dot_product(&b, self.statement.shuffled_ciphers)?;
+        let temp = accumulated_dot_product(&self.b, prover.shuffled_ciphers)?;
         let zero_cipher = Enc::Plaintext::zero();
         let masking_cipher = Enc::encrypt(
-            self.parameters.encrypt_parameters,
-            self.parameters.public_key,
+            parameters.encrypt_parameters,
+            parameters.public_key,
             &zero_cipher,
             &rho,
         )?;
 
-        let product = temp + masking_cipher;
+        let product = Enc::Ciphertext::normalize(&(temp + masking_cipher.accumulator()));
 
-        let shuffled_chunks = self
-            .statement
+        let shuffled_chunks = prover
             .shuffled_ciphers
-            .chunks(self.statement.n)
+            .chunks(prover.n)
             .map(|c| c.to_vec())
             .collect::<Vec<_>>();
 
-        let multi_exp_statement =
-            multi_exponentiation::Statement::new(&shuffled_chunks, product, &b_commits);
-
-        let multi_exp_witness = multi_exponentiation::Witness::new(&b_chunks, &s, rho);
-
-        let multi_exp_proof = multi_exponentiation::MultiExponentiation::prove(
-            rng,
-            &multi_exp_parameters,
-            &multi_exp_statement,
-            &multi_exp_witness,
-            fs_rng,
-        )?;
+        let b_chunks = self
+            .b
+            .chunks(prover.n)
+            .map(|c| c.to_vec())
+            .collect::<Vec<_>>();
 
-        // Produce proof
-        let proof = Proof {
-            a_commits,
-            b_commits,
-            product_argument_proof,
-            multi_exp_proof,
+        let multi_exp_prover = multi_exponentiation::prover::Prover {
+            parameters: multi_exp_parameters,
+            shuffled_ciphers: Cow::Owned(shuffled_chunks),
+            product,
+            commitments_to_exponents: Cow::Owned(self.b_commits),
+            matrix_a: Cow::Owned(b_chunks),
+            matrix_blinders: Cow::Owned(self.s),
+            rho,
         };
 
-        Ok(proof)
+        Ok((product_argument_prover, multi_exp_prover))
     }
 }
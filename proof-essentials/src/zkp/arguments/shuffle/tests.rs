@@ -1,18 +1,28 @@
 #[cfg(test)]
 
 mod test {
-    use crate::homomorphic_encryption::{el_gamal, HomomorphicEncryptionScheme};
+    use crate::error::CryptoError;
+    use crate::homomorphic_encryption::{el_gamal, wide, HomomorphicEncryptionScheme};
     use crate::utils::permutation::Permutation;
     use crate::utils::rand::sample_vector;
+    use crate::utils::validation::{
+        deserialize_compressed_validated, deserialize_validated, Validate,
+    };
     use crate::vector_commitment::{pedersen, HomomorphicCommitmentScheme};
+    use crate::zkp::arguments::{
+        hadamard_product, multi_exponentiation, single_value_product, zero_value_bilinear_map,
+    };
     use crate::zkp::{arguments::shuffle, ArgumentOfKnowledge};
 
-    use ark_ff::Zero;
+    use ark_ff::{One, Zero};
     use ark_marlin::rng::FiatShamirRng;
+    use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
     use ark_std::{rand::thread_rng, UniformRand};
     use blake2::Blake2s;
     use starknet_curve;
     use std::iter::Iterator;
+    use std::sync::mpsc::{channel, Receiver, Sender};
+    use std::thread;
 
     // Choose ellitptic curve setting
     type Curve = starknet_curve::Projective;
@@ -31,6 +41,153 @@ mod test {
 
     type FS = FiatShamirRng<Blake2s>;
 
+    /// One end of an in-memory connection between the prover and the verifier, carrying serialized messages.
+    struct Channel {
+        sender: Sender<Vec<u8>>,
+        receiver: Receiver<Vec<u8>>,
+    }
+
+    impl Channel {
+        fn pair() -> (Self, Self) {
+            let (prover_sender, verifier_receiver) = channel();
+            let (verifier_sender, prover_receiver) = channel();
+
+            (
+                Self {
+                    sender: prover_sender,
+                    receiver: prover_receiver,
+                },
+                Self {
+                    sender: verifier_sender,
+                    receiver: verifier_receiver,
+                },
+            )
+        }
+
+        fn send<T: CanonicalSerialize>(&self, message: &T) {
+            let mut bytes = Vec::new();
+            message.serialize(&mut bytes).unwrap();
+
+            // The other side hangs up as soon as it rejects, which the next call to receive will notice
+            let _ = self.sender.send(bytes);
+        }
+
+        fn receive<T: CanonicalDeserialize>(&self) -> Option<T> {
+            let bytes = self.receiver.recv().ok()?;
+            T::deserialize(&bytes[..]).ok()
+        }
+
+        fn receive_validated<T: CanonicalDeserialize + Validate>(&self) -> Option<T> {
+            let bytes = self.receiver.recv().ok()?;
+            deserialize_compressed_validated(&bytes[..]).ok()
+        }
+    }
+
+    /// Run the prover of the shuffle argument and of all its sub-arguments round by round. Returns None if the
+    /// verifier hangs up.
+    fn prove_interactively(
+        parameters: &Parameters,
+        statement: &Statement,
+        witness: &Witness,
+        channel: Channel,
+    ) -> Option<()> {
+        let rng = &mut thread_rng();
+
+        // Shuffle argument
+        let prover = shuffle::prover::Prover::new(parameters, statement, witness);
+        let (prover, message) = prover.round_1(rng).unwrap();
+        channel.send(&message);
+        let (prover, message) = prover.round_2(rng, &channel.receive()?).unwrap();
+        channel.send(&message);
+        let (product_prover, multi_exp_prover) = prover.sub_arguments(&channel.receive()?).unwrap();
+
+        // Product argument, made of a Hadamard product argument and a single value product argument
+        let (message, hadamard_prover, single_value_prover) = product_prover.round_1(rng).unwrap();
+        channel.send(&message);
+
+        let (hadamard_prover, message) = hadamard_prover.round_1(rng).unwrap();
+        channel.send(&message);
+        let zero_prover = hadamard_prover.zero_argument(&channel.receive()?).unwrap();
+
+        let (zero_prover, message) = zero_prover.round_1(rng).unwrap();
+        channel.send(&message);
+        channel.send(&zero_prover.round_2(&channel.receive()?).unwrap());
+
+        let (single_value_prover, message) = single_value_prover.round_1(rng).unwrap();
+        channel.send(&message);
+        channel.send(&single_value_prover.round_2(&channel.receive()?));
+
+        // Multi-exponentiation argument
+        let (multi_exp_prover, message) = multi_exp_prover.round_1(rng).unwrap();
+        channel.send(&message);
+        channel.send(&multi_exp_prover.round_2(&channel.receive()?).unwrap());
+
+        Some(())
+    }
+
+    /// Run the verifier of the shuffle argument and of all its sub-arguments round by round, sampling the
+    /// challenges at random.
+    fn verify_interactively(
+        parameters: &Parameters,
+        statement: &Statement,
+        channel: Channel,
+    ) -> Result<(), CryptoError> {
+        let rng = &mut thread_rng();
+
+        // Shuffle argument
+        let verifier = shuffle::verifier::Verifier::new(parameters, statement);
+        let message = channel.receive_validated().unwrap();
+        let challenge = shuffle::messages::Round1Challenge::rand(rng);
+        channel.send(&challenge);
+        let verifier = verifier.round_1(&message, &challenge)?;
+
+        let message = channel.receive_validated().unwrap();
+        let challenge = shuffle::messages::Round2Challenge::rand(rng);
+        channel.send(&challenge);
+        let (product_verifier, multi_exp_verifier) = verifier.round_2(&message, &challenge)?;
+
+        // Product argument, made of a Hadamard product argument and a single value product argument
+        let message = channel.receive_validated().unwrap();
+        let (hadamard_verifier, single_value_verifier) = product_verifier.round_1(&message)?;
+
+        let message = channel.receive_validated().unwrap();
+        let challenge = hadamard_product::messages::Round1Challenge::rand(rng);
+        channel.send(&challenge);
+        let zero_verifier = hadamard_verifier.round_1(&message, &challenge)?;
+
+        let message = channel.receive_validated().unwrap();
+        let challenge = zero_value_bilinear_map::messages::Round1Challenge::rand(rng);
+        channel.send(&challenge);
+        let zero_verifier = zero_verifier.round_1(&message, &challenge)?;
+        zero_verifier.round_2(&channel.receive().unwrap())?;
+
+        let message = channel.receive_validated().unwrap();
+        let challenge = single_value_product::messages::Round1Challenge::rand(rng);
+        channel.send(&challenge);
+        let single_value_verifier = single_value_verifier.round_1(&message, &challenge);
+        single_value_verifier.round_2(&channel.receive().unwrap())?;
+
+        // Multi-exponentiation argument
+        let message = channel.receive_validated().unwrap();
+        let challenge = multi_exponentiation::messages::Round1Challenge::rand(rng);
+        channel.send(&challenge);
+        let multi_exp_verifier = multi_exp_verifier.round_1(&message, &challenge)?;
+        multi_exp_verifier.round_2(&channel.receive().unwrap())
+    }
+
+    fn run_interactively(
+        parameters: &Parameters,
+        statement: &Statement,
+        witness: &Witness,
+    ) -> Result<(), CryptoError> {
+        let (prover_channel, verifier_channel) = Channel::pair();
+
+        thread::scope(|scope| {
+            scope.spawn(|| prove_interactively(parameters, statement, witness, prover_channel));
+            verify_interactively(parameters, statement, verifier_channel)
+        })
+    }
+
     #[test]
     fn test_shuffle_argument() {
         let m = 4;
@@ -79,6 +236,17 @@ mod test {
             ShuffleArgument::verify(&parameters, &statement, &valid_proof, &mut fs_rng)
         );
 
+        // A proof received from the prover goes through validated deserialization before verification
+        let mut bytes = Vec::new();
+        valid_proof.serialize_unchecked(&mut bytes).unwrap();
+        let received_proof = deserialize_validated(&bytes[..]).unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            Ok(()),
+            ShuffleArgument::verify(&parameters, &statement, &received_proof, &mut fs_rng)
+        );
+
         let new_permutation = Permutation::new(rng, number_of_ciphers);
         let bad_witness = Witness::new(&new_permutation, &masking_factors);
 
@@ -93,4 +261,331 @@ mod test {
             ShuffleArgument::verify(&parameters, &statement, &invalid_proof, &mut fs_rng)
         );
     }
+
+    #[test]
+    fn test_shuffle_argument_with_merlin_transcript() {
+        let m = 2;
+        let n = 5;
+        let number_of_ciphers = n * m;
+
+        let rng = &mut thread_rng();
+
+        let encrypt_parameters = Enc::setup(rng).unwrap();
+        let (pk, _) = Enc::keygen(&encrypt_parameters, rng).unwrap();
+        let commit_key = Comm::setup(rng, n);
+        let generator = Generator::rand(rng);
+
+        let ciphers: Vec<Ciphertext> = sample_vector(rng, number_of_ciphers);
+        let masking_factors: Vec<Scalar> = sample_vector(rng, number_of_ciphers);
+        let permutation = Permutation::new(rng, number_of_ciphers);
+
+        let shuffled_deck = permutation
+            .permute_array(&ciphers)
+            .iter()
+            .zip(masking_factors.iter())
+            .map(|(&cipher, masking_factor)| {
+                cipher
+                    + Enc::encrypt(&encrypt_parameters, &pk, &Plaintext::zero(), masking_factor)
+                        .unwrap()
+            })
+            .collect::<Vec<_>>();
+
+        let parameters = Parameters::new(&encrypt_parameters, &pk, &commit_key, &generator);
+        let statement = Statement::new(&ciphers, &shuffled_deck, m, n);
+        let witness = Witness::new(&permutation, &masking_factors);
+
+        let mut transcript = merlin::Transcript::new(b"shuffle test");
+        let proof = ShuffleArgument::prove(rng, &parameters, &statement, &witness, &mut transcript)
+            .unwrap();
+
+        let mut transcript = merlin::Transcript::new(b"shuffle test");
+        assert_eq!(
+            Ok(()),
+            ShuffleArgument::verify(&parameters, &statement, &proof, &mut transcript)
+        );
+
+        // The challenges are bound to the whole transcript, including what came before the argument
+        let mut transcript = merlin::Transcript::new(b"another shuffle test");
+        assert_ne!(
+            Ok(()),
+            ShuffleArgument::verify(&parameters, &statement, &proof, &mut transcript)
+        );
+    }
+
+    #[test]
+    fn test_interactive_shuffle_argument() {
+        let m = 2;
+        let n = 5;
+        let number_of_ciphers = n * m;
+
+        let rng = &mut thread_rng();
+
+        let encrypt_parameters = Enc::setup(rng).unwrap();
+        let (pk, _) = Enc::keygen(&encrypt_parameters, rng).unwrap();
+        let commit_key = Comm::setup(rng, n);
+        let generator = Generator::rand(rng);
+
+        let ciphers: Vec<Ciphertext> = sample_vector(rng, number_of_ciphers);
+        let masking_factors: Vec<Scalar> = sample_vector(rng, number_of_ciphers);
+        let permutation = Permutation::new(rng, number_of_ciphers);
+
+        let shuffled_deck = permutation
+            .permute_array(&ciphers)
+            .iter()
+            .zip(masking_factors.iter())
+            .map(|(&cipher, masking_factor)| {
+                cipher
+                    + Enc::encrypt(&encrypt_parameters, &pk, &Plaintext::zero(), masking_factor)
+                        .unwrap()
+            })
+            .collect::<Vec<_>>();
+
+        let parameters = Parameters::new(&encrypt_parameters, &pk, &commit_key, &generator);
+        let statement = Statement::new(&ciphers, &shuffled_deck, m, n);
+
+        let witness = Witness::new(&permutation, &masking_factors);
+        assert_eq!(Ok(()), run_interactively(&parameters, &statement, &witness));
+
+        let new_permutation = Permutation::new(rng, number_of_ciphers);
+        let bad_witness = Witness::new(&new_permutation, &masking_factors);
+        assert_ne!(
+            Ok(()),
+            run_interactively(&parameters, &statement, &bad_witness)
+        );
+    }
+
+    #[test]
+    fn test_batch_verification() {
+        let m = 2;
+        let n = 5;
+        let number_of_ciphers = n * m;
+        let number_of_proofs = 4;
+
+        let rng = &mut thread_rng();
+
+        let encrypt_parameters = Enc::setup(rng).unwrap();
+        let (pk, _) = Enc::keygen(&encrypt_parameters, rng).unwrap();
+        let commit_key = Comm::setup(rng, n);
+        let generator = Generator::rand(rng);
+
+        let parameters = Parameters::new(&encrypt_parameters, &pk, &commit_key, &generator);
+
+        let decks = (0..number_of_proofs)
+            .map(|_| {
+                let ciphers: Vec<Ciphertext> = sample_vector(rng, number_of_ciphers);
+                let masking_factors: Vec<Scalar> = sample_vector(rng, number_of_ciphers);
+                let permutation = Permutation::new(rng, number_of_ciphers);
+
+                let shuffled_deck = permutation
+                    .permute_array(&ciphers)
+                    .iter()
+                    .zip(masking_factors.iter())
+                    .map(|(&cipher, masking_factor)| {
+                        cipher
+                            + Enc::encrypt(
+                                &encrypt_parameters,
+                                &pk,
+                                &Plaintext::zero(),
+                                masking_factor,
+                            )
+                            .unwrap()
+                    })
+                    .collect::<Vec<_>>();
+
+                (ciphers, shuffled_deck, permutation, masking_factors)
+            })
+            .collect::<Vec<_>>();
+
+        let statements = decks
+            .iter()
+            .map(|(ciphers, shuffled_deck, _, _)| Statement::new(ciphers, shuffled_deck, m, n))
+            .collect::<Vec<_>>();
+
+        let mut proofs = decks
+            .iter()
+            .zip(statements.iter())
+            .map(|((_, _, permutation, masking_factors), statement)| {
+                let witness = Witness::new(permutation, masking_factors);
+                let mut fs_rng = FS::from_seed(b"Initialised with some input");
+                ShuffleArgument::prove(rng, &parameters, statement, &witness, &mut fs_rng).unwrap()
+            })
+            .collect::<Vec<_>>();
+
+        let transcripts = || {
+            (0..number_of_proofs)
+                .map(|_| FS::from_seed(b"Initialised with some input"))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            Ok(()),
+            shuffle::proof::Proof::batch_verify(
+                rng,
+                &parameters,
+                &statements,
+                &proofs,
+                &mut transcripts()
+            )
+        );
+
+        // An invalid proof is located when the batch is rejected
+        let (_, _, _, masking_factors) = &decks[2];
+        let new_permutation = Permutation::new(rng, number_of_ciphers);
+        let bad_witness = Witness::new(&new_permutation, masking_factors);
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let invalid_proof =
+            ShuffleArgument::prove(rng, &parameters, &statements[2], &bad_witness, &mut fs_rng)
+                .unwrap();
+        let valid_proof = std::mem::replace(&mut proofs[2], invalid_proof);
+
+        assert_eq!(
+            Err(CryptoError::BatchProofVerificationError(
+                String::from("Shuffle Argument"),
+                2
+            )),
+            shuffle::proof::Proof::batch_verify(
+                rng,
+                &parameters,
+                &statements,
+                &proofs,
+                &mut transcripts()
+            )
+        );
+
+        // A proof that only fails the final group equations is located as well
+        proofs[2] = valid_proof;
+        proofs[1].multi_exp_proof.round_2.tau_blinded += Scalar::one();
+
+        assert_eq!(
+            Err(CryptoError::BatchProofVerificationError(
+                String::from("Shuffle Argument"),
+                1
+            )),
+            shuffle::proof::Proof::batch_verify(
+                rng,
+                &parameters,
+                &statements,
+                &proofs,
+                &mut transcripts()
+            )
+        );
+
+        assert_eq!(
+            Err(CryptoError::BatchVerificationLengthError(
+                number_of_proofs,
+                number_of_proofs - 1
+            )),
+            shuffle::proof::Proof::batch_verify(
+                rng,
+                &parameters,
+                &statements[1..],
+                &proofs,
+                &mut transcripts()
+            )
+        );
+    }
+
+    #[test]
+    fn test_shuffle_of_tuples() {
+        type WideEnc = wide::WideEncryption<Enc, 2>;
+        type WideCiphertext = wide::Ciphertext<Scalar, Enc, 2>;
+        type WideRandomness = wide::Randomness<Scalar, Enc, 2>;
+
+        let m = 2;
+        let n = 4;
+        let number_of_ciphers = n * m;
+
+        let rng = &mut thread_rng();
+
+        let encrypt_parameters = WideEnc::setup(rng).unwrap();
+        let (pk, sk) = WideEnc::keygen(&encrypt_parameters, rng).unwrap();
+
+        let commit_key = Comm::setup(rng, n);
+        let generator = WideEnc::generator(rng).unwrap();
+
+        // Suits and ranks of the cards are encrypted separately
+        let suits: Vec<Ciphertext> = sample_vector(rng, number_of_ciphers);
+        let ranks: Vec<Ciphertext> = sample_vector(rng, number_of_ciphers);
+        let cards = WideCiphertext::from_columns(&[suits, ranks]).unwrap();
+
+        let masking_factors: Vec<WideRandomness> = sample_vector(rng, number_of_ciphers);
+        let permutation = Permutation::new(rng, number_of_ciphers);
+
+        let shuffled_cards = permutation
+            .permute_array(&cards)
+            .iter()
+            .zip(masking_factors.iter())
+            .map(|(&card, masking_factor)| {
+                let masking_cipher = WideEnc::encrypt(
+                    &encrypt_parameters,
+                    &pk,
+                    &wide::Plaintext::<Scalar, Enc, 2>::zero(),
+                    masking_factor,
+                )
+                .unwrap();
+
+                card + masking_cipher
+            })
+            .collect::<Vec<_>>();
+
+        // Both attributes of a card stay together
+        for (card, shuffled_card) in permutation
+            .permute_array(&cards)
+            .iter()
+            .zip(&shuffled_cards)
+        {
+            assert_eq!(
+                WideEnc::decrypt(&encrypt_parameters, &sk, card),
+                WideEnc::decrypt(&encrypt_parameters, &sk, shuffled_card)
+            );
+        }
+
+        let parameters =
+            shuffle::Parameters::new(&encrypt_parameters, &pk, &commit_key, &generator);
+        let statement = shuffle::Statement::new(&cards, &shuffled_cards, m, n);
+        let witness = shuffle::Witness::new(&permutation, &masking_factors);
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let proof = shuffle::ShuffleArgument::<Scalar, WideEnc, Comm>::prove(
+            rng,
+            &parameters,
+            &statement,
+            &witness,
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            Ok(()),
+            shuffle::ShuffleArgument::verify(&parameters, &statement, &proof, &mut fs_rng)
+        );
+
+        // Shuffling the ranks with a different permutation than the suits is rejected
+        let [suits, mut ranks] = WideCiphertext::to_columns(&shuffled_cards);
+        ranks.swap(0, 1);
+        let mixed_cards = WideCiphertext::from_columns(&[suits, ranks]).unwrap();
+        let bad_statement = shuffle::Statement::new(&cards, &mixed_cards, m, n);
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let invalid_proof = shuffle::ShuffleArgument::<Scalar, WideEnc, Comm>::prove(
+            rng,
+            &parameters,
+            &bad_statement,
+            &witness,
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_ne!(
+            Ok(()),
+            shuffle::ShuffleArgument::verify(
+                &parameters,
+                &bad_statement,
+                &invalid_proof,
+                &mut fs_rng
+            )
+        );
+    }
 }
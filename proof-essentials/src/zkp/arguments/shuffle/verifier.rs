@@ -0,0 +1,224 @@
+use super::messages::{Round1Challenge, Round1Message, Round2Challenge, Round2Message};
+use super::{proof::Proof, round_1_challenge, round_2_challenges, Parameters, Statement};
+
+use crate::error::CryptoError;
+use crate::homomorphic_encryption::{Accumulate, HomomorphicEncryptionScheme};
+use crate::utils::vector_arithmetic::accumulated_dot_product;
+use crate::vector_commitment::HomomorphicCommitmentScheme;
+use crate::zkp::arguments::batch::Equations;
+use crate::zkp::arguments::scalar_powers;
+use crate::zkp::arguments::{matrix_elements_product as product_argument, multi_exponentiation};
+use crate::zkp::transcript::Transcript;
+
+use ark_ff::Field;
+use std::borrow::Cow;
+
+/// Verifiers of the product and multi-exponentiation arguments.
+type SubArguments<'a, Scalar, Enc, Comm> = (
+    product_argument::verifier::Verifier<'a, Scalar, Comm>,
+    multi_exponentiation::verifier::Verifier<'a, Scalar, Enc, Comm>,
+);
+
+pub struct Verifier<'a, Scalar, Enc, Comm>
+where
+    Scalar: Field,
+    Enc: HomomorphicEncryptionScheme<Scalar>,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+{
+    parameters: Parameters<'a, Scalar, Enc, Comm>,
+    input_ciphers: &'a Vec<Enc::Ciphertext>,
+    shuffled_ciphers: &'a Vec<Enc::Ciphertext>,
+    m: usize,
+    n: usize,
+}
+
+/// Verifier of the shuffle argument once the commitments to the permuted indices and the challenge x are
+/// exchanged.
+pub struct VerifierRound1<'a, Scalar, Enc, Comm>
+where
+    Scalar: Field,
+    Enc: HomomorphicEncryptionScheme<Scalar>,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+{
+    verifier: Verifier<'a, Scalar, Enc, Comm>,
+    a_commits: Vec<Comm::Commitment>,
+    challenge_powers: Vec<Scalar>,
+}
+
+impl<'a, Scalar, Enc, Comm> Verifier<'a, Scalar, Enc, Comm>
+where
+    Scalar: Field,
+    Enc: HomomorphicEncryptionScheme<Scalar>,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+{
+    pub fn new(
+        parameters: &Parameters<'a, Scalar, Enc, Comm>,
+        statement: &Statement<'a, Scalar, Enc>,
+    ) -> Self {
+        Self {
+            parameters: Parameters::new(
+                parameters.encrypt_parameters,
+                parameters.public_key,
+                parameters.commit_key,
+                parameters.generator,
+            ),
+            input_ciphers: statement.input_ciphers,
+            shuffled_ciphers: statement.shuffled_ciphers,
+            m: statement.m,
+            n: statement.n,
+        }
+    }
+
+    fn statement(&self) -> Statement<'a, Scalar, Enc> {
+        Statement::new(self.input_ciphers, self.shuffled_ciphers, self.m, self.n)
+    }
+
+    pub fn round_1(
+        self,
+        message: &Round1Message<Scalar, Comm>,
+        challenge: &Round1Challenge<Scalar>,
+    ) -> Result<VerifierRound1<'a, Scalar, Enc, Comm>, CryptoError> {
+        self.statement().is_valid()?;
+
+        let challenge_powers = scalar_powers(challenge.x, self.m * self.n)[1..].to_vec();
+
+        Ok(VerifierRound1 {
+            verifier: self,
+            a_commits: message.a_commits.clone(),
+            challenge_powers,
+        })
+    }
+
+    pub fn verify<T: Transcript>(
+        self,
+        proof: &Proof<Scalar, Enc, Comm>,
+        transcript: &mut T,
+    ) -> Result<(), CryptoError> {
+        // round 1
+        let x = round_1_challenge(
+            &self.parameters,
+            &self.statement(),
+            &proof.round_1,
+            transcript,
+        )?;
+        let verifier = self.round_1(&proof.round_1, &x)?;
+
+        // round 2
+        let y_z = round_2_challenges(&proof.round_2, transcript)?;
+        let (product_argument_verifier, multi_exp_verifier) =
+            verifier.round_2(&proof.round_2, &y_z)?;
+
+        product_argument_verifier.verify(&proof.product_argument_proof, transcript)?;
+        multi_exp_verifier.verify(&proof.multi_exp_proof, transcript)?;
+
+        Ok(())
+    }
+
+    /// Run the verifier and return the final equations of all the sub-arguments instead of checking them.
+    pub(crate) fn equations<T: Transcript>(
+        self,
+        proof: &Proof<Scalar, Enc, Comm>,
+        transcript: &mut T,
+    ) -> Result<Equations<Scalar, Enc, Comm>, CryptoError> {
+        // round 1
+        let x = round_1_challenge(
+            &self.parameters,
+            &self.statement(),
+            &proof.round_1,
+            transcript,
+        )?;
+        let verifier = self.round_1(&proof.round_1, &x)?;
+
+        // round 2
+        let y_z = round_2_challenges(&proof.round_2, transcript)?;
+        let (product_argument_verifier, multi_exp_verifier) =
+            verifier.round_2(&proof.round_2, &y_z)?;
+
+        let mut equations = Equations::new(
+            product_argument_verifier.equations(&proof.product_argument_proof, transcript)?,
+            vec![],
+        );
+        equations.extend(multi_exp_verifier.equations(&proof.multi_exp_proof, transcript)?);
+
+        Ok(equations)
+    }
+}
+
+impl<'a, Scalar, Enc, Comm> VerifierRound1<'a, Scalar, Enc, Comm>
+where
+    Scalar: Field,
+    Enc: HomomorphicEncryptionScheme<Scalar>,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+{
+    /// Receive the commitments to the permuted powers of x and hand over to the verifiers of the product and
+    /// multi-exponentiation arguments.
+    pub fn round_2(
+        self,
+        message: &Round2Message<Scalar, Comm>,
+        challenge: &Round2Challenge<Scalar>,
+    ) -> Result<SubArguments<'a, Scalar, Enc, Comm>, CryptoError> {
+        let Round2Challenge { y, z } = *challenge;
+        let verifier = &self.verifier;
+        let parameters = &verifier.parameters;
+
+        // PRODUCT ARGUMENT -------------------------------------------------------------
+        let z_vec = vec![-z; verifier.n];
+        let zero = Scalar::zero();
+        let single_neg_z_commit = Comm::commit(parameters.commit_key, &z_vec, zero)?;
+        let neg_z_commit = vec![single_neg_z_commit; verifier.m];
+
+        let c_d = self
+            .a_commits
+            .iter()
+            .zip(message.b_commits.iter())
+            .map(|(&a, &b)| a * y + b)
+            .collect::<Vec<_>>();
+
+        let verifier_side_expected_product = (1..=verifier.n * verifier.m)
+            .zip(self.challenge_powers.iter())
+            .map(|(i, x_pow_i)| y * Scalar::from(i as u64) + x_pow_i - z)
+            .product();
+
+        let product_argument_parameters =
+            product_argument::Parameters::new(verifier.m, verifier.n, parameters.commit_key);
+
+        let commitments_to_a = c_d
+            .iter()
+            .zip(neg_z_commit.iter())
+            .map(|(&d_commit, &z_commit)| d_commit + z_commit)
+            .collect::<Vec<_>>();
+
+        // MULTI-EXPONENTIATION ARGUMENT -------------------------------------------------------
+        let multi_exp_parameters = multi_exponentiation::Parameters::new(
+            parameters.encrypt_parameters,
+            parameters.public_key,
+            parameters.commit_key,
+            parameters.generator,
+        );
+
+        let shuffled_chunks = verifier
+            .shuffled_ciphers
+            .chunks(verifier.n)
+            .map(|c| c.to_vec())
+            .collect::<Vec<_>>();
+
+        let product = Enc::Ciphertext::normalize(&accumulated_dot_product(
+            &self.challenge_powers,
+            verifier.input_ciphers,
+        )?);
+
+        Ok((
+            product_argument::verifier::Verifier {
+                parameters: product_argument_parameters,
+                commitments_to_a: Cow::Owned(commitments_to_a),
+                b: verifier_side_expected_product,
+            },
+            multi_exponentiation::verifier::Verifier {
+                parameters: multi_exp_parameters,
+                shuffled_ciphers: Cow::Owned(shuffled_chunks),
+                product,
+                commitments_to_exponents: Cow::Owned(message.b_commits.clone()),
+            },
+        ))
+    }
+}
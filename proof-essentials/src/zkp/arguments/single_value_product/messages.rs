@@ -0,0 +1,75 @@
+use crate::error::CryptoError;
+use crate::utils::validation::{Validate, ValidatePoint};
+use crate::vector_commitment::{pedersen::Commitment, HomomorphicCommitmentScheme};
+
+use ark_ec::ProjectiveCurve;
+use ark_ff::Field;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::io::{Read, Write};
+use ark_std::{rand::Rng, UniformRand};
+
+/// First message of the prover: commitments to the random vector d, to the products of the random deltas with d
+/// and to the differences linking the partial products b of a.
+#[derive(CanonicalDeserialize, CanonicalSerialize)]
+pub struct Round1Message<Scalar, Comm>
+where
+    Scalar: Field,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+{
+    pub d_commit: Comm::Commitment,
+    pub delta_commit: Comm::Commitment,
+    pub diff_commit: Comm::Commitment,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, CanonicalDeserialize, CanonicalSerialize)]
+pub struct Round1Challenge<Scalar: Field> {
+    pub x: Scalar,
+}
+
+/// Second message of the prover: the vectors a and b and their commitment randoms, blinded with the challenge.
+#[derive(CanonicalDeserialize, CanonicalSerialize)]
+pub struct Round2Message<Scalar: Field> {
+    pub a_blinded: Vec<Scalar>,
+    pub b_blinded: Vec<Scalar>,
+    pub r_blinded: Scalar,
+    pub s_blinded: Scalar,
+}
+
+/// First message of the compressed argument: a commitment to the partial products (1, a_1, a_1 a_2, ...) of a.
+#[derive(CanonicalDeserialize, CanonicalSerialize)]
+pub struct CompressedRound1Message<C: ProjectiveCurve> {
+    pub partial_products_commit: Commitment<C>,
+}
+
+impl<Scalar: Field> UniformRand for Round1Challenge<Scalar> {
+    fn rand<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Self {
+            x: Scalar::rand(rng),
+        }
+    }
+}
+
+impl<Scalar, Comm> Validate for Round1Message<Scalar, Comm>
+where
+    Scalar: Field,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+    Comm::Commitment: Validate,
+{
+    fn validate(&self) -> Result<(), CryptoError> {
+        self.d_commit.validate()?;
+        self.delta_commit.validate()?;
+        self.diff_commit.validate()?;
+
+        Ok(())
+    }
+}
+
+impl<C> Validate for CompressedRound1Message<C>
+where
+    C: ProjectiveCurve,
+    C::Affine: ValidatePoint,
+{
+    fn validate(&self) -> Result<(), CryptoError> {
+        self.partial_products_commit.validate()
+    }
+}
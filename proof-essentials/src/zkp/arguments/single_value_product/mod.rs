@@ -1,15 +1,17 @@
+pub mod messages;
 pub mod proof;
 pub mod prover;
 mod tests;
+pub mod verifier;
 
 use crate::error::CryptoError;
+use crate::vector_commitment::pedersen::{CommitKey, Commitment, PedersenCommitment};
 use crate::vector_commitment::HomomorphicCommitmentScheme;
-use crate::zkp::ArgumentOfKnowledge;
-use ark_ff::Field;
-use ark_marlin::rng::FiatShamirRng;
+use crate::zkp::{transcript::Transcript, ArgumentOfKnowledge};
+use ark_ec::ProjectiveCurve;
+use ark_ff::{Field, One, Zero};
 use ark_std::marker::PhantomData;
 use ark_std::rand::Rng;
-use digest::Digest;
 
 pub struct SingleValueProductArgument<'a, F, Comm>
 where
@@ -30,26 +32,65 @@ where
     type Witness = Witness<'a, Scalar>;
     type Proof = proof::Proof<Scalar, Comm>;
 
-    fn prove<R: Rng, D: Digest>(
+    fn prove<R: Rng, T: Transcript>(
         rng: &mut R,
         common_reference_string: &Self::CommonReferenceString,
         statement: &Self::Statement,
         witness: &Self::Witness,
-        fs_rng: &mut FiatShamirRng<D>,
+        transcript: &mut T,
     ) -> Result<Self::Proof, CryptoError> {
         let prover = prover::Prover::new(common_reference_string, statement, witness);
-        let proof = prover.prove(rng, fs_rng)?;
+        let proof = prover.prove(rng, transcript)?;
 
         Ok(proof)
     }
 
-    fn verify<D: Digest>(
+    fn verify<T: Transcript>(
         common_reference_string: &Self::CommonReferenceString,
         statement: &Self::Statement,
         proof: &Self::Proof,
-        fs_rng: &mut FiatShamirRng<D>,
+        transcript: &mut T,
     ) -> Result<(), CryptoError> {
-        proof.verify(&common_reference_string, &statement, fs_rng)
+        proof.verify(&common_reference_string, &statement, transcript)
+    }
+}
+
+/// Single value product argument over Pedersen commitments, reduced to a compressed zero argument so that the
+/// proof grows logarithmically with the length of the vector.
+pub struct CompressedSingleValueProductArgument<'a, C>
+where
+    C: ProjectiveCurve,
+{
+    _group: PhantomData<&'a C>,
+}
+
+impl<'a, C> ArgumentOfKnowledge for CompressedSingleValueProductArgument<'a, C>
+where
+    C: ProjectiveCurve,
+{
+    type CommonReferenceString = Parameters<'a, C::ScalarField, PedersenCommitment<C>>;
+    type Statement = Statement<'a, C::ScalarField, PedersenCommitment<C>>;
+    type Witness = Witness<'a, C::ScalarField>;
+    type Proof = proof::CompressedProof<C>;
+
+    fn prove<R: Rng, T: Transcript>(
+        rng: &mut R,
+        common_reference_string: &Self::CommonReferenceString,
+        statement: &Self::Statement,
+        witness: &Self::Witness,
+        transcript: &mut T,
+    ) -> Result<Self::Proof, CryptoError> {
+        let prover = prover::Prover::new(common_reference_string, statement, witness);
+        prover.prove_compressed(rng, transcript)
+    }
+
+    fn verify<T: Transcript>(
+        common_reference_string: &Self::CommonReferenceString,
+        statement: &Self::Statement,
+        proof: &Self::Proof,
+        transcript: &mut T,
+    ) -> Result<(), CryptoError> {
+        proof.verify(common_reference_string, statement, transcript)
     }
 }
 
@@ -75,12 +116,12 @@ where
 
 /// Witness
 pub struct Witness<'a, Scalar: Field> {
-    pub a: &'a Vec<Scalar>,
+    pub a: &'a [Scalar],
     pub random_for_a_commit: &'a Scalar,
 }
 
 impl<'a, Scalar: Field> Witness<'a, Scalar> {
-    pub fn new(a: &'a Vec<Scalar>, random_for_a_commit: &'a Scalar) -> Self {
+    pub fn new(a: &'a [Scalar], random_for_a_commit: &'a Scalar) -> Self {
         Self {
             a,
             random_for_a_commit,
@@ -107,3 +148,92 @@ where
         Self { a_commit, b }
     }
 }
+
+/// Absorb the public parameters, the statement and the commitments of the first round, and derive the challenge x.
+pub(crate) fn round_1_challenge<Scalar, Comm, T>(
+    parameters: &Parameters<Scalar, Comm>,
+    statement: &Statement<Scalar, Comm>,
+    message: &messages::Round1Message<Scalar, Comm>,
+    transcript: &mut T,
+) -> Result<messages::Round1Challenge<Scalar>, CryptoError>
+where
+    Scalar: Field,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+    T: Transcript,
+{
+    transcript.domain_separator(b"Single Value Product Argument");
+    transcript.round(1);
+    transcript.append(b"commit key", parameters.commit_key)?;
+    transcript.append(b"commitment to a", statement.a_commit)?;
+    transcript.append(b"b", &statement.b)?;
+    transcript.append(b"d commit", &message.d_commit)?;
+    transcript.append(b"delta commit", &message.delta_commit)?;
+    transcript.append(b"diff commit", &message.diff_commit)?;
+
+    Ok(messages::Round1Challenge {
+        x: transcript.challenge_scalar(b"x"),
+    })
+}
+
+/// Absorb the public parameters, the statement and the commitment to the partial products, and derive the weight x
+/// of the bilinear map of the compressed argument.
+pub(crate) fn compressed_round_1_challenge<C, T>(
+    parameters: &Parameters<C::ScalarField, PedersenCommitment<C>>,
+    statement: &Statement<C::ScalarField, PedersenCommitment<C>>,
+    message: &messages::CompressedRound1Message<C>,
+    transcript: &mut T,
+) -> Result<messages::Round1Challenge<C::ScalarField>, CryptoError>
+where
+    C: ProjectiveCurve,
+    T: Transcript,
+{
+    transcript.domain_separator(b"Compressed Single Value Product Argument");
+    transcript.round(1);
+    transcript.append(b"commit key", parameters.commit_key)?;
+    transcript.append(b"commitment to a", statement.a_commit)?;
+    transcript.append(b"b", &statement.b)?;
+    transcript.append(b"partial products commit", &message.partial_products_commit)?;
+
+    Ok(messages::Round1Challenge {
+        x: transcript.challenge_scalar(b"x"),
+    })
+}
+
+/// Commitments to the columns of the two matrices of a zero argument.
+type ZeroArgumentColumns<C> = (Vec<Commitment<C>>, Vec<Commitment<C>>);
+
+/// Commitments to the two columns of each matrix of the zero argument that the compressed argument reduces to.
+///
+/// With p = (1, a_1, a_1 a_2, ..., a_1 ... a_{n-1}) the partial products of a, the polynomial
+/// `sum x^i p_i (x a_i - 1) + x (1 - x^n b)` is zero if and only if p_1 = 1, p_{i+1} = p_i a_i and p_n a_n = b, so
+/// for a random x it is zero only if the product of a is b. It is the sum of the bilinear map with weights
+/// x, x^2, ..., x^n applied to the columns (p, e_1) and (x a - 1, (1 - x^n b) e_1), whose commitments the verifier
+/// derives from the commitment to a, the commitment to p and the first generator. The constant term sits in public
+/// columns, so the prover cannot cancel it by committing to p = 0.
+pub(crate) fn zero_argument_commitments<C: ProjectiveCurve>(
+    commit_key: &CommitKey<C>,
+    n: usize,
+    statement: &Statement<C::ScalarField, PedersenCommitment<C>>,
+    partial_products_commit: &Commitment<C>,
+    x: C::ScalarField,
+) -> Result<ZeroArgumentColumns<C>, CryptoError> {
+    let zero = C::ScalarField::zero();
+    let one = C::ScalarField::one();
+
+    let commits =
+        PedersenCommitment::commit_columns(commit_key, &[vec![one], vec![-one; n]], &[zero, zero])?;
+    let (e_1_commit, minus_ones_commit) = (commits[0], commits[1]);
+
+    let commitments_to_p = vec![*partial_products_commit, e_1_commit];
+    let commitments_to_a = vec![
+        *statement.a_commit * x + minus_ones_commit,
+        e_1_commit * public_constant(x, n, statement.b),
+    ];
+
+    Ok((commitments_to_p, commitments_to_a))
+}
+
+/// Entry of the public column of the compressed argument, i.e. 1 - x^n b.
+pub(crate) fn public_constant<F: Field>(x: F, n: usize, b: F) -> F {
+    F::one() - x.pow([n as u64]) * b
+}
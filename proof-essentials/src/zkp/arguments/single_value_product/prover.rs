@@ -1,24 +1,66 @@
-use super::proof::Proof;
-use super::{Parameters, Statement, Witness};
+use super::messages::{CompressedRound1Message, Round1Challenge, Round1Message, Round2Message};
+use super::proof::{CompressedProof, Proof};
+use super::{
+    compressed_round_1_challenge, public_constant, round_1_challenge, zero_argument_commitments,
+    Parameters, Statement, Witness,
+};
 
 use crate::error::CryptoError;
 use crate::utils::rand::sample_vector;
+use crate::vector_commitment::pedersen::{Commitment, PedersenCommitment};
 use crate::vector_commitment::HomomorphicCommitmentScheme;
+use crate::zkp::arguments::zero_value_bilinear_map::{self, YMapping};
+use crate::zkp::transcript::Transcript;
 
-use ark_ff::{to_bytes, Field};
-use ark_marlin::rng::FiatShamirRng;
+use ark_ec::ProjectiveCurve;
+use ark_ff::{Field, One, UniformRand, Zero};
 use ark_std::rand::Rng;
-use digest::Digest;
-use std::iter;
+use std::{borrow::Cow, iter};
 
+/// Prover waiting for the first challenge, with the first message to send to the verifier.
+type Round1<'a, Scalar, Comm> = (ProverRound1<'a, Scalar, Comm>, Round1Message<Scalar, Comm>);
+
+/// Prover of the compressed argument waiting for the first challenge, with the first message to send.
+type CompressedRound1<'a, C> = (ProverCompressedRound1<'a, C>, CompressedRound1Message<C>);
+
+/// Prover of the single value product argument. It borrows the witness, except when another argument hands over to
+/// it with a vector derived during its own rounds, which the prover then owns.
 pub struct Prover<'a, Scalar, Comm>
 where
     Scalar: Field,
     Comm: HomomorphicCommitmentScheme<Scalar>,
 {
-    parameters: &'a Parameters<'a, Scalar, Comm>,
-    statement: &'a Statement<'a, Scalar, Comm>,
-    witness: &'a Witness<'a, Scalar>,
+    pub(crate) parameters: Parameters<'a, Scalar, Comm>,
+    pub(crate) a_commit: Comm::Commitment,
+    pub(crate) b: Scalar,
+    pub(crate) a: Cow<'a, [Scalar]>,
+    pub(crate) random_for_a_commit: Scalar,
+}
+
+/// Prover of the single value product argument once the first message is sent, waiting for the challenge x.
+pub struct ProverRound1<'a, Scalar, Comm>
+where
+    Scalar: Field,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+{
+    prover: Prover<'a, Scalar, Comm>,
+    b: Vec<Scalar>,
+    d: Vec<Scalar>,
+    deltas: Vec<Scalar>,
+    r_d: Scalar,
+    s_1: Scalar,
+    s_x: Scalar,
+}
+
+/// Prover of the compressed argument once the partial products are committed, waiting for the challenge x.
+pub struct ProverCompressedRound1<'a, C>
+where
+    C: ProjectiveCurve,
+{
+    prover: Prover<'a, C::ScalarField, PedersenCommitment<C>>,
+    partial_products: Vec<C::ScalarField>,
+    random_for_partial_products: C::ScalarField,
+    partial_products_commit: Commitment<C>,
 }
 
 impl<'a, Scalar, Comm> Prover<'a, Scalar, Comm>
@@ -27,36 +69,30 @@ where
     Comm: HomomorphicCommitmentScheme<Scalar>,
 {
     pub fn new(
-        parameters: &'a Parameters<'a, Scalar, Comm>,
-        statement: &'a Statement<Scalar, Comm>,
-        witness: &'a Witness<'a, Scalar>,
+        parameters: &Parameters<'a, Scalar, Comm>,
+        statement: &Statement<Scalar, Comm>,
+        witness: &Witness<'a, Scalar>,
     ) -> Self {
         Self {
-            parameters,
-            statement,
-            witness,
+            parameters: Parameters::new(parameters.n, parameters.commit_key),
+            a_commit: *statement.a_commit,
+            b: statement.b,
+            a: Cow::Borrowed(witness.a),
+            random_for_a_commit: *witness.random_for_a_commit,
         }
     }
 
-    pub fn prove<R: Rng, D: Digest>(
-        &self,
-        rng: &mut R,
-        fs_rng: &mut FiatShamirRng<D>,
-    ) -> Result<Proof<Scalar, Comm>, CryptoError> {
-        fs_rng.absorb(&to_bytes![b"single_value_product_argument"]?);
+    fn statement(&self) -> Statement<'_, Scalar, Comm> {
+        Statement::new(&self.a_commit, self.b)
+    }
 
+    pub fn round_1<R: Rng>(self, rng: &mut R) -> Result<Round1<'a, Scalar, Comm>, CryptoError> {
         // generate vector b
-        let b: Vec<Scalar> = iter::once(self.witness.a[0])
-            .chain(
-                self.witness
-                    .a
-                    .iter()
-                    .skip(1)
-                    .scan(self.witness.a[0], |st, elem| {
-                        *st *= elem;
-                        Some(*st)
-                    }),
-            )
+        let b: Vec<Scalar> = iter::once(self.a[0])
+            .chain(self.a.iter().skip(1).scan(self.a[0], |st, elem| {
+                *st *= elem;
+                Some(*st)
+            }))
             .collect();
 
         let d: Vec<Scalar> = sample_vector(rng, self.parameters.n);
@@ -71,8 +107,6 @@ where
         let s_1 = Scalar::rand(rng);
         let s_x = Scalar::rand(rng);
 
-        let d_commit = Comm::commit(&self.parameters.commit_key, &d, r_d)?;
-
         let minus_one = -Scalar::one();
         let delta_ds = deltas
             .iter()
@@ -81,11 +115,8 @@ where
             .map(|(delta, d)| minus_one * delta * d)
             .collect::<Vec<_>>();
 
-        let delta_commit = Comm::commit(&self.parameters.commit_key, &delta_ds, s_1)?;
-
         // skip frist a, skip first d, skip last b, and use all deltas
         let diffs = self
-            .witness
             .a
             .iter()
             .skip(1)
@@ -100,42 +131,72 @@ where
             )
             .collect::<Vec<_>>();
 
-        let diff_commit = Comm::commit(&self.parameters.commit_key, &diffs, s_x)?;
-
-        //public information
-        fs_rng.absorb(&to_bytes![
+        let commits = Comm::commit_columns(
             self.parameters.commit_key,
-            self.statement.a_commit
-        ]?);
+            &[&d[..], &delta_ds[..], &diffs[..]],
+            &[r_d, s_1, s_x],
+        )?;
 
-        //commits
-        fs_rng.absorb(&to_bytes![d_commit, delta_commit, diff_commit]?);
+        let message = Round1Message {
+            d_commit: commits[0],
+            delta_commit: commits[1],
+            diff_commit: commits[2],
+        };
 
-        let x = Scalar::rand(fs_rng);
+        let state = ProverRound1 {
+            prover: self,
+            b,
+            d,
+            deltas,
+            r_d,
+            s_1,
+            s_x,
+        };
 
-        let a_blinded = Self::blind(&self.witness.a, &d, x);
-        let r_blinded = x * self.witness.random_for_a_commit + r_d;
+        Ok((state, message))
+    }
 
-        let b_blinded = Self::blind(&b, &deltas, x);
-        let s_blinded = x * s_x + s_1;
+    pub fn prove<R: Rng, T: Transcript>(
+        self,
+        rng: &mut R,
+        transcript: &mut T,
+    ) -> Result<Proof<Scalar, Comm>, CryptoError> {
+        let (prover, round_1) = self.round_1(rng)?;
+        let x = round_1_challenge(
+            &prover.prover.parameters,
+            &prover.prover.statement(),
+            &round_1,
+            transcript,
+        )?;
+        let round_2 = prover.round_2(&x);
 
-        let proof = Proof {
-            // round 1
-            d_commit,
-            delta_commit,
-            diff_commit,
+        Ok(Proof { round_1, round_2 })
+    }
+}
 
-            // round 2
+impl<'a, Scalar, Comm> ProverRound1<'a, Scalar, Comm>
+where
+    Scalar: Field,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+{
+    pub fn round_2(self, challenge: &Round1Challenge<Scalar>) -> Round2Message<Scalar> {
+        let x = challenge.x;
+
+        let a_blinded = Self::blind(&self.prover.a, &self.d, x);
+        let r_blinded = x * self.prover.random_for_a_commit + self.r_d;
+
+        let b_blinded = Self::blind(&self.b, &self.deltas, x);
+        let s_blinded = x * self.s_x + self.s_1;
+
+        Round2Message {
             a_blinded,
             b_blinded,
             r_blinded,
             s_blinded,
-        };
-
-        Ok(proof)
+        }
     }
 
-    fn blind(x: &Vec<Scalar>, blinders: &Vec<Scalar>, challenge: Scalar) -> Vec<Scalar> {
+    fn blind(x: &[Scalar], blinders: &[Scalar], challenge: Scalar) -> Vec<Scalar> {
         let blinded = x
             .iter()
             .zip(blinders.iter())
@@ -145,3 +206,121 @@ where
         blinded
     }
 }
+
+impl<'a, C> Prover<'a, C::ScalarField, PedersenCommitment<C>>
+where
+    C: ProjectiveCurve,
+{
+    /// First round of the compressed argument: commit to the partial products (1, a_1, a_1 a_2, ...) of a.
+    pub fn compressed_round_1<R: Rng>(
+        self,
+        rng: &mut R,
+    ) -> Result<CompressedRound1<'a, C>, CryptoError> {
+        let partial_products: Vec<C::ScalarField> = iter::once(C::ScalarField::one())
+            .chain(self.a.iter().scan(C::ScalarField::one(), |st, elem| {
+                *st *= elem;
+                Some(*st)
+            }))
+            .take(self.parameters.n)
+            .collect();
+
+        let random_for_partial_products = C::ScalarField::rand(rng);
+        let partial_products_commit = PedersenCommitment::commit(
+            self.parameters.commit_key,
+            &partial_products,
+            random_for_partial_products,
+        )?;
+
+        let message = CompressedRound1Message {
+            partial_products_commit,
+        };
+
+        let state = ProverCompressedRound1 {
+            prover: self,
+            partial_products,
+            random_for_partial_products,
+            partial_products_commit,
+        };
+
+        Ok((state, message))
+    }
+
+    pub fn prove_compressed<R: Rng, T: Transcript>(
+        self,
+        rng: &mut R,
+        transcript: &mut T,
+    ) -> Result<CompressedProof<C>, CryptoError> {
+        let (prover, round_1) = self.compressed_round_1(rng)?;
+        let x = compressed_round_1_challenge(
+            &prover.prover.parameters,
+            &prover.prover.statement(),
+            &round_1,
+            transcript,
+        )?;
+        let zero_argument_proof = prover.compressed_round_2(rng, &x, transcript)?;
+
+        Ok(CompressedProof {
+            round_1,
+            zero_argument_proof,
+        })
+    }
+}
+
+impl<'a, C> ProverCompressedRound1<'a, C>
+where
+    C: ProjectiveCurve,
+{
+    /// Second round of the compressed argument: prove that the bilinear map with weights x, x^2, ..., x^n sums to
+    /// zero on the columns derived from the partial products and from a, with a compressed zero argument.
+    pub fn compressed_round_2<R: Rng, T: Transcript>(
+        self,
+        rng: &mut R,
+        challenge: &Round1Challenge<C::ScalarField>,
+        transcript: &mut T,
+    ) -> Result<zero_value_bilinear_map::proof::CompressedProof<C>, CryptoError> {
+        let prover = &self.prover;
+        let n = prover.parameters.n;
+        let x = challenge.x;
+        let zero = C::ScalarField::zero();
+        let one = C::ScalarField::one();
+
+        let (commitments_to_p, commitments_to_a) = zero_argument_commitments(
+            prover.parameters.commit_key,
+            n,
+            &prover.statement(),
+            &self.partial_products_commit,
+            x,
+        )?;
+
+        let mut e_1 = vec![zero; n];
+        e_1[0] = one;
+        let constant_column = e_1
+            .iter()
+            .map(|&e| e * public_constant(x, n, prover.b))
+            .collect();
+        let shifted_a = prover.a.iter().map(|&a_i| x * a_i - one).collect();
+
+        let matrix_p = vec![self.partial_products, e_1];
+        let randoms_for_p = vec![self.random_for_partial_products, zero];
+        let matrix_a = vec![shifted_a, constant_column];
+        let randoms_for_a = vec![x * prover.random_for_a_commit, zero];
+
+        let bilinear_map = YMapping::new(x, n);
+        let parameters =
+            zero_value_bilinear_map::Parameters::new(2, n, prover.parameters.commit_key);
+        let statement = zero_value_bilinear_map::Statement::new(
+            &commitments_to_p,
+            &commitments_to_a,
+            &bilinear_map,
+        );
+        let witness = zero_value_bilinear_map::Witness::new(
+            &matrix_p,
+            &randoms_for_p,
+            &matrix_a,
+            &randoms_for_a,
+        );
+
+        zero_value_bilinear_map::prover::Prover::new(&parameters, &statement, &witness)
+            .prove_compressed(rng, transcript)
+    }
+}
@@ -3,10 +3,12 @@
 mod test {
     use crate::error::CryptoError;
     use crate::utils::rand::sample_vector;
+    use crate::utils::validation::deserialize_validated;
     use crate::vector_commitment::{pedersen, HomomorphicCommitmentScheme};
     use crate::zkp::{arguments::single_value_product, ArgumentOfKnowledge};
 
     use ark_marlin::rng::FiatShamirRng;
+    use ark_serialize::CanonicalSerialize;
     use ark_std::{rand::thread_rng, UniformRand};
     use blake2::Blake2s;
     use starknet_curve;
@@ -22,6 +24,8 @@ mod test {
     type Statement<'a> = single_value_product::Statement<'a, Scalar, Comm>;
     type SingleValueProd<'a> = single_value_product::SingleValueProductArgument<'a, Scalar, Comm>;
     type Parameters<'a> = single_value_product::Parameters<'a, Scalar, Comm>;
+    type CompressedSingleValueProd<'a> =
+        single_value_product::CompressedSingleValueProductArgument<'a, Curve>;
 
     type FS = FiatShamirRng<Blake2s>;
 
@@ -67,4 +71,77 @@ mod test {
             SingleValueProd::verify(&parameters, &statement, &invalid_proof, &mut fs_rng)
         );
     }
+
+    #[test]
+    fn test_compressed_single_product_argument() {
+        let n = 13;
+        let rng = &mut thread_rng();
+        let commit_key = Comm::setup(rng, n);
+
+        let mut a: Vec<Scalar> = sample_vector(rng, n);
+        let b: Scalar = a.iter().product();
+
+        let r = Scalar::rand(rng);
+        let a_commit = Comm::commit(&commit_key, &a, r).unwrap();
+
+        let parameters = Parameters::new(n, &commit_key);
+        let witness = Witness::new(&a, &r);
+        let statement = Statement::new(&a_commit, b);
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let valid_proof =
+            CompressedSingleValueProd::prove(rng, &parameters, &statement, &witness, &mut fs_rng)
+                .unwrap();
+
+        let mut bytes = Vec::new();
+        valid_proof.serialize_unchecked(&mut bytes).unwrap();
+        let received_proof = deserialize_validated(&bytes[..]).unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            Ok(()),
+            CompressedSingleValueProd::verify(
+                &parameters,
+                &statement,
+                &received_proof,
+                &mut fs_rng
+            )
+        );
+
+        // The proof does not hold for another product
+        let other_statement = Statement::new(&a_commit, b + b);
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            Err(CryptoError::ProofVerificationError(String::from(
+                "Single Value Product Argument (5.3)",
+            ))),
+            CompressedSingleValueProd::verify(
+                &parameters,
+                &other_statement,
+                &valid_proof,
+                &mut fs_rng
+            )
+        );
+
+        a[0] = a[0] + a[0];
+        let bad_witness = Witness::new(&a, &r);
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let invalid_proof = CompressedSingleValueProd::prove(
+            rng,
+            &parameters,
+            &statement,
+            &bad_witness,
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            Err(CryptoError::ProofVerificationError(String::from(
+                "Single Value Product Argument (5.3)",
+            ))),
+            CompressedSingleValueProd::verify(&parameters, &statement, &invalid_proof, &mut fs_rng)
+        );
+    }
 }
@@ -0,0 +1,263 @@
+use super::messages::{CompressedRound1Message, Round1Challenge, Round1Message, Round2Message};
+use super::proof::{CompressedProof, Proof};
+use super::{
+    compressed_round_1_challenge, round_1_challenge, zero_argument_commitments, Parameters,
+    Statement,
+};
+
+use crate::error::CryptoError;
+use crate::vector_commitment::pedersen::{Commitment, PedersenCommitment};
+use crate::vector_commitment::HomomorphicCommitmentScheme;
+use crate::zkp::arguments::batch::{check_commitment_equations, CommitmentEquation};
+use crate::zkp::arguments::zero_value_bilinear_map::{self, YMapping};
+use crate::zkp::transcript::Transcript;
+
+use ark_ec::ProjectiveCurve;
+use ark_ff::Field;
+
+pub struct Verifier<'a, Scalar, Comm>
+where
+    Scalar: Field,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+{
+    parameters: Parameters<'a, Scalar, Comm>,
+    a_commit: Comm::Commitment,
+    b: Scalar,
+}
+
+/// Verifier of the single value product argument once the first message and the challenge are exchanged.
+pub struct VerifierRound1<'a, Scalar, Comm>
+where
+    Scalar: Field,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+{
+    verifier: Verifier<'a, Scalar, Comm>,
+    x: Scalar,
+    commitment_to_a_blinded: Comm::Commitment,
+    commitment_to_diffs_blinded: Comm::Commitment,
+}
+
+/// Verifier of the compressed argument once the commitment to the partial products and the challenge are exchanged.
+pub struct VerifierCompressedRound1<'a, C>
+where
+    C: ProjectiveCurve,
+{
+    verifier: Verifier<'a, C::ScalarField, PedersenCommitment<C>>,
+    x: C::ScalarField,
+    partial_products_commit: Commitment<C>,
+}
+
+impl<'a, Scalar, Comm> Verifier<'a, Scalar, Comm>
+where
+    Scalar: Field,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+{
+    pub fn new(
+        parameters: &Parameters<'a, Scalar, Comm>,
+        statement: &Statement<Scalar, Comm>,
+    ) -> Self {
+        Self {
+            parameters: Parameters::new(parameters.n, parameters.commit_key),
+            a_commit: *statement.a_commit,
+            b: statement.b,
+        }
+    }
+
+    fn statement(&self) -> Statement<'_, Scalar, Comm> {
+        Statement::new(&self.a_commit, self.b)
+    }
+
+    pub fn round_1(
+        self,
+        message: &Round1Message<Scalar, Comm>,
+        challenge: &Round1Challenge<Scalar>,
+    ) -> VerifierRound1<'a, Scalar, Comm> {
+        let x = challenge.x;
+
+        VerifierRound1 {
+            commitment_to_a_blinded: self.a_commit * x + message.d_commit,
+            commitment_to_diffs_blinded: message.diff_commit * x + message.delta_commit,
+            verifier: self,
+            x,
+        }
+    }
+
+    pub fn verify<T: Transcript>(
+        self,
+        proof: &Proof<Scalar, Comm>,
+        transcript: &mut T,
+    ) -> Result<(), CryptoError> {
+        let x = round_1_challenge(
+            &self.parameters,
+            &self.statement(),
+            &proof.round_1,
+            transcript,
+        )?;
+
+        self.round_1(&proof.round_1, &x).round_2(&proof.round_2)
+    }
+
+    /// Run the verifier and return the equations of the last round instead of checking them.
+    pub(crate) fn equations<T: Transcript>(
+        self,
+        proof: &Proof<Scalar, Comm>,
+        transcript: &mut T,
+    ) -> Result<Vec<CommitmentEquation<Scalar, Comm>>, CryptoError> {
+        let x = round_1_challenge(
+            &self.parameters,
+            &self.statement(),
+            &proof.round_1,
+            transcript,
+        )?;
+
+        self.round_1(&proof.round_1, &x)
+            .round_2_equations(&proof.round_2)
+    }
+}
+
+impl<'a, Scalar, Comm> VerifierRound1<'a, Scalar, Comm>
+where
+    Scalar: Field,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+{
+    pub fn round_2(self, message: &Round2Message<Scalar>) -> Result<(), CryptoError> {
+        let commit_key = self.verifier.parameters.commit_key;
+        let equations = self.round_2_equations(message)?;
+
+        check_commitment_equations(
+            &equations,
+            commit_key,
+            "Single Value Product Argument (5.3)",
+        )
+    }
+
+    /// Check the first and last blinded partial products, and return the equations stating that the blinded
+    /// vector a and the blinded differences open the combinations of the commitments computed in the first round.
+    pub(crate) fn round_2_equations(
+        self,
+        message: &Round2Message<Scalar>,
+    ) -> Result<Vec<CommitmentEquation<Scalar, Comm>>, CryptoError> {
+        let parameters = &self.verifier.parameters;
+        let x = self.x;
+
+        if message.b_blinded.len() != parameters.n {
+            return Err(CryptoError::ProofVerificationError(String::from(
+                "Single Value Product Argument (5.3)",
+            )));
+        }
+        if message.a_blinded.len() != parameters.n {
+            return Err(CryptoError::ProofVerificationError(String::from(
+                "Single Value Product Argument (5.3)",
+            )));
+        }
+        if message.b_blinded[0] != message.a_blinded[0] {
+            return Err(CryptoError::ProofVerificationError(String::from(
+                "Single Value Product Argument (5.3)",
+            )));
+        }
+
+        if message.b_blinded[parameters.n - 1] != x * self.verifier.b {
+            return Err(CryptoError::ProofVerificationError(String::from(
+                "Single Value Product Argument (5.3)",
+            )));
+        }
+
+        let blinded_diffs = message
+            .b_blinded
+            .iter()
+            .skip(1)
+            .zip(message.b_blinded.iter().take(message.b_blinded.len() - 1))
+            .zip(message.a_blinded.iter().skip(1))
+            .map(|((&b, &b_minus_one), &a)| x * b - b_minus_one * a)
+            .collect::<Vec<_>>();
+
+        Ok(vec![
+            // blinded a is correctly formed
+            CommitmentEquation::new(
+                self.commitment_to_a_blinded,
+                message.a_blinded.clone(),
+                message.r_blinded,
+            ),
+            // diffs are correctly formed
+            CommitmentEquation::new(
+                self.commitment_to_diffs_blinded,
+                blinded_diffs,
+                message.s_blinded,
+            ),
+        ])
+    }
+}
+
+impl<'a, C> Verifier<'a, C::ScalarField, PedersenCommitment<C>>
+where
+    C: ProjectiveCurve,
+{
+    pub fn compressed_round_1(
+        self,
+        message: &CompressedRound1Message<C>,
+        challenge: &Round1Challenge<C::ScalarField>,
+    ) -> VerifierCompressedRound1<'a, C> {
+        VerifierCompressedRound1 {
+            verifier: self,
+            x: challenge.x,
+            partial_products_commit: message.partial_products_commit,
+        }
+    }
+
+    pub fn verify_compressed<T: Transcript>(
+        self,
+        proof: &CompressedProof<C>,
+        transcript: &mut T,
+    ) -> Result<(), CryptoError> {
+        let x = compressed_round_1_challenge(
+            &self.parameters,
+            &self.statement(),
+            &proof.round_1,
+            transcript,
+        )?;
+
+        self.compressed_round_1(&proof.round_1, &x)
+            .compressed_round_2(&proof.zero_argument_proof, transcript)
+    }
+}
+
+impl<'a, C> VerifierCompressedRound1<'a, C>
+where
+    C: ProjectiveCurve,
+{
+    /// Second round of the compressed argument: verify the compressed zero argument on the columns derived from the
+    /// commitments to a and to the partial products.
+    pub fn compressed_round_2<T: Transcript>(
+        self,
+        proof: &zero_value_bilinear_map::proof::CompressedProof<C>,
+        transcript: &mut T,
+    ) -> Result<(), CryptoError> {
+        let verifier = &self.verifier;
+        let n = verifier.parameters.n;
+
+        let (commitments_to_p, commitments_to_a) = zero_argument_commitments(
+            verifier.parameters.commit_key,
+            n,
+            &verifier.statement(),
+            &self.partial_products_commit,
+            self.x,
+        )?;
+
+        let bilinear_map = YMapping::new(self.x, n);
+        let parameters =
+            zero_value_bilinear_map::Parameters::new(2, n, verifier.parameters.commit_key);
+        let statement = zero_value_bilinear_map::Statement::new(
+            &commitments_to_p,
+            &commitments_to_a,
+            &bilinear_map,
+        );
+
+        proof
+            .verify(&parameters, &statement, transcript)
+            .map_err(|_| {
+                CryptoError::ProofVerificationError(String::from(
+                    "Single Value Product Argument (5.3)",
+                ))
+            })
+    }
+}
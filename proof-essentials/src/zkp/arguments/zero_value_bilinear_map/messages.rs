@@ -0,0 +1,71 @@
+use crate::error::CryptoError;
+use crate::utils::validation::Validate;
+use crate::vector_commitment::HomomorphicCommitmentScheme;
+
+use ark_ec::ProjectiveCurve;
+use ark_ff::Field;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::io::{Read, Write};
+use ark_std::{rand::Rng, UniformRand};
+
+/// First message of the prover: commitments to the random vectors a_0 and b_m and to the diagonals of the
+/// products of the columns of the extended matrices.
+#[derive(CanonicalDeserialize, CanonicalSerialize)]
+pub struct Round1Message<Scalar, Comm>
+where
+    Scalar: Field,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+{
+    pub a_0_commit: Comm::Commitment,
+    pub b_m_commit: Comm::Commitment,
+    pub vector_of_committed_diagonals: Vec<Comm::Commitment>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, CanonicalDeserialize, CanonicalSerialize)]
+pub struct Round1Challenge<Scalar: Field> {
+    pub x: Scalar,
+}
+
+/// Second message of the prover: openings of the commitments to A and B and to the diagonals, blinded with the
+/// powers of the challenge.
+#[derive(CanonicalDeserialize, CanonicalSerialize)]
+pub struct Round2Message<Scalar: Field> {
+    pub a_blinded: Vec<Scalar>,
+    pub b_blinded: Vec<Scalar>,
+    pub r_blinded: Scalar,
+    pub s_blinded: Scalar,
+    pub t_blinded: Scalar,
+}
+
+/// Second message of the compressed zero argument: the blinding factors and the value of the bilinear map on the
+/// blinded vectors, which are then proven with an inner product argument instead of being sent.
+#[derive(CanonicalDeserialize, CanonicalSerialize)]
+pub struct CompressedRound2Message<C: ProjectiveCurve> {
+    pub r_blinded: C::ScalarField,
+    pub s_blinded: C::ScalarField,
+    pub t_blinded: C::ScalarField,
+    pub a_star_b: C::ScalarField,
+}
+
+impl<Scalar: Field> UniformRand for Round1Challenge<Scalar> {
+    fn rand<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Self {
+            x: Scalar::rand(rng),
+        }
+    }
+}
+
+impl<Scalar, Comm> Validate for Round1Message<Scalar, Comm>
+where
+    Scalar: Field,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+    Comm::Commitment: Validate,
+{
+    fn validate(&self) -> Result<(), CryptoError> {
+        self.a_0_commit.validate()?;
+        self.b_m_commit.validate()?;
+        self.vector_of_committed_diagonals.validate()?;
+
+        Ok(())
+    }
+}
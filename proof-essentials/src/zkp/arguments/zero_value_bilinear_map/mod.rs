@@ -1,14 +1,16 @@
+pub mod messages;
 pub mod proof;
 pub mod prover;
 pub mod tests;
+pub mod verifier;
 
 use crate::error::CryptoError;
-use crate::vector_commitment::HomomorphicCommitmentScheme;
-use crate::zkp::{arguments::scalar_powers, ArgumentOfKnowledge};
+use crate::vector_commitment::{pedersen::PedersenCommitment, HomomorphicCommitmentScheme};
+use crate::zkp::{arguments::scalar_powers, transcript::Transcript, ArgumentOfKnowledge};
+use ark_ec::ProjectiveCurve;
 use ark_ff::Field;
-use ark_marlin::rng::FiatShamirRng;
 use ark_std::{marker::PhantomData, rand::Rng};
-use digest::Digest;
+use std::borrow::Cow;
 
 pub struct ZeroValueArgument<'a, F, Comm>
 where
@@ -29,26 +31,65 @@ where
     type Witness = Witness<'a, Scalar>;
     type Proof = proof::Proof<Scalar, Comm>;
 
-    fn prove<R: Rng, D: Digest>(
+    fn prove<R: Rng, T: Transcript>(
         rng: &mut R,
         common_reference_string: &Self::CommonReferenceString,
         statement: &Self::Statement,
         witness: &Self::Witness,
-        fs_rng: &mut FiatShamirRng<D>,
+        transcript: &mut T,
     ) -> Result<Self::Proof, CryptoError> {
         let prover = prover::Prover::new(common_reference_string, statement, witness);
-        let proof = prover.prove(rng, fs_rng)?;
+        let proof = prover.prove(rng, transcript)?;
 
         Ok(proof)
     }
 
-    fn verify<D: Digest>(
+    fn verify<T: Transcript>(
         common_reference_string: &Self::CommonReferenceString,
         statement: &Self::Statement,
         proof: &Self::Proof,
-        fs_rng: &mut FiatShamirRng<D>,
+        transcript: &mut T,
     ) -> Result<(), CryptoError> {
-        proof.verify(&common_reference_string, &statement, fs_rng)
+        proof.verify(&common_reference_string, &statement, transcript)
+    }
+}
+
+/// Zero argument for a bilinear map over Pedersen commitments, where the blinded vectors of the last round are
+/// replaced by an inner product argument of logarithmic size.
+pub struct CompressedZeroValueArgument<'a, C>
+where
+    C: ProjectiveCurve,
+{
+    _group: PhantomData<&'a C>,
+}
+
+impl<'a, C> ArgumentOfKnowledge for CompressedZeroValueArgument<'a, C>
+where
+    C: ProjectiveCurve,
+{
+    type CommonReferenceString = Parameters<'a, C::ScalarField, PedersenCommitment<C>>;
+    type Statement = Statement<'a, C::ScalarField, PedersenCommitment<C>>;
+    type Witness = Witness<'a, C::ScalarField>;
+    type Proof = proof::CompressedProof<C>;
+
+    fn prove<R: Rng, T: Transcript>(
+        rng: &mut R,
+        common_reference_string: &Self::CommonReferenceString,
+        statement: &Self::Statement,
+        witness: &Self::Witness,
+        transcript: &mut T,
+    ) -> Result<Self::Proof, CryptoError> {
+        let prover = prover::Prover::new(common_reference_string, statement, witness);
+        prover.prove_compressed(rng, transcript)
+    }
+
+    fn verify<T: Transcript>(
+        common_reference_string: &Self::CommonReferenceString,
+        statement: &Self::Statement,
+        proof: &Self::Proof,
+        transcript: &mut T,
+    ) -> Result<(), CryptoError> {
+        proof.verify(common_reference_string, statement, transcript)
     }
 }
 
@@ -81,18 +122,18 @@ pub struct Witness<'a, Scalar>
 where
     Scalar: Field,
 {
-    pub matrix_a: &'a Vec<Vec<Scalar>>,
-    pub randoms_for_a_commit: &'a Vec<Scalar>,
-    pub matrix_b: &'a Vec<Vec<Scalar>>,
-    pub randoms_for_b_commit: &'a Vec<Scalar>,
+    pub matrix_a: &'a [Vec<Scalar>],
+    pub randoms_for_a_commit: &'a [Scalar],
+    pub matrix_b: &'a [Vec<Scalar>],
+    pub randoms_for_b_commit: &'a [Scalar],
 }
 
 impl<'a, Scalar: Field> Witness<'a, Scalar> {
     pub fn new(
-        matrix_a: &'a Vec<Vec<Scalar>>,
-        randoms_for_a_commit: &'a Vec<Scalar>,
-        matrix_b: &'a Vec<Vec<Scalar>>,
-        randoms_for_b_commit: &'a Vec<Scalar>,
+        matrix_a: &'a [Vec<Scalar>],
+        randoms_for_a_commit: &'a [Scalar],
+        matrix_b: &'a [Vec<Scalar>],
+        randoms_for_b_commit: &'a [Scalar],
     ) -> Self {
         Self {
             matrix_a,
@@ -111,8 +152,8 @@ where
     Scalar: Field,
     Comm: HomomorphicCommitmentScheme<Scalar>,
 {
-    pub commitment_to_a: &'a Vec<Comm::Commitment>,
-    pub commitment_to_b: &'a Vec<Comm::Commitment>,
+    pub commitment_to_a: &'a [Comm::Commitment],
+    pub commitment_to_b: &'a [Comm::Commitment],
     pub bilinear_map: &'a YMapping<Scalar>,
 }
 
@@ -122,8 +163,8 @@ where
     Comm: HomomorphicCommitmentScheme<Scalar>,
 {
     pub fn new(
-        commitment_to_a: &'a Vec<Comm::Commitment>,
-        commitment_to_b: &'a Vec<Comm::Commitment>,
+        commitment_to_a: &'a [Comm::Commitment],
+        commitment_to_b: &'a [Comm::Commitment],
         bilinear_map: &'a YMapping<Scalar>,
     ) -> Self {
         Self {
@@ -138,6 +179,7 @@ pub trait BilinearMap<Scalar: Field> {
     fn compute_mapping(&self, a: &Vec<Scalar>, b: &Vec<Scalar>) -> Result<Scalar, CryptoError>;
 }
 
+#[derive(Clone)]
 pub struct YMapping<Scalar: Field> {
     powers: Vec<Scalar>,
 }
@@ -149,6 +191,19 @@ impl<F: Field> YMapping<F> {
             powers: powers[1..].to_vec(),
         }
     }
+
+    /// Weights of the mapping, i.e. the powers y, y^2, ..., y^n.
+    pub fn weights(&self) -> &[F] {
+        &self.powers
+    }
+}
+
+/// Weights of a borrowed or owned mapping, which are borrowed or owned alike.
+fn mapping_weights<F: Field>(mapping: Cow<'_, YMapping<F>>) -> Cow<'_, [F]> {
+    match mapping {
+        Cow::Borrowed(mapping) => Cow::Borrowed(mapping.weights()),
+        Cow::Owned(mapping) => Cow::Owned(mapping.powers),
+    }
 }
 
 impl<Scalar: Field> BilinearMap<Scalar> for YMapping<Scalar> {
@@ -1,15 +1,17 @@
-use super::{BilinearMap, Parameters, Statement};
+use super::messages::{CompressedRound2Message, Round1Challenge, Round1Message, Round2Message};
+use super::{verifier::Verifier, Parameters, Statement};
 
 use crate::error::CryptoError;
-use crate::utils::vector_arithmetic::dot_product;
+use crate::utils::validation::{Validate, ValidatePoint};
+use crate::vector_commitment::pedersen::PedersenCommitment;
 use crate::vector_commitment::HomomorphicCommitmentScheme;
-use crate::zkp::arguments::scalar_powers;
+use crate::zkp::arguments::inner_product;
+use crate::zkp::transcript::Transcript;
 
-use ark_ff::{to_bytes, Field};
-use ark_marlin::rng::FiatShamirRng;
+use ark_ec::ProjectiveCurve;
+use ark_ff::Field;
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
 use ark_std::io::{Read, Write};
-use digest::Digest;
 
 #[derive(CanonicalDeserialize, CanonicalSerialize)]
 pub struct Proof<Scalar, Comm>
@@ -17,17 +19,8 @@ where
     Scalar: Field,
     Comm: HomomorphicCommitmentScheme<Scalar>,
 {
-    // Round 1
-    pub a_0_commit: Comm::Commitment,
-    pub b_m_commit: Comm::Commitment,
-    pub vector_of_committed_diagonals: Vec<Comm::Commitment>,
-
-    // Round 2
-    pub a_blinded: Vec<Scalar>,
-    pub b_blinded: Vec<Scalar>,
-    pub r_blinded: Scalar,
-    pub s_blinded: Scalar,
-    pub t_blinded: Scalar,
+    pub round_1: Round1Message<Scalar, Comm>,
+    pub round_2: Round2Message<Scalar>,
 }
 
 impl<Scalar, Comm> Proof<Scalar, Comm>
@@ -35,99 +28,91 @@ where
     Scalar: Field,
     Comm: HomomorphicCommitmentScheme<Scalar>,
 {
-    pub fn verify<D: Digest>(
+    pub fn verify<T: Transcript>(
         &self,
         proof_parameters: &Parameters<Scalar, Comm>,
         statement: &Statement<Scalar, Comm>,
-        fs_rng: &mut FiatShamirRng<D>,
+        transcript: &mut T,
     ) -> Result<(), CryptoError> {
-        if self.vector_of_committed_diagonals[proof_parameters.m + 1]
-            != Comm::commit(
-                proof_parameters.commit_key,
-                &vec![Scalar::zero()],
-                Scalar::zero(),
-            )?
-        {
-            return Err(CryptoError::ProofVerificationError(String::from(
-                "Zero Argument (5.2)",
-            )));
-        }
-
-        fs_rng.absorb(&to_bytes![b"zero_argument"]?);
-
-        // Public parameters
-        fs_rng.absorb(&to_bytes![
-            proof_parameters.commit_key,
-            proof_parameters.m as u32,
-            proof_parameters.n as u32
-        ]?);
-
-        // Random values
-        fs_rng.absorb(&to_bytes![self.a_0_commit, self.b_m_commit]?);
-
-        // Commitments
-        fs_rng.absorb(
-            &to_bytes![
-                statement.commitment_to_a,
-                statement.commitment_to_b,
-                self.vector_of_committed_diagonals
-            ]
-            .unwrap(),
-        );
-
-        let x = Scalar::rand(fs_rng);
-
-        // Precompute all powers of the challenge from 0 to number_of_diagonals
-        let challenge_powers = scalar_powers(x, 2 * proof_parameters.m);
-
-        let first_m_powers = challenge_powers[0..proof_parameters.m].to_vec();
-        let mut first_m_powers_reversed = first_m_powers[..].to_vec();
-        first_m_powers_reversed.reverse();
+        Verifier::new(proof_parameters, statement).verify(self, transcript)
+    }
+}
 
-        let first_m_non_zero_powers = challenge_powers[1..proof_parameters.m + 1].to_vec();
-        let mut first_m_non_zero_powers_reversed = first_m_non_zero_powers[..].to_vec();
-        first_m_non_zero_powers_reversed.reverse();
+/// Zero argument whose last round is replaced by an inner product argument: instead of the blinded vectors a and
+/// b, the prover sends the value of the bilinear map on them and proves it with a proof of logarithmic size.
+#[derive(CanonicalDeserialize, CanonicalSerialize)]
+pub struct CompressedProof<C>
+where
+    C: ProjectiveCurve,
+{
+    pub round_1: Round1Message<C::ScalarField, PedersenCommitment<C>>,
+    pub round_2: CompressedRound2Message<C>,
+    pub inner_product_proof: inner_product::proof::Proof<C>,
+}
 
-        // Verify commitment to A against a commitment on blinded a with blinded random r
-        let left: Comm::Commitment =
-            self.a_0_commit + dot_product(&first_m_non_zero_powers, statement.commitment_to_a)?;
-        let right = Comm::commit(
-            &proof_parameters.commit_key,
-            &self.a_blinded,
-            self.r_blinded,
-        )?;
-        if left != right {
-            return Err(CryptoError::ProofVerificationError(String::from(
-                "Zero Argument (5.2)",
-            )));
-        }
+impl<C: ProjectiveCurve> CompressedProof<C> {
+    pub fn verify<T: Transcript>(
+        &self,
+        proof_parameters: &Parameters<C::ScalarField, PedersenCommitment<C>>,
+        statement: &Statement<C::ScalarField, PedersenCommitment<C>>,
+        transcript: &mut T,
+    ) -> Result<(), CryptoError> {
+        Verifier::new(proof_parameters, statement).verify_compressed(self, transcript)
+    }
+}
 
-        // Verify commitment to B against a commitment on blinded b with blinded random s
-        let left = self.b_m_commit
-            + dot_product(&first_m_non_zero_powers_reversed, statement.commitment_to_b)?;
-        let right = Comm::commit(
-            &proof_parameters.commit_key,
-            &self.b_blinded,
-            self.s_blinded,
-        )?;
-        if left != right {
-            return Err(CryptoError::ProofVerificationError(String::from(
-                "Zero Argument (5.2)",
-            )));
-        }
+/// Absorb the public parameters, the statement and the commitments of the first round, and derive the challenge.
+pub(crate) fn challenge<Scalar, Comm, T>(
+    proof_parameters: &Parameters<Scalar, Comm>,
+    statement: &Statement<Scalar, Comm>,
+    message: &Round1Message<Scalar, Comm>,
+    transcript: &mut T,
+) -> Result<Round1Challenge<Scalar>, CryptoError>
+where
+    Scalar: Field,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+    T: Transcript,
+{
+    transcript.domain_separator(b"Zero Argument");
+    transcript.round(1);
+
+    // Public parameters
+    transcript.append(b"commit key", proof_parameters.commit_key)?;
+    transcript.append(b"m", &(proof_parameters.m as u32))?;
+    transcript.append(b"n", &(proof_parameters.n as u32))?;
+
+    // Random values
+    transcript.append(b"a_0 commit", &message.a_0_commit)?;
+    transcript.append(b"b_m commit", &message.b_m_commit)?;
+
+    // Commitments
+    transcript.append_slice(b"commitments to a", statement.commitment_to_a)?;
+    transcript.append_slice(b"commitments to b", statement.commitment_to_b)?;
+    transcript.append(b"diagonal commits", &message.vector_of_committed_diagonals)?;
+
+    Ok(Round1Challenge {
+        x: transcript.challenge_scalar(b"x"),
+    })
+}
 
-        // Verify commitments to the diagonals against a commitment on bilinear_map(blinded a, blinded a) with blinded random t
-        let left = dot_product(&challenge_powers, &self.vector_of_committed_diagonals)?;
-        let a_star_b = statement
-            .bilinear_map
-            .compute_mapping(&self.a_blinded, &self.b_blinded)?;
-        let right = Comm::commit(proof_parameters.commit_key, &vec![a_star_b], self.t_blinded)?;
-        if left != right {
-            return Err(CryptoError::ProofVerificationError(String::from(
-                "Zero Argument (5.2)",
-            )));
-        }
+impl<Scalar, Comm> Validate for Proof<Scalar, Comm>
+where
+    Scalar: Field,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+    Comm::Commitment: Validate,
+{
+    fn validate(&self) -> Result<(), CryptoError> {
+        self.round_1.validate()
+    }
+}
 
-        Ok(())
+impl<C> Validate for CompressedProof<C>
+where
+    C: ProjectiveCurve,
+    C::Affine: ValidatePoint,
+{
+    fn validate(&self) -> Result<(), CryptoError> {
+        self.round_1.validate()?;
+        self.inner_product_proof.validate()
     }
 }
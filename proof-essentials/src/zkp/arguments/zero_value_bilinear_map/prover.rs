@@ -1,23 +1,50 @@
-use super::{proof::Proof, BilinearMap, Parameters, Statement, Witness};
+use super::messages::{CompressedRound2Message, Round1Challenge, Round1Message, Round2Message};
+use super::proof::{challenge, CompressedProof, Proof};
+use super::{mapping_weights, BilinearMap, Parameters, Statement, Witness, YMapping};
 
 use crate::error::CryptoError;
 use crate::utils::{rand::sample_vector, vector_arithmetic::dot_product};
-use crate::vector_commitment::HomomorphicCommitmentScheme;
-use crate::zkp::arguments::scalar_powers;
-use ark_ff::{to_bytes, Field};
-use ark_marlin::rng::FiatShamirRng;
-use digest::Digest;
+use crate::vector_commitment::{pedersen::PedersenCommitment, HomomorphicCommitmentScheme};
+use crate::zkp::arguments::{inner_product, scalar_powers};
+use crate::zkp::transcript::Transcript;
+use ark_ec::ProjectiveCurve;
+use ark_ff::{Field, Zero};
 
 use rand::Rng;
+use std::{borrow::Cow, slice};
 
+/// Prover waiting for the first challenge, with the first message to send to the verifier.
+type Round1<'a, Scalar, Comm> = (ProverRound1<'a, Scalar, Comm>, Round1Message<Scalar, Comm>);
+
+/// Prover of the zero argument. It borrows the statement and the witness, except when another argument hands over
+/// to it with values derived during its own rounds, which the prover then owns.
 pub struct Prover<'a, Scalar, Comm>
 where
     Scalar: Field,
     Comm: HomomorphicCommitmentScheme<Scalar>,
 {
-    parameters: &'a Parameters<'a, Scalar, Comm>,
-    statement: &'a Statement<'a, Scalar, Comm>,
-    witness: &'a Witness<'a, Scalar>,
+    pub(crate) parameters: Parameters<'a, Scalar, Comm>,
+    pub(crate) commitment_to_a: Cow<'a, [Comm::Commitment]>,
+    pub(crate) commitment_to_b: Cow<'a, [Comm::Commitment]>,
+    pub(crate) bilinear_map: Cow<'a, YMapping<Scalar>>,
+    pub(crate) matrix_a: Cow<'a, [Vec<Scalar>]>,
+    pub(crate) randoms_for_a_commit: Cow<'a, [Scalar]>,
+    pub(crate) matrix_b: Cow<'a, [Vec<Scalar>]>,
+    pub(crate) randoms_for_b_commit: Cow<'a, [Scalar]>,
+}
+
+/// Prover of the zero argument once the first message is sent, waiting for the challenge x.
+pub struct ProverRound1<'a, Scalar, Comm>
+where
+    Scalar: Field,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+{
+    prover: Prover<'a, Scalar, Comm>,
+    a_0: Vec<Scalar>,
+    b_m: Vec<Scalar>,
+    r_0: Scalar,
+    s_m: Scalar,
+    t: Vec<Scalar>,
 }
 
 impl<'a, Scalar, Comm> Prover<'a, Scalar, Comm>
@@ -26,38 +53,49 @@ where
     Comm: HomomorphicCommitmentScheme<Scalar>,
 {
     pub fn new(
-        parameters: &'a Parameters<'a, Scalar, Comm>,
-        statement: &'a Statement<'a, Scalar, Comm>,
-        witness: &'a Witness<'a, Scalar>,
+        parameters: &Parameters<'a, Scalar, Comm>,
+        statement: &Statement<'a, Scalar, Comm>,
+        witness: &Witness<'a, Scalar>,
     ) -> Self {
         Self {
-            parameters,
-            statement,
-            witness,
+            parameters: Parameters::new(parameters.m, parameters.n, parameters.commit_key),
+            commitment_to_a: Cow::Borrowed(statement.commitment_to_a),
+            commitment_to_b: Cow::Borrowed(statement.commitment_to_b),
+            bilinear_map: Cow::Borrowed(statement.bilinear_map),
+            matrix_a: Cow::Borrowed(witness.matrix_a),
+            randoms_for_a_commit: Cow::Borrowed(witness.randoms_for_a_commit),
+            matrix_b: Cow::Borrowed(witness.matrix_b),
+            randoms_for_b_commit: Cow::Borrowed(witness.randoms_for_b_commit),
         }
     }
 
-    pub fn prove<R: Rng, D: Digest>(
-        &self,
-        rng: &mut R,
-        fs_rng: &mut FiatShamirRng<D>,
-    ) -> Result<Proof<Scalar, Comm>, CryptoError> {
-        fs_rng.absorb(&to_bytes![b"zero_argument"]?);
+    fn statement(&self) -> Statement<'_, Scalar, Comm> {
+        Statement::new(
+            &self.commitment_to_a,
+            &self.commitment_to_b,
+            &self.bilinear_map,
+        )
+    }
 
+    pub fn round_1<R: Rng>(self, rng: &mut R) -> Result<Round1<'a, Scalar, Comm>, CryptoError> {
         let a_0: Vec<Scalar> = sample_vector(rng, self.parameters.n);
         let b_m: Vec<Scalar> = sample_vector(rng, self.parameters.n);
 
         let r_0 = Scalar::rand(rng);
         let s_m = Scalar::rand(rng);
 
-        let a_0_commit = Comm::commit(self.parameters.commit_key, &a_0, r_0)?;
-        let b_m_commit = Comm::commit(self.parameters.commit_key, &b_m, s_m)?;
+        let commits = Comm::commit_columns(
+            self.parameters.commit_key,
+            &[&a_0[..], &b_m[..]],
+            &[r_0, s_m],
+        )?;
+        let (a_0_commit, b_m_commit) = (commits[0], commits[1]);
 
         let a_0_vec = vec![a_0.clone(); 1];
-        let extended_a = [&a_0_vec[..], &self.witness.matrix_a[..]].concat();
+        let extended_a = [&a_0_vec[..], &self.matrix_a[..]].concat();
 
         let b_m_vec = vec![b_m.clone(); 1];
-        let extended_b = [&self.witness.matrix_b[..], &b_m_vec[..]].concat();
+        let extended_b = [&self.matrix_b[..], &b_m_vec[..]].concat();
 
         let diagonals = self
             .diagonals_from_chunks(
@@ -71,95 +109,43 @@ where
         let mut t: Vec<Scalar> = sample_vector(rng, 2 * self.parameters.m + 1);
         t[self.parameters.m + 1] = Scalar::zero();
 
-        let vector_of_committed_diagonals = diagonals
-            .iter()
-            .zip(t.iter())
-            .map(|(&diagonal, &random)| -> Result<_, CryptoError> {
-                Comm::commit(self.parameters.commit_key, &vec![diagonal], random)
-            })
-            .collect::<Result<Vec<_>, CryptoError>>()?;
-
-        // Public parameters
-        fs_rng.absorb(
-            &to_bytes![
-                self.parameters.commit_key,
-                self.parameters.m as u32,
-                self.parameters.n as u32
-            ]
-            .unwrap(),
-        );
-
-        // Random values
-        fs_rng.absorb(&to_bytes![a_0_commit, b_m_commit]?);
-
-        // Commitments
-        fs_rng.absorb(
-            &to_bytes![
-                self.statement.commitment_to_a,
-                self.statement.commitment_to_b,
-                vector_of_committed_diagonals
-            ]
-            .unwrap(),
-        );
-
-        let x = Scalar::rand(fs_rng);
-
-        // Precompute all powers of the challenge from 0 to number_of_diagonals of the extended matrix
-        let challenge_powers = scalar_powers(x, 2 * self.parameters.m);
+        let diagonal_columns = diagonals.iter().map(slice::from_ref).collect::<Vec<_>>();
+        let vector_of_committed_diagonals =
+            Comm::commit_columns(self.parameters.commit_key, &diagonal_columns, &t)?;
 
-        let first_m_powers = challenge_powers[0..self.parameters.m].to_vec();
-        let mut first_m_powers_reversed = first_m_powers[..].to_vec();
-        first_m_powers_reversed.reverse();
-
-        let first_m_non_zero_powers = challenge_powers[1..self.parameters.m + 1].to_vec();
-        let mut first_m_non_zero_powers_reversed = first_m_non_zero_powers[..].to_vec();
-        first_m_non_zero_powers_reversed.reverse();
-
-        // a1[0]x + a2[0]x^2 ... am[0]x^m
-        // a1[1]x + a2[1]x^2 ... am[1]x^m
-        // a1[2]x + a2[2]x^2 ... am[2]x^m
-        // a1[3]x + a2[3]x^2 ... am[3]x^m
-        // ...
-        // a1[n]x + a2[n]x^2 ... am[n]x^m = b[n]
-        let mut a_blinded: Vec<Scalar> = Vec::with_capacity(self.parameters.m + 1);
-        for i in 0..self.parameters.n {
-            let mut poly = a_0[i];
-            for j in 0..self.parameters.m {
-                poly = poly + self.witness.matrix_a[j][i] * first_m_non_zero_powers[j];
-            }
-            a_blinded.push(poly);
-        }
-
-        let mut b_blinded: Vec<Scalar> = Vec::with_capacity(self.parameters.m + 1);
-        for i in 0..self.parameters.n {
-            let mut poly = b_m[i];
-            for j in 0..self.parameters.m {
-                poly = poly + self.witness.matrix_b[j][i] * first_m_non_zero_powers_reversed[j];
-            }
-            b_blinded.push(poly);
-        }
-
-        let r_blinded =
-            r_0 + dot_product(&self.witness.randoms_for_a_commit, &first_m_non_zero_powers)?;
-        let s_blinded = dot_product(
-            &self.witness.randoms_for_b_commit,
-            &first_m_non_zero_powers_reversed,
-        )? + s_m;
-        let t_blinded = dot_product(&t, &challenge_powers)?;
-
-        let proof = Proof {
+        let message = Round1Message {
             a_0_commit,
             b_m_commit,
             vector_of_committed_diagonals,
+        };
 
-            a_blinded,
-            b_blinded,
-            r_blinded,
-            s_blinded,
-            t_blinded,
+        let state = ProverRound1 {
+            prover: self,
+            a_0,
+            b_m,
+            r_0,
+            s_m,
+            t,
         };
 
-        Ok(proof)
+        Ok((state, message))
+    }
+
+    pub fn prove<R: Rng, T: Transcript>(
+        self,
+        rng: &mut R,
+        transcript: &mut T,
+    ) -> Result<Proof<Scalar, Comm>, CryptoError> {
+        let (prover, round_1) = self.round_1(rng)?;
+        let x = challenge(
+            &prover.prover.parameters,
+            &prover.prover.statement(),
+            &round_1,
+            transcript,
+        )?;
+        let round_2 = prover.round_2(&x)?;
+
+        Ok(Proof { round_1, round_2 })
     }
 
     fn diagonals_from_chunks(
@@ -187,14 +173,12 @@ where
             let mut tmp_product2 = Scalar::zero();
             for i in d..m {
                 let dot = self
-                    .statement
                     .bilinear_map
                     .compute_mapping(&a_chunks[i - d], &b_chunks[i])
                     .unwrap();
                 tmp_product1 = tmp_product1 + dot;
 
                 let dot = self
-                    .statement
                     .bilinear_map
                     .compute_mapping(&a_chunks[i], &b_chunks[i - d])
                     .unwrap();
@@ -208,12 +192,7 @@ where
         let product: Scalar = a_chunks
             .iter()
             .zip(b_chunks.iter())
-            .map(|(a_i, b_i)| {
-                self.statement
-                    .bilinear_map
-                    .compute_mapping(a_i, b_i)
-                    .unwrap()
-            })
+            .map(|(a_i, b_i)| self.bilinear_map.compute_mapping(a_i, b_i).unwrap())
             .sum();
 
         diagonal_sums[center] = product;
@@ -222,3 +201,147 @@ where
         Ok(diagonal_sums)
     }
 }
+
+impl<'a, Scalar, Comm> ProverRound1<'a, Scalar, Comm>
+where
+    Scalar: Field,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+{
+    pub fn round_2(
+        self,
+        challenge: &Round1Challenge<Scalar>,
+    ) -> Result<Round2Message<Scalar>, CryptoError> {
+        let prover = &self.prover;
+        let parameters = &prover.parameters;
+
+        // Precompute all powers of the challenge from 0 to number_of_diagonals of the extended matrix
+        let challenge_powers = scalar_powers(challenge.x, 2 * parameters.m);
+
+        let first_m_non_zero_powers = challenge_powers[1..parameters.m + 1].to_vec();
+        let mut first_m_non_zero_powers_reversed = first_m_non_zero_powers[..].to_vec();
+        first_m_non_zero_powers_reversed.reverse();
+
+        // a1[0]x + a2[0]x^2 ... am[0]x^m
+        // a1[1]x + a2[1]x^2 ... am[1]x^m
+        // a1[2]x + a2[2]x^2 ... am[2]x^m
+        // a1[3]x + a2[3]x^2 ... am[3]x^m
+        // ...
+        // a1[n]x + a2[n]x^2 ... am[n]x^m = b[n]
+        let mut a_blinded: Vec<Scalar> = Vec::with_capacity(parameters.m + 1);
+        for i in 0..parameters.n {
+            let mut poly = self.a_0[i];
+            for j in 0..parameters.m {
+                poly = poly + prover.matrix_a[j][i] * first_m_non_zero_powers[j];
+            }
+            a_blinded.push(poly);
+        }
+
+        let mut b_blinded: Vec<Scalar> = Vec::with_capacity(parameters.m + 1);
+        for i in 0..parameters.n {
+            let mut poly = self.b_m[i];
+            for j in 0..parameters.m {
+                poly = poly + prover.matrix_b[j][i] * first_m_non_zero_powers_reversed[j];
+            }
+            b_blinded.push(poly);
+        }
+
+        let r_blinded =
+            self.r_0 + dot_product(&prover.randoms_for_a_commit, &first_m_non_zero_powers)?;
+        let s_blinded = dot_product(
+            &prover.randoms_for_b_commit,
+            &first_m_non_zero_powers_reversed,
+        )? + self.s_m;
+        let t_blinded = dot_product(&self.t, &challenge_powers)?;
+
+        Ok(Round2Message {
+            a_blinded,
+            b_blinded,
+            r_blinded,
+            s_blinded,
+            t_blinded,
+        })
+    }
+}
+
+impl<'a, C> Prover<'a, C::ScalarField, PedersenCommitment<C>>
+where
+    C: ProjectiveCurve,
+{
+    pub fn prove_compressed<R: Rng, T: Transcript>(
+        self,
+        rng: &mut R,
+        transcript: &mut T,
+    ) -> Result<CompressedProof<C>, CryptoError> {
+        let (prover, round_1) = self.round_1(rng)?;
+        let x = challenge(
+            &prover.prover.parameters,
+            &prover.prover.statement(),
+            &round_1,
+            transcript,
+        )?;
+        let (round_2, inner_product_prover) = prover.compressed_round_2(&x)?;
+        let inner_product_proof = inner_product_prover.prove(transcript)?;
+
+        Ok(CompressedProof {
+            round_1,
+            round_2,
+            inner_product_proof,
+        })
+    }
+}
+
+impl<'a, C> ProverRound1<'a, C::ScalarField, PedersenCommitment<C>>
+where
+    C: ProjectiveCurve,
+{
+    /// Second round of the compressed zero argument. Instead of sending the blinded vectors, the prover sends the
+    /// value of the bilinear map on them and hands over to an inner product argument proving it.
+    pub fn compressed_round_2(
+        self,
+        challenge: &Round1Challenge<C::ScalarField>,
+    ) -> Result<
+        (
+            CompressedRound2Message<C>,
+            inner_product::prover::Prover<'a, C>,
+        ),
+        CryptoError,
+    > {
+        let commit_key = self.prover.parameters.commit_key;
+        let bilinear_map = self.prover.bilinear_map.clone();
+        let Round2Message {
+            a_blinded,
+            b_blinded,
+            r_blinded,
+            s_blinded,
+            t_blinded,
+        } = self.round_2(challenge)?;
+
+        let zero = C::ScalarField::zero();
+        let commits = PedersenCommitment::commit_columns(
+            commit_key,
+            &[&a_blinded[..], &b_blinded[..]],
+            &[zero, zero],
+        )?;
+        let (commitment_to_a, commitment_to_b) = (commits[0], commits[1]);
+        let a_star_b = bilinear_map.compute_mapping(&a_blinded, &b_blinded)?;
+
+        let inner_product_prover = inner_product::prover::Prover {
+            parameters: Cow::Borrowed(commit_key),
+            commitment_to_a,
+            commitment_to_b,
+            weights: mapping_weights(bilinear_map),
+            value: a_star_b,
+            a: Cow::Owned(a_blinded),
+            b: Cow::Owned(b_blinded),
+        };
+
+        let message = CompressedRound2Message {
+            r_blinded,
+            s_blinded,
+            t_blinded,
+            a_star_b,
+        };
+
+        Ok((message, inner_product_prover))
+    }
+}
@@ -2,6 +2,7 @@
 mod test {
     use crate::error::CryptoError;
     use crate::utils::rand::sample_vector;
+    use crate::utils::validation::deserialize_validated;
     use crate::utils::vector_arithmetic::reshape;
     use crate::vector_commitment::{pedersen, HomomorphicCommitmentScheme};
     use crate::zkp::{arguments::zero_value_bilinear_map, ArgumentOfKnowledge};
@@ -9,6 +10,7 @@ mod test {
     use super::super::YMapping;
     use ark_ff::Zero;
     use ark_marlin::rng::FiatShamirRng;
+    use ark_serialize::CanonicalSerialize;
     use ark_std::{rand::thread_rng, UniformRand};
     use blake2::Blake2s;
     use starknet_curve;
@@ -23,6 +25,8 @@ mod test {
     type Witness<'a> = zero_value_bilinear_map::Witness<'a, Scalar>;
     type Statement<'a> = zero_value_bilinear_map::Statement<'a, Scalar, Comm>;
     type ZeroValueArgument<'a> = zero_value_bilinear_map::ZeroValueArgument<'a, Scalar, Comm>;
+    type CompressedZeroValueArgument<'a> =
+        zero_value_bilinear_map::CompressedZeroValueArgument<'a, Curve>;
     type Parameters<'a> = zero_value_bilinear_map::Parameters<'a, Scalar, Comm>;
 
     // Fiat Shamir
@@ -108,4 +112,93 @@ mod test {
             ZeroValueArgument::verify(&proof_parameters, &statement, &invalid_proof, &mut fs_rng)
         );
     }
+
+    #[test]
+    fn test_compressed_zero_argument() {
+        let m = 4;
+        let n = 13;
+
+        let rng = &mut thread_rng();
+
+        let commit_key = Comm::setup(rng, n);
+
+        let random_scalars: Vec<Scalar> = sample_vector(rng, m * n);
+        let a_chunks = reshape(&random_scalars, m, n).unwrap();
+
+        let r: Vec<Scalar> = sample_vector(rng, a_chunks.len());
+        let a_commits = a_chunks
+            .iter()
+            .zip(r.iter())
+            .map(|(a_chunk, &random)| Comm::commit(&commit_key, a_chunk, random).unwrap())
+            .collect::<Vec<_>>();
+
+        let zeros = vec![Scalar::zero(); m * n];
+        let b_chunks = zeros.chunks(n).map(|c| c.to_vec()).collect::<Vec<_>>();
+
+        let s: Vec<Scalar> = sample_vector(rng, a_chunks.len());
+        let b_commits = b_chunks
+            .iter()
+            .zip(s.iter())
+            .map(|(b_chunk, &random)| Comm::commit(&commit_key, b_chunk, random).unwrap())
+            .collect::<Vec<_>>();
+
+        let proof_parameters = Parameters::new(m, n, &commit_key);
+
+        let y = Scalar::rand(rng);
+        let test_mapping = YMapping::new(y, n);
+
+        let statement = Statement::new(&a_commits, &b_commits, &test_mapping);
+
+        let valid_witness = Witness::new(&a_chunks, &r, &b_chunks, &s);
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let valid_proof = CompressedZeroValueArgument::prove(
+            rng,
+            &proof_parameters,
+            &statement,
+            &valid_witness,
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        let mut bytes = Vec::new();
+        valid_proof.serialize_unchecked(&mut bytes).unwrap();
+        let received_proof = deserialize_validated(&bytes[..]).unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            Ok(()),
+            CompressedZeroValueArgument::verify(
+                &proof_parameters,
+                &statement,
+                &received_proof,
+                &mut fs_rng
+            )
+        );
+
+        let bad_witness = Witness::new(&a_chunks, &r, &a_chunks, &r);
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let invalid_proof = CompressedZeroValueArgument::prove(
+            rng,
+            &proof_parameters,
+            &statement,
+            &bad_witness,
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            Err(CryptoError::ProofVerificationError(String::from(
+                "Zero Argument (5.2)",
+            ))),
+            CompressedZeroValueArgument::verify(
+                &proof_parameters,
+                &statement,
+                &invalid_proof,
+                &mut fs_rng
+            )
+        );
+    }
 }
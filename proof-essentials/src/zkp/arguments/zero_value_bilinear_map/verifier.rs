@@ -0,0 +1,244 @@
+use super::messages::{CompressedRound2Message, Round1Challenge, Round1Message, Round2Message};
+use super::proof::{challenge, CompressedProof, Proof};
+use super::{mapping_weights, BilinearMap, Parameters, Statement, YMapping};
+
+use crate::error::CryptoError;
+use crate::utils::vector_arithmetic::msm_dot_product;
+use crate::vector_commitment::{pedersen::PedersenCommitment, HomomorphicCommitmentScheme};
+use crate::zkp::arguments::batch::{check_commitment_equations, CommitmentEquation};
+use crate::zkp::arguments::{inner_product, scalar_powers};
+use crate::zkp::transcript::Transcript;
+
+use ark_ec::ProjectiveCurve;
+use ark_ff::Field;
+use std::borrow::Cow;
+
+/// Verifier of the zero argument. It borrows the statement, except when another argument hands over to it with
+/// commitments derived during its own rounds, which the verifier then owns.
+pub struct Verifier<'a, Scalar, Comm>
+where
+    Scalar: Field,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+{
+    pub(crate) parameters: Parameters<'a, Scalar, Comm>,
+    pub(crate) commitment_to_a: Cow<'a, [Comm::Commitment]>,
+    pub(crate) commitment_to_b: Cow<'a, [Comm::Commitment]>,
+    pub(crate) bilinear_map: Cow<'a, YMapping<Scalar>>,
+}
+
+/// Verifier of the zero argument once the first message is received. Holds the combinations of the commitments
+/// that the openings of the second round are checked against.
+pub struct VerifierRound1<'a, Scalar, Comm>
+where
+    Scalar: Field,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+{
+    verifier: Verifier<'a, Scalar, Comm>,
+    commitment_to_a_blinded: Comm::Commitment,
+    commitment_to_b_blinded: Comm::Commitment,
+    commitment_to_diagonals: Comm::Commitment,
+}
+
+impl<'a, Scalar, Comm> Verifier<'a, Scalar, Comm>
+where
+    Scalar: Field,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+{
+    pub fn new(
+        parameters: &Parameters<'a, Scalar, Comm>,
+        statement: &Statement<'a, Scalar, Comm>,
+    ) -> Self {
+        Self {
+            parameters: Parameters::new(parameters.m, parameters.n, parameters.commit_key),
+            commitment_to_a: Cow::Borrowed(statement.commitment_to_a),
+            commitment_to_b: Cow::Borrowed(statement.commitment_to_b),
+            bilinear_map: Cow::Borrowed(statement.bilinear_map),
+        }
+    }
+
+    fn statement(&self) -> Statement<'_, Scalar, Comm> {
+        Statement::new(
+            &self.commitment_to_a,
+            &self.commitment_to_b,
+            &self.bilinear_map,
+        )
+    }
+
+    pub fn round_1(
+        self,
+        message: &Round1Message<Scalar, Comm>,
+        challenge: &Round1Challenge<Scalar>,
+    ) -> Result<VerifierRound1<'a, Scalar, Comm>, CryptoError> {
+        let m = self.parameters.m;
+        let diagonals = &message.vector_of_committed_diagonals;
+
+        if diagonals.len() != 2 * m + 1
+            || diagonals[m + 1]
+                != Comm::commit(
+                    self.parameters.commit_key,
+                    &[Scalar::zero()],
+                    Scalar::zero(),
+                )?
+        {
+            return Err(CryptoError::ProofVerificationError(String::from(
+                "Zero Argument (5.2)",
+            )));
+        }
+
+        // Precompute all powers of the challenge from 0 to number_of_diagonals
+        let challenge_powers = scalar_powers(challenge.x, 2 * m);
+
+        let first_m_non_zero_powers = challenge_powers[1..m + 1].to_vec();
+        let mut first_m_non_zero_powers_reversed = first_m_non_zero_powers[..].to_vec();
+        first_m_non_zero_powers_reversed.reverse();
+
+        let commitment_to_a_blinded =
+            message.a_0_commit + msm_dot_product(&first_m_non_zero_powers, &self.commitment_to_a)?;
+        let commitment_to_b_blinded = message.b_m_commit
+            + msm_dot_product(&first_m_non_zero_powers_reversed, &self.commitment_to_b)?;
+        let commitment_to_diagonals = msm_dot_product(&challenge_powers, diagonals)?;
+
+        Ok(VerifierRound1 {
+            verifier: self,
+            commitment_to_a_blinded,
+            commitment_to_b_blinded,
+            commitment_to_diagonals,
+        })
+    }
+
+    pub fn verify<T: Transcript>(
+        self,
+        proof: &Proof<Scalar, Comm>,
+        transcript: &mut T,
+    ) -> Result<(), CryptoError> {
+        let x = challenge(
+            &self.parameters,
+            &self.statement(),
+            &proof.round_1,
+            transcript,
+        )?;
+
+        self.round_1(&proof.round_1, &x)?.round_2(&proof.round_2)
+    }
+
+    /// Run the verifier and return the equations of the last round instead of checking them.
+    pub(crate) fn equations<T: Transcript>(
+        self,
+        proof: &Proof<Scalar, Comm>,
+        transcript: &mut T,
+    ) -> Result<Vec<CommitmentEquation<Scalar, Comm>>, CryptoError> {
+        let x = challenge(
+            &self.parameters,
+            &self.statement(),
+            &proof.round_1,
+            transcript,
+        )?;
+
+        self.round_1(&proof.round_1, &x)?
+            .round_2_equations(&proof.round_2)
+    }
+}
+
+impl<'a, Scalar, Comm> VerifierRound1<'a, Scalar, Comm>
+where
+    Scalar: Field,
+    Comm: HomomorphicCommitmentScheme<Scalar>,
+{
+    pub fn round_2(self, message: &Round2Message<Scalar>) -> Result<(), CryptoError> {
+        let commit_key = self.verifier.parameters.commit_key;
+        let equations = self.round_2_equations(message)?;
+
+        check_commitment_equations(&equations, commit_key, "Zero Argument (5.2)")
+    }
+
+    /// Equations checked by the second round: the blinded vectors and the value of the bilinear map on them open
+    /// the combinations of the commitments computed in the first round.
+    pub(crate) fn round_2_equations(
+        self,
+        message: &Round2Message<Scalar>,
+    ) -> Result<Vec<CommitmentEquation<Scalar, Comm>>, CryptoError> {
+        let a_star_b = self
+            .verifier
+            .bilinear_map
+            .compute_mapping(&message.a_blinded, &message.b_blinded)?;
+
+        Ok(vec![
+            CommitmentEquation::new(
+                self.commitment_to_a_blinded,
+                message.a_blinded.clone(),
+                message.r_blinded,
+            ),
+            CommitmentEquation::new(
+                self.commitment_to_b_blinded,
+                message.b_blinded.clone(),
+                message.s_blinded,
+            ),
+            CommitmentEquation::new(
+                self.commitment_to_diagonals,
+                vec![a_star_b],
+                message.t_blinded,
+            ),
+        ])
+    }
+}
+
+impl<'a, C> Verifier<'a, C::ScalarField, PedersenCommitment<C>>
+where
+    C: ProjectiveCurve,
+{
+    pub fn verify_compressed<T: Transcript>(
+        self,
+        proof: &CompressedProof<C>,
+        transcript: &mut T,
+    ) -> Result<(), CryptoError> {
+        let x = challenge(
+            &self.parameters,
+            &self.statement(),
+            &proof.round_1,
+            transcript,
+        )?;
+
+        self.round_1(&proof.round_1, &x)?
+            .compressed_round_2(&proof.round_2)?
+            .verify(&proof.inner_product_proof, transcript)
+            .map_err(|_| CryptoError::ProofVerificationError(String::from("Zero Argument (5.2)")))
+    }
+}
+
+impl<'a, C> VerifierRound1<'a, C::ScalarField, PedersenCommitment<C>>
+where
+    C: ProjectiveCurve,
+{
+    /// Second round of the compressed zero argument. Checks the claimed value of the bilinear map against the
+    /// diagonals and hands over to an inner product argument proving it on the committed blinded vectors.
+    pub fn compressed_round_2(
+        self,
+        message: &CompressedRound2Message<C>,
+    ) -> Result<inner_product::verifier::Verifier<'a, C>, CryptoError> {
+        let commit_key = self.verifier.parameters.commit_key;
+
+        // Verify commitments to the diagonals against a commitment on the claimed bilinear_map(blinded a, blinded b)
+        let right = PedersenCommitment::commit(commit_key, &[message.a_star_b], message.t_blinded)?;
+        if self.commitment_to_diagonals != right {
+            return Err(CryptoError::ProofVerificationError(String::from(
+                "Zero Argument (5.2)",
+            )));
+        }
+
+        // Commitments to blinded a and blinded b without their blinding factors, i.e. <a, g> and <b, g>
+        let commitment_to_a = self.commitment_to_a_blinded
+            + PedersenCommitment::commit(commit_key, &[], -message.r_blinded)?;
+        let commitment_to_b = self.commitment_to_b_blinded
+            + PedersenCommitment::commit(commit_key, &[], -message.s_blinded)?;
+
+        Ok(inner_product::verifier::Verifier {
+            parameters: Cow::Borrowed(commit_key),
+            commitment_to_a,
+            commitment_to_b,
+            weights: mapping_weights(self.verifier.bilinear_map),
+            value: message.a_star_b,
+            rounds: Vec::new(),
+            challenges: Vec::new(),
+        })
+    }
+}
@@ -1,30 +1,34 @@
 use crate::error::CryptoError;
-use ark_marlin::rng::FiatShamirRng;
 use ark_std::rand::Rng;
-use digest::Digest;
+use transcript::Transcript;
 
 pub mod arguments;
 pub mod proofs;
 pub mod transcript;
 
+/// Non-interactive version of an argument, made with the Fiat-Shamir heuristic. Each argument in
+/// [`arguments`] also exposes its `prover::Prover` and `verifier::Verifier` as state machines, which exchange the
+/// messages of the `messages` module round by round and can be run interactively with challenges chosen by the
+/// verifier. Arguments built on top of other arguments hand over to the provers and verifiers of their
+/// sub-arguments once their own rounds are done.
 pub trait ArgumentOfKnowledge {
     type CommonReferenceString;
     type Statement;
     type Witness;
     type Proof;
 
-    fn prove<R: Rng, D: Digest>(
+    fn prove<R: Rng, T: Transcript>(
         rng: &mut R,
         common_reference_string: &Self::CommonReferenceString,
         statement: &Self::Statement,
         witness: &Self::Witness,
-        fs_rng: &mut FiatShamirRng<D>,
+        transcript: &mut T,
     ) -> Result<Self::Proof, CryptoError>;
 
-    fn verify<D: Digest>(
+    fn verify<T: Transcript>(
         common_reference_string: &Self::CommonReferenceString,
         statement: &Self::Statement,
         proof: &Self::Proof,
-        fs_rng: &mut FiatShamirRng<D>,
+        transcript: &mut T,
     ) -> Result<(), CryptoError>;
 }
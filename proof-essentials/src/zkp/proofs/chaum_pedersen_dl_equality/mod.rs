@@ -1,14 +1,15 @@
+pub mod messages;
 pub mod proof;
 pub mod prover;
+pub mod simulator;
 mod test;
+pub mod verifier;
 
 use crate::error::CryptoError;
-use crate::zkp::ArgumentOfKnowledge;
+use crate::zkp::{proofs::SigmaProtocol, transcript::Transcript, ArgumentOfKnowledge};
 use ark_ec::ProjectiveCurve;
-use ark_marlin::rng::FiatShamirRng;
 use ark_std::marker::PhantomData;
 use ark_std::rand::Rng;
-use digest::Digest;
 
 pub struct DLEquality<'a, C: ProjectiveCurve> {
     _group: PhantomData<&'a C>,
@@ -49,22 +50,120 @@ where
     type Witness = Witness<C>;
     type Proof = proof::Proof<C>;
 
-    fn prove<R: Rng, D: Digest>(
+    fn prove<R: Rng, T: Transcript>(
         rng: &mut R,
         common_reference_string: &Self::CommonReferenceString,
         statement: &Self::Statement,
         witness: &Self::Witness,
-        fs_rng: &mut FiatShamirRng<D>,
+        transcript: &mut T,
     ) -> Result<Self::Proof, CryptoError> {
-        prover::Prover::create_proof(rng, common_reference_string, statement, witness, fs_rng)
+        let prover = prover::Prover::new(common_reference_string, statement, witness);
+        let proof = prover.prove(rng, transcript)?;
+
+        Ok(proof)
     }
 
-    fn verify<D: Digest>(
+    fn verify<T: Transcript>(
         common_reference_string: &Self::CommonReferenceString,
         statement: &Self::Statement,
         proof: &Self::Proof,
-        fs_rng: &mut FiatShamirRng<D>,
+        transcript: &mut T,
+    ) -> Result<(), CryptoError> {
+        proof.verify(common_reference_string, statement, transcript)
+    }
+}
+
+impl<'a, C> SigmaProtocol for DLEquality<'a, C>
+where
+    C: ProjectiveCurve,
+{
+    type Parameters = Parameters<'a, C>;
+    type Statement = Statement<'a, C>;
+    type Witness = Witness<C>;
+    type Challenge = C::ScalarField;
+    type ProverState = C::ScalarField;
+    type Commitment = (C, C);
+    type Proof = proof::Proof<C>;
+
+    fn absorb_statement<T: Transcript>(
+        parameters: &Self::Parameters,
+        statement: &Self::Statement,
+        transcript: &mut T,
+    ) -> Result<(), CryptoError> {
+        transcript.domain_separator(Self::PROTOCOL_NAME);
+        transcript.append(b"g", parameters.g)?;
+        transcript.append(b"h", parameters.h)?;
+        transcript.append(b"a", statement.0)?;
+        transcript.append(b"b", statement.1)?;
+
+        Ok(())
+    }
+
+    fn absorb_commitment<T: Transcript>(
+        (a, b): &Self::Commitment,
+        transcript: &mut T,
+    ) -> Result<(), CryptoError> {
+        transcript.append(
+            b"random commits",
+            &C::batch_normalization_into_affine(&[*a, *b]),
+        )
+    }
+
+    fn commit<R: Rng>(
+        rng: &mut R,
+        parameters: &Self::Parameters,
+        _statement: &Self::Statement,
+        _witness: &Self::Witness,
+    ) -> Result<(Self::ProverState, Self::Commitment), CryptoError> {
+        Ok(prover::Prover::commit(rng, parameters))
+    }
+
+    fn respond(
+        witness: &Self::Witness,
+        state: Self::ProverState,
+        commitment: Self::Commitment,
+        challenge: &Self::Challenge,
+    ) -> Self::Proof {
+        prover::Prover::respond(witness, state, commitment, challenge)
+    }
+
+    fn simulate<R: Rng>(
+        rng: &mut R,
+        parameters: &Self::Parameters,
+        statement: &Self::Statement,
+        challenge: &Self::Challenge,
+    ) -> Result<Self::Proof, CryptoError> {
+        simulator::Simulator::simulate(rng, parameters, statement, challenge)
+    }
+
+    fn commitment(proof: &Self::Proof) -> Self::Commitment {
+        (proof.a, proof.b)
+    }
+
+    fn check(
+        parameters: &Self::Parameters,
+        statement: &Self::Statement,
+        proof: &Self::Proof,
+        challenge: &Self::Challenge,
     ) -> Result<(), CryptoError> {
-        proof.verify(common_reference_string, statement, fs_rng)
+        proof.check(parameters, statement, *challenge)
     }
 }
+
+impl<'a, C: ProjectiveCurve> DLEquality<'a, C> {
+    pub const PROTOCOL_NAME: &'static [u8] = b"Chaum-Pedersen DL Equality";
+}
+
+/// Absorb the public parameters, the statement and the commitments of the prover, and derive the challenge.
+pub(crate) fn challenge<C: ProjectiveCurve, T: Transcript>(
+    parameters: &Parameters<C>,
+    statement: &Statement<C>,
+    a: &C,
+    b: &C,
+    transcript: &mut T,
+) -> Result<C::ScalarField, CryptoError> {
+    DLEquality::<C>::absorb_statement(parameters, statement, transcript)?;
+    DLEquality::<C>::absorb_commitment(&(*a, *b), transcript)?;
+
+    Ok(transcript.challenge_scalar(b"challenge"))
+}
@@ -1,16 +1,18 @@
 use crate::error::CryptoError;
+use crate::utils::rand::sample_vector;
+use crate::utils::validation::{validate_projective_point, Validate, ValidatePoint};
 
-use super::{Parameters, Statement};
+use crate::zkp::transcript::Transcript;
 
-use ark_ec::{AffineCurve, ProjectiveCurve};
-use ark_ff::to_bytes;
-use ark_marlin::rng::FiatShamirRng;
+use super::{challenge, verifier::Verifier, Parameters, Statement};
+
+use ark_ec::{msm::VariableBaseMSM, AffineCurve, ProjectiveCurve};
+use ark_ff::PrimeField;
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
 use ark_std::io::{Read, Write};
-use ark_std::UniformRand;
-use digest::Digest;
+use ark_std::rand::Rng;
 
-#[derive(CanonicalDeserialize, CanonicalSerialize)]
+#[derive(Copy, Clone, CanonicalDeserialize, CanonicalSerialize, Debug, PartialEq, Eq)]
 pub struct Proof<C>
 where
     C: ProjectiveCurve,
@@ -21,23 +23,95 @@ where
 }
 
 impl<C: ProjectiveCurve> Proof<C> {
-    pub fn verify<D: Digest>(
+    pub fn verify<T: Transcript>(
         &self,
         parameters: &Parameters<C>,
         statement: &Statement<C>,
-        fs_rng: &mut FiatShamirRng<D>,
+        transcript: &mut T,
+    ) -> Result<(), CryptoError> {
+        Verifier::new(parameters, statement).verify(self, transcript)
+    }
+
+    /// Verify proofs for the same pair of generators at once. Both equations of every proof are combined with
+    /// random weights into a single multi-scalar multiplication, and each challenge is derived from its own
+    /// transcript. When the batch is rejected, the proofs are checked one by one to report the index of the first
+    /// invalid one.
+    pub fn batch_verify<R: Rng, T: Transcript>(
+        rng: &mut R,
+        parameters: &Parameters<C>,
+        statements: &[Statement<C>],
+        proofs: &[Self],
+        transcripts: &mut [T],
     ) -> Result<(), CryptoError> {
-        fs_rng.absorb(&to_bytes![
-            b"chaum_pedersen",
-            parameters.g,
-            parameters.h,
-            statement.0,
-            statement.1
-        ]?);
-        fs_rng.absorb(&to_bytes![&self.a, &self.b]?);
+        for len in [statements.len(), transcripts.len()] {
+            if len != proofs.len() {
+                return Err(CryptoError::BatchVerificationLengthError(proofs.len(), len));
+            }
+        }
+
+        let challenges = proofs
+            .iter()
+            .zip(statements.iter())
+            .zip(transcripts.iter_mut())
+            .map(|((proof, statement), transcript)| {
+                challenge(parameters, statement, &proof.a, &proof.b, transcript)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // sum rho_i * (g*r_i - a_i - x_i*c_i) + sigma_i * (h*r_i - b_i - y_i*c_i) ==? 0
+        let rho: Vec<C::ScalarField> = sample_vector(rng, proofs.len());
+        let sigma: Vec<C::ScalarField> = sample_vector(rng, proofs.len());
+        let random_commits = proofs
+            .iter()
+            .map(|proof| proof.a)
+            .chain(proofs.iter().map(|proof| proof.b))
+            .collect::<Vec<_>>();
 
-        let c = C::ScalarField::rand(fs_rng);
+        let mut bases = vec![*parameters.g, *parameters.h];
+        bases.extend(statements.iter().map(|statement| *statement.0));
+        bases.extend(statements.iter().map(|statement| *statement.1));
+        bases.extend(C::batch_normalization_into_affine(&random_commits));
 
+        let weighted_sum = |weights: &[C::ScalarField]| {
+            weights
+                .iter()
+                .zip(proofs.iter())
+                .map(|(w, proof)| *w * proof.r)
+                .sum::<C::ScalarField>()
+        };
+        let mut scalars = vec![weighted_sum(&rho), weighted_sum(&sigma)];
+        for weights in [&rho, &sigma] {
+            scalars.extend(weights.iter().zip(challenges.iter()).map(|(w, c)| -*w * c));
+        }
+        scalars.extend(rho.iter().chain(sigma.iter()).map(|w| -*w));
+
+        let scalars = scalars.iter().map(|s| s.into_repr()).collect::<Vec<_>>();
+        if VariableBaseMSM::multi_scalar_mul(&bases, &scalars).is_zero() {
+            return Ok(());
+        }
+
+        // Locate the invalid proof
+        for (i, ((proof, statement), c)) in proofs
+            .iter()
+            .zip(statements.iter())
+            .zip(challenges)
+            .enumerate()
+        {
+            proof.check(parameters, statement, c).map_err(|_| {
+                CryptoError::BatchProofVerificationError(String::from("Chaum-Pedersen"), i)
+            })?;
+        }
+
+        Ok(())
+    }
+
+    /// Check the proof against a challenge.
+    pub(crate) fn check(
+        &self,
+        parameters: &Parameters<C>,
+        statement: &Statement<C>,
+        c: C::ScalarField,
+    ) -> Result<(), CryptoError> {
         // g * r ==? a + x*c
         if parameters.g.mul(self.r) != self.a + statement.0.mul(c) {
             return Err(CryptoError::ProofVerificationError(String::from(
@@ -55,3 +129,16 @@ impl<C: ProjectiveCurve> Proof<C> {
         Ok(())
     }
 }
+
+impl<C> Validate for Proof<C>
+where
+    C: ProjectiveCurve,
+    C::Affine: ValidatePoint,
+{
+    fn validate(&self) -> Result<(), CryptoError> {
+        validate_projective_point(&self.a)?;
+        validate_projective_point(&self.b)?;
+
+        Ok(())
+    }
+}
@@ -1,55 +1,128 @@
 use crate::error::CryptoError;
 
+use super::messages::{Round1Challenge, Round1Message, Round2Message};
 use super::proof::Proof;
-use super::{Parameters, Statement, Witness};
+use super::{challenge, Parameters, Statement, Witness};
+use crate::zkp::transcript::Transcript;
 
 use ark_ec::{AffineCurve, ProjectiveCurve};
-use ark_ff::{to_bytes, PrimeField};
-use ark_marlin::rng::FiatShamirRng;
+use ark_ff::PrimeField;
 use ark_std::{rand::Rng, UniformRand};
-use digest::Digest;
 
-use std::marker::PhantomData;
+/// Prover waiting for the challenge, with the random commits.
+type Round1<'a, C> = (ProverRound1<'a, C>, Round1Message<C>);
 
-pub struct Prover<C>
+pub struct Prover<'a, C>
 where
     C: ProjectiveCurve,
 {
-    phantom: PhantomData<C>,
+    parameters: Parameters<'a, C>,
+    statement: Statement<'a, C>,
+    witness: Witness<C>,
 }
 
-impl<C> Prover<C>
+/// Prover of the Chaum-Pedersen proof once the random commits are sent, waiting for the challenge.
+pub struct ProverRound1<'a, C>
 where
     C: ProjectiveCurve,
 {
-    pub fn create_proof<R: Rng, D: Digest>(
-        rng: &mut R,
-        parameters: &Parameters<C>,
-        statement: &Statement<C>,
+    prover: Prover<'a, C>,
+    omega: C::ScalarField,
+    a: C,
+    b: C,
+}
+
+impl<'a, C> Prover<'a, C>
+where
+    C: ProjectiveCurve,
+{
+    pub fn new(
+        parameters: &Parameters<'a, C>,
+        statement: &Statement<'a, C>,
         witness: &Witness<C>,
-        fs_rng: &mut FiatShamirRng<D>,
+    ) -> Self {
+        Self {
+            parameters: *parameters,
+            statement: *statement,
+            witness: *witness,
+        }
+    }
+
+    pub fn round_1<R: Rng>(self, rng: &mut R) -> Result<Round1<'a, C>, CryptoError> {
+        let (omega, (a, b)) = Self::commit(rng, &self.parameters);
+
+        Ok((
+            ProverRound1 {
+                prover: self,
+                omega,
+                a,
+                b,
+            },
+            Round1Message { a, b },
+        ))
+    }
+
+    pub fn prove<R: Rng, T: Transcript>(
+        self,
+        rng: &mut R,
+        transcript: &mut T,
     ) -> Result<Proof<C>, CryptoError> {
-        fs_rng.absorb(
-            &to_bytes![
-                b"chaum_pedersen",
-                parameters.g,
-                parameters.h,
-                statement.0,
-                statement.1
-            ]
-            .unwrap(),
-        );
+        let (prover, round_1) = self.round_1(rng)?;
+        let c = challenge(
+            &prover.prover.parameters,
+            &prover.prover.statement,
+            &round_1.a,
+            &round_1.b,
+            transcript,
+        )?;
 
+        let round_2 = prover.round_2(&Round1Challenge { c });
+
+        Ok(Proof {
+            a: round_1.a,
+            b: round_1.b,
+            r: round_2.r,
+        })
+    }
+
+    pub(crate) fn commit<R: Rng>(
+        rng: &mut R,
+        parameters: &Parameters<C>,
+    ) -> (C::ScalarField, (C, C)) {
         let omega = C::ScalarField::rand(rng);
         let a = parameters.g.mul(omega.into_repr());
         let b = parameters.h.mul(omega.into_repr());
 
-        fs_rng.absorb(&to_bytes![a, b]?);
+        (omega, (a, b))
+    }
+
+    pub(crate) fn respond(
+        witness: &Witness<C>,
+        omega: C::ScalarField,
+        (a, b): (C, C),
+        c: &C::ScalarField,
+    ) -> Proof<C> {
+        let r = omega + *c * *witness;
 
-        let c = C::ScalarField::rand(fs_rng);
+        Proof { a, b, r }
+    }
+}
 
-        let r = omega + c * *witness;
+impl<'a, C> ProverRound1<'a, C>
+where
+    C: ProjectiveCurve,
+{
+    pub fn round_2(
+        self,
+        challenge: &Round1Challenge<C::ScalarField>,
+    ) -> Round2Message<C::ScalarField> {
+        let Proof { r, .. } = Prover::respond(
+            &self.prover.witness,
+            self.omega,
+            (self.a, self.b),
+            &challenge.c,
+        );
 
-        Ok(Proof { a, b, r })
+        Round2Message { r }
     }
 }
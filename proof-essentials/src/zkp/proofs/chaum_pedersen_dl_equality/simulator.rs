@@ -0,0 +1,38 @@
+use crate::error::CryptoError;
+
+use super::{proof::Proof, Parameters, Statement};
+
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::PrimeField;
+use ark_std::{rand::Rng, UniformRand};
+
+use std::marker::PhantomData;
+
+pub struct Simulator<C>
+where
+    C: ProjectiveCurve,
+{
+    phantom: PhantomData<C>,
+}
+
+impl<C> Simulator<C>
+where
+    C: ProjectiveCurve,
+{
+    /// Output a proof for the challenge c without the discrete logarithm, by sampling the response first and
+    /// solving both verification equations for the random commits.
+    pub fn simulate<R: Rng>(
+        rng: &mut R,
+        parameters: &Parameters<C>,
+        statement: &Statement<C>,
+        c: &C::ScalarField,
+    ) -> Result<Proof<C>, CryptoError> {
+        let r = C::ScalarField::rand(rng);
+
+        // a = g*r - x*c and b = h*r - y*c
+        let a = parameters.g.mul(r.into_repr()) - statement.0.mul(c.into_repr());
+        let b = parameters.h.mul(r.into_repr()) - statement.1.mul(c.into_repr());
+
+        Ok(Proof { a, b, r })
+    }
+}
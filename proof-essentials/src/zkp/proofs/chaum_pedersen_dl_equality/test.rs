@@ -2,8 +2,13 @@
 mod test {
 
     use crate::error::CryptoError;
+    use crate::utils::rand::sample_vector;
     use crate::zkp::proofs::chaum_pedersen_dl_equality;
+    use crate::zkp::proofs::chaum_pedersen_dl_equality::messages::Round1Challenge;
+    use crate::zkp::proofs::chaum_pedersen_dl_equality::simulator::Simulator;
     use crate::zkp::proofs::chaum_pedersen_dl_equality::DLEquality;
+    use crate::zkp::proofs::chaum_pedersen_dl_equality::{prover::Prover, verifier::Verifier};
+    use crate::zkp::proofs::SigmaProtocol;
     use crate::zkp::ArgumentOfKnowledge;
     use ark_ec::{AffineCurve, ProjectiveCurve};
     use ark_marlin::rng::FiatShamirRng;
@@ -16,6 +21,8 @@ mod test {
     type Curve = starknet_curve::Projective;
     type Scalar = starknet_curve::Fr;
     type Parameters<'a> = chaum_pedersen_dl_equality::Parameters<'a, Curve>;
+    type Statement<'a> = chaum_pedersen_dl_equality::Statement<'a, Curve>;
+    type Proof = chaum_pedersen_dl_equality::proof::Proof<Curve>;
     type FS = FiatShamirRng<Blake2s>;
 
     fn setup<R: Rng>(rng: &mut R) -> (AffinePoint, AffinePoint) {
@@ -104,4 +111,135 @@ mod test {
             )))
         );
     }
+
+    #[test]
+    fn test_batch_verification() {
+        let (mut rng, g, h, _) = test_template();
+        let number_of_proofs = 8;
+
+        let secrets: Vec<Scalar> = sample_vector(&mut rng, number_of_proofs);
+        let points = secrets
+            .iter()
+            .map(|secret| (g.mul(*secret).into_affine(), h.mul(*secret).into_affine()))
+            .collect::<Vec<_>>();
+
+        let crs = Parameters::new(&g, &h);
+        let statements = points
+            .iter()
+            .map(|(point_a, point_b)| Statement::new(point_a, point_b))
+            .collect::<Vec<_>>();
+
+        let mut proofs = secrets
+            .iter()
+            .zip(statements.iter())
+            .map(|(secret, statement)| {
+                let mut fs_rng = FS::from_seed(b"Initialised with some input");
+                DLEquality::<Curve>::prove(&mut rng, &crs, statement, secret, &mut fs_rng).unwrap()
+            })
+            .collect::<Vec<_>>();
+
+        let transcripts = || {
+            (0..number_of_proofs)
+                .map(|_| FS::from_seed(b"Initialised with some input"))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            Proof::batch_verify(&mut rng, &crs, &statements, &proofs, &mut transcripts()),
+            Ok(())
+        );
+
+        // An invalid proof is located when the batch is rejected
+        let another_scalar = Scalar::rand(&mut rng);
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        proofs[2] = DLEquality::<Curve>::prove(
+            &mut rng,
+            &crs,
+            &statements[2],
+            &another_scalar,
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        assert_eq!(
+            Proof::batch_verify(&mut rng, &crs, &statements, &proofs, &mut transcripts()),
+            Err(CryptoError::BatchProofVerificationError(
+                String::from("Chaum-Pedersen"),
+                2
+            ))
+        );
+
+        assert_eq!(
+            Proof::batch_verify(
+                &mut rng,
+                &crs,
+                &statements,
+                &proofs,
+                &mut transcripts()[1..]
+            ),
+            Err(CryptoError::BatchVerificationLengthError(
+                number_of_proofs,
+                number_of_proofs - 1
+            ))
+        );
+    }
+
+    #[test]
+    fn test_simulator() {
+        let (mut rng, g, h, _) = test_template();
+
+        // Points with distinct discrete logarithms
+        let point_a = Curve::rand(&mut rng).into_affine();
+        let point_b = Curve::rand(&mut rng).into_affine();
+
+        let crs = Parameters::new(&g, &h);
+        let statement = Statement::new(&point_a, &point_b);
+        let c = Scalar::rand(&mut rng);
+
+        let proof = Simulator::simulate(&mut rng, &crs, &statement, &c).unwrap();
+        assert_eq!(DLEquality::check(&crs, &statement, &proof, &c), Ok(()));
+
+        let another_challenge = Scalar::rand(&mut rng);
+        assert_eq!(
+            DLEquality::check(&crs, &statement, &proof, &another_challenge),
+            Err(CryptoError::ProofVerificationError(String::from(
+                "Chaum-Pedersen"
+            )))
+        );
+    }
+
+    #[test]
+    fn test_interactive_rounds() {
+        let (mut rng, g, h, secret) = test_template();
+
+        let point_a = g.mul(secret).into_affine();
+        let point_b = h.mul(secret).into_affine();
+
+        let crs = Parameters::new(&g, &h);
+        let statement = Statement::new(&point_a, &point_b);
+
+        let (prover, round_1) = Prover::new(&crs, &statement, &secret)
+            .round_1(&mut rng)
+            .unwrap();
+        let challenge = Round1Challenge::rand(&mut rng);
+        let round_2 = prover.round_2(&challenge);
+
+        let verifier = Verifier::new(&crs, &statement).round_1(&round_1, &challenge);
+        assert_eq!(verifier.round_2(&round_2), Ok(()));
+
+        // A different discrete logarithm cannot answer the challenge
+        let another_secret = Scalar::rand(&mut rng);
+        let (prover, round_1) = Prover::new(&crs, &statement, &another_secret)
+            .round_1(&mut rng)
+            .unwrap();
+        let round_2 = prover.round_2(&challenge);
+
+        let verifier = Verifier::new(&crs, &statement).round_1(&round_1, &challenge);
+        assert_eq!(
+            verifier.round_2(&round_2),
+            Err(CryptoError::ProofVerificationError(String::from(
+                "Chaum-Pedersen"
+            )))
+        );
+    }
 }
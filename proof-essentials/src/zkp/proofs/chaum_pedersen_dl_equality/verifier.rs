@@ -0,0 +1,82 @@
+use super::messages::{Round1Challenge, Round1Message, Round2Message};
+use super::{challenge, proof::Proof, Parameters, Statement};
+
+use crate::error::CryptoError;
+use crate::zkp::transcript::Transcript;
+
+use ark_ec::ProjectiveCurve;
+
+pub struct Verifier<'a, C>
+where
+    C: ProjectiveCurve,
+{
+    parameters: Parameters<'a, C>,
+    statement: Statement<'a, C>,
+}
+
+/// Verifier of the Chaum-Pedersen proof once the random commits are received and the challenge is sent.
+pub struct VerifierRound1<'a, C>
+where
+    C: ProjectiveCurve,
+{
+    verifier: Verifier<'a, C>,
+    a: C,
+    b: C,
+    c: C::ScalarField,
+}
+
+impl<'a, C> Verifier<'a, C>
+where
+    C: ProjectiveCurve,
+{
+    pub fn new(parameters: &Parameters<'a, C>, statement: &Statement<'a, C>) -> Self {
+        Self {
+            parameters: *parameters,
+            statement: *statement,
+        }
+    }
+
+    pub fn round_1(
+        self,
+        message: &Round1Message<C>,
+        challenge: &Round1Challenge<C::ScalarField>,
+    ) -> VerifierRound1<'a, C> {
+        VerifierRound1 {
+            verifier: self,
+            a: message.a,
+            b: message.b,
+            c: challenge.c,
+        }
+    }
+
+    pub fn verify<T: Transcript>(
+        self,
+        proof: &Proof<C>,
+        transcript: &mut T,
+    ) -> Result<(), CryptoError> {
+        let c = challenge(
+            &self.parameters,
+            &self.statement,
+            &proof.a,
+            &proof.b,
+            transcript,
+        )?;
+
+        proof.check(&self.parameters, &self.statement, c)
+    }
+}
+
+impl<'a, C> VerifierRound1<'a, C>
+where
+    C: ProjectiveCurve,
+{
+    pub fn round_2(self, message: &Round2Message<C::ScalarField>) -> Result<(), CryptoError> {
+        let proof = Proof {
+            a: self.a,
+            b: self.b,
+            r: message.r,
+        };
+
+        proof.check(&self.verifier.parameters, &self.verifier.statement, self.c)
+    }
+}
@@ -0,0 +1,45 @@
+use crate::error::CryptoError;
+use crate::utils::validation::{Validate, ValidatePoint};
+use crate::vector_commitment::pedersen::Commitment;
+
+use ark_ec::ProjectiveCurve;
+use ark_ff::Field;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::io::{Read, Write};
+use ark_std::{rand::Rng, UniformRand};
+
+/// First message of the prover: a commitment to random masks for the vector and the blinding factor.
+#[derive(Clone, Copy, Debug, PartialEq, CanonicalDeserialize, CanonicalSerialize)]
+pub struct Round1Message<C: ProjectiveCurve> {
+    pub masking_commitment: Commitment<C>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, CanonicalDeserialize, CanonicalSerialize)]
+pub struct Round1Challenge<Scalar: Field> {
+    pub c: Scalar,
+}
+
+/// Second message of the prover: the masks plus the challenge times the opening.
+#[derive(Clone, Debug, PartialEq, CanonicalDeserialize, CanonicalSerialize)]
+pub struct Round2Message<Scalar: Field> {
+    pub z: Vec<Scalar>,
+    pub z_r: Scalar,
+}
+
+impl<Scalar: Field> UniformRand for Round1Challenge<Scalar> {
+    fn rand<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Self {
+            c: Scalar::rand(rng),
+        }
+    }
+}
+
+impl<C> Validate for Round1Message<C>
+where
+    C: ProjectiveCurve,
+    C::Affine: ValidatePoint,
+{
+    fn validate(&self) -> Result<(), CryptoError> {
+        self.masking_commitment.validate()
+    }
+}
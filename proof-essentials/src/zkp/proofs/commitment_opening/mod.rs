@@ -0,0 +1,231 @@
+pub mod messages;
+pub mod proof;
+pub mod prover;
+pub mod simulator;
+mod test;
+pub mod verifier;
+
+use crate::error::CryptoError;
+use crate::vector_commitment::pedersen::{CommitKey, Commitment};
+use crate::zkp::{proofs::SigmaProtocol, transcript::Transcript, ArgumentOfKnowledge};
+use ark_ec::ProjectiveCurve;
+use ark_ff::Zero;
+use ark_std::marker::PhantomData;
+use ark_std::rand::Rng;
+
+/// Proof of knowledge of an opening (x, r) of a Pedersen vector commitment `C = <x, g> + r*h`. The prover
+/// commits to random masks `A = <a, g> + s*h` and answers a challenge c with `z = a + c*x` and `z_r = s + c*r`,
+/// which the verifier checks as `<z, g> + z_r*h == A + c*C`. The opening is padded with zeros to the length of the
+/// commit key, so the response contains one scalar per generator, as does the response of the simulator.
+pub struct CommitmentOpening<'a, C: ProjectiveCurve> {
+    _group: PhantomData<&'a C>,
+}
+
+/// Compressed variant of [`CommitmentOpening`]. Instead of sending the response z, the prover shows knowledge of
+/// z by repeatedly folding it in half along with the commit key, as in the inner product argument of
+/// Bulletproofs. The proof then contains `2 * ceil(log2(n))` points and a constant number of other elements.
+pub struct CompressedCommitmentOpening<'a, C: ProjectiveCurve> {
+    _group: PhantomData<&'a C>,
+}
+
+pub type Parameters<C> = CommitKey<C>;
+
+pub type Statement<C> = Commitment<C>;
+
+/// Masks of the committed vector and of the blinding factor, sampled by the prover.
+type Masks<C> = (
+    Vec<<C as ProjectiveCurve>::ScalarField>,
+    <C as ProjectiveCurve>::ScalarField,
+);
+
+/// Opening of a Pedersen commitment: the committed vector and the blinding factor.
+#[derive(Copy, Clone)]
+pub struct Witness<'a, C: ProjectiveCurve> {
+    pub x: &'a [C::ScalarField],
+    pub r: &'a C::ScalarField,
+}
+
+impl<'a, C: ProjectiveCurve> Witness<'a, C> {
+    pub fn new(x: &'a [C::ScalarField], r: &'a C::ScalarField) -> Self {
+        Self { x, r }
+    }
+}
+
+impl<'a, C: ProjectiveCurve> ArgumentOfKnowledge for CommitmentOpening<'a, C> {
+    type CommonReferenceString = Parameters<C>;
+    type Statement = Statement<C>;
+    type Witness = Witness<'a, C>;
+    type Proof = proof::Proof<C>;
+
+    fn prove<R: Rng, T: Transcript>(
+        rng: &mut R,
+        common_reference_string: &Self::CommonReferenceString,
+        statement: &Self::Statement,
+        witness: &Self::Witness,
+        transcript: &mut T,
+    ) -> Result<Self::Proof, CryptoError> {
+        let prover = prover::Prover::new(common_reference_string, statement, witness);
+        let proof = prover.prove(rng, transcript)?;
+
+        Ok(proof)
+    }
+
+    fn verify<T: Transcript>(
+        common_reference_string: &Self::CommonReferenceString,
+        statement: &Self::Statement,
+        proof: &Self::Proof,
+        transcript: &mut T,
+    ) -> Result<(), CryptoError> {
+        proof.verify(common_reference_string, statement, transcript)
+    }
+}
+
+impl<'a, C: ProjectiveCurve> SigmaProtocol for CommitmentOpening<'a, C> {
+    type Parameters = Parameters<C>;
+    type Statement = Statement<C>;
+    type Witness = Witness<'a, C>;
+    type Challenge = C::ScalarField;
+    type ProverState = Masks<C>;
+    type Commitment = Commitment<C>;
+    type Proof = proof::Proof<C>;
+
+    fn absorb_statement<T: Transcript>(
+        parameters: &Self::Parameters,
+        statement: &Self::Statement,
+        transcript: &mut T,
+    ) -> Result<(), CryptoError> {
+        transcript.domain_separator(b"Commitment Opening");
+        transcript.append(b"commit key", parameters)?;
+        transcript.append(b"commitment", statement)?;
+
+        Ok(())
+    }
+
+    fn absorb_commitment<T: Transcript>(
+        commitment: &Self::Commitment,
+        transcript: &mut T,
+    ) -> Result<(), CryptoError> {
+        transcript.append(b"masking commitment", commitment)
+    }
+
+    fn commit<R: Rng>(
+        rng: &mut R,
+        parameters: &Self::Parameters,
+        _statement: &Self::Statement,
+        witness: &Self::Witness,
+    ) -> Result<(Self::ProverState, Self::Commitment), CryptoError> {
+        prover::Prover::commit(rng, parameters, witness)
+    }
+
+    fn respond(
+        witness: &Self::Witness,
+        state: Self::ProverState,
+        commitment: Self::Commitment,
+        challenge: &Self::Challenge,
+    ) -> Self::Proof {
+        prover::Prover::respond(witness, state, commitment, challenge)
+    }
+
+    fn simulate<R: Rng>(
+        rng: &mut R,
+        parameters: &Self::Parameters,
+        statement: &Self::Statement,
+        challenge: &Self::Challenge,
+    ) -> Result<Self::Proof, CryptoError> {
+        simulator::Simulator::simulate(rng, parameters, statement, challenge)
+    }
+
+    fn commitment(proof: &Self::Proof) -> Self::Commitment {
+        proof.masking_commitment
+    }
+
+    fn check(
+        parameters: &Self::Parameters,
+        statement: &Self::Statement,
+        proof: &Self::Proof,
+        challenge: &Self::Challenge,
+    ) -> Result<(), CryptoError> {
+        proof.check(parameters, statement, challenge)
+    }
+}
+
+impl<'a, C: ProjectiveCurve> ArgumentOfKnowledge for CompressedCommitmentOpening<'a, C> {
+    type CommonReferenceString = Parameters<C>;
+    type Statement = Statement<C>;
+    type Witness = Witness<'a, C>;
+    type Proof = proof::CompressedProof<C>;
+
+    fn prove<R: Rng, T: Transcript>(
+        rng: &mut R,
+        common_reference_string: &Self::CommonReferenceString,
+        statement: &Self::Statement,
+        witness: &Self::Witness,
+        transcript: &mut T,
+    ) -> Result<Self::Proof, CryptoError> {
+        prover::Prover::create_compressed_proof(
+            rng,
+            common_reference_string,
+            statement,
+            witness,
+            transcript,
+        )
+    }
+
+    fn verify<T: Transcript>(
+        common_reference_string: &Self::CommonReferenceString,
+        statement: &Self::Statement,
+        proof: &Self::Proof,
+        transcript: &mut T,
+    ) -> Result<(), CryptoError> {
+        proof.verify(common_reference_string, statement, transcript)
+    }
+}
+
+/// Absorb the commit key, the statement and the prover's masking commitment, and derive the challenge.
+pub(crate) fn challenge<C: ProjectiveCurve, T: Transcript>(
+    parameters: &Parameters<C>,
+    statement: &Statement<C>,
+    masking_commitment: &Commitment<C>,
+    transcript: &mut T,
+) -> Result<C::ScalarField, CryptoError> {
+    CommitmentOpening::<C>::absorb_statement(parameters, statement, transcript)?;
+    CommitmentOpening::<C>::absorb_commitment(masking_commitment, transcript)?;
+
+    Ok(transcript.challenge_scalar(b"challenge"))
+}
+
+/// Absorb the cross terms of a folding round of the compressed proof, and derive the folding challenge.
+pub(crate) fn folding_challenge<C: ProjectiveCurve, T: Transcript>(
+    round: usize,
+    l: &C::Affine,
+    r: &C::Affine,
+    transcript: &mut T,
+) -> Result<C::ScalarField, CryptoError> {
+    transcript.round(round as u64);
+    transcript.append(b"l", l)?;
+    transcript.append(b"r", r)?;
+
+    Ok(transcript.challenge_scalar(b"folding challenge"))
+}
+
+/// Generators used by the compressed proof for `rounds` folding rounds: the first `2^rounds` generators of the
+/// commit key, padded with the identity if the key is shorter.
+pub(crate) fn padded_generators<C: ProjectiveCurve>(
+    parameters: &Parameters<C>,
+    rounds: usize,
+) -> Vec<C::Affine> {
+    let len = 1 << rounds;
+    let g = parameters.g();
+
+    g.iter()
+        .take(len)
+        .copied()
+        .chain(std::iter::repeat(C::Affine::zero()))
+        .take(len)
+        .collect()
+}
+
+/// Number of folding rounds needed for a vector of length `n`.
+pub(crate) fn rounds(n: usize) -> usize {
+    n.next_power_of_two().trailing_zeros() as usize
+}
@@ -0,0 +1,151 @@
+use crate::error::CryptoError;
+use crate::utils::validation::{validate_point, Validate, ValidatePoint};
+use crate::vector_commitment::pedersen::{Commitment, PedersenCommitment};
+use crate::vector_commitment::HomomorphicCommitmentScheme;
+
+use crate::zkp::transcript::Transcript;
+
+use super::{
+    challenge, folding_challenge, padded_generators, verifier::Verifier, Parameters, Statement,
+};
+
+use ark_ec::{msm::VariableBaseMSM, ProjectiveCurve};
+use ark_ff::{Field, One, PrimeField};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::io::{Read, Write};
+
+#[derive(Clone, CanonicalDeserialize, CanonicalSerialize, Debug, PartialEq)]
+pub struct Proof<C>
+where
+    C: ProjectiveCurve,
+{
+    pub(crate) masking_commitment: Commitment<C>,
+    pub(crate) z: Vec<C::ScalarField>,
+    pub(crate) z_r: C::ScalarField,
+}
+
+#[derive(Clone, CanonicalDeserialize, CanonicalSerialize, Debug, PartialEq)]
+pub struct CompressedProof<C>
+where
+    C: ProjectiveCurve,
+{
+    pub(crate) masking_commitment: Commitment<C>,
+    pub(crate) z_r: C::ScalarField,
+    pub(crate) l: Vec<C::Affine>,
+    pub(crate) r: Vec<C::Affine>,
+    pub(crate) z: C::ScalarField,
+}
+
+impl<C: ProjectiveCurve> Proof<C> {
+    pub fn verify<T: Transcript>(
+        &self,
+        parameters: &Parameters<C>,
+        statement: &Statement<C>,
+        transcript: &mut T,
+    ) -> Result<(), CryptoError> {
+        Verifier::new(parameters, statement).verify(self, transcript)
+    }
+
+    /// Check the proof against a challenge.
+    pub(crate) fn check(
+        &self,
+        parameters: &Parameters<C>,
+        statement: &Statement<C>,
+        c: &C::ScalarField,
+    ) -> Result<(), CryptoError> {
+        // <z, g> + z_r*h ==? A + c*C
+        if PedersenCommitment::commit(parameters, &self.z, self.z_r)?
+            != self.masking_commitment + *statement * *c
+        {
+            return Err(CryptoError::ProofVerificationError(String::from(
+                "Commitment Opening",
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+impl<C: ProjectiveCurve> CompressedProof<C> {
+    pub fn verify<T: Transcript>(
+        &self,
+        parameters: &Parameters<C>,
+        statement: &Statement<C>,
+        transcript: &mut T,
+    ) -> Result<(), CryptoError> {
+        let rounds = self.l.len();
+
+        // Every folded vector beyond the length of the commit key would only multiply the identity
+        if self.r.len() != rounds || (rounds > 0 && 1 << (rounds - 1) >= parameters.g().len()) {
+            return Err(CryptoError::ProofVerificationError(String::from(
+                "Compressed Commitment Opening",
+            )));
+        }
+
+        let c = challenge(parameters, statement, &self.masking_commitment, transcript)?;
+
+        let mut challenges = Vec::with_capacity(rounds);
+        for (j, (l_i, r_i)) in self.l.iter().zip(self.r.iter()).enumerate() {
+            challenges.push(folding_challenge::<C, _>(j + 1, l_i, r_i, transcript)?);
+        }
+
+        // After all rounds, the folded generator is <s, g> where s_i is the product of the challenges of the
+        // rounds in which the i-th generator was in the lower half
+        let mut s = vec![C::ScalarField::one()];
+        for x in &challenges {
+            s = s.iter().flat_map(|s_i| [*s_i * x, *s_i]).collect();
+        }
+
+        // The folded commitment is L_j + x_j*P_j + x_j^2*R_j at each round, starting from P_0 = A + c*C - z_r*h.
+        // suffix[j] is the product of the challenges of the rounds after j.
+        let mut suffix = vec![C::ScalarField::one(); rounds + 1];
+        for j in (0..rounds).rev() {
+            suffix[j] = suffix[j + 1] * challenges[j];
+        }
+        let product = suffix[0];
+
+        // <z*s, g> - sum suffix[j+1]*(L_j + x_j^2*R_j) - product*(A + c*C - z_r*h) ==? 0
+        let mut bases = padded_generators(parameters, rounds);
+        let mut scalars = s.iter().map(|s_i| self.z * s_i).collect::<Vec<_>>();
+
+        bases.extend_from_slice(&self.l);
+        scalars.extend((0..rounds).map(|j| -suffix[j + 1]));
+
+        bases.extend_from_slice(&self.r);
+        scalars.extend((0..rounds).map(|j| -suffix[j + 1] * challenges[j].square()));
+
+        bases.extend_from_slice(&[self.masking_commitment.0, statement.0, *parameters.h()]);
+        scalars.extend_from_slice(&[-product, -product * c, product * self.z_r]);
+
+        let scalars = scalars.iter().map(|s| s.into_repr()).collect::<Vec<_>>();
+        if !VariableBaseMSM::multi_scalar_mul(&bases, &scalars).is_zero() {
+            return Err(CryptoError::ProofVerificationError(String::from(
+                "Compressed Commitment Opening",
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+impl<C> Validate for Proof<C>
+where
+    C: ProjectiveCurve,
+    C::Affine: ValidatePoint,
+{
+    fn validate(&self) -> Result<(), CryptoError> {
+        validate_point(&self.masking_commitment.0)
+    }
+}
+
+impl<C> Validate for CompressedProof<C>
+where
+    C: ProjectiveCurve,
+    C::Affine: ValidatePoint,
+{
+    fn validate(&self) -> Result<(), CryptoError> {
+        validate_point(&self.masking_commitment.0)?;
+        self.l.iter().try_for_each(validate_point)?;
+        self.r.iter().try_for_each(validate_point)
+    }
+}
@@ -0,0 +1,238 @@
+use crate::error::CryptoError;
+use crate::utils::rand::sample_vector;
+use crate::vector_commitment::pedersen::{Commitment, PedersenCommitment};
+use crate::vector_commitment::HomomorphicCommitmentScheme;
+
+use super::messages::{Round1Challenge, Round1Message, Round2Message};
+use super::proof::{CompressedProof, Proof};
+use super::{
+    challenge, folding_challenge, padded_generators, rounds, Masks, Parameters, Statement, Witness,
+};
+use crate::zkp::transcript::Transcript;
+
+use ark_ec::{msm::VariableBaseMSM, AffineCurve, ProjectiveCurve};
+use ark_ff::{PrimeField, Zero};
+use ark_std::{rand::Rng, UniformRand};
+
+/// Prover waiting for the challenge, with the masking commitment.
+type Round1<'a, C> = (ProverRound1<'a, C>, Round1Message<C>);
+
+pub struct Prover<'a, C>
+where
+    C: ProjectiveCurve,
+{
+    parameters: &'a Parameters<C>,
+    statement: Statement<C>,
+    witness: Witness<'a, C>,
+}
+
+/// Prover of the commitment opening proof once the masking commitment is sent, waiting for the challenge.
+pub struct ProverRound1<'a, C>
+where
+    C: ProjectiveCurve,
+{
+    prover: Prover<'a, C>,
+    masks: Masks<C>,
+    masking_commitment: Commitment<C>,
+}
+
+impl<'a, C> Prover<'a, C>
+where
+    C: ProjectiveCurve,
+{
+    pub fn new(
+        parameters: &'a Parameters<C>,
+        statement: &Statement<C>,
+        witness: &Witness<'a, C>,
+    ) -> Self {
+        Self {
+            parameters,
+            statement: *statement,
+            witness: *witness,
+        }
+    }
+
+    pub fn round_1<R: Rng>(self, rng: &mut R) -> Result<Round1<'a, C>, CryptoError> {
+        let (masks, masking_commitment) = Self::commit(rng, self.parameters, &self.witness)?;
+
+        Ok((
+            ProverRound1 {
+                prover: self,
+                masks,
+                masking_commitment,
+            },
+            Round1Message { masking_commitment },
+        ))
+    }
+
+    pub fn prove<R: Rng, T: Transcript>(
+        self,
+        rng: &mut R,
+        transcript: &mut T,
+    ) -> Result<Proof<C>, CryptoError> {
+        let (prover, round_1) = self.round_1(rng)?;
+        let c = challenge(
+            prover.prover.parameters,
+            &prover.prover.statement,
+            &round_1.masking_commitment,
+            transcript,
+        )?;
+
+        let Round2Message { z, z_r } = prover.round_2(&Round1Challenge { c });
+
+        Ok(Proof {
+            masking_commitment: round_1.masking_commitment,
+            z,
+            z_r,
+        })
+    }
+
+    /// Commit to random masks for the vector and the blinding factor of the witness. The vector is masked as if it
+    /// was padded with zeros to the length of the commit key, so that the response has the same length as a
+    /// simulated one and does not reveal the length of the opening.
+    pub(crate) fn commit<R: Rng>(
+        rng: &mut R,
+        parameters: &Parameters<C>,
+        witness: &Witness<C>,
+    ) -> Result<(Masks<C>, Commitment<C>), CryptoError> {
+        let n = parameters.g().len();
+        if witness.x.len() > n {
+            return Err(CryptoError::CommitmentLengthError(
+                String::from("Pedersen"),
+                witness.x.len(),
+                n,
+            ));
+        }
+
+        Self::commit_to_masks(rng, parameters, n)
+    }
+
+    /// Commit to `n` random masks for the vector and to a random mask for the blinding factor.
+    fn commit_to_masks<R: Rng>(
+        rng: &mut R,
+        parameters: &Parameters<C>,
+        n: usize,
+    ) -> Result<(Masks<C>, Commitment<C>), CryptoError> {
+        let masks: Vec<C::ScalarField> = sample_vector(rng, n);
+        let s = C::ScalarField::rand(rng);
+        let masking_commitment = PedersenCommitment::commit(parameters, &masks, s)?;
+
+        Ok(((masks, s), masking_commitment))
+    }
+
+    pub(crate) fn respond(
+        witness: &Witness<C>,
+        (masks, s): Masks<C>,
+        masking_commitment: Commitment<C>,
+        c: &C::ScalarField,
+    ) -> Proof<C> {
+        // The entries of the mask beyond the opening answer for the zeros of the padding
+        let z = masks
+            .iter()
+            .zip(
+                witness
+                    .x
+                    .iter()
+                    .chain(std::iter::repeat(&C::ScalarField::zero())),
+            )
+            .map(|(a, x)| *a + *c * x)
+            .collect();
+        let z_r = s + *c * witness.r;
+
+        Proof {
+            masking_commitment,
+            z,
+            z_r,
+        }
+    }
+
+    pub fn create_compressed_proof<R: Rng, T: Transcript>(
+        rng: &mut R,
+        parameters: &Parameters<C>,
+        statement: &Statement<C>,
+        witness: &Witness<C>,
+        transcript: &mut T,
+    ) -> Result<CompressedProof<C>, CryptoError> {
+        // The response of the linear proof is not sent but folded, so it only needs to be as long as the opening
+        let (masks, masking_commitment) = Self::commit_to_masks(rng, parameters, witness.x.len())?;
+
+        let c = challenge(parameters, statement, &masking_commitment, transcript)?;
+
+        let Proof {
+            masking_commitment,
+            mut z,
+            z_r,
+        } = Self::respond(witness, masks, masking_commitment, &c);
+
+        let rounds = rounds(z.len());
+        let mut g = padded_generators(parameters, rounds);
+        z.resize(g.len(), C::ScalarField::zero());
+
+        let mut l = Vec::with_capacity(rounds);
+        let mut r = Vec::with_capacity(rounds);
+
+        // Fold (z, g) into (z_lo + x*z_hi, x*g_lo + g_hi), so that <z', g'> = L + x*<z, g> + x^2*R
+        while z.len() > 1 {
+            let half = z.len() / 2;
+            let (z_lo, z_hi) = z.split_at(half);
+            let (g_lo, g_hi) = g.split_at(half);
+
+            let l_i = Self::inner_product(g_hi, z_lo).into_affine();
+            let r_i = Self::inner_product(g_lo, z_hi).into_affine();
+
+            let x = folding_challenge::<C, _>(l.len() + 1, &l_i, &r_i, transcript)?;
+
+            z = z_lo
+                .iter()
+                .zip(z_hi.iter())
+                .map(|(lo, hi)| *lo + x * hi)
+                .collect();
+
+            let folded_g = g_lo
+                .iter()
+                .zip(g_hi.iter())
+                .map(|(lo, hi)| {
+                    let mut point = lo.mul(x.into_repr());
+                    point.add_assign_mixed(hi);
+                    point
+                })
+                .collect::<Vec<_>>();
+            g = C::batch_normalization_into_affine(&folded_g);
+
+            l.push(l_i);
+            r.push(r_i);
+        }
+
+        Ok(CompressedProof {
+            masking_commitment,
+            z_r,
+            l,
+            r,
+            z: z[0],
+        })
+    }
+
+    fn inner_product(bases: &[C::Affine], scalars: &[C::ScalarField]) -> C {
+        let scalars = scalars.iter().map(|s| s.into_repr()).collect::<Vec<_>>();
+        VariableBaseMSM::multi_scalar_mul(bases, &scalars)
+    }
+}
+
+impl<'a, C> ProverRound1<'a, C>
+where
+    C: ProjectiveCurve,
+{
+    pub fn round_2(
+        self,
+        challenge: &Round1Challenge<C::ScalarField>,
+    ) -> Round2Message<C::ScalarField> {
+        let Proof { z, z_r, .. } = Prover::respond(
+            &self.prover.witness,
+            self.masks,
+            self.masking_commitment,
+            &challenge.c,
+        );
+
+        Round2Message { z, z_r }
+    }
+}
@@ -0,0 +1,46 @@
+use crate::error::CryptoError;
+use crate::utils::rand::sample_vector;
+use crate::vector_commitment::pedersen::PedersenCommitment;
+use crate::vector_commitment::HomomorphicCommitmentScheme;
+
+use super::{proof::Proof, Parameters, Statement};
+
+use ark_ec::ProjectiveCurve;
+use ark_std::{rand::Rng, UniformRand};
+
+use std::marker::PhantomData;
+
+pub struct Simulator<C>
+where
+    C: ProjectiveCurve,
+{
+    phantom: PhantomData<C>,
+}
+
+impl<C> Simulator<C>
+where
+    C: ProjectiveCurve,
+{
+    /// Output a proof for the challenge c without an opening, by sampling the responses first and solving the
+    /// verification equation for the masking commitment. The simulated response has one entry per generator of
+    /// the commit key, so it is distributed as a real proof of an opening of that length.
+    pub fn simulate<R: Rng>(
+        rng: &mut R,
+        parameters: &Parameters<C>,
+        statement: &Statement<C>,
+        c: &C::ScalarField,
+    ) -> Result<Proof<C>, CryptoError> {
+        let z: Vec<C::ScalarField> = sample_vector(rng, parameters.g().len());
+        let z_r = C::ScalarField::rand(rng);
+
+        // A = <z, g> + z_r*h - c*C
+        let masking_commitment =
+            PedersenCommitment::commit(parameters, &z, z_r)? + *statement * -*c;
+
+        Ok(Proof {
+            masking_commitment,
+            z,
+            z_r,
+        })
+    }
+}
@@ -0,0 +1,245 @@
+#[cfg(test)]
+mod test {
+
+    use crate::error::CryptoError;
+    use crate::utils::rand::sample_vector;
+    use crate::utils::validation::deserialize_validated;
+    use crate::vector_commitment::{pedersen, HomomorphicCommitmentScheme};
+    use crate::zkp::proofs::commitment_opening::messages::Round1Challenge;
+    use crate::zkp::proofs::commitment_opening::{prover::Prover, verifier::Verifier};
+    use crate::zkp::proofs::commitment_opening::{
+        simulator::Simulator, CommitmentOpening, CompressedCommitmentOpening, Witness,
+    };
+    use crate::zkp::proofs::SigmaProtocol;
+    use crate::zkp::ArgumentOfKnowledge;
+    use ark_ec::ProjectiveCurve;
+    use ark_marlin::rng::FiatShamirRng;
+    use ark_serialize::CanonicalSerialize;
+    use ark_std::{rand::thread_rng, UniformRand};
+    use blake2::Blake2s;
+    use starknet_curve;
+
+    type Curve = starknet_curve::Projective;
+    type Scalar = starknet_curve::Fr;
+    type Pedersen = pedersen::PedersenCommitment<Curve>;
+    type Opening<'a> = CommitmentOpening<'a, Curve>;
+    type CompressedOpening<'a> = CompressedCommitmentOpening<'a, Curve>;
+    type FS = FiatShamirRng<Blake2s>;
+
+    #[test]
+    fn test_honest_prover() {
+        let rng = &mut thread_rng();
+        let n = 13;
+
+        let commit_key = Pedersen::setup(rng, n);
+        let x: Vec<Scalar> = sample_vector(rng, n);
+        let r = Scalar::rand(rng);
+        let commitment = Pedersen::commit(&commit_key, &x, r).unwrap();
+        let witness = Witness::new(&x, &r);
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let proof = Opening::prove(rng, &commit_key, &commitment, &witness, &mut fs_rng).unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            Ok(()),
+            Opening::verify(&commit_key, &commitment, &proof, &mut fs_rng)
+        );
+    }
+
+    #[test]
+    fn test_malicious_prover() {
+        let rng = &mut thread_rng();
+        let n = 13;
+
+        let commit_key = Pedersen::setup(rng, n);
+        let x: Vec<Scalar> = sample_vector(rng, n);
+        let r = Scalar::rand(rng);
+        let commitment = Pedersen::commit(&commit_key, &x, r).unwrap();
+
+        let another_r = Scalar::rand(rng);
+        let witness = Witness::new(&x, &another_r);
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let proof = Opening::prove(rng, &commit_key, &commitment, &witness, &mut fs_rng).unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            Opening::verify(&commit_key, &commitment, &proof, &mut fs_rng),
+            Err(CryptoError::ProofVerificationError(String::from(
+                "Commitment Opening"
+            )))
+        );
+    }
+
+    #[test]
+    fn test_opening_shorter_than_commit_key() {
+        let rng = &mut thread_rng();
+        let n = 13;
+
+        let commit_key = Pedersen::setup(rng, n);
+        let x: Vec<Scalar> = sample_vector(rng, 5);
+        let r = Scalar::rand(rng);
+        let commitment = Pedersen::commit(&commit_key, &x, r).unwrap();
+        let witness = Witness::new(&x, &r);
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let proof = Opening::prove(rng, &commit_key, &commitment, &witness, &mut fs_rng).unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            Ok(()),
+            Opening::verify(&commit_key, &commitment, &proof, &mut fs_rng)
+        );
+
+        // The response has the length of a simulated one, whatever the length of the opening
+        let c = Scalar::rand(rng);
+        let simulated = Simulator::simulate(rng, &commit_key, &commitment, &c).unwrap();
+        assert_eq!(proof.z.len(), simulated.z.len());
+
+        let x: Vec<Scalar> = sample_vector(rng, n + 1);
+        let witness = Witness::new(&x, &r);
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            Opening::prove(rng, &commit_key, &commitment, &witness, &mut fs_rng).err(),
+            Some(CryptoError::CommitmentLengthError(
+                String::from("Pedersen"),
+                n + 1,
+                n
+            ))
+        );
+    }
+
+    #[test]
+    fn test_compressed_honest_prover() {
+        let rng = &mut thread_rng();
+        let commit_key = Pedersen::setup(rng, 16);
+
+        // Vectors of any length up to that of the commit key, including those which need padding
+        for n in [0, 1, 2, 5, 13, 16] {
+            let x: Vec<Scalar> = sample_vector(rng, n);
+            let r = Scalar::rand(rng);
+            let commitment = Pedersen::commit(&commit_key, &x, r).unwrap();
+            let witness = Witness::new(&x, &r);
+
+            let mut fs_rng = FS::from_seed(b"Initialised with some input");
+            let proof =
+                CompressedOpening::prove(rng, &commit_key, &commitment, &witness, &mut fs_rng)
+                    .unwrap();
+            assert_eq!(
+                proof.l.len(),
+                n.next_power_of_two().trailing_zeros() as usize
+            );
+
+            let mut bytes = Vec::new();
+            proof.serialize_unchecked(&mut bytes).unwrap();
+            let received_proof = deserialize_validated(&bytes[..]).unwrap();
+
+            let mut fs_rng = FS::from_seed(b"Initialised with some input");
+            assert_eq!(
+                Ok(()),
+                CompressedOpening::verify(&commit_key, &commitment, &received_proof, &mut fs_rng)
+            );
+        }
+    }
+
+    #[test]
+    fn test_compressed_malicious_prover() {
+        let rng = &mut thread_rng();
+        let n = 13;
+
+        let commit_key = Pedersen::setup(rng, n);
+        let x: Vec<Scalar> = sample_vector(rng, n);
+        let r = Scalar::rand(rng);
+        let commitment = Pedersen::commit(&commit_key, &x, r).unwrap();
+
+        let mut another_x = x.clone();
+        another_x[7] = Scalar::rand(rng);
+        let witness = Witness::new(&another_x, &r);
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let proof =
+            CompressedOpening::prove(rng, &commit_key, &commitment, &witness, &mut fs_rng).unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            CompressedOpening::verify(&commit_key, &commitment, &proof, &mut fs_rng),
+            Err(CryptoError::ProofVerificationError(String::from(
+                "Compressed Commitment Opening"
+            )))
+        );
+
+        // Tampering with a folding round of an honest proof is detected
+        let witness = Witness::new(&x, &r);
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let mut proof =
+            CompressedOpening::prove(rng, &commit_key, &commitment, &witness, &mut fs_rng).unwrap();
+        proof.l.swap(0, 1);
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_ne!(
+            Ok(()),
+            CompressedOpening::verify(&commit_key, &commitment, &proof, &mut fs_rng)
+        );
+    }
+
+    #[test]
+    fn test_simulator() {
+        let rng = &mut thread_rng();
+        let n = 13;
+
+        let commit_key = Pedersen::setup(rng, n);
+        let commitment = pedersen::Commitment(Curve::rand(rng).into_affine());
+        let c = Scalar::rand(rng);
+
+        let proof = Simulator::simulate(rng, &commit_key, &commitment, &c).unwrap();
+        assert_eq!(Opening::check(&commit_key, &commitment, &proof, &c), Ok(()));
+
+        let another_challenge = Scalar::rand(rng);
+        assert_eq!(
+            Opening::check(&commit_key, &commitment, &proof, &another_challenge),
+            Err(CryptoError::ProofVerificationError(String::from(
+                "Commitment Opening"
+            )))
+        );
+    }
+
+    #[test]
+    fn test_interactive_rounds() {
+        let rng = &mut thread_rng();
+        let n = 13;
+
+        let commit_key = Pedersen::setup(rng, n);
+        let x: Vec<Scalar> = sample_vector(rng, n);
+        let r = Scalar::rand(rng);
+        let commitment = Pedersen::commit(&commit_key, &x, r).unwrap();
+        let witness = Witness::new(&x, &r);
+
+        let (prover, round_1) = Prover::new(&commit_key, &commitment, &witness)
+            .round_1(rng)
+            .unwrap();
+        let challenge = Round1Challenge::rand(rng);
+        let round_2 = prover.round_2(&challenge);
+
+        let verifier = Verifier::new(&commit_key, &commitment).round_1(&round_1, &challenge);
+        assert_eq!(verifier.round_2(&round_2), Ok(()));
+
+        // Another blinding factor cannot answer the challenge
+        let another_r = Scalar::rand(rng);
+        let witness = Witness::new(&x, &another_r);
+
+        let (prover, round_1) = Prover::new(&commit_key, &commitment, &witness)
+            .round_1(rng)
+            .unwrap();
+        let round_2 = prover.round_2(&challenge);
+
+        let verifier = Verifier::new(&commit_key, &commitment).round_1(&round_1, &challenge);
+        assert_eq!(
+            verifier.round_2(&round_2),
+            Err(CryptoError::ProofVerificationError(String::from(
+                "Commitment Opening"
+            )))
+        );
+    }
+}
@@ -0,0 +1,80 @@
+use super::messages::{Round1Challenge, Round1Message, Round2Message};
+use super::{challenge, proof::Proof, Parameters, Statement};
+
+use crate::error::CryptoError;
+use crate::vector_commitment::pedersen::Commitment;
+use crate::zkp::transcript::Transcript;
+
+use ark_ec::ProjectiveCurve;
+
+pub struct Verifier<'a, C>
+where
+    C: ProjectiveCurve,
+{
+    parameters: &'a Parameters<C>,
+    statement: Statement<C>,
+}
+
+/// Verifier of the commitment opening proof once the masking commitment is received and the challenge is sent.
+pub struct VerifierRound1<'a, C>
+where
+    C: ProjectiveCurve,
+{
+    verifier: Verifier<'a, C>,
+    masking_commitment: Commitment<C>,
+    c: C::ScalarField,
+}
+
+impl<'a, C> Verifier<'a, C>
+where
+    C: ProjectiveCurve,
+{
+    pub fn new(parameters: &'a Parameters<C>, statement: &Statement<C>) -> Self {
+        Self {
+            parameters,
+            statement: *statement,
+        }
+    }
+
+    pub fn round_1(
+        self,
+        message: &Round1Message<C>,
+        challenge: &Round1Challenge<C::ScalarField>,
+    ) -> VerifierRound1<'a, C> {
+        VerifierRound1 {
+            verifier: self,
+            masking_commitment: message.masking_commitment,
+            c: challenge.c,
+        }
+    }
+
+    pub fn verify<T: Transcript>(
+        self,
+        proof: &Proof<C>,
+        transcript: &mut T,
+    ) -> Result<(), CryptoError> {
+        let c = challenge(
+            self.parameters,
+            &self.statement,
+            &proof.masking_commitment,
+            transcript,
+        )?;
+
+        proof.check(self.parameters, &self.statement, &c)
+    }
+}
+
+impl<'a, C> VerifierRound1<'a, C>
+where
+    C: ProjectiveCurve,
+{
+    pub fn round_2(self, message: &Round2Message<C::ScalarField>) -> Result<(), CryptoError> {
+        let proof = Proof {
+            masking_commitment: self.masking_commitment,
+            z: message.z.clone(),
+            z_r: message.z_r,
+        };
+
+        proof.check(self.verifier.parameters, &self.verifier.statement, &self.c)
+    }
+}
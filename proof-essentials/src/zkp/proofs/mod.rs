@@ -1,2 +1,74 @@
+use crate::error::CryptoError;
+use crate::zkp::transcript::Transcript;
+use ark_ff::Field;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::rand::Rng;
+
 pub mod chaum_pedersen_dl_equality;
+pub mod commitment_opening;
+pub mod or_composition;
+pub mod rerandomization;
 pub mod schnorr_identification;
+
+/// Three-move proof in which the prover sends a commitment, receives a random challenge and answers with a
+/// response. The proofs of this module are special honest-verifier zero-knowledge: given the challenge in advance,
+/// their simulator outputs a proof that passes the check without knowing a witness. The [`or_composition`] is
+/// built on top of this.
+pub trait SigmaProtocol {
+    type Parameters;
+    type Statement;
+    type Witness;
+    type Challenge: Field;
+    /// Randomness of the prover, kept between the commitment and the response.
+    type ProverState;
+    /// First message of the prover.
+    type Commitment: Clone;
+    /// Commitment and response of the prover.
+    type Proof: CanonicalSerialize + CanonicalDeserialize;
+
+    /// Absorb the public parameters and the statement, as the non-interactive proof does before the commitment.
+    fn absorb_statement<T: Transcript>(
+        parameters: &Self::Parameters,
+        statement: &Self::Statement,
+        transcript: &mut T,
+    ) -> Result<(), CryptoError>;
+
+    /// Absorb the first message of the prover.
+    fn absorb_commitment<T: Transcript>(
+        commitment: &Self::Commitment,
+        transcript: &mut T,
+    ) -> Result<(), CryptoError>;
+
+    fn commit<R: Rng>(
+        rng: &mut R,
+        parameters: &Self::Parameters,
+        statement: &Self::Statement,
+        witness: &Self::Witness,
+    ) -> Result<(Self::ProverState, Self::Commitment), CryptoError>;
+
+    fn respond(
+        witness: &Self::Witness,
+        state: Self::ProverState,
+        commitment: Self::Commitment,
+        challenge: &Self::Challenge,
+    ) -> Self::Proof;
+
+    /// Output a proof that passes the check against the given challenge, without a witness.
+    fn simulate<R: Rng>(
+        rng: &mut R,
+        parameters: &Self::Parameters,
+        statement: &Self::Statement,
+        challenge: &Self::Challenge,
+    ) -> Result<Self::Proof, CryptoError>;
+
+    /// First message of the prover contained in a proof.
+    fn commitment(proof: &Self::Proof) -> Self::Commitment;
+
+    /// Check a proof against a challenge.
+    fn check(
+        parameters: &Self::Parameters,
+        statement: &Self::Statement,
+        proof: &Self::Proof,
+        challenge: &Self::Challenge,
+    ) -> Result<(), CryptoError>;
+}
@@ -0,0 +1,83 @@
+pub mod proof;
+pub mod prover;
+mod test;
+
+use crate::error::CryptoError;
+use crate::zkp::{proofs::SigmaProtocol, transcript::Transcript, ArgumentOfKnowledge};
+use ark_std::marker::PhantomData;
+use ark_std::rand::Rng;
+
+/// Disjunctive composition of a Sigma protocol, as described by Cramer, Damgård and Schoenmakers: a proof that the
+/// prover knows a witness for one of several statements, which does not reveal which one. The prover simulates the
+/// proofs of the other statements on challenges of its choice and answers the remaining challenge honestly, so that
+/// the challenges of all the branches add up to the challenge of the verifier.
+pub struct OrComposition<'a, S: SigmaProtocol> {
+    _protocol: PhantomData<&'a S>,
+}
+
+pub type Parameters<S> = <S as SigmaProtocol>::Parameters;
+
+/// Statements of the branches, all sharing the same parameters.
+pub type Statement<'a, S> = &'a [<S as SigmaProtocol>::Statement];
+
+/// Witness for the statement at position `index`.
+pub struct Witness<'a, S: SigmaProtocol> {
+    pub index: usize,
+    pub witness: &'a S::Witness,
+}
+
+impl<'a, S: SigmaProtocol> Witness<'a, S> {
+    pub fn new(index: usize, witness: &'a S::Witness) -> Self {
+        Self { index, witness }
+    }
+}
+
+impl<'a, S: SigmaProtocol> ArgumentOfKnowledge for OrComposition<'a, S> {
+    type CommonReferenceString = Parameters<S>;
+    type Statement = Statement<'a, S>;
+    type Witness = Witness<'a, S>;
+    type Proof = proof::Proof<S>;
+
+    fn prove<R: Rng, T: Transcript>(
+        rng: &mut R,
+        common_reference_string: &Self::CommonReferenceString,
+        statement: &Self::Statement,
+        witness: &Self::Witness,
+        transcript: &mut T,
+    ) -> Result<Self::Proof, CryptoError> {
+        prover::Prover::create_proof(rng, common_reference_string, statement, witness, transcript)
+    }
+
+    fn verify<T: Transcript>(
+        common_reference_string: &Self::CommonReferenceString,
+        statement: &Self::Statement,
+        proof: &Self::Proof,
+        transcript: &mut T,
+    ) -> Result<(), CryptoError> {
+        proof.verify(common_reference_string, statement, transcript)
+    }
+}
+
+impl<'a, S: SigmaProtocol> OrComposition<'a, S> {
+    pub const PROTOCOL_NAME: &'static [u8] = b"OR Composition";
+}
+
+/// Absorb the public parameters, then the statement and the commitment of every branch, and derive the challenge
+/// that the challenges of the branches add up to.
+pub(crate) fn challenge<S: SigmaProtocol, T: Transcript>(
+    parameters: &Parameters<S>,
+    statement: &Statement<S>,
+    commitments: &[S::Commitment],
+    transcript: &mut T,
+) -> Result<S::Challenge, CryptoError> {
+    transcript.domain_separator(OrComposition::<S>::PROTOCOL_NAME);
+    transcript.append(b"branches", &(statement.len() as u64))?;
+
+    for (i, (branch, commitment)) in statement.iter().zip(commitments.iter()).enumerate() {
+        transcript.round(i as u64);
+        S::absorb_statement(parameters, branch, transcript)?;
+        S::absorb_commitment(commitment, transcript)?;
+    }
+
+    Ok(transcript.challenge_scalar(b"challenge"))
+}
@@ -0,0 +1,64 @@
+use crate::error::CryptoError;
+use crate::utils::validation::Validate;
+use crate::zkp::proofs::SigmaProtocol;
+use crate::zkp::transcript::Transcript;
+
+use super::{challenge, Parameters, Statement};
+
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::io::{Read, Write};
+
+#[derive(CanonicalDeserialize, CanonicalSerialize)]
+pub struct Proof<S>
+where
+    S: SigmaProtocol,
+{
+    pub(crate) proofs: Vec<S::Proof>,
+    pub(crate) challenges: Vec<S::Challenge>,
+}
+
+impl<S: SigmaProtocol> Proof<S> {
+    pub fn verify<T: Transcript>(
+        &self,
+        parameters: &Parameters<S>,
+        statement: &Statement<S>,
+        transcript: &mut T,
+    ) -> Result<(), CryptoError> {
+        if self.proofs.len() != statement.len() || self.challenges.len() != statement.len() {
+            return Err(CryptoError::ProofVerificationError(String::from(
+                "OR Composition",
+            )));
+        }
+
+        let commitments = self.proofs.iter().map(S::commitment).collect::<Vec<_>>();
+        let c = challenge::<S, T>(parameters, statement, &commitments, transcript)?;
+
+        // sum c_i ==? c
+        if self.challenges.iter().sum::<S::Challenge>() != c {
+            return Err(CryptoError::ProofVerificationError(String::from(
+                "OR Composition",
+            )));
+        }
+
+        for ((branch, proof), c_i) in statement
+            .iter()
+            .zip(self.proofs.iter())
+            .zip(self.challenges.iter())
+        {
+            S::check(parameters, branch, proof, c_i)
+                .map_err(|_| CryptoError::ProofVerificationError(String::from("OR Composition")))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<S> Validate for Proof<S>
+where
+    S: SigmaProtocol,
+    S::Proof: Validate,
+{
+    fn validate(&self) -> Result<(), CryptoError> {
+        self.proofs.iter().try_for_each(Validate::validate)
+    }
+}
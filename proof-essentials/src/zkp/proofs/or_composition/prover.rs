@@ -0,0 +1,81 @@
+use crate::error::CryptoError;
+use crate::utils::rand::sample_vector;
+
+use super::proof::Proof;
+use super::{challenge, Parameters, Statement, Witness};
+use crate::zkp::proofs::SigmaProtocol;
+use crate::zkp::transcript::Transcript;
+
+use ark_std::rand::Rng;
+
+use std::marker::PhantomData;
+
+pub struct Prover<S>
+where
+    S: SigmaProtocol,
+{
+    phantom: PhantomData<S>,
+}
+
+impl<S> Prover<S>
+where
+    S: SigmaProtocol,
+{
+    pub fn create_proof<R: Rng, T: Transcript>(
+        rng: &mut R,
+        parameters: &Parameters<S>,
+        statement: &Statement<S>,
+        witness: &Witness<S>,
+        transcript: &mut T,
+    ) -> Result<Proof<S>, CryptoError> {
+        let index = witness.index;
+        if index >= statement.len() {
+            return Err(CryptoError::OrCompositionIndexError(index, statement.len()));
+        }
+
+        // Simulate the branches without a witness on random challenges
+        let mut challenges: Vec<S::Challenge> = sample_vector(rng, statement.len());
+        let mut proofs = statement
+            .iter()
+            .zip(challenges.iter())
+            .enumerate()
+            .map(|(i, (branch, c_i))| match i == index {
+                true => Ok(None),
+                false => S::simulate(rng, parameters, branch, c_i).map(Some),
+            })
+            .collect::<Result<Vec<_>, CryptoError>>()?;
+
+        let (state, commitment) = S::commit(rng, parameters, &statement[index], witness.witness)?;
+
+        let commitments = proofs
+            .iter()
+            .map(|proof| match proof {
+                Some(proof) => S::commitment(proof),
+                None => commitment.clone(),
+            })
+            .collect::<Vec<_>>();
+
+        let c = challenge::<S, T>(parameters, statement, &commitments, transcript)?;
+
+        // The challenge of the real branch makes all the challenges add up to c
+        let simulated_challenges = challenges
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| *i != index)
+            .map(|(_, c_i)| *c_i)
+            .sum::<S::Challenge>();
+        challenges[index] = c - simulated_challenges;
+
+        proofs[index] = Some(S::respond(
+            witness.witness,
+            state,
+            commitment,
+            &challenges[index],
+        ));
+
+        Ok(Proof {
+            proofs: proofs.into_iter().flatten().collect(),
+            challenges,
+        })
+    }
+}
@@ -0,0 +1,320 @@
+#[cfg(test)]
+mod test {
+
+    use crate::error::CryptoError;
+    use crate::homomorphic_encryption::{el_gamal, HomomorphicEncryptionScheme};
+    use crate::utils::rand::sample_vector;
+    use crate::utils::validation::deserialize_validated;
+    use crate::vector_commitment::{pedersen, HomomorphicCommitmentScheme};
+    use crate::zkp::proofs::{
+        chaum_pedersen_dl_equality, commitment_opening, or_composition, rerandomization,
+        schnorr_identification,
+    };
+    use crate::zkp::ArgumentOfKnowledge;
+    use ark_ec::{AffineCurve, ProjectiveCurve};
+    use ark_ff::PrimeField;
+    use ark_marlin::rng::FiatShamirRng;
+    use ark_serialize::CanonicalSerialize;
+    use ark_std::{rand::thread_rng, UniformRand};
+    use blake2::Blake2s;
+    use starknet_curve;
+
+    type Curve = starknet_curve::Projective;
+    type Point = starknet_curve::Affine;
+    type Scalar = starknet_curve::Fr;
+    type Schnorr = schnorr_identification::SchnorrIdentification<Curve>;
+    type DLEquality<'a> = chaum_pedersen_dl_equality::DLEquality<'a, Curve>;
+    type SchnorrOr<'a> = or_composition::OrComposition<'a, Schnorr>;
+    type DLEqualityOr<'a> = or_composition::OrComposition<'a, DLEquality<'a>>;
+    type Opening<'a> = commitment_opening::CommitmentOpening<'a, Curve>;
+    type OpeningOr<'a> = or_composition::OrComposition<'a, Opening<'a>>;
+    type Rerandomization<'a> = rerandomization::Rerandomization<'a, Curve>;
+    type RerandomizationOr<'a> = or_composition::OrComposition<'a, Rerandomization<'a>>;
+    type Pedersen = pedersen::PedersenCommitment<Curve>;
+    type ElGamal = el_gamal::ElGamal<Curve>;
+    type Plaintext = el_gamal::Plaintext<Curve>;
+    type FS = FiatShamirRng<Blake2s>;
+
+    #[test]
+    fn test_one_of_many_secret_keys() {
+        let mut rng = thread_rng();
+        let number_of_keys = 5;
+
+        let crs = Curve::rand(&mut rng).into_affine();
+        let secret_keys: Vec<Scalar> = sample_vector(&mut rng, number_of_keys);
+        let public_keys = secret_keys
+            .iter()
+            .map(|sk| crs.mul(*sk).into_affine())
+            .collect::<Vec<_>>();
+        let statement = &public_keys[..];
+
+        for (index, sk) in secret_keys.iter().enumerate() {
+            let witness = or_composition::Witness::new(index, sk);
+
+            let mut fs_rng = FS::from_seed(b"Initialised with some input");
+            let proof =
+                SchnorrOr::prove(&mut rng, &crs, &statement, &witness, &mut fs_rng).unwrap();
+
+            let mut fs_rng = FS::from_seed(b"Initialised with some input");
+            assert_eq!(
+                SchnorrOr::verify(&crs, &statement, &proof, &mut fs_rng),
+                Ok(())
+            );
+        }
+
+        // A proof received from the prover goes through validated deserialization before verification
+        let witness = or_composition::Witness::new(3, &secret_keys[3]);
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let proof = SchnorrOr::prove(&mut rng, &crs, &statement, &witness, &mut fs_rng).unwrap();
+
+        let mut bytes = Vec::new();
+        proof.serialize_unchecked(&mut bytes).unwrap();
+        let received_proof = deserialize_validated(&bytes[..]).unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            SchnorrOr::verify(&crs, &statement, &received_proof, &mut fs_rng),
+            Ok(())
+        );
+
+        // The proof does not hold for other statements
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            SchnorrOr::verify(&crs, &&public_keys[1..], &proof, &mut fs_rng),
+            Err(CryptoError::ProofVerificationError(String::from(
+                "OR Composition"
+            )))
+        );
+
+        let mut other_keys = public_keys.clone();
+        other_keys.swap(0, 3);
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            SchnorrOr::verify(&crs, &&other_keys[..], &proof, &mut fs_rng),
+            Err(CryptoError::ProofVerificationError(String::from(
+                "OR Composition"
+            )))
+        );
+
+        let witness = or_composition::Witness::new(number_of_keys, &secret_keys[0]);
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            SchnorrOr::prove(&mut rng, &crs, &statement, &witness, &mut fs_rng).err(),
+            Some(CryptoError::OrCompositionIndexError(
+                number_of_keys,
+                number_of_keys
+            ))
+        );
+    }
+
+    #[test]
+    fn test_malicious_prover() {
+        let mut rng = thread_rng();
+        let number_of_keys = 3;
+
+        let crs = Curve::rand(&mut rng).into_affine();
+        let public_keys: Vec<Point> = (0..number_of_keys)
+            .map(|_| Curve::rand(&mut rng).into_affine())
+            .collect();
+        let statement = &public_keys[..];
+
+        let another_scalar = Scalar::rand(&mut rng);
+        let witness = or_composition::Witness::new(1, &another_scalar);
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let mut invalid_proof =
+            SchnorrOr::prove(&mut rng, &crs, &statement, &witness, &mut fs_rng).unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            SchnorrOr::verify(&crs, &statement, &invalid_proof, &mut fs_rng),
+            Err(CryptoError::ProofVerificationError(String::from(
+                "OR Composition"
+            )))
+        );
+
+        // Simulating every branch does not help, as the challenges must add up to the challenge of the verifier
+        invalid_proof.challenges = sample_vector(&mut rng, number_of_keys);
+        invalid_proof.proofs = public_keys
+            .iter()
+            .zip(invalid_proof.challenges.iter())
+            .map(|(pk, c)| {
+                schnorr_identification::simulator::Simulator::simulate(&mut rng, &crs, pk, c)
+                    .unwrap()
+            })
+            .collect();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            SchnorrOr::verify(&crs, &statement, &invalid_proof, &mut fs_rng),
+            Err(CryptoError::ProofVerificationError(String::from(
+                "OR Composition"
+            )))
+        );
+    }
+
+    #[test]
+    fn test_encryption_of_a_bit() {
+        let mut rng = thread_rng();
+
+        // ElGamal encryption (r*G, m*M + r*pk) of a bit m, with M the generator of the plaintexts
+        let generator = Curve::rand(&mut rng).into_affine();
+        let public_key = Curve::rand(&mut rng).into_affine();
+        let message_generator = Curve::rand(&mut rng);
+        let crs = chaum_pedersen_dl_equality::Parameters::new(&generator, &public_key);
+
+        for bit in [0u64, 1] {
+            let r = Scalar::rand(&mut rng);
+            let c1 = generator.mul(r).into_affine();
+            let c2 = public_key.mul(r) + message_generator.mul(Scalar::from(bit).into_repr());
+
+            // The ciphertext minus an encryption of m without randomness is (r*G, r*pk) for the right m
+            let c2_minus_zero = c2.into_affine();
+            let c2_minus_one = (c2 - message_generator).into_affine();
+            let branches = [
+                chaum_pedersen_dl_equality::Statement::new(&c1, &c2_minus_zero),
+                chaum_pedersen_dl_equality::Statement::new(&c1, &c2_minus_one),
+            ];
+            let statement = &branches[..];
+
+            let witness = or_composition::Witness::new(bit as usize, &r);
+            let mut fs_rng = FS::from_seed(b"Initialised with some input");
+            let proof =
+                DLEqualityOr::prove(&mut rng, &crs, &statement, &witness, &mut fs_rng).unwrap();
+
+            let mut fs_rng = FS::from_seed(b"Initialised with some input");
+            assert_eq!(
+                DLEqualityOr::verify(&crs, &statement, &proof, &mut fs_rng),
+                Ok(())
+            );
+
+            // Claiming the other bit fails
+            let witness = or_composition::Witness::new(1 - bit as usize, &r);
+            let mut fs_rng = FS::from_seed(b"Initialised with some input");
+            let invalid_proof =
+                DLEqualityOr::prove(&mut rng, &crs, &statement, &witness, &mut fs_rng).unwrap();
+
+            let mut fs_rng = FS::from_seed(b"Initialised with some input");
+            assert_eq!(
+                DLEqualityOr::verify(&crs, &statement, &invalid_proof, &mut fs_rng),
+                Err(CryptoError::ProofVerificationError(String::from(
+                    "OR Composition"
+                )))
+            );
+        }
+    }
+
+    #[test]
+    fn test_one_of_many_openings() {
+        let rng = &mut thread_rng();
+        let n = 8;
+        let number_of_commitments = 4;
+
+        // The known opening is shorter than the commit key
+        let commit_key = Pedersen::setup(rng, n);
+        let x: Vec<Scalar> = sample_vector(rng, n - 3);
+        let r = Scalar::rand(rng);
+
+        let mut commitments = (0..number_of_commitments)
+            .map(|_| pedersen::Commitment(Curve::rand(rng).into_affine()))
+            .collect::<Vec<_>>();
+
+        for index in 0..number_of_commitments {
+            let saved = commitments[index];
+            commitments[index] = Pedersen::commit(&commit_key, &x, r).unwrap();
+            let statement = &commitments[..];
+
+            let opening = commitment_opening::Witness::new(&x, &r);
+            let witness = or_composition::Witness::new(index, &opening);
+
+            let mut fs_rng = FS::from_seed(b"Initialised with some input");
+            let proof =
+                OpeningOr::prove(rng, &commit_key, &statement, &witness, &mut fs_rng).unwrap();
+
+            let mut fs_rng = FS::from_seed(b"Initialised with some input");
+            assert_eq!(
+                OpeningOr::verify(&commit_key, &statement, &proof, &mut fs_rng),
+                Ok(())
+            );
+
+            // The honest branch has the shape of the simulated ones, so it cannot be told apart
+            assert!(proof.proofs.iter().all(|branch| branch.z.len() == n));
+
+            commitments[index] = saved;
+        }
+
+        // Without an opening of any of the commitments, the proof fails
+        let statement = &commitments[..];
+        let opening = commitment_opening::Witness::new(&x, &r);
+        let witness = or_composition::Witness::new(0, &opening);
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let invalid_proof =
+            OpeningOr::prove(rng, &commit_key, &statement, &witness, &mut fs_rng).unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            OpeningOr::verify(&commit_key, &statement, &invalid_proof, &mut fs_rng),
+            Err(CryptoError::ProofVerificationError(String::from(
+                "OR Composition"
+            )))
+        );
+    }
+
+    #[test]
+    fn test_one_of_many_rerandomizations() {
+        let rng = &mut thread_rng();
+        let number_of_ciphertexts = 4;
+        let index = 2;
+
+        let pp = ElGamal::setup(rng).unwrap();
+        let (pk, _) = ElGamal::keygen(&pp, rng).unwrap();
+        let parameters = rerandomization::Parameters::new(&pp, &pk);
+
+        let original =
+            ElGamal::encrypt(&pp, &pk, &Plaintext::rand(rng), &Scalar::rand(rng)).unwrap();
+
+        // Only one of the candidates is a re-randomization of the original ciphertext
+        let r = Scalar::rand(rng);
+        let candidates = (0..number_of_ciphertexts)
+            .map(|i| {
+                if i == index {
+                    ElGamal::rerandomize(&pp, &pk, &original, &r).unwrap()
+                } else {
+                    ElGamal::encrypt(&pp, &pk, &Plaintext::rand(rng), &Scalar::rand(rng)).unwrap()
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let branches = candidates
+            .iter()
+            .map(|candidate| rerandomization::Statement::new(&original, candidate))
+            .collect::<Vec<_>>();
+        let statement = &branches[..];
+
+        let witness = or_composition::Witness::new(index, &r);
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let proof =
+            RerandomizationOr::prove(rng, &parameters, &statement, &witness, &mut fs_rng).unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            RerandomizationOr::verify(&parameters, &statement, &proof, &mut fs_rng),
+            Ok(())
+        );
+
+        // Pointing at another candidate fails
+        let witness = or_composition::Witness::new(index - 1, &r);
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let invalid_proof =
+            RerandomizationOr::prove(rng, &parameters, &statement, &witness, &mut fs_rng).unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            RerandomizationOr::verify(&parameters, &statement, &invalid_proof, &mut fs_rng),
+            Err(CryptoError::ProofVerificationError(String::from(
+                "OR Composition"
+            )))
+        );
+    }
+}
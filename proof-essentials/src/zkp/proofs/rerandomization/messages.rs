@@ -0,0 +1,45 @@
+use crate::error::CryptoError;
+use crate::utils::validation::{validate_projective_point, Validate, ValidatePoint};
+
+use ark_ec::ProjectiveCurve;
+use ark_ff::Field;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::io::{Read, Write};
+use ark_std::{rand::Rng, UniformRand};
+
+/// First message of the prover: an encryption of zero under a random scalar, (omega*G, omega*pk).
+#[derive(Clone, Copy, Debug, PartialEq, CanonicalDeserialize, CanonicalSerialize)]
+pub struct Round1Message<C: ProjectiveCurve> {
+    pub a: C,
+    pub b: C,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, CanonicalDeserialize, CanonicalSerialize)]
+pub struct Round1Challenge<Scalar: Field> {
+    pub c: Scalar,
+}
+
+/// Second message of the prover: the random scalar plus the challenge times the re-randomization factor.
+#[derive(Clone, Copy, Debug, PartialEq, CanonicalDeserialize, CanonicalSerialize)]
+pub struct Round2Message<Scalar: Field> {
+    pub r: Scalar,
+}
+
+impl<Scalar: Field> UniformRand for Round1Challenge<Scalar> {
+    fn rand<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Self {
+            c: Scalar::rand(rng),
+        }
+    }
+}
+
+impl<C> Validate for Round1Message<C>
+where
+    C: ProjectiveCurve,
+    C::Affine: ValidatePoint,
+{
+    fn validate(&self) -> Result<(), CryptoError> {
+        validate_projective_point(&self.a)?;
+        validate_projective_point(&self.b)
+    }
+}
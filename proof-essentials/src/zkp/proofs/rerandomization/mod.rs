@@ -0,0 +1,306 @@
+pub mod messages;
+pub mod proof;
+pub mod prover;
+pub mod simulator;
+mod test;
+pub mod verifier;
+
+use crate::error::CryptoError;
+use crate::homomorphic_encryption::el_gamal::{self, Ciphertext, PublicKey};
+use crate::zkp::{proofs::SigmaProtocol, transcript::Transcript, ArgumentOfKnowledge};
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::PrimeField;
+use ark_std::marker::PhantomData;
+use ark_std::rand::Rng;
+
+/// Proof that an ElGamal ciphertext is a re-randomization of another, i.e. that both encrypt the same
+/// plaintext under the same public key. This is a Chaum-Pedersen proof that the difference between the two
+/// ciphertexts is an encryption of zero: (c1' - c1, c2' - c2) = (r*G, r*pk).
+pub struct Rerandomization<'a, C: ProjectiveCurve> {
+    _group: PhantomData<&'a C>,
+}
+
+/// Proof that every ciphertext of a vector is a re-randomization of the ciphertext at the same position in
+/// another vector. The pairs are folded together using random weights derived from the transcript, so that
+/// the proof has the size of a single [`Rerandomization`] proof and is checked against a single challenge.
+pub struct BatchRerandomization<'a, C: ProjectiveCurve> {
+    _group: PhantomData<&'a C>,
+}
+
+#[derive(Copy, Clone)]
+pub struct Parameters<'a, C: ProjectiveCurve> {
+    pub encrypt_parameters: &'a el_gamal::Parameters<C>,
+    pub public_key: &'a PublicKey<C>,
+}
+
+impl<'a, C: ProjectiveCurve> Parameters<'a, C> {
+    pub fn new(
+        encrypt_parameters: &'a el_gamal::Parameters<C>,
+        public_key: &'a PublicKey<C>,
+    ) -> Self {
+        Self {
+            encrypt_parameters,
+            public_key,
+        }
+    }
+}
+
+/// Statement for a re-randomization proof: an original ciphertext and its claimed re-randomization.
+#[derive(Copy, Clone)]
+pub struct Statement<'a, C: ProjectiveCurve>(pub &'a Ciphertext<C>, pub &'a Ciphertext<C>);
+
+impl<'a, C: ProjectiveCurve> Statement<'a, C> {
+    pub fn new(original: &'a Ciphertext<C>, rerandomized: &'a Ciphertext<C>) -> Self {
+        Self(original, rerandomized)
+    }
+}
+
+/// Statement for a batched re-randomization proof: two vectors of ciphertexts of the same length.
+#[derive(Copy, Clone)]
+pub struct BatchStatement<'a, C: ProjectiveCurve>(pub &'a [Ciphertext<C>], pub &'a [Ciphertext<C>]);
+
+impl<'a, C: ProjectiveCurve> BatchStatement<'a, C> {
+    pub fn new(original: &'a [Ciphertext<C>], rerandomized: &'a [Ciphertext<C>]) -> Self {
+        Self(original, rerandomized)
+    }
+
+    pub fn is_valid(&self) -> Result<(), CryptoError> {
+        if self.0.len() != self.1.len() {
+            return Err(CryptoError::RerandomizationLengthError(
+                self.0.len(),
+                self.1.len(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+pub type Witness<C> = <C as ProjectiveCurve>::ScalarField;
+
+pub type BatchWitness<'a, C> = &'a [<C as ProjectiveCurve>::ScalarField];
+
+impl<'a, C: ProjectiveCurve> ArgumentOfKnowledge for Rerandomization<'a, C> {
+    type CommonReferenceString = Parameters<'a, C>;
+    type Statement = Statement<'a, C>;
+    type Witness = Witness<C>;
+    type Proof = proof::Proof<C>;
+
+    fn prove<R: Rng, T: Transcript>(
+        rng: &mut R,
+        common_reference_string: &Self::CommonReferenceString,
+        statement: &Self::Statement,
+        witness: &Self::Witness,
+        transcript: &mut T,
+    ) -> Result<Self::Proof, CryptoError> {
+        let prover = prover::Prover::new(common_reference_string, statement, witness);
+        let proof = prover.prove(rng, transcript)?;
+
+        Ok(proof)
+    }
+
+    fn verify<T: Transcript>(
+        common_reference_string: &Self::CommonReferenceString,
+        statement: &Self::Statement,
+        proof: &Self::Proof,
+        transcript: &mut T,
+    ) -> Result<(), CryptoError> {
+        proof.verify(common_reference_string, statement, transcript)
+    }
+}
+
+impl<'a, C: ProjectiveCurve> SigmaProtocol for Rerandomization<'a, C> {
+    type Parameters = Parameters<'a, C>;
+    type Statement = Statement<'a, C>;
+    type Witness = Witness<C>;
+    type Challenge = C::ScalarField;
+    type ProverState = C::ScalarField;
+    type Commitment = (C, C);
+    type Proof = proof::Proof<C>;
+
+    fn absorb_statement<T: Transcript>(
+        parameters: &Self::Parameters,
+        statement: &Self::Statement,
+        transcript: &mut T,
+    ) -> Result<(), CryptoError> {
+        absorb_statement(parameters, statement, transcript)?;
+
+        let (d1, d2) = difference(statement);
+        absorb_difference(&d1, &d2, transcript)
+    }
+
+    fn absorb_commitment<T: Transcript>(
+        (a, b): &Self::Commitment,
+        transcript: &mut T,
+    ) -> Result<(), CryptoError> {
+        transcript.append(
+            b"random commits",
+            &C::batch_normalization_into_affine(&[*a, *b]),
+        )
+    }
+
+    fn commit<R: Rng>(
+        rng: &mut R,
+        parameters: &Self::Parameters,
+        _statement: &Self::Statement,
+        _witness: &Self::Witness,
+    ) -> Result<(Self::ProverState, Self::Commitment), CryptoError> {
+        Ok(prover::Prover::commit(rng, parameters))
+    }
+
+    fn respond(
+        witness: &Self::Witness,
+        state: Self::ProverState,
+        commitment: Self::Commitment,
+        challenge: &Self::Challenge,
+    ) -> Self::Proof {
+        prover::Prover::respond(witness, state, commitment, challenge)
+    }
+
+    fn simulate<R: Rng>(
+        rng: &mut R,
+        parameters: &Self::Parameters,
+        statement: &Self::Statement,
+        challenge: &Self::Challenge,
+    ) -> Result<Self::Proof, CryptoError> {
+        simulator::Simulator::simulate(rng, parameters, statement, challenge)
+    }
+
+    fn commitment(proof: &Self::Proof) -> Self::Commitment {
+        (proof.a, proof.b)
+    }
+
+    fn check(
+        parameters: &Self::Parameters,
+        statement: &Self::Statement,
+        proof: &Self::Proof,
+        challenge: &Self::Challenge,
+    ) -> Result<(), CryptoError> {
+        proof.check(parameters, statement, challenge)
+    }
+}
+
+impl<'a, C: ProjectiveCurve> ArgumentOfKnowledge for BatchRerandomization<'a, C> {
+    type CommonReferenceString = Parameters<'a, C>;
+    type Statement = BatchStatement<'a, C>;
+    type Witness = BatchWitness<'a, C>;
+    type Proof = proof::Proof<C>;
+
+    fn prove<R: Rng, T: Transcript>(
+        rng: &mut R,
+        common_reference_string: &Self::CommonReferenceString,
+        statement: &Self::Statement,
+        witness: &Self::Witness,
+        transcript: &mut T,
+    ) -> Result<Self::Proof, CryptoError> {
+        prover::Prover::create_batch_proof(
+            rng,
+            common_reference_string,
+            statement,
+            witness,
+            transcript,
+        )
+    }
+
+    fn verify<T: Transcript>(
+        common_reference_string: &Self::CommonReferenceString,
+        statement: &Self::Statement,
+        proof: &Self::Proof,
+        transcript: &mut T,
+    ) -> Result<(), CryptoError> {
+        proof.verify_batch(common_reference_string, statement, transcript)
+    }
+}
+
+/// Absorb the public parameters and a statement.
+pub(crate) fn absorb_statement<C: ProjectiveCurve, T: Transcript>(
+    parameters: &Parameters<C>,
+    statement: &Statement<C>,
+    transcript: &mut T,
+) -> Result<(), CryptoError> {
+    transcript.domain_separator(b"Rerandomization");
+    transcript.append(b"generator", &parameters.encrypt_parameters.generator)?;
+    transcript.append(b"public key", parameters.public_key)?;
+    transcript.append(b"original", statement.0)?;
+    transcript.append(b"rerandomized", statement.1)?;
+
+    Ok(())
+}
+
+/// Absorb a batched statement and derive the weights used to fold it into a single pair of points.
+pub(crate) fn batch_weights<C: ProjectiveCurve, T: Transcript>(
+    parameters: &Parameters<C>,
+    statement: &BatchStatement<C>,
+    transcript: &mut T,
+) -> Result<Vec<C::ScalarField>, CryptoError> {
+    statement.is_valid()?;
+
+    transcript.domain_separator(b"Batch Rerandomization");
+    transcript.append(b"generator", &parameters.encrypt_parameters.generator)?;
+    transcript.append(b"public key", parameters.public_key)?;
+    transcript.append(b"originals", &statement.0)?;
+    transcript.append(b"rerandomized", &statement.1)?;
+
+    Ok((0..statement.0.len())
+        .map(|_| transcript.challenge_scalar(b"batch weight"))
+        .collect())
+}
+
+/// Absorb the difference between the ciphertexts and the commitments of the Chaum-Pedersen proof that it is an
+/// encryption of zero, and derive the challenge.
+pub(crate) fn challenge<C: ProjectiveCurve, T: Transcript>(
+    d1: &C,
+    d2: &C,
+    a: &C,
+    b: &C,
+    transcript: &mut T,
+) -> Result<C::ScalarField, CryptoError> {
+    absorb_difference(d1, d2, transcript)?;
+    Rerandomization::<C>::absorb_commitment(&(*a, *b), transcript)?;
+
+    Ok(transcript.challenge_scalar(b"challenge"))
+}
+
+/// Absorb the difference between the ciphertexts.
+fn absorb_difference<C: ProjectiveCurve, T: Transcript>(
+    d1: &C,
+    d2: &C,
+    transcript: &mut T,
+) -> Result<(), CryptoError> {
+    transcript.append(
+        b"difference",
+        &C::batch_normalization_into_affine(&[*d1, *d2]),
+    )
+}
+
+/// Compute sum of weight_i * (rerandomized_i - original_i), component-wise.
+pub(crate) fn weighted_difference<C: ProjectiveCurve>(
+    weights: &[C::ScalarField],
+    statement: &BatchStatement<C>,
+) -> (C, C) {
+    weights
+        .iter()
+        .zip(statement.0.iter().zip(statement.1.iter()))
+        .fold(
+            (C::zero(), C::zero()),
+            |(acc_1, acc_2), (weight, (original, rerandomized))| {
+                let weight = weight.into_repr();
+                (
+                    acc_1
+                        + (rerandomized.0.into_projective() - original.0.into_projective())
+                            .mul(weight),
+                    acc_2
+                        + (rerandomized.1.into_projective() - original.1.into_projective())
+                            .mul(weight),
+                )
+            },
+        )
+}
+
+/// Compute (c1' - c1, c2' - c2).
+pub(crate) fn difference<C: ProjectiveCurve>(statement: &Statement<C>) -> (C, C) {
+    (
+        statement.1 .0.into_projective() - statement.0 .0.into_projective(),
+        statement.1 .1.into_projective() - statement.0 .1.into_projective(),
+    )
+}
@@ -0,0 +1,113 @@
+use crate::error::CryptoError;
+use crate::utils::validation::{validate_projective_point, Validate, ValidatePoint};
+
+use crate::zkp::transcript::Transcript;
+
+use super::{
+    batch_weights, challenge, difference, verifier::Verifier, weighted_difference, BatchStatement,
+    Parameters, Statement,
+};
+
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::PrimeField;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::io::{Read, Write};
+
+#[derive(Copy, Clone, CanonicalDeserialize, CanonicalSerialize, Debug, PartialEq, Eq)]
+pub struct Proof<C>
+where
+    C: ProjectiveCurve,
+{
+    pub(crate) a: C,
+    pub(crate) b: C,
+    pub(crate) r: C::ScalarField,
+}
+
+impl<C: ProjectiveCurve> Proof<C> {
+    pub fn verify<T: Transcript>(
+        &self,
+        parameters: &Parameters<C>,
+        statement: &Statement<C>,
+        transcript: &mut T,
+    ) -> Result<(), CryptoError> {
+        Verifier::new(parameters, statement).verify(self, transcript)
+    }
+
+    pub fn verify_batch<T: Transcript>(
+        &self,
+        parameters: &Parameters<C>,
+        statement: &BatchStatement<C>,
+        transcript: &mut T,
+    ) -> Result<(), CryptoError> {
+        let weights = batch_weights(parameters, statement, transcript)?;
+        let (d1, d2) = weighted_difference(&weights, statement);
+
+        self.verify_encryption_of_zero(parameters, &d1, &d2, transcript)
+    }
+
+    pub(crate) fn verify_encryption_of_zero<T: Transcript>(
+        &self,
+        parameters: &Parameters<C>,
+        d1: &C,
+        d2: &C,
+        transcript: &mut T,
+    ) -> Result<(), CryptoError> {
+        let c = challenge(d1, d2, &self.a, &self.b, transcript)?;
+
+        self.check_encryption_of_zero(parameters, d1, d2, &c)
+    }
+
+    /// Check the proof against a challenge.
+    pub(crate) fn check(
+        &self,
+        parameters: &Parameters<C>,
+        statement: &Statement<C>,
+        c: &C::ScalarField,
+    ) -> Result<(), CryptoError> {
+        let (d1, d2) = difference(statement);
+
+        self.check_encryption_of_zero(parameters, &d1, &d2, c)
+    }
+
+    fn check_encryption_of_zero(
+        &self,
+        parameters: &Parameters<C>,
+        d1: &C,
+        d2: &C,
+        c: &C::ScalarField,
+    ) -> Result<(), CryptoError> {
+        // G * r ==? a + (c1' - c1)*c
+        if parameters
+            .encrypt_parameters
+            .generator
+            .mul(self.r.into_repr())
+            != self.a + d1.mul(c.into_repr())
+        {
+            return Err(CryptoError::ProofVerificationError(String::from(
+                "Rerandomization",
+            )));
+        }
+
+        // pk * r ==? b + (c2' - c2)*c
+        if parameters.public_key.mul(self.r.into_repr()) != self.b + d2.mul(c.into_repr()) {
+            return Err(CryptoError::ProofVerificationError(String::from(
+                "Rerandomization",
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+impl<C> Validate for Proof<C>
+where
+    C: ProjectiveCurve,
+    C::Affine: ValidatePoint,
+{
+    fn validate(&self) -> Result<(), CryptoError> {
+        validate_projective_point(&self.a)?;
+        validate_projective_point(&self.b)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,174 @@
+use crate::error::CryptoError;
+
+use super::messages::{Round1Challenge, Round1Message, Round2Message};
+use super::proof::Proof;
+use super::{
+    absorb_statement, batch_weights, challenge, difference, weighted_difference, BatchStatement,
+    BatchWitness, Parameters, Statement, Witness,
+};
+use crate::zkp::transcript::Transcript;
+
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::PrimeField;
+use ark_std::{rand::Rng, UniformRand};
+
+/// Prover waiting for the challenge, with the random commits.
+type Round1<'a, C> = (ProverRound1<'a, C>, Round1Message<C>);
+
+pub struct Prover<'a, C>
+where
+    C: ProjectiveCurve,
+{
+    parameters: Parameters<'a, C>,
+    statement: Statement<'a, C>,
+    witness: Witness<C>,
+}
+
+/// Prover of the re-randomization proof once the random commits are sent, waiting for the challenge.
+pub struct ProverRound1<'a, C>
+where
+    C: ProjectiveCurve,
+{
+    prover: Prover<'a, C>,
+    omega: C::ScalarField,
+    a: C,
+    b: C,
+}
+
+impl<'a, C> Prover<'a, C>
+where
+    C: ProjectiveCurve,
+{
+    pub fn new(
+        parameters: &Parameters<'a, C>,
+        statement: &Statement<'a, C>,
+        witness: &Witness<C>,
+    ) -> Self {
+        Self {
+            parameters: *parameters,
+            statement: *statement,
+            witness: *witness,
+        }
+    }
+
+    pub fn round_1<R: Rng>(self, rng: &mut R) -> Result<Round1<'a, C>, CryptoError> {
+        let (omega, (a, b)) = Self::commit(rng, &self.parameters);
+
+        Ok((
+            ProverRound1 {
+                prover: self,
+                omega,
+                a,
+                b,
+            },
+            Round1Message { a, b },
+        ))
+    }
+
+    pub fn prove<R: Rng, T: Transcript>(
+        self,
+        rng: &mut R,
+        transcript: &mut T,
+    ) -> Result<Proof<C>, CryptoError> {
+        absorb_statement(&self.parameters, &self.statement, transcript)?;
+
+        let (d1, d2) = difference(&self.statement);
+
+        let (prover, round_1) = self.round_1(rng)?;
+        let c = challenge(&d1, &d2, &round_1.a, &round_1.b, transcript)?;
+
+        let round_2 = prover.round_2(&Round1Challenge { c });
+
+        Ok(Proof {
+            a: round_1.a,
+            b: round_1.b,
+            r: round_2.r,
+        })
+    }
+
+    pub fn create_batch_proof<R: Rng, T: Transcript>(
+        rng: &mut R,
+        parameters: &Parameters<C>,
+        statement: &BatchStatement<C>,
+        witness: &BatchWitness<C>,
+        transcript: &mut T,
+    ) -> Result<Proof<C>, CryptoError> {
+        if witness.len() != statement.0.len() {
+            return Err(CryptoError::RerandomizationLengthError(
+                statement.0.len(),
+                witness.len(),
+            ));
+        }
+
+        let weights = batch_weights(parameters, statement, transcript)?;
+        let (d1, d2) = weighted_difference(&weights, statement);
+
+        let folded_witness = weights
+            .iter()
+            .zip(witness.iter())
+            .map(|(&weight, &r)| weight * r)
+            .sum();
+
+        Self::prove_encryption_of_zero(rng, parameters, &d1, &d2, &folded_witness, transcript)
+    }
+
+    /// Chaum-Pedersen proof that (d1, d2) = (r*G, r*pk).
+    fn prove_encryption_of_zero<R: Rng, T: Transcript>(
+        rng: &mut R,
+        parameters: &Parameters<C>,
+        d1: &C,
+        d2: &C,
+        witness: &Witness<C>,
+        transcript: &mut T,
+    ) -> Result<Proof<C>, CryptoError> {
+        let (omega, (a, b)) = Self::commit(rng, parameters);
+
+        let c = challenge(d1, d2, &a, &b, transcript)?;
+
+        Ok(Self::respond(witness, omega, (a, b), &c))
+    }
+
+    pub(crate) fn commit<R: Rng>(
+        rng: &mut R,
+        parameters: &Parameters<C>,
+    ) -> (C::ScalarField, (C, C)) {
+        let omega = C::ScalarField::rand(rng);
+        let a = parameters
+            .encrypt_parameters
+            .generator
+            .mul(omega.into_repr());
+        let b = parameters.public_key.mul(omega.into_repr());
+
+        (omega, (a, b))
+    }
+
+    pub(crate) fn respond(
+        witness: &Witness<C>,
+        omega: C::ScalarField,
+        (a, b): (C, C),
+        c: &C::ScalarField,
+    ) -> Proof<C> {
+        let r = omega + *c * witness;
+
+        Proof { a, b, r }
+    }
+}
+
+impl<'a, C> ProverRound1<'a, C>
+where
+    C: ProjectiveCurve,
+{
+    pub fn round_2(
+        self,
+        challenge: &Round1Challenge<C::ScalarField>,
+    ) -> Round2Message<C::ScalarField> {
+        let Proof { r, .. } = Prover::respond(
+            &self.prover.witness,
+            self.omega,
+            (self.a, self.b),
+            &challenge.c,
+        );
+
+        Round2Message { r }
+    }
+}
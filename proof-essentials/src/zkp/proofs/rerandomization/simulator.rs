@@ -0,0 +1,39 @@
+use crate::error::CryptoError;
+
+use super::{difference, proof::Proof, Parameters, Statement};
+
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::PrimeField;
+use ark_std::{rand::Rng, UniformRand};
+
+use std::marker::PhantomData;
+
+pub struct Simulator<C>
+where
+    C: ProjectiveCurve,
+{
+    phantom: PhantomData<C>,
+}
+
+impl<C> Simulator<C>
+where
+    C: ProjectiveCurve,
+{
+    /// Output a proof for the challenge c without the re-randomization factor, by sampling the response first and
+    /// solving both verification equations for the random commits.
+    pub fn simulate<R: Rng>(
+        rng: &mut R,
+        parameters: &Parameters<C>,
+        statement: &Statement<C>,
+        c: &C::ScalarField,
+    ) -> Result<Proof<C>, CryptoError> {
+        let (d1, d2) = difference(statement);
+        let r = C::ScalarField::rand(rng);
+
+        // a = G*r - (c1' - c1)*c and b = pk*r - (c2' - c2)*c
+        let a = parameters.encrypt_parameters.generator.mul(r.into_repr()) - d1.mul(c.into_repr());
+        let b = parameters.public_key.mul(r.into_repr()) - d2.mul(c.into_repr());
+
+        Ok(Proof { a, b, r })
+    }
+}
@@ -0,0 +1,246 @@
+#[cfg(test)]
+mod test {
+
+    use crate::error::CryptoError;
+    use crate::homomorphic_encryption::{el_gamal, HomomorphicEncryptionScheme};
+    use crate::utils::rand::sample_vector;
+    use crate::zkp::proofs::rerandomization::messages::Round1Challenge;
+    use crate::zkp::proofs::rerandomization::{prover::Prover, verifier::Verifier};
+    use crate::zkp::proofs::rerandomization::{
+        simulator::Simulator, BatchRerandomization, BatchStatement, Parameters, Rerandomization,
+        Statement,
+    };
+    use crate::zkp::proofs::SigmaProtocol;
+    use crate::zkp::ArgumentOfKnowledge;
+    use ark_marlin::rng::FiatShamirRng;
+    use ark_std::{rand::thread_rng, UniformRand};
+    use blake2::Blake2s;
+    use starknet_curve;
+
+    type Curve = starknet_curve::Projective;
+    type Scalar = starknet_curve::Fr;
+    type ElGamal = el_gamal::ElGamal<Curve>;
+    type Plaintext = el_gamal::Plaintext<Curve>;
+    type Ciphertext = el_gamal::Ciphertext<Curve>;
+    type FS = FiatShamirRng<Blake2s>;
+
+    #[test]
+    fn test_honest_prover() {
+        let rng = &mut thread_rng();
+        let pp = ElGamal::setup(rng).unwrap();
+        let (pk, sk) = ElGamal::keygen(&pp, rng).unwrap();
+
+        let message = Plaintext::rand(rng);
+        let ciphertext = ElGamal::encrypt(&pp, &pk, &message, &Scalar::rand(rng)).unwrap();
+
+        let r = Scalar::rand(rng);
+        let rerandomized = ElGamal::rerandomize(&pp, &pk, &ciphertext, &r).unwrap();
+        assert_ne!(ciphertext, rerandomized);
+        assert_eq!(message, ElGamal::decrypt(&pp, &sk, &rerandomized).unwrap());
+
+        let parameters = Parameters::new(&pp, &pk);
+        let statement = Statement::new(&ciphertext, &rerandomized);
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let proof =
+            Rerandomization::<Curve>::prove(rng, &parameters, &statement, &r, &mut fs_rng).unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            Rerandomization::<Curve>::verify(&parameters, &statement, &proof, &mut fs_rng),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_malicious_prover() {
+        let rng = &mut thread_rng();
+        let pp = ElGamal::setup(rng).unwrap();
+        let (pk, _) = ElGamal::keygen(&pp, rng).unwrap();
+
+        let ciphertext =
+            ElGamal::encrypt(&pp, &pk, &Plaintext::rand(rng), &Scalar::rand(rng)).unwrap();
+
+        // An encryption of a different plaintext cannot pass as a re-randomization
+        let r = Scalar::rand(rng);
+        let other = ElGamal::encrypt(&pp, &pk, &Plaintext::rand(rng), &r).unwrap();
+
+        let parameters = Parameters::new(&pp, &pk);
+        let statement = Statement::new(&ciphertext, &other);
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let proof =
+            Rerandomization::<Curve>::prove(rng, &parameters, &statement, &r, &mut fs_rng).unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            Rerandomization::<Curve>::verify(&parameters, &statement, &proof, &mut fs_rng),
+            Err(CryptoError::ProofVerificationError(String::from(
+                "Rerandomization"
+            )))
+        );
+
+        // A re-randomization under a different public key is rejected
+        let (other_pk, _) = ElGamal::keygen(&pp, rng).unwrap();
+        let rerandomized = ElGamal::rerandomize(&pp, &other_pk, &ciphertext, &r).unwrap();
+        let statement = Statement::new(&ciphertext, &rerandomized);
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let proof =
+            Rerandomization::<Curve>::prove(rng, &parameters, &statement, &r, &mut fs_rng).unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            Rerandomization::<Curve>::verify(&parameters, &statement, &proof, &mut fs_rng),
+            Err(CryptoError::ProofVerificationError(String::from(
+                "Rerandomization"
+            )))
+        );
+    }
+
+    #[test]
+    fn test_batch() {
+        let rng = &mut thread_rng();
+        let n = 20;
+        let pp = ElGamal::setup(rng).unwrap();
+        let (pk, sk) = ElGamal::keygen(&pp, rng).unwrap();
+
+        let messages: Vec<Plaintext> = sample_vector(rng, n);
+        let ciphertexts = messages
+            .iter()
+            .map(|m| ElGamal::encrypt(&pp, &pk, m, &Scalar::rand(rng)).unwrap())
+            .collect::<Vec<_>>();
+
+        let randomness: Vec<Scalar> = sample_vector(rng, n);
+        let rerandomized = ciphertexts
+            .iter()
+            .zip(randomness.iter())
+            .map(|(c, r)| ElGamal::rerandomize(&pp, &pk, c, r).unwrap())
+            .collect::<Vec<_>>();
+
+        for (m, c) in messages.iter().zip(rerandomized.iter()) {
+            assert_eq!(*m, ElGamal::decrypt(&pp, &sk, c).unwrap());
+        }
+
+        let parameters = Parameters::new(&pp, &pk);
+        let statement = BatchStatement::new(&ciphertexts, &rerandomized);
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let proof = BatchRerandomization::<Curve>::prove(
+            rng,
+            &parameters,
+            &statement,
+            &&randomness[..],
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            BatchRerandomization::<Curve>::verify(&parameters, &statement, &proof, &mut fs_rng),
+            Ok(())
+        );
+
+        // Tampering with a single entry invalidates the batch
+        let mut tampered: Vec<Ciphertext> = rerandomized.clone();
+        tampered[n / 2] = ElGamal::encrypt(&pp, &pk, &Plaintext::rand(rng), &randomness[n / 2])
+            .unwrap()
+            + ciphertexts[n / 2];
+        let statement = BatchStatement::new(&ciphertexts, &tampered);
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        let proof = BatchRerandomization::<Curve>::prove(
+            rng,
+            &parameters,
+            &statement,
+            &&randomness[..],
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            BatchRerandomization::<Curve>::verify(&parameters, &statement, &proof, &mut fs_rng),
+            Err(CryptoError::ProofVerificationError(String::from(
+                "Rerandomization"
+            )))
+        );
+
+        // Vectors of different lengths are rejected
+        let statement = BatchStatement::new(&ciphertexts, &rerandomized[1..]);
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        assert_eq!(
+            BatchRerandomization::<Curve>::verify(&parameters, &statement, &proof, &mut fs_rng),
+            Err(CryptoError::RerandomizationLengthError(n, n - 1))
+        );
+    }
+
+    #[test]
+    fn test_simulator() {
+        let rng = &mut thread_rng();
+        let pp = ElGamal::setup(rng).unwrap();
+        let (pk, _) = ElGamal::keygen(&pp, rng).unwrap();
+
+        // Encryptions of different messages
+        let ciphertext =
+            ElGamal::encrypt(&pp, &pk, &Plaintext::rand(rng), &Scalar::rand(rng)).unwrap();
+        let other = ElGamal::encrypt(&pp, &pk, &Plaintext::rand(rng), &Scalar::rand(rng)).unwrap();
+
+        let parameters = Parameters::new(&pp, &pk);
+        let statement = Statement::new(&ciphertext, &other);
+        let c = Scalar::rand(rng);
+
+        let proof = Simulator::simulate(rng, &parameters, &statement, &c).unwrap();
+        assert_eq!(
+            Rerandomization::<Curve>::check(&parameters, &statement, &proof, &c),
+            Ok(())
+        );
+
+        let another_challenge = Scalar::rand(rng);
+        assert_eq!(
+            Rerandomization::<Curve>::check(&parameters, &statement, &proof, &another_challenge),
+            Err(CryptoError::ProofVerificationError(String::from(
+                "Rerandomization"
+            )))
+        );
+    }
+
+    #[test]
+    fn test_interactive_rounds() {
+        let rng = &mut thread_rng();
+        let pp = ElGamal::setup(rng).unwrap();
+        let (pk, _) = ElGamal::keygen(&pp, rng).unwrap();
+
+        let ciphertext =
+            ElGamal::encrypt(&pp, &pk, &Plaintext::rand(rng), &Scalar::rand(rng)).unwrap();
+        let r = Scalar::rand(rng);
+        let rerandomized = ElGamal::rerandomize(&pp, &pk, &ciphertext, &r).unwrap();
+
+        let parameters = Parameters::new(&pp, &pk);
+        let statement = Statement::new(&ciphertext, &rerandomized);
+
+        let (prover, round_1) = Prover::new(&parameters, &statement, &r)
+            .round_1(rng)
+            .unwrap();
+        let challenge = Round1Challenge::rand(rng);
+        let round_2 = prover.round_2(&challenge);
+
+        let verifier = Verifier::new(&parameters, &statement).round_1(&round_1, &challenge);
+        assert_eq!(verifier.round_2(&round_2), Ok(()));
+
+        // Another re-randomization factor cannot answer the challenge
+        let another_r = Scalar::rand(rng);
+        let (prover, round_1) = Prover::new(&parameters, &statement, &another_r)
+            .round_1(rng)
+            .unwrap();
+        let round_2 = prover.round_2(&challenge);
+
+        let verifier = Verifier::new(&parameters, &statement).round_1(&round_1, &challenge);
+        assert_eq!(
+            verifier.round_2(&round_2),
+            Err(CryptoError::ProofVerificationError(String::from(
+                "Rerandomization"
+            )))
+        );
+    }
+}
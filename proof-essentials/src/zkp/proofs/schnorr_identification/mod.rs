@@ -1,14 +1,15 @@
+pub mod messages;
 pub mod proof;
 pub mod prover;
+pub mod simulator;
 mod test;
+pub mod verifier;
 
 use crate::error::CryptoError;
-use crate::zkp::ArgumentOfKnowledge;
+use crate::zkp::{proofs::SigmaProtocol, transcript::Transcript, ArgumentOfKnowledge};
 use ark_ec::ProjectiveCurve;
-use ark_marlin::rng::FiatShamirRng;
 use ark_std::marker::PhantomData;
 use ark_std::rand::Rng;
-use digest::Digest;
 
 pub struct SchnorrIdentification<C: ProjectiveCurve> {
     _group: PhantomData<C>,
@@ -26,26 +27,111 @@ impl<C: ProjectiveCurve> ArgumentOfKnowledge for SchnorrIdentification<C> {
     type Witness = Witness<C>;
     type Proof = proof::Proof<C>;
 
-    fn prove<R: Rng, D: Digest>(
+    fn prove<R: Rng, T: Transcript>(
         rng: &mut R,
         common_reference_string: &Self::CommonReferenceString,
         statement: &Self::Statement,
         witness: &Self::Witness,
-        fs_rng: &mut FiatShamirRng<D>,
+        transcript: &mut T,
     ) -> Result<Self::Proof, CryptoError> {
-        prover::Prover::create_proof(rng, common_reference_string, statement, witness, fs_rng)
+        let prover = prover::Prover::new(common_reference_string, statement, witness);
+        let proof = prover.prove(rng, transcript)?;
+
+        Ok(proof)
     }
 
-    fn verify<D: Digest>(
+    fn verify<T: Transcript>(
         common_reference_string: &Self::CommonReferenceString,
         statement: &Self::Statement,
         proof: &Self::Proof,
-        fs_rng: &mut FiatShamirRng<D>,
+        transcript: &mut T,
     ) -> Result<(), CryptoError> {
-        proof.verify(common_reference_string, statement, fs_rng)
+        proof.verify(common_reference_string, statement, transcript)
+    }
+}
+
+impl<C: ProjectiveCurve> SigmaProtocol for SchnorrIdentification<C> {
+    type Parameters = Parameters<C>;
+    type Statement = Statement<C>;
+    type Witness = Witness<C>;
+    type Challenge = C::ScalarField;
+    type ProverState = C::ScalarField;
+    type Commitment = C;
+    type Proof = proof::Proof<C>;
+
+    fn absorb_statement<T: Transcript>(
+        parameters: &Self::Parameters,
+        statement: &Self::Statement,
+        transcript: &mut T,
+    ) -> Result<(), CryptoError> {
+        transcript.domain_separator(Self::PROTOCOL_NAME);
+        transcript.append(b"generator", parameters)?;
+        transcript.append(b"public key", statement)?;
+
+        Ok(())
+    }
+
+    fn absorb_commitment<T: Transcript>(
+        commitment: &Self::Commitment,
+        transcript: &mut T,
+    ) -> Result<(), CryptoError> {
+        transcript.append(b"random commit", &commitment.into_affine())
+    }
+
+    fn commit<R: Rng>(
+        rng: &mut R,
+        parameters: &Self::Parameters,
+        _statement: &Self::Statement,
+        _witness: &Self::Witness,
+    ) -> Result<(Self::ProverState, Self::Commitment), CryptoError> {
+        Ok(prover::Prover::commit(rng, parameters))
+    }
+
+    fn respond(
+        witness: &Self::Witness,
+        state: Self::ProverState,
+        commitment: Self::Commitment,
+        challenge: &Self::Challenge,
+    ) -> Self::Proof {
+        prover::Prover::respond(witness, state, commitment, challenge)
+    }
+
+    fn simulate<R: Rng>(
+        rng: &mut R,
+        parameters: &Self::Parameters,
+        statement: &Self::Statement,
+        challenge: &Self::Challenge,
+    ) -> Result<Self::Proof, CryptoError> {
+        simulator::Simulator::simulate(rng, parameters, statement, challenge)
+    }
+
+    fn commitment(proof: &Self::Proof) -> Self::Commitment {
+        proof.random_commit
+    }
+
+    fn check(
+        parameters: &Self::Parameters,
+        statement: &Self::Statement,
+        proof: &Self::Proof,
+        challenge: &Self::Challenge,
+    ) -> Result<(), CryptoError> {
+        proof.check(parameters, statement, *challenge)
     }
 }
 
 impl<C: ProjectiveCurve> SchnorrIdentification<C> {
     pub const PROTOCOL_NAME: &'static [u8] = b"Schnorr Identification Scheme";
 }
+
+/// Absorb the public parameters, the statement and the commitment of the prover, and derive the challenge.
+pub(crate) fn challenge<C: ProjectiveCurve, T: Transcript>(
+    pp: &Parameters<C>,
+    statement: &Statement<C>,
+    random_commit: &C,
+    transcript: &mut T,
+) -> Result<C::ScalarField, CryptoError> {
+    SchnorrIdentification::<C>::absorb_statement(pp, statement, transcript)?;
+    SchnorrIdentification::<C>::absorb_commitment(random_commit, transcript)?;
+
+    Ok(transcript.challenge_scalar(b"challenge"))
+}
@@ -1,13 +1,14 @@
-use super::{Parameters, Statement};
+use super::{challenge, verifier::Verifier, Parameters, Statement};
 use crate::error::CryptoError;
+use crate::utils::rand::sample_vector;
+use crate::utils::validation::{validate_projective_point, Validate, ValidatePoint};
+use crate::zkp::transcript::Transcript;
 
-use ark_ec::{AffineCurve, ProjectiveCurve};
-use ark_ff::{to_bytes, PrimeField};
-use ark_marlin::rng::FiatShamirRng;
+use ark_ec::{msm::VariableBaseMSM, AffineCurve, ProjectiveCurve};
+use ark_ff::PrimeField;
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
 use ark_std::io::{Read, Write};
-use ark_std::UniformRand;
-use digest::Digest;
+use ark_std::rand::Rng;
 
 #[derive(Copy, Clone, CanonicalDeserialize, CanonicalSerialize, Debug, PartialEq, Eq)]
 pub struct Proof<C>
@@ -19,21 +20,91 @@ where
 }
 
 impl<C: ProjectiveCurve> Proof<C> {
-    pub fn verify<D: Digest>(
+    pub fn verify<T: Transcript>(
         &self,
         pp: &Parameters<C>,
         statement: &Statement<C>,
-        fs_rng: &mut FiatShamirRng<D>,
+        transcript: &mut T,
     ) -> Result<(), CryptoError> {
-        fs_rng.absorb(&to_bytes![
-            b"schnorr_identity",
-            pp,
-            statement,
-            &self.random_commit
-        ]?);
+        Verifier::new(pp, statement).verify(self, transcript)
+    }
+
+    /// Verify proofs for the same generator at once, with a single multi-scalar multiplication over a random linear
+    /// combination of their equations. The challenge of each proof is derived from its own transcript. When the
+    /// batch is rejected, the proofs are checked one by one to report the index of the first invalid one.
+    pub fn batch_verify<R: Rng, T: Transcript>(
+        rng: &mut R,
+        pp: &Parameters<C>,
+        statements: &[Statement<C>],
+        proofs: &[Self],
+        transcripts: &mut [T],
+    ) -> Result<(), CryptoError> {
+        for len in [statements.len(), transcripts.len()] {
+            if len != proofs.len() {
+                return Err(CryptoError::BatchVerificationLengthError(proofs.len(), len));
+            }
+        }
+
+        let challenges = proofs
+            .iter()
+            .zip(statements.iter())
+            .zip(transcripts.iter_mut())
+            .map(|((proof, statement), transcript)| {
+                challenge(pp, statement, &proof.random_commit, transcript)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // sum rho_i * (g*opening_i + pk_i*c_i - random_commit_i) ==? 0
+        let weights: Vec<C::ScalarField> = sample_vector(rng, proofs.len());
+        let random_commits = proofs
+            .iter()
+            .map(|proof| proof.random_commit)
+            .collect::<Vec<_>>();
 
-        let c = C::ScalarField::rand(fs_rng);
+        let mut bases = vec![*pp];
+        bases.extend_from_slice(statements);
+        bases.extend(C::batch_normalization_into_affine(&random_commits));
 
+        let mut scalars = vec![weights
+            .iter()
+            .zip(proofs.iter())
+            .map(|(rho, proof)| *rho * proof.opening)
+            .sum::<C::ScalarField>()];
+        scalars.extend(
+            weights
+                .iter()
+                .zip(challenges.iter())
+                .map(|(rho, c)| *rho * c),
+        );
+        scalars.extend(weights.iter().map(|rho| -*rho));
+
+        let scalars = scalars.iter().map(|s| s.into_repr()).collect::<Vec<_>>();
+        if VariableBaseMSM::multi_scalar_mul(&bases, &scalars).is_zero() {
+            return Ok(());
+        }
+
+        // Locate the invalid proof
+        for (i, ((proof, statement), c)) in proofs
+            .iter()
+            .zip(statements.iter())
+            .zip(challenges)
+            .enumerate()
+        {
+            proof.check(pp, statement, c).map_err(|_| {
+                CryptoError::BatchProofVerificationError(String::from("Schnorr Identification"), i)
+            })?;
+        }
+
+        Ok(())
+    }
+
+    /// Check the proof against a challenge.
+    pub(crate) fn check(
+        &self,
+        pp: &Parameters<C>,
+        statement: &Statement<C>,
+        c: C::ScalarField,
+    ) -> Result<(), CryptoError> {
         if pp.mul(self.opening.into_repr()) + statement.mul(c.into_repr()) != self.random_commit {
             return Err(CryptoError::ProofVerificationError(String::from(
                 "Schnorr Identification",
@@ -43,3 +114,15 @@ impl<C: ProjectiveCurve> Proof<C> {
         Ok(())
     }
 }
+
+impl<C> Validate for Proof<C>
+where
+    C: ProjectiveCurve,
+    C::Affine: ValidatePoint,
+{
+    fn validate(&self) -> Result<(), CryptoError> {
+        validate_projective_point(&self.random_commit)?;
+
+        Ok(())
+    }
+}
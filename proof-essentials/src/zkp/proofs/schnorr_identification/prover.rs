@@ -1,52 +1,120 @@
 use crate::error::CryptoError;
 
-use super::{proof::Proof, Parameters, Statement, Witness};
+use super::messages::{Round1Challenge, Round1Message, Round2Message};
+use super::{challenge, proof::Proof, Parameters, Statement, Witness};
+use crate::zkp::transcript::Transcript;
 
 use ark_ec::{AffineCurve, ProjectiveCurve};
-use ark_ff::{to_bytes, PrimeField};
-use ark_marlin::rng::FiatShamirRng;
+use ark_ff::PrimeField;
 use ark_std::rand::Rng;
 use ark_std::UniformRand;
-use digest::Digest;
 
-use std::marker::PhantomData;
+/// Prover waiting for the challenge, with the random commit.
+type Round1<C> = (ProverRound1<C>, Round1Message<C>);
 
 pub struct Prover<C>
 where
     C: ProjectiveCurve,
 {
-    phantom: PhantomData<C>,
+    pp: Parameters<C>,
+    statement: Statement<C>,
+    witness: Witness<C>,
+}
+
+/// Prover of the Schnorr identification scheme once the random commit is sent, waiting for the challenge.
+pub struct ProverRound1<C>
+where
+    C: ProjectiveCurve,
+{
+    prover: Prover<C>,
+    random: C::ScalarField,
+    random_commit: C,
 }
 
 impl<C> Prover<C>
 where
     C: ProjectiveCurve,
 {
-    pub fn create_proof<R: Rng, D: Digest>(
+    pub fn new(pp: &Parameters<C>, statement: &Statement<C>, witness: &Witness<C>) -> Self {
+        Self {
+            pp: *pp,
+            statement: *statement,
+            witness: *witness,
+        }
+    }
+
+    pub fn round_1<R: Rng>(self, rng: &mut R) -> Result<Round1<C>, CryptoError> {
+        let (random, random_commit) = Self::commit(rng, &self.pp);
+
+        Ok((
+            ProverRound1 {
+                prover: self,
+                random,
+                random_commit,
+            },
+            Round1Message { random_commit },
+        ))
+    }
+
+    pub fn prove<R: Rng, T: Transcript>(
+        self,
         rng: &mut R,
-        pp: &Parameters<C>,
-        statement: &Statement<C>,
-        witness: &Witness<C>,
-        fs_rng: &mut FiatShamirRng<D>,
+        transcript: &mut T,
     ) -> Result<Proof<C>, CryptoError> {
+        let (prover, round_1) = self.round_1(rng)?;
+        let c = challenge(
+            &prover.prover.pp,
+            &prover.prover.statement,
+            &round_1.random_commit,
+            transcript,
+        )?;
+
+        let round_2 = prover.round_2(&Round1Challenge { c });
+
+        Ok(Proof {
+            random_commit: round_1.random_commit,
+            opening: round_2.opening,
+        })
+    }
+
+    pub(crate) fn commit<R: Rng>(rng: &mut R, pp: &Parameters<C>) -> (C::ScalarField, C) {
         let random = C::ScalarField::rand(rng);
 
         let random_commit = pp.mul(random.into_repr());
 
-        fs_rng.absorb(&to_bytes![
-            b"schnorr_identity",
-            pp,
-            statement,
-            random_commit
-        ]?);
-
-        let c = C::ScalarField::rand(fs_rng);
+        (random, random_commit)
+    }
 
-        let opening = random - c * witness;
+    pub(crate) fn respond(
+        witness: &Witness<C>,
+        random: C::ScalarField,
+        random_commit: C,
+        c: &C::ScalarField,
+    ) -> Proof<C> {
+        let opening = random - *c * witness;
 
-        Ok(Proof {
+        Proof {
             random_commit,
             opening,
-        })
+        }
+    }
+}
+
+impl<C> ProverRound1<C>
+where
+    C: ProjectiveCurve,
+{
+    pub fn round_2(
+        self,
+        challenge: &Round1Challenge<C::ScalarField>,
+    ) -> Round2Message<C::ScalarField> {
+        let Proof { opening, .. } = Prover::respond(
+            &self.prover.witness,
+            self.random,
+            self.random_commit,
+            &challenge.c,
+        );
+
+        Round2Message { opening }
     }
 }
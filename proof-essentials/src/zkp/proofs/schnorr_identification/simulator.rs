@@ -0,0 +1,40 @@
+use crate::error::CryptoError;
+
+use super::{proof::Proof, Parameters, Statement};
+
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::PrimeField;
+use ark_std::{rand::Rng, UniformRand};
+
+use std::marker::PhantomData;
+
+pub struct Simulator<C>
+where
+    C: ProjectiveCurve,
+{
+    phantom: PhantomData<C>,
+}
+
+impl<C> Simulator<C>
+where
+    C: ProjectiveCurve,
+{
+    /// Output a proof for the challenge c without the secret key, by sampling the opening first and solving the
+    /// verification equation for the random commit.
+    pub fn simulate<R: Rng>(
+        rng: &mut R,
+        pp: &Parameters<C>,
+        statement: &Statement<C>,
+        c: &C::ScalarField,
+    ) -> Result<Proof<C>, CryptoError> {
+        let opening = C::ScalarField::rand(rng);
+
+        // g*opening + pk*c = random_commit
+        let random_commit = pp.mul(opening.into_repr()) + statement.mul(c.into_repr());
+
+        Ok(Proof {
+            random_commit,
+            opening,
+        })
+    }
+}
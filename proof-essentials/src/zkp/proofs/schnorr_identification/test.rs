@@ -2,7 +2,12 @@
 mod test {
 
     use crate::error::CryptoError;
-    use crate::zkp::{proofs::schnorr_identification, ArgumentOfKnowledge};
+    use crate::utils::rand::sample_vector;
+    use crate::zkp::proofs::schnorr_identification::messages::Round1Challenge;
+    use crate::zkp::proofs::schnorr_identification::simulator::Simulator;
+    use crate::zkp::proofs::schnorr_identification::{prover::Prover, verifier::Verifier};
+    use crate::zkp::proofs::{schnorr_identification, SigmaProtocol};
+    use crate::zkp::ArgumentOfKnowledge;
     use ark_ec::{AffineCurve, ProjectiveCurve};
     use ark_marlin::rng::FiatShamirRng;
     use ark_std::rand::thread_rng;
@@ -16,6 +21,7 @@ mod test {
     type Schnorr<'a> = schnorr_identification::SchnorrIdentification<Curve>;
     type Scalar = starknet_curve::Fr;
     type Parameters = schnorr_identification::Parameters<Curve>;
+    type Proof = schnorr_identification::proof::Proof<Curve>;
     type FS = FiatShamirRng<Blake2s>;
 
     fn setup<R: Rng>(rng: &mut R) -> Result<Parameters, CryptoError> {
@@ -62,4 +68,129 @@ mod test {
             )))
         );
     }
+
+    #[test]
+    fn test_batch_verification() {
+        let (mut rng, crs, _, _) = test_template();
+        let number_of_proofs = 8;
+
+        let secret_keys: Vec<Scalar> = sample_vector(&mut rng, number_of_proofs);
+        let public_keys = secret_keys
+            .iter()
+            .map(|sk| crs.mul(*sk).into_affine())
+            .collect::<Vec<_>>();
+
+        let mut proofs = secret_keys
+            .iter()
+            .zip(public_keys.iter())
+            .map(|(sk, pk)| {
+                let mut fs_rng = FS::from_seed(b"Initialised with some input");
+                Schnorr::prove(&mut rng, &crs, pk, sk, &mut fs_rng).unwrap()
+            })
+            .collect::<Vec<_>>();
+
+        let transcripts = || {
+            (0..number_of_proofs)
+                .map(|_| FS::from_seed(b"Initialised with some input"))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            Proof::batch_verify(&mut rng, &crs, &public_keys, &proofs, &mut transcripts()),
+            Ok(())
+        );
+
+        // An invalid proof is located when the batch is rejected
+        let another_scalar = Scalar::rand(&mut rng);
+        let mut fs_rng = FS::from_seed(b"Initialised with some input");
+        proofs[5] = Schnorr::prove(
+            &mut rng,
+            &crs,
+            &public_keys[5],
+            &another_scalar,
+            &mut fs_rng,
+        )
+        .unwrap();
+
+        assert_eq!(
+            Proof::batch_verify(&mut rng, &crs, &public_keys, &proofs, &mut transcripts()),
+            Err(CryptoError::BatchProofVerificationError(
+                String::from("Schnorr Identification"),
+                5
+            ))
+        );
+
+        assert_eq!(
+            Proof::batch_verify(
+                &mut rng,
+                &crs,
+                &public_keys[1..],
+                &proofs,
+                &mut transcripts()
+            ),
+            Err(CryptoError::BatchVerificationLengthError(
+                number_of_proofs,
+                number_of_proofs - 1
+            ))
+        );
+
+        // Each proof needs its own transcript as well
+        assert_eq!(
+            Proof::batch_verify(
+                &mut rng,
+                &crs,
+                &public_keys,
+                &proofs,
+                &mut transcripts()[1..]
+            ),
+            Err(CryptoError::BatchVerificationLengthError(
+                number_of_proofs,
+                number_of_proofs - 1
+            ))
+        );
+    }
+
+    #[test]
+    fn test_simulator() {
+        let (mut rng, crs, _, _) = test_template();
+
+        // Public key with an unknown secret key
+        let pk = Curve::rand(&mut rng).into_affine();
+        let c = Scalar::rand(&mut rng);
+
+        let proof = Simulator::simulate(&mut rng, &crs, &pk, &c).unwrap();
+        assert_eq!(Schnorr::check(&crs, &pk, &proof, &c), Ok(()));
+
+        let another_challenge = Scalar::rand(&mut rng);
+        assert_eq!(
+            Schnorr::check(&crs, &pk, &proof, &another_challenge),
+            Err(CryptoError::ProofVerificationError(String::from(
+                "Schnorr Identification"
+            )))
+        );
+    }
+
+    #[test]
+    fn test_interactive_rounds() {
+        let (mut rng, crs, sk, pk) = test_template();
+
+        let (prover, round_1) = Prover::new(&crs, &pk, &sk).round_1(&mut rng).unwrap();
+        let challenge = Round1Challenge::rand(&mut rng);
+        let round_2 = prover.round_2(&challenge);
+
+        let verifier = Verifier::<Curve>::new(&crs, &pk).round_1(&round_1, &challenge);
+        assert_eq!(verifier.round_2(&round_2), Ok(()));
+
+        // The response must answer the challenge that the verifier sent
+        let (prover, round_1) = Prover::new(&crs, &pk, &sk).round_1(&mut rng).unwrap();
+        let round_2 = prover.round_2(&Round1Challenge::rand(&mut rng));
+
+        let verifier = Verifier::<Curve>::new(&crs, &pk).round_1(&round_1, &challenge);
+        assert_eq!(
+            verifier.round_2(&round_2),
+            Err(CryptoError::ProofVerificationError(String::from(
+                "Schnorr Identification"
+            )))
+        );
+    }
 }
@@ -0,0 +1,73 @@
+use super::messages::{Round1Challenge, Round1Message, Round2Message};
+use super::{challenge, proof::Proof, Parameters, Statement};
+
+use crate::error::CryptoError;
+use crate::zkp::transcript::Transcript;
+
+use ark_ec::ProjectiveCurve;
+
+pub struct Verifier<C>
+where
+    C: ProjectiveCurve,
+{
+    pp: Parameters<C>,
+    statement: Statement<C>,
+}
+
+/// Verifier of the Schnorr identification scheme once the random commit is received and the challenge is sent.
+pub struct VerifierRound1<C>
+where
+    C: ProjectiveCurve,
+{
+    verifier: Verifier<C>,
+    random_commit: C,
+    c: C::ScalarField,
+}
+
+impl<C> Verifier<C>
+where
+    C: ProjectiveCurve,
+{
+    pub fn new(pp: &Parameters<C>, statement: &Statement<C>) -> Self {
+        Self {
+            pp: *pp,
+            statement: *statement,
+        }
+    }
+
+    pub fn round_1(
+        self,
+        message: &Round1Message<C>,
+        challenge: &Round1Challenge<C::ScalarField>,
+    ) -> VerifierRound1<C> {
+        VerifierRound1 {
+            verifier: self,
+            random_commit: message.random_commit,
+            c: challenge.c,
+        }
+    }
+
+    pub fn verify<T: Transcript>(
+        self,
+        proof: &Proof<C>,
+        transcript: &mut T,
+    ) -> Result<(), CryptoError> {
+        let c = challenge(&self.pp, &self.statement, &proof.random_commit, transcript)?;
+
+        proof.check(&self.pp, &self.statement, c)
+    }
+}
+
+impl<C> VerifierRound1<C>
+where
+    C: ProjectiveCurve,
+{
+    pub fn round_2(self, message: &Round2Message<C::ScalarField>) -> Result<(), CryptoError> {
+        let proof = Proof {
+            random_commit: self.random_commit,
+            opening: message.opening,
+        };
+
+        proof.check(&self.verifier.pp, &self.verifier.statement, self.c)
+    }
+}
@@ -1,38 +1,137 @@
-use ark_ff::Field;
-use ark_serialize::CanonicalSerialize;
-use merlin::Transcript;
+use crate::error::CryptoError;
 
-pub(crate) trait TranscriptProtocol {
-    fn append(&mut self, label: &'static [u8], item: &impl CanonicalSerialize);
+use ark_ff::{Field, ToBytes};
+use ark_marlin::rng::FiatShamirRng;
+use ark_std::rand::{RngCore, SeedableRng};
+use digest::Digest;
+use rand_chacha::ChaCha20Rng;
 
-    fn challenge_scalar<F: Field>(&mut self, label: &'static [u8]) -> F;
-}
+/// Public-coin transcript shared by the prover and the verifier of a non-interactive argument. Every message is
+/// absorbed under a label, and every challenge is derived under a label, so that messages cannot be moved from one
+/// position of the transcript to another. Each protocol starts with its own domain separator and each of its rounds
+/// is numbered, which keeps the transcripts of nested or sequentially composed arguments apart.
+pub trait Transcript {
+    /// Absorb a labelled message.
+    fn append_message(&mut self, label: &'static [u8], message: &[u8]);
+
+    /// Fill `dest` with challenge bytes derived from everything absorbed so far and the label.
+    fn challenge_bytes(&mut self, label: &'static [u8], dest: &mut [u8]);
+
+    /// Mark the start of a protocol.
+    fn domain_separator(&mut self, protocol: &'static [u8]) {
+        self.append_message(b"dom-sep", protocol);
+    }
+
+    /// Mark the start of a round of the current protocol.
+    fn round(&mut self, round: u64) {
+        self.append_message(b"round", &round.to_le_bytes());
+    }
+
+    /// Absorb the byte representation of an item.
+    fn append<T: ToBytes>(&mut self, label: &'static [u8], item: &T) -> Result<(), CryptoError> {
+        let mut bytes = Vec::new();
+        item.write(&mut bytes)?;
+        self.append_message(label, &bytes);
+
+        Ok(())
+    }
 
-impl TranscriptProtocol for Transcript {
-    fn append(&mut self, label: &'static [u8], item: &impl CanonicalSerialize) {
+    /// Absorb the byte representations of a sequence of items, which are the same as the ones of a vector of them.
+    fn append_slice<T: ToBytes>(
+        &mut self,
+        label: &'static [u8],
+        items: &[T],
+    ) -> Result<(), CryptoError> {
         let mut bytes = Vec::new();
-        item.serialize(&mut bytes).unwrap();
-        self.append_message(label, &bytes)
+        items.iter().try_for_each(|item| item.write(&mut bytes))?;
+        self.append_message(label, &bytes);
+
+        Ok(())
+    }
+
+    /// Derive a challenge scalar, sampled from a ChaCha20 generator seeded with 32 challenge bytes.
+    fn challenge_scalar<F: Field>(&mut self, label: &'static [u8]) -> F {
+        let mut seed = [0u8; 32];
+        self.challenge_bytes(label, &mut seed);
+
+        F::rand(&mut ChaCha20Rng::from_seed(seed))
+    }
+}
+
+impl Transcript for merlin::Transcript {
+    fn append_message(&mut self, label: &'static [u8], message: &[u8]) {
+        merlin::Transcript::append_message(self, label, message)
     }
 
-    fn challenge_scalar<F>(&mut self, label: &'static [u8]) -> F
-    where
-        F: Field,
-    {
-        let example = F::one();
-        let size = example.serialized_size();
-        // let size = F::size_in_bits() / 8;
-        let mut buf = vec![0u8; size];
-        self.challenge_bytes(label, &mut buf);
-        F::from_random_bytes(&buf).unwrap()
+    fn challenge_bytes(&mut self, label: &'static [u8], dest: &mut [u8]) {
+        merlin::Transcript::challenge_bytes(self, label, dest)
     }
 }
 
+/// Labels and messages are absorbed along with their lengths, so that distinct sequences of messages cannot be
+/// absorbed as the same bytes.
+impl<D: Digest> Transcript for FiatShamirRng<D> {
+    fn append_message(&mut self, label: &'static [u8], message: &[u8]) {
+        self.absorb(&frame(label, message));
+    }
+
+    fn challenge_bytes(&mut self, label: &'static [u8], dest: &mut [u8]) {
+        self.absorb(&frame(label, &(dest.len() as u64).to_le_bytes()));
+        self.fill_bytes(dest);
+    }
+}
+
+fn frame(label: &[u8], message: &[u8]) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(label.len() + message.len() + 16);
+    bytes.extend_from_slice(&(label.len() as u64).to_le_bytes());
+    bytes.extend_from_slice(label);
+    bytes.extend_from_slice(&(message.len() as u64).to_le_bytes());
+    bytes.extend_from_slice(message);
+
+    bytes
+}
+
 #[cfg(test)]
 mod transcript_test {
+    use super::Transcript;
     use ark_ff::One;
+    use ark_marlin::rng::FiatShamirRng;
     use ark_serialize::CanonicalSerialize;
+    use blake2::Blake2s;
     use starknet_curve::Fr;
+
+    fn challenges<T: Transcript>(transcript: &mut T, message: &[u8]) -> (Fr, Fr) {
+        transcript.domain_separator(b"test protocol");
+        transcript.round(1);
+        transcript.append_message(b"message", message);
+        let first = transcript.challenge_scalar(b"first");
+        transcript.round(2);
+        let second = transcript.challenge_scalar(b"second");
+
+        (first, second)
+    }
+
+    fn check_backend<T: Transcript>(new: impl Fn() -> T) {
+        let expected = challenges(&mut new(), b"hello");
+        assert_eq!(expected, challenges(&mut new(), b"hello"));
+        assert_ne!(expected.0, expected.1);
+        assert_ne!(expected, challenges(&mut new(), b"hellO"));
+
+        // The same bytes under another label give other challenges
+        let mut transcript = new();
+        transcript.domain_separator(b"test protocol");
+        transcript.round(1);
+        transcript.append_message(b"other label", b"hello");
+        assert_ne!(expected.0, transcript.challenge_scalar::<Fr>(b"first"));
+
+        // Challenges depend on their label
+        let mut transcript = new();
+        transcript.domain_separator(b"test protocol");
+        transcript.round(1);
+        transcript.append_message(b"message", b"hello");
+        assert_ne!(expected.0, transcript.challenge_scalar::<Fr>(b"second"));
+    }
+
     #[test]
     fn f_size() {
         let one = Fr::one();
@@ -42,4 +141,14 @@ mod transcript_test {
         // expect serialized_size&uncompressed_size to be same for the field
         assert_eq!(serialized_size, uncompressed_size);
     }
+
+    #[test]
+    fn merlin_backend() {
+        check_backend(|| merlin::Transcript::new(b"transcript test"));
+    }
+
+    #[test]
+    fn fiat_shamir_rng_backend() {
+        check_backend(|| FiatShamirRng::<Blake2s>::from_seed(b"transcript test"));
+    }
 }
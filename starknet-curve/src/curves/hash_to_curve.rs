@@ -0,0 +1,142 @@
+//! Hashing to the STARK curve following RFC 9380, with the suite `STARKNET_XMD:SHA-256_SSWU_RO_`:
+//! `expand_message_xmd` with SHA-256, `L = 48` bytes per field element and the simplified SWU map, which
+//! applies directly since both `COEFF_A` and `COEFF_B` are non-zero. The cofactor is 1 so no clearing is needed.
+//!
+//! `Z = 19` is the first element in the order 1, -1, 2, -2, ... meeting the criteria of RFC 9380 appendix H.2:
+//! it is a non-square, is not -1, `x^3 + x + COEFF_B - Z` has no root in Fq and `g(COEFF_B / (Z * COEFF_A))` is a
+//! square.
+
+use crate::{Affine, Fq, StarkwareParameters};
+use ark_ec::{AffineCurve, ProjectiveCurve, SWModelParameters};
+use ark_ff::{field_new, BigInteger, Field, One, PrimeField, SquareRootField, Zero};
+use ark_std::vec::Vec;
+use sha2::{Digest, Sha256};
+
+/// Non-square constant of the simplified SWU map.
+pub const SSWU_Z: Fq = field_new!(Fq, "19");
+
+/// Number of bytes hashed into each field element: ceil((ceil(log2(p)) + 128) / 8).
+const HASH_TO_FIELD_LENGTH: usize = 48;
+
+/// Output size and input block size of SHA-256.
+const B_IN_BYTES: usize = 32;
+const S_IN_BYTES: usize = 64;
+
+/// Hash an arbitrary message to a point of the curve, in a way which is indifferentiable from a random oracle.
+/// Points obtained for different `dst` (domain separation tags) are independent.
+pub fn hash_to_curve(msg: &[u8], dst: &[u8]) -> Affine {
+    let u = hash_to_field(msg, dst, 2);
+    let q0 = map_to_curve(u[0]);
+    let q1 = map_to_curve(u[1]);
+
+    (q0.into_projective() + q1.into_projective()).into_affine()
+}
+
+/// Hash a message to `count` elements of the base field.
+pub fn hash_to_field(msg: &[u8], dst: &[u8], count: usize) -> Vec<Fq> {
+    let uniform_bytes = expand_message_xmd(msg, dst, count * HASH_TO_FIELD_LENGTH);
+
+    uniform_bytes
+        .chunks(HASH_TO_FIELD_LENGTH)
+        .map(Fq::from_be_bytes_mod_order)
+        .collect()
+}
+
+/// Simplified Shallue-van de Woestijne-Ulas map from a field element to a point of the curve.
+pub fn map_to_curve(u: Fq) -> Affine {
+    let a = StarkwareParameters::COEFF_A;
+    let b = StarkwareParameters::COEFF_B;
+    let z = SSWU_Z;
+
+    let u2 = u.square();
+    let tv1 = (z.square() * u2.square() + z * u2)
+        .inverse()
+        .unwrap_or_else(Fq::zero);
+
+    let x1 = if tv1.is_zero() {
+        b * (z * a).inverse().unwrap()
+    } else {
+        -b * a.inverse().unwrap() * (Fq::one() + tv1)
+    };
+    let gx1 = curve_equation(x1);
+
+    let (x, mut y) = match gx1.sqrt() {
+        Some(y1) => (x1, y1),
+        None => {
+            // g(x2) = Z^3 u^6 g(x1) is a square whenever g(x1) is not
+            let x2 = z * u2 * x1;
+            (x2, curve_equation(x2).sqrt().unwrap())
+        }
+    };
+
+    if sgn0(&u) != sgn0(&y) {
+        y = -y;
+    }
+
+    Affine::new(x, y, false)
+}
+
+/// Produce `len_in_bytes` uniformly random bytes from a message and a domain separation tag, as specified in
+/// section 5.3.1 of RFC 9380. Tags longer than 255 bytes are first hashed as per section 5.3.3.
+///
+/// Panics if `len_in_bytes` is larger than `255 * 32`.
+pub fn expand_message_xmd(msg: &[u8], dst: &[u8], len_in_bytes: usize) -> Vec<u8> {
+    let ell = len_in_bytes.div_ceil(B_IN_BYTES);
+    assert!(ell <= 255 && len_in_bytes <= u16::MAX as usize);
+
+    let dst = if dst.len() > 255 {
+        Sha256::new()
+            .chain(b"H2C-OVERSIZE-DST-")
+            .chain(dst)
+            .finalize()
+            .to_vec()
+    } else {
+        dst.to_vec()
+    };
+    let dst_prime = [&dst[..], &[dst.len() as u8]].concat();
+
+    let b_0 = Sha256::new()
+        .chain([0u8; S_IN_BYTES])
+        .chain(msg)
+        .chain((len_in_bytes as u16).to_be_bytes())
+        .chain([0u8])
+        .chain(&dst_prime)
+        .finalize();
+
+    let mut b_i = Sha256::new()
+        .chain(b_0)
+        .chain([1u8])
+        .chain(&dst_prime)
+        .finalize();
+
+    let mut uniform_bytes = Vec::with_capacity(ell * B_IN_BYTES);
+    uniform_bytes.extend_from_slice(&b_i);
+
+    for i in 2..=ell {
+        let xored = b_0
+            .iter()
+            .zip(b_i.iter())
+            .map(|(x, y)| x ^ y)
+            .collect::<Vec<_>>();
+
+        b_i = Sha256::new()
+            .chain(&xored)
+            .chain([i as u8])
+            .chain(&dst_prime)
+            .finalize();
+        uniform_bytes.extend_from_slice(&b_i);
+    }
+
+    uniform_bytes.truncate(len_in_bytes);
+    uniform_bytes
+}
+
+/// x^3 + COEFF_A * x + COEFF_B
+fn curve_equation(x: Fq) -> Fq {
+    x.square() * x + StarkwareParameters::COEFF_A * x + StarkwareParameters::COEFF_B
+}
+
+/// Sign of a field element as defined in section 4.1 of RFC 9380: its parity.
+fn sgn0(x: &Fq) -> bool {
+    x.into_repr().is_odd()
+}
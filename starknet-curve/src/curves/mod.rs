@@ -5,6 +5,8 @@ use ark_ec::{
 };
 use ark_ff::field_new;
 
+#[cfg(feature = "hash_to_curve")]
+pub mod hash_to_curve;
 #[cfg(test)]
 mod tests;
 
@@ -37,3 +37,97 @@ fn test_generator() {
     assert!(generator.is_on_curve());
     assert!(generator.is_in_correct_subgroup_assuming_on_curve());
 }
+
+#[cfg(feature = "hash_to_curve")]
+mod hash_to_curve {
+    use crate::curves::hash_to_curve::{
+        expand_message_xmd, hash_to_curve, hash_to_field, map_to_curve,
+    };
+    use crate::{Affine, Fq};
+    use ark_ec::AffineCurve;
+    use ark_ff::{field_new, UniformRand, Zero};
+    use ark_std::test_rng;
+
+    const DST: &[u8] = b"QUUX-V01-CS02-with-STARKNET_XMD:SHA-256_SSWU_RO_";
+
+    #[test]
+    fn test_expand_message_xmd() {
+        // RFC 9380, appendix K.1
+        let expected = [
+            0x68, 0xa9, 0x85, 0xb8, 0x7e, 0xb6, 0xb4, 0x69, 0x52, 0x12, 0x89, 0x11, 0xf2, 0xa4,
+            0x41, 0x2b, 0xbc, 0x30, 0x2a, 0x9d, 0x75, 0x96, 0x67, 0xf8, 0x7f, 0x7a, 0x21, 0xd8,
+            0x03, 0xf0, 0x72, 0x35,
+        ];
+        assert_eq!(
+            expand_message_xmd(b"", b"QUUX-V01-CS02-with-expander-SHA256-128", 0x20),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_hash_to_curve_vectors() {
+        // Computed with an independent implementation of RFC 9380 for this curve
+        let u = hash_to_field(b"", DST, 2);
+        assert_eq!(
+            u,
+            vec![
+                field_new!(
+                    Fq,
+                    "934898176742757932138398044476660605749809606104669499565873401294798675816"
+                ),
+                field_new!(
+                    Fq,
+                    "617430085850456567930329429359196755183899564112116536535582201078123475039"
+                ),
+            ]
+        );
+        assert_eq!(
+            hash_to_curve(b"", DST),
+            Affine::new(
+                field_new!(
+                    Fq,
+                    "1347262678291855544963255085052694476664379370007603028719507648972270745388"
+                ),
+                field_new!(
+                    Fq,
+                    "40115488157781862086832303520072798904170659179464773508588491935299612335"
+                ),
+                false
+            )
+        );
+        assert_eq!(
+            hash_to_curve(b"abc", DST),
+            Affine::new(
+                field_new!(
+                    Fq,
+                    "987139456987909649063691077093455448119365392582634697455062757278630745426"
+                ),
+                field_new!(
+                    Fq,
+                    "2748357399964081843270765885252933104762393446043071115477456524861624434807"
+                ),
+                false
+            )
+        );
+    }
+
+    #[test]
+    fn test_map_to_curve() {
+        let mut rng = test_rng();
+        for _ in 0..100 {
+            let point = map_to_curve(Fq::rand(&mut rng));
+            assert!(point.is_on_curve());
+            assert!(point.is_in_correct_subgroup_assuming_on_curve());
+        }
+
+        // Exceptional case of the map
+        assert!(map_to_curve(field_new!(Fq, "0")).is_on_curve());
+    }
+
+    #[test]
+    fn test_domain_separation() {
+        assert_ne!(hash_to_curve(b"abc", DST), hash_to_curve(b"abc", b"other"));
+        assert_ne!(hash_to_curve(b"abc", DST), hash_to_curve(b"abd", DST));
+        assert!(!hash_to_curve(b"abc", DST).is_zero());
+    }
+}